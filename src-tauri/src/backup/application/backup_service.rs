@@ -0,0 +1,326 @@
+use crate::backup::domain::entities::{BackupInfo, BackupKind, BackupPolicy, RestorePreview};
+use crate::backup::domain::errors::BackupError;
+use crate::backup::domain::ports::{BackupStore, LibraryFiles};
+use chrono::{DateTime, Utc};
+
+/// Backup use cases
+/// Orchestrates: scheduled snapshots → retention, and restore with preview
+pub struct BackupService<S: BackupStore> {
+    store: S,
+}
+
+impl<S: BackupStore> BackupService<S> {
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+
+    /// All backups, newest first
+    pub fn list(&self) -> Result<Vec<BackupInfo>, BackupError> {
+        let mut backups = self.store.list_backups()?;
+        backups.sort_by(|a, b| b.id.cmp(&a.id));
+        Ok(backups)
+    }
+
+    /// Take a backup right now
+    pub fn create(&self, kind: BackupKind) -> Result<BackupInfo, BackupError> {
+        let info = self.store.create_backup(kind, Utc::now())?;
+        log::info!("[BackupService] Created {} backup {} ({} bytes)", kind.as_str(), info.id, info.size_bytes);
+        Ok(info)
+    }
+
+    /// Take a scheduled backup if the policy says one is due, then apply retention
+    /// Only scheduled backups count towards the interval, so a manual one doesn't delay it
+    /// Returns the new backup, or None if none was due
+    pub fn run_scheduled(
+        &self,
+        policy: &BackupPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<Option<BackupInfo>, BackupError> {
+        let last_backup = self
+            .list()?
+            .iter()
+            .find(|b| b.kind == BackupKind::Scheduled)
+            .and_then(|b| b.created_at_utc());
+        if !policy.is_due(last_backup, now) {
+            return Ok(None);
+        }
+
+        let info = self.store.create_backup(BackupKind::Scheduled, now)?;
+        log::info!("[BackupService] Created scheduled backup {}", info.id);
+        self.apply_retention(policy)?;
+        Ok(Some(info))
+    }
+
+    /// Delete scheduled backups beyond the policy's retention count; returns deleted IDs
+    pub fn apply_retention(&self, policy: &BackupPolicy) -> Result<Vec<String>, BackupError> {
+        let backups = self.store.list_backups()?;
+        let mut deleted = Vec::new();
+        for expired in policy.expired(&backups) {
+            self.store.delete_backup(&expired.id)?;
+            deleted.push(expired.id.clone());
+        }
+        if !deleted.is_empty() {
+            log::info!("[BackupService] Retention removed {} old backups", deleted.len());
+        }
+        Ok(deleted)
+    }
+
+    /// Restore a backup into the library
+    /// With `dry_run` only returns the preview; otherwise snapshots the current
+    /// library first (PreRestore backup) and then applies the changes
+    pub fn restore(&self, backup_id: &str, dry_run: bool) -> Result<RestorePreview, BackupError> {
+        let archived = self.store.read_backup(backup_id)?;
+        let current = self.store.read_library()?;
+        let mut preview = Self::diff(backup_id, &archived, &current);
+        preview.dry_run = dry_run;
+
+        if dry_run || !preview.has_changes() {
+            return Ok(preview);
+        }
+
+        let safety = self.store.create_backup(BackupKind::PreRestore, Utc::now())?;
+        preview.safety_backup_id = Some(safety.id);
+
+        let changed: LibraryFiles = archived
+            .into_iter()
+            .filter(|(path, _)| preview.added.contains(path) || preview.modified.contains(path))
+            .collect();
+        self.store.write_library(&changed, &preview.removed)?;
+
+        log::info!(
+            "[BackupService] Restored {}: {} added, {} modified, {} removed",
+            backup_id,
+            preview.added.len(),
+            preview.modified.len(),
+            preview.removed.len()
+        );
+        Ok(preview)
+    }
+
+    fn diff(backup_id: &str, archived: &LibraryFiles, current: &LibraryFiles) -> RestorePreview {
+        let mut preview = RestorePreview {
+            backup_id: backup_id.to_string(),
+            ..Default::default()
+        };
+
+        for (path, content) in archived {
+            match current.get(path) {
+                None => preview.added.push(path.clone()),
+                Some(existing) if existing != content => preview.modified.push(path.clone()),
+                Some(_) => preview.unchanged += 1,
+            }
+        }
+        preview.removed = current
+            .keys()
+            .filter(|path| !archived.contains_key(*path))
+            .cloned()
+            .collect();
+
+        preview
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    /// In-memory store: library files plus named snapshots
+    #[derive(Default)]
+    struct MockBackupStore {
+        library: Mutex<LibraryFiles>,
+        backups: Mutex<BTreeMap<String, (BackupInfo, LibraryFiles)>>,
+    }
+
+    impl MockBackupStore {
+        fn with_library(files: &[(&str, &str)]) -> Self {
+            let store = Self::default();
+            *store.library.lock().unwrap() = files
+                .iter()
+                .map(|(path, content)| (path.to_string(), content.as_bytes().to_vec()))
+                .collect();
+            store
+        }
+    }
+
+    impl BackupStore for MockBackupStore {
+        fn list_backups(&self) -> Result<Vec<BackupInfo>, BackupError> {
+            Ok(self.backups.lock().unwrap().values().map(|(info, _)| info.clone()).collect())
+        }
+
+        fn create_backup(&self, kind: BackupKind, created_at: DateTime<Utc>) -> Result<BackupInfo, BackupError> {
+            let info = BackupInfo {
+                id: BackupInfo::file_name(created_at, kind),
+                kind,
+                created_at: created_at.to_rfc3339(),
+                size_bytes: 0,
+            };
+            let files = self.library.lock().unwrap().clone();
+            self.backups.lock().unwrap().insert(info.id.clone(), (info.clone(), files));
+            Ok(info)
+        }
+
+        fn delete_backup(&self, id: &str) -> Result<(), BackupError> {
+            self.backups.lock().unwrap().remove(id).map(|_| ()).ok_or_else(|| BackupError::NotFound(id.to_string()))
+        }
+
+        fn read_backup(&self, id: &str) -> Result<LibraryFiles, BackupError> {
+            self.backups
+                .lock()
+                .unwrap()
+                .get(id)
+                .map(|(_, files)| files.clone())
+                .ok_or_else(|| BackupError::NotFound(id.to_string()))
+        }
+
+        fn read_library(&self) -> Result<LibraryFiles, BackupError> {
+            Ok(self.library.lock().unwrap().clone())
+        }
+
+        fn write_library(&self, files: &LibraryFiles, removed: &[String]) -> Result<(), BackupError> {
+            let mut library = self.library.lock().unwrap();
+            for path in removed {
+                library.remove(path);
+            }
+            library.extend(files.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_run_scheduled_creates_first_backup() {
+        let service = BackupService::new(MockBackupStore::default());
+        let policy = BackupPolicy::new(true, 24, 5);
+
+        let created = service.run_scheduled(&policy, Utc::now()).unwrap();
+        assert!(created.is_some());
+        assert_eq!(service.list().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_run_scheduled_waits_for_interval() {
+        let service = BackupService::new(MockBackupStore::default());
+        let policy = BackupPolicy::new(true, 24, 5);
+        let now = Utc::now();
+
+        service.run_scheduled(&policy, now).unwrap();
+        assert!(service.run_scheduled(&policy, now + Duration::hours(1)).unwrap().is_none());
+        assert!(service.run_scheduled(&policy, now + Duration::hours(25)).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_run_scheduled_applies_retention() {
+        let service = BackupService::new(MockBackupStore::default());
+        let policy = BackupPolicy::new(true, 1, 2);
+        let start = Utc::now();
+
+        for hour in 0..4 {
+            service.run_scheduled(&policy, start + Duration::hours(hour)).unwrap();
+        }
+
+        let backups = service.list().unwrap();
+        assert_eq!(backups.len(), 2);
+        assert_eq!(backups[0].created_at_utc().unwrap().timestamp(), (start + Duration::hours(3)).timestamp());
+    }
+
+    #[test]
+    fn test_retention_keeps_manual_and_pre_restore_backups() {
+        let store = MockBackupStore::with_library(&[("prompts/a.md", "A")]);
+        let service = BackupService::new(store);
+        let policy = BackupPolicy::new(true, 1, 1);
+        let start = Utc::now();
+
+        let manual = service.create(BackupKind::Manual).unwrap();
+        service.store.write_library(&[("prompts/a.md".to_string(), b"B".to_vec())].into_iter().collect(), &[]).unwrap();
+        let safety = service.restore(&manual.id, false).unwrap().safety_backup_id.unwrap();
+
+        for hour in 1..4 {
+            service.run_scheduled(&policy, start + Duration::hours(hour)).unwrap();
+        }
+
+        let ids: Vec<String> = service.list().unwrap().into_iter().map(|b| b.id).collect();
+        assert_eq!(ids.len(), 3);
+        assert!(ids.contains(&manual.id) && ids.contains(&safety));
+    }
+
+    #[test]
+    fn test_manual_backup_does_not_delay_schedule() {
+        let service = BackupService::new(MockBackupStore::default());
+        let policy = BackupPolicy::new(true, 24, 5);
+        let now = Utc::now();
+
+        service.run_scheduled(&policy, now - Duration::hours(25)).unwrap();
+        service.create(BackupKind::Manual).unwrap();
+        assert!(service.run_scheduled(&policy, now).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_restore_dry_run_previews_changes() {
+        let store = MockBackupStore::with_library(&[
+            ("prompts/a.md", "A"),
+            ("prompts/b.md", "B"),
+            ("usage.json", "{}"),
+        ]);
+        let service = BackupService::new(store);
+        let backup = service.create(BackupKind::Manual).unwrap();
+
+        // Library changes after the backup
+        service.store.write_library(
+            &[("prompts/b.md".to_string(), b"B2".to_vec()), ("prompts/c.md".to_string(), b"C".to_vec())]
+                .into_iter()
+                .collect(),
+            &["prompts/a.md".to_string()],
+        )
+        .unwrap();
+
+        let preview = service.restore(&backup.id, true).unwrap();
+        assert!(preview.dry_run);
+        assert_eq!(preview.added, vec!["prompts/a.md"]);
+        assert_eq!(preview.modified, vec!["prompts/b.md"]);
+        assert_eq!(preview.removed, vec!["prompts/c.md"]);
+        assert_eq!(preview.unchanged, 1);
+        assert!(preview.safety_backup_id.is_none());
+
+        // Nothing written
+        assert!(service.store.read_library().unwrap().contains_key("prompts/c.md"));
+    }
+
+    #[test]
+    fn test_restore_applies_changes_after_safety_backup() {
+        let store = MockBackupStore::with_library(&[("prompts/a.md", "A")]);
+        let service = BackupService::new(store);
+        let backup = service.create(BackupKind::Manual).unwrap();
+
+        service.store.write_library(
+            &[("prompts/a.md".to_string(), b"edited".to_vec())].into_iter().collect(),
+            &[],
+        )
+        .unwrap();
+
+        let preview = service.restore(&backup.id, false).unwrap();
+        assert!(!preview.dry_run);
+        assert_eq!(preview.modified, vec!["prompts/a.md"]);
+
+        let library = service.store.read_library().unwrap();
+        assert_eq!(library.get("prompts/a.md").unwrap(), b"A");
+
+        // The edited state is recoverable from the safety backup
+        let safety_id = preview.safety_backup_id.unwrap();
+        let safety = service.store.read_backup(&safety_id).unwrap();
+        assert_eq!(safety.get("prompts/a.md").unwrap(), b"edited");
+    }
+
+    #[test]
+    fn test_restore_without_changes_skips_safety_backup() {
+        let store = MockBackupStore::with_library(&[("prompts/a.md", "A")]);
+        let service = BackupService::new(store);
+        let backup = service.create(BackupKind::Manual).unwrap();
+
+        let preview = service.restore(&backup.id, false).unwrap();
+        assert!(!preview.has_changes());
+        assert!(preview.safety_backup_id.is_none());
+        assert_eq!(service.list().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,4 @@
+// Application layer - orchestrates backup scheduling, retention and restore
+pub mod backup_service;
+
+pub use backup_service::BackupService;
@@ -0,0 +1,140 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+const FILE_PREFIX: &str = "prompter-backup-";
+const FILE_EXTENSION: &str = ".zip";
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S%.3f";
+
+/// Why a backup was taken
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupKind {
+    /// Taken by the interval scheduler
+    Scheduled,
+    /// Requested by the user
+    Manual,
+    /// Safety snapshot taken right before a restore
+    PreRestore,
+}
+
+impl BackupKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Scheduled => "scheduled",
+            Self::Manual => "manual",
+            Self::PreRestore => "pre-restore",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "scheduled" => Some(Self::Scheduled),
+            "manual" => Some(Self::Manual),
+            "pre-restore" => Some(Self::PreRestore),
+            _ => None,
+        }
+    }
+}
+
+/// A backup archive of the whole library
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupInfo {
+    /// Archive file name, e.g. "prompter-backup-20250101-120000.000-scheduled.zip"
+    pub id: String,
+    pub kind: BackupKind,
+    pub created_at: String,
+    pub size_bytes: u64,
+}
+
+impl BackupInfo {
+    /// Business rule: archive names encode creation time and kind so they sort chronologically
+    pub fn file_name(created_at: DateTime<Utc>, kind: BackupKind) -> String {
+        format!(
+            "{}{}-{}{}",
+            FILE_PREFIX,
+            created_at.format(TIMESTAMP_FORMAT),
+            kind.as_str(),
+            FILE_EXTENSION
+        )
+    }
+
+    /// Parse an archive file name; returns None for files that are not backups
+    pub fn from_file_name(file_name: &str, size_bytes: u64) -> Option<Self> {
+        let stem = file_name.strip_prefix(FILE_PREFIX)?.strip_suffix(FILE_EXTENSION)?;
+
+        // "<date>-<time>.<millis>-<kind>" where kind may itself contain '-'
+        let (date, rest) = stem.split_once('-')?;
+        let (time, kind) = rest.split_once('-')?;
+        let timestamp = NaiveDateTime::parse_from_str(&format!("{}-{}", date, time), TIMESTAMP_FORMAT).ok()?;
+
+        Some(Self {
+            id: file_name.to_string(),
+            kind: BackupKind::parse(kind)?,
+            created_at: timestamp.and_utc().to_rfc3339(),
+            size_bytes,
+        })
+    }
+
+    pub fn created_at_utc(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.created_at)
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc))
+    }
+}
+
+/// What restoring a backup changes (or changed) in the library
+/// Paths are relative to the data directory, e.g. "prompts/Coding/review.md", "usage.json"
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RestorePreview {
+    pub backup_id: String,
+    /// True if nothing was written
+    pub dry_run: bool,
+    /// Files the restore creates
+    pub added: Vec<String>,
+    /// Files the restore overwrites with different content
+    pub modified: Vec<String>,
+    /// Files the restore deletes (not present in the backup)
+    pub removed: Vec<String>,
+    /// Number of files identical in the backup and the library
+    pub unchanged: usize,
+    /// Snapshot of the library taken before restoring
+    pub safety_backup_id: Option<String>,
+}
+
+impl RestorePreview {
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.modified.is_empty() || !self.removed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_file_name_round_trip() {
+        let created_at = Utc.with_ymd_and_hms(2025, 3, 14, 9, 26, 53).unwrap();
+        let name = BackupInfo::file_name(created_at, BackupKind::PreRestore);
+        assert_eq!(name, "prompter-backup-20250314-092653.000-pre-restore.zip");
+
+        let info = BackupInfo::from_file_name(&name, 42).unwrap();
+        assert_eq!(info.kind, BackupKind::PreRestore);
+        assert_eq!(info.size_bytes, 42);
+        assert_eq!(info.created_at_utc(), Some(created_at));
+    }
+
+    #[test]
+    fn test_from_file_name_rejects_other_files() {
+        assert!(BackupInfo::from_file_name("notes.zip", 0).is_none());
+        assert!(BackupInfo::from_file_name("prompter-backup-garbage.zip", 0).is_none());
+        assert!(BackupInfo::from_file_name("prompter-backup-20250314-092653.000-unknown.zip", 0).is_none());
+    }
+
+    #[test]
+    fn test_file_names_sort_chronologically() {
+        let earlier = BackupInfo::file_name(Utc.with_ymd_and_hms(2025, 1, 9, 23, 0, 0).unwrap(), BackupKind::Manual);
+        let later = BackupInfo::file_name(Utc.with_ymd_and_hms(2025, 1, 10, 1, 0, 0).unwrap(), BackupKind::Manual);
+        assert!(earlier < later);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::backup::domain::entities::{BackupInfo, BackupKind};
+use chrono::{DateTime, Duration, Utc};
+
+/// Backup schedule and retention rules (from config)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BackupPolicy {
+    pub enabled: bool,
+    pub interval_hours: u32,
+    /// Number of most recent scheduled backups to keep
+    pub retention_count: usize,
+}
+
+impl BackupPolicy {
+    /// Build a policy from raw config values, clamping nonsensical ones
+    pub fn new(enabled: bool, interval_hours: i32, retention_count: i32) -> Self {
+        Self {
+            enabled,
+            interval_hours: interval_hours.max(1) as u32,
+            retention_count: retention_count.max(1) as usize,
+        }
+    }
+
+    /// Business rule: a backup is due when enabled and the newest backup is older than the interval
+    pub fn is_due(&self, last_backup: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        match last_backup {
+            Some(last) => now - last >= Duration::hours(self.interval_hours as i64),
+            None => true,
+        }
+    }
+
+    /// Business rule: scheduled backups beyond the retention count, oldest last, are expired
+    /// Manual and pre-restore backups are kept until the user deletes them
+    pub fn expired<'a>(&self, backups: &'a [BackupInfo]) -> Vec<&'a BackupInfo> {
+        let mut newest_first: Vec<&BackupInfo> =
+            backups.iter().filter(|b| b.kind == BackupKind::Scheduled).collect();
+        newest_first.sort_by(|a, b| b.id.cmp(&a.id));
+        newest_first.into_iter().skip(self.retention_count).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn backup_at(hour: u32) -> BackupInfo {
+        backup_of_kind(hour, BackupKind::Scheduled)
+    }
+
+    fn backup_of_kind(hour: u32, kind: BackupKind) -> BackupInfo {
+        let created_at = Utc.with_ymd_and_hms(2025, 1, 1, hour, 0, 0).unwrap();
+        BackupInfo {
+            id: BackupInfo::file_name(created_at, kind),
+            kind,
+            created_at: created_at.to_rfc3339(),
+            size_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn test_first_backup_is_due() {
+        let policy = BackupPolicy::new(true, 24, 5);
+        assert!(policy.is_due(None, Utc::now()));
+    }
+
+    #[test]
+    fn test_backup_due_after_interval() {
+        let policy = BackupPolicy::new(true, 24, 5);
+        let now = Utc::now();
+
+        assert!(!policy.is_due(Some(now - Duration::hours(23)), now));
+        assert!(policy.is_due(Some(now - Duration::hours(24)), now));
+    }
+
+    #[test]
+    fn test_disabled_policy_is_never_due() {
+        let policy = BackupPolicy::new(false, 24, 5);
+        assert!(!policy.is_due(None, Utc::now()));
+    }
+
+    #[test]
+    fn test_new_clamps_invalid_values() {
+        let policy = BackupPolicy::new(true, 0, -3);
+        assert_eq!(policy.interval_hours, 1);
+        assert_eq!(policy.retention_count, 1);
+    }
+
+    #[test]
+    fn test_expired_keeps_newest_backups() {
+        let policy = BackupPolicy::new(true, 24, 2);
+        let backups = vec![backup_at(1), backup_at(3), backup_at(2)];
+
+        let expired = policy.expired(&backups);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, backups[0].id);
+    }
+
+    #[test]
+    fn test_expired_ignores_manual_and_pre_restore_backups() {
+        let policy = BackupPolicy::new(true, 24, 1);
+        let backups = vec![
+            backup_of_kind(1, BackupKind::Manual),
+            backup_of_kind(2, BackupKind::PreRestore),
+            backup_at(3),
+            backup_at(4),
+        ];
+
+        let expired = policy.expired(&backups);
+        assert_eq!(expired, vec![&backups[2]]);
+    }
+}
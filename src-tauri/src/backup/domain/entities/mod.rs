@@ -0,0 +1,6 @@
+// Domain entities
+pub mod backup_info;
+pub mod backup_policy;
+
+pub use backup_info::{BackupInfo, BackupKind, RestorePreview};
+pub use backup_policy::BackupPolicy;
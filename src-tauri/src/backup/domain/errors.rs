@@ -0,0 +1,45 @@
+use thiserror::Error;
+
+/// Errors returned by backup archives and the backup service
+#[derive(Debug, Error)]
+pub enum BackupError {
+    #[error("Backup not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid backup id: {0}")]
+    InvalidId(String),
+
+    /// A restore tried to write outside the backed-up files (zip-slip, `.git`, other data)
+    #[error("Refusing to restore unexpected path: {0}")]
+    UnsafePath(String),
+
+    #[error("{0}")]
+    Other(String),
+}
+
+impl BackupError {
+    /// Stable machine-readable code sent to the frontend
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotFound(_) => "not_found",
+            Self::InvalidId(_) | Self::UnsafePath(_) => "invalid_input",
+            Self::Other(_) => "backup_error",
+        }
+    }
+
+    /// The backup or path the error is about
+    pub fn details(&self) -> Option<String> {
+        match self {
+            Self::NotFound(id) | Self::InvalidId(id) => Some(id.clone()),
+            Self::UnsafePath(path) => Some(path.clone()),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+/// I/O and archive failures are reported as plain messages
+impl From<String> for BackupError {
+    fn from(message: String) -> Self {
+        Self::Other(message)
+    }
+}
@@ -0,0 +1,6 @@
+// Domain layer - backup entities, policy rules and the archive port
+pub mod entities;
+pub mod errors;
+pub mod ports;
+
+pub use errors::BackupError;
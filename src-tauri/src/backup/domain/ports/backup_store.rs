@@ -0,0 +1,30 @@
+use crate::backup::domain::entities::{BackupInfo, BackupKind};
+use crate::backup::domain::errors::BackupError;
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+/// Library files keyed by path relative to the data directory
+/// e.g. "prompts/Coding/review.md", "prompts/.versions/...", "usage.json", "config.json"
+pub type LibraryFiles = BTreeMap<String, Vec<u8>>;
+
+/// Backup archive storage interface (Port)
+/// Infrastructure layer will implement this trait
+pub trait BackupStore: Send + Sync {
+    /// List all backups (any order)
+    fn list_backups(&self) -> Result<Vec<BackupInfo>, BackupError>;
+
+    /// Snapshot the current library into a new backup
+    fn create_backup(&self, kind: BackupKind, created_at: DateTime<Utc>) -> Result<BackupInfo, BackupError>;
+
+    /// Delete a backup archive
+    fn delete_backup(&self, id: &str) -> Result<(), BackupError>;
+
+    /// Read every file stored in a backup
+    fn read_backup(&self, id: &str) -> Result<LibraryFiles, BackupError>;
+
+    /// Read every file of the current library
+    fn read_library(&self) -> Result<LibraryFiles, BackupError>;
+
+    /// Write files into the library and delete `removed` paths
+    fn write_library(&self, files: &LibraryFiles, removed: &[String]) -> Result<(), BackupError>;
+}
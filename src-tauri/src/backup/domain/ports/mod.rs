@@ -0,0 +1,4 @@
+// Domain ports (interfaces) - infrastructure layer will implement these
+pub mod backup_store;
+
+pub use backup_store::{BackupStore, LibraryFiles};
@@ -0,0 +1,4 @@
+// Infrastructure layer - archive adapters
+pub mod zip_backup_store;
+
+pub use zip_backup_store::ZipBackupStore;
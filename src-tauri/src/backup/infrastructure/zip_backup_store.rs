@@ -0,0 +1,349 @@
+use crate::backup::domain::entities::{BackupInfo, BackupKind};
+use crate::backup::domain::errors::BackupError;
+use crate::backup::domain::ports::{BackupStore, LibraryFiles};
+use crate::storage::application::services::VERSIONS_DIR_NAME;
+use chrono::{DateTime, Utc};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// Library directory backed up recursively (includes `.versions`)
+const PROMPTS_DIR: &str = "prompts";
+
+/// Whether a file or directory name under the prompts dir is left out of backups
+/// Hidden entries are tool state (the sync repository's `.git`, editor files); version
+/// history is the one hidden directory that belongs to the library
+fn is_ignored(name: &str) -> bool {
+    name.starts_with('.') && name != VERSIONS_DIR_NAME
+}
+
+/// Top-level data files backed up alongside the prompts
+const DATA_FILES: [&str; 2] = ["usage.json", "config.json"];
+
+/// Zip implementation of BackupStore (Adapter)
+/// Archives ~/.prompter/{prompts/**, usage.json, config.json} into ~/.prompter/backups/
+pub struct ZipBackupStore {
+    data_dir: PathBuf,
+    backups_dir: PathBuf,
+}
+
+impl ZipBackupStore {
+    pub fn new() -> Result<Self, BackupError> {
+        let data_dir = dirs::home_dir()
+            .ok_or_else(|| BackupError::Other("Could not find home directory".to_string()))?
+            .join(".prompter");
+        Self::with_data_dir(data_dir)
+    }
+
+    /// Create a store for a custom data directory (backups go to `<data_dir>/backups`)
+    pub fn with_data_dir(data_dir: PathBuf) -> Result<Self, BackupError> {
+        let backups_dir = data_dir.join("backups");
+        fs::create_dir_all(&backups_dir)
+            .map_err(|e| format!("Failed to create backups directory: {}", e))?;
+
+        Ok(Self { data_dir, backups_dir })
+    }
+
+    fn backup_path(&self, id: &str) -> Result<PathBuf, BackupError> {
+        // IDs come from the frontend; only accept plain backup file names
+        if BackupInfo::from_file_name(id, 0).is_none() {
+            return Err(BackupError::InvalidId(id.to_string()));
+        }
+        Ok(self.backups_dir.join(id))
+    }
+
+    /// Map a library-relative path to a file under the data directory, rejecting
+    /// anything outside the backed-up set (absolute paths, "..", `.git`, other files)
+    fn library_path(&self, relative: &str) -> Result<PathBuf, BackupError> {
+        let path = Path::new(relative);
+        let is_safe = path.components().all(|c| matches!(c, Component::Normal(_)));
+        let in_scope =
+            DATA_FILES.contains(&relative) || (path.starts_with(PROMPTS_DIR) && !Self::is_ignored_path(path));
+
+        if !is_safe || !in_scope {
+            return Err(BackupError::UnsafePath(relative.to_string()));
+        }
+        Ok(self.data_dir.join(path))
+    }
+
+    /// A missing archive is NotFound; anything else keeps its I/O message
+    fn missing_or(id: &str, error: io::Error, action: &str) -> BackupError {
+        match error.kind() {
+            io::ErrorKind::NotFound => BackupError::NotFound(id.to_string()),
+            _ => BackupError::Other(format!("{} {}: {}", action, id, error)),
+        }
+    }
+
+    fn is_ignored_path(path: &Path) -> bool {
+        path.components()
+            .any(|c| matches!(c, Component::Normal(name) if is_ignored(&name.to_string_lossy())))
+    }
+}
+
+impl BackupStore for ZipBackupStore {
+    fn list_backups(&self) -> Result<Vec<BackupInfo>, BackupError> {
+        let entries = fs::read_dir(&self.backups_dir)
+            .map_err(|e| format!("Failed to read backups directory: {}", e))?;
+
+        Ok(entries
+            .filter_map(|e| e.ok())
+            .filter_map(|entry| {
+                let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+                entry.file_name().to_str().and_then(|name| BackupInfo::from_file_name(name, size))
+            })
+            .collect())
+    }
+
+    fn create_backup(&self, kind: BackupKind, created_at: DateTime<Utc>) -> Result<BackupInfo, BackupError> {
+        let files = self.read_library()?;
+        let id = BackupInfo::file_name(created_at, kind);
+        let path = self.backup_path(&id)?;
+
+        // Write to a temp file first so a crash never leaves a truncated backup
+        let temp_path = path.with_extension("zip.tmp");
+        let file = fs::File::create(&temp_path)
+            .map_err(|e| format!("Failed to create backup file: {}", e))?;
+
+        let mut zip = ZipWriter::new(file);
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        for (name, content) in &files {
+            zip.start_file(name.as_str(), options)
+                .map_err(|e| format!("Failed to add {} to backup: {}", name, e))?;
+            zip.write_all(content)
+                .map_err(|e| format!("Failed to write {} to backup: {}", name, e))?;
+        }
+        zip.finish()
+            .map_err(|e| format!("Failed to finish backup archive: {}", e))?;
+
+        fs::rename(&temp_path, &path)
+            .map_err(|e| format!("Failed to finalize backup: {}", e))?;
+
+        let size_bytes = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        BackupInfo::from_file_name(&id, size_bytes).ok_or(BackupError::InvalidId(id))
+    }
+
+    fn delete_backup(&self, id: &str) -> Result<(), BackupError> {
+        let path = self.backup_path(id)?;
+        fs::remove_file(&path).map_err(|e| Self::missing_or(id, e, "Failed to delete backup"))
+    }
+
+    fn read_backup(&self, id: &str) -> Result<LibraryFiles, BackupError> {
+        let path = self.backup_path(id)?;
+        let file = fs::File::open(&path).map_err(|e| Self::missing_or(id, e, "Failed to open backup"))?;
+        let mut archive = ZipArchive::new(file)
+            .map_err(|e| format!("Failed to open backup archive: {}", e))?;
+
+        let mut files = LibraryFiles::new();
+        for index in 0..archive.len() {
+            let mut entry = archive
+                .by_index(index)
+                .map_err(|e| format!("Failed to read backup entry: {}", e))?;
+            if entry.is_dir() {
+                continue;
+            }
+
+            // enclosed_name rejects absolute paths and ".." (zip-slip)
+            let name = entry
+                .enclosed_name()
+                .and_then(|p| p.to_str().map(|s| s.replace('\\', "/")))
+                .ok_or_else(|| format!("Unsafe path in backup: {}", entry.name()))?;
+            // Older backups archived the sync repository too; it is never restored
+            if Self::is_ignored_path(Path::new(&name)) {
+                continue;
+            }
+
+            let mut content = Vec::new();
+            entry
+                .read_to_end(&mut content)
+                .map_err(|e| format!("Failed to read {} from backup: {}", name, e))?;
+            files.insert(name, content);
+        }
+
+        Ok(files)
+    }
+
+    fn read_library(&self) -> Result<LibraryFiles, BackupError> {
+        let mut files = LibraryFiles::new();
+
+        let prompts_dir = self.data_dir.join(PROMPTS_DIR);
+        for entry in WalkDir::new(&prompts_dir)
+            .into_iter()
+            .filter_entry(|e| e.depth() == 0 || !is_ignored(&e.file_name().to_string_lossy()))
+            .filter_map(|e| e.ok())
+        {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let relative = entry
+                .path()
+                .strip_prefix(&self.data_dir)
+                .map_err(|_| "Path is not under data directory".to_string())?
+                .to_str()
+                .ok_or_else(|| "Invalid path encoding".to_string())?
+                .replace('\\', "/");
+            let content = fs::read(entry.path())
+                .map_err(|e| format!("Failed to read {}: {}", relative, e))?;
+            files.insert(relative, content);
+        }
+
+        for name in DATA_FILES {
+            let path = self.data_dir.join(name);
+            if path.is_file() {
+                let content = fs::read(&path)
+                    .map_err(|e| format!("Failed to read {}: {}", name, e))?;
+                files.insert(name.to_string(), content);
+            }
+        }
+
+        Ok(files)
+    }
+
+    fn write_library(&self, files: &LibraryFiles, removed: &[String]) -> Result<(), BackupError> {
+        for relative in removed {
+            let path = self.library_path(relative)?;
+            if path.exists() {
+                fs::remove_file(&path)
+                    .map_err(|e| format!("Failed to delete {}: {}", relative, e))?;
+            }
+        }
+
+        for (relative, content) in files {
+            let path = self.library_path(relative)?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create directory: {}", e))?;
+            }
+            fs::write(&path, content)
+                .map_err(|e| format!("Failed to write {}: {}", relative, e))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, ZipBackupStore) {
+        let temp_dir = TempDir::new().unwrap();
+        let data_dir = temp_dir.path().to_path_buf();
+        fs::create_dir_all(data_dir.join("prompts/Coding")).unwrap();
+        fs::create_dir_all(data_dir.join("prompts/.versions/Coding/review.md")).unwrap();
+        fs::write(data_dir.join("prompts/Coding/review.md"), "---\nname: Review\n---\nReview this").unwrap();
+        fs::write(data_dir.join("prompts/.versions/Coding/review.md/v1.json"), "{}").unwrap();
+        fs::write(data_dir.join("usage.json"), "{}").unwrap();
+        let store = ZipBackupStore::with_data_dir(data_dir).unwrap();
+        (temp_dir, store)
+    }
+
+    #[test]
+    fn test_read_library_includes_prompts_versions_and_data_files() {
+        let (_temp_dir, store) = setup();
+        let files = store.read_library().unwrap();
+
+        let names: Vec<&str> = files.keys().map(|k| k.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["prompts/.versions/Coding/review.md/v1.json", "prompts/Coding/review.md", "usage.json"]
+        );
+    }
+
+    #[test]
+    fn test_sync_repository_is_not_backed_up_or_restored() {
+        let (temp_dir, store) = setup();
+        let git_dir = temp_dir.path().join("prompts/.git");
+        fs::create_dir_all(git_dir.join("refs/heads")).unwrap();
+        fs::write(git_dir.join("refs/heads/main"), "abc123").unwrap();
+        fs::write(temp_dir.path().join("prompts/Coding/.review.md.swp"), "x").unwrap();
+
+        let files = store.read_library().unwrap();
+        assert!(files.keys().all(|k| !k.contains("/.git/") && !k.ends_with(".swp")));
+        assert!(files.contains_key("prompts/.versions/Coding/review.md/v1.json"));
+
+        // Restoring never touches the repository, even when asked to
+        let old_ref: LibraryFiles =
+            [("prompts/.git/refs/heads/main".to_string(), b"old".to_vec())].into_iter().collect();
+        assert!(store.write_library(&old_ref, &[]).is_err());
+        assert!(store.write_library(&LibraryFiles::new(), &["prompts/.git/refs/heads/main".to_string()]).is_err());
+        assert_eq!(fs::read(git_dir.join("refs/heads/main")).unwrap(), b"abc123");
+
+        // Backups made before `.git` was skipped still restore, without it
+        let id = BackupInfo::file_name(Utc::now(), BackupKind::Manual);
+        let mut zip = ZipWriter::new(fs::File::create(temp_dir.path().join("backups").join(&id)).unwrap());
+        for name in ["prompts/.git/refs/heads/main", "prompts/Coding/review.md"] {
+            zip.start_file(name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(b"old").unwrap();
+        }
+        zip.finish().unwrap();
+        assert_eq!(store.read_backup(&id).unwrap().keys().collect::<Vec<_>>(), vec!["prompts/Coding/review.md"]);
+    }
+
+    #[test]
+    fn test_backup_round_trip() {
+        let (_temp_dir, store) = setup();
+        let info = store.create_backup(BackupKind::Manual, Utc::now()).unwrap();
+
+        assert!(info.size_bytes > 0);
+        assert_eq!(store.list_backups().unwrap(), vec![info.clone()]);
+        assert_eq!(store.read_backup(&info.id).unwrap(), store.read_library().unwrap());
+    }
+
+    #[test]
+    fn test_backups_are_not_backed_up() {
+        let (_temp_dir, store) = setup();
+        store.create_backup(BackupKind::Manual, Utc::now()).unwrap();
+
+        let files = store.read_library().unwrap();
+        assert!(files.keys().all(|k| !k.starts_with("backups")));
+    }
+
+    #[test]
+    fn test_delete_backup() {
+        let (_temp_dir, store) = setup();
+        let info = store.create_backup(BackupKind::Scheduled, Utc::now()).unwrap();
+
+        store.delete_backup(&info.id).unwrap();
+        assert!(store.list_backups().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_write_library_restores_and_removes_files() {
+        let (temp_dir, store) = setup();
+        let files: LibraryFiles = [("prompts/General/new.md".to_string(), b"new".to_vec())].into_iter().collect();
+
+        store.write_library(&files, &["usage.json".to_string()]).unwrap();
+
+        assert_eq!(fs::read(temp_dir.path().join("prompts/General/new.md")).unwrap(), b"new");
+        assert!(!temp_dir.path().join("usage.json").exists());
+    }
+
+    #[test]
+    fn test_write_library_rejects_paths_outside_library() {
+        let (_temp_dir, store) = setup();
+        for bad in ["../evil.md", "/etc/passwd", "backups/x.zip", "prompts/../../evil"] {
+            let files: LibraryFiles = [(bad.to_string(), b"x".to_vec())].into_iter().collect();
+            assert!(store.write_library(&files, &[]).is_err(), "accepted {}", bad);
+        }
+    }
+
+    #[test]
+    fn test_invalid_backup_id_rejected() {
+        let (_temp_dir, store) = setup();
+        assert_eq!(store.read_backup("../usage.json").unwrap_err().code(), "invalid_input");
+        assert_eq!(store.delete_backup("notes.zip").unwrap_err().code(), "invalid_input");
+    }
+
+    #[test]
+    fn test_missing_backup_is_not_found() {
+        let (_temp_dir, store) = setup();
+        let id = BackupInfo::file_name(Utc::now(), BackupKind::Manual);
+
+        assert!(matches!(store.read_backup(&id), Err(BackupError::NotFound(missing)) if missing == id));
+        assert_eq!(store.delete_backup(&id).unwrap_err().code(), "not_found");
+    }
+}
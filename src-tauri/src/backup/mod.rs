@@ -0,0 +1,14 @@
+// Backup module - Hexagonal Architecture
+// - domain/ (BackupInfo, BackupPolicy, RestorePreview, BackupError, BackupStore port)
+// - application/ (BackupService: scheduling, retention, restore preview)
+// - infrastructure/ (ZipBackupStore)
+
+pub mod domain;
+pub mod application;
+pub mod infrastructure;
+
+// Re-export types used by commands layer
+pub use domain::entities::{BackupInfo, BackupKind, BackupPolicy, RestorePreview};
+pub use domain::BackupError;
+pub use application::BackupService;
+pub use infrastructure::ZipBackupStore;
@@ -0,0 +1,46 @@
+// Backup commands - Presentation layer (thin wrapper)
+use crate::backup::{
+    BackupError, BackupInfo, BackupKind, BackupPolicy, BackupService, RestorePreview, ZipBackupStore,
+};
+use crate::commands::error::CommandResult;
+use crate::commands::prompts_changed;
+use crate::commands::prompts::load_config;
+use chrono::Utc;
+
+/// List all backups, newest first
+#[tauri::command(rename_all = "snake_case")]
+pub async fn list_backups() -> CommandResult<Vec<BackupInfo>> {
+    log::info!("[COMMAND] list_backups");
+    Ok(BackupService::new(ZipBackupStore::new()?).list()?)
+}
+
+/// Take a backup right now
+#[tauri::command(rename_all = "snake_case")]
+pub async fn create_backup() -> CommandResult<BackupInfo> {
+    log::info!("[COMMAND] create_backup");
+    Ok(BackupService::new(ZipBackupStore::new()?).create(BackupKind::Manual)?)
+}
+
+/// Restore a backup into the library
+/// With `dry_run` (the default) only returns a preview of what would change
+#[tauri::command(rename_all = "snake_case")]
+pub async fn restore_backup(backup_id: String, dry_run: Option<bool>) -> CommandResult<RestorePreview> {
+    let dry_run = dry_run.unwrap_or(true);
+    log::info!("[COMMAND] restore_backup: backup_id={}, dry_run={}", backup_id, dry_run);
+    let preview = BackupService::new(ZipBackupStore::new()?).restore(&backup_id, dry_run)?;
+    if !dry_run {
+        prompts_changed();
+    }
+    Ok(preview)
+}
+
+/// Take a scheduled backup if one is due (used by the background scheduler)
+pub fn run_scheduled_backup() -> Result<Option<BackupInfo>, BackupError> {
+    let config = load_config().map_err(|e| e.to_string())?;
+    let policy = BackupPolicy::new(
+        config.backup_enabled,
+        config.backup_interval_hours,
+        config.backup_retention_count,
+    );
+    BackupService::new(ZipBackupStore::new()?).run_scheduled(&policy, Utc::now())
+}
@@ -1,25 +1,50 @@
 // OS integration commands - Presentation layer (thin wrapper)
-use crate::os::application::use_cases::{PastePromptUseCase, ShowWindowUseCase};
-use crate::os::domain::ports::WindowManager;
-use crate::os::domain::CopyPasteResult;
-use crate::os::infrastructure::{TauriClipboardAdapter, WindowsFocusTracker, WindowsInputSimulator};
+use crate::commands::error::CommandResult;
+use crate::commands::prompts::load_config;
+use crate::logging::redact;
+use crate::os::application::use_cases::{PastePromptUseCase, RunMacroUseCase, ShowWindowUseCase};
+use crate::os::domain::ports::{KeyCode, WindowManager};
+use crate::os::domain::{CopyPasteResult, MacroAction, PasteProfile};
+use crate::os::infrastructure::{input_simulator, window_manager, TauriClipboardAdapter};
+use crate::storage::{
+    EncryptedSecretStore, FilePromptRepository, FrecencyCalculator, MacroStep, PasteFormat, PasteMode, Prompt,
+    PromptId, PromptRepository, RecordUsageUseCase, SecretService, StorageError, TargetApp,
+};
 use std::sync::Arc;
+use std::time::Duration;
 use tauri::{Emitter, Manager};
 
 /// Copy text, hide window, restore focus, optionally paste
+/// `{{secret:name}}` references are filled from the secrets store here, after logging
 /// Returns detailed result about what succeeded/failed
 #[tauri::command(rename_all = "snake_case")]
 pub async fn copy_and_paste(
     app: tauri::AppHandle,
     text: String,
     auto_paste: bool,
-) -> Result<CopyPasteResult, String> {
-    log::info!("[COMMAND] copy_and_paste called: text_len={}, auto_paste={}", text.len(), auto_paste);
-
-    // Construct adapters (infrastructure layer)
-    let clipboard = Arc::new(TauriClipboardAdapter::new(app.clone()));
-    let window_manager = Arc::new(WindowsFocusTracker::new());
-    let input_simulator = Arc::new(WindowsInputSimulator::new());
+    prompt_id: Option<String>,
+) -> CommandResult<CopyPasteResult> {
+    let secrets = SecretService::new(EncryptedSecretStore::new()?);
+    // Lengths are of the text as sent, so they never reveal the length of a secret
+    log::info!(
+        "[COMMAND] copy_and_paste called: text_len={}, secrets={}, auto_paste={}",
+        text.len(),
+        secrets.count_references(&text),
+        auto_paste
+    );
+    log::debug!("[COMMAND] copy_and_paste text: {}", redact(&text));
+    // Resolve before hiding the window so a missing secret or locked vault is shown to the user
+    let text = secrets.resolve(&text)?;
+
+    // The prompt may ask to be typed, pasted as plain or rich text, or run as a macro
+    let overrides = match prompt_id {
+        Some(id) => match FilePromptRepository::new()?.find_by_id(&PromptId::new(&id)) {
+            Ok(prompt) => PasteOverrides::for_prompt(&prompt, &text)?,
+            Err(_) => PasteOverrides::default(),
+        },
+        None => PasteOverrides::default(),
+    };
+    let window_manager = window_manager();
 
     // Get window reference
     let window = app
@@ -27,27 +52,165 @@ pub async fn copy_and_paste(
         .ok_or("Could not find main window")?;
 
     // Hide window before use case execution
-    log::info!("[COMMAND] Hiding Prompter window");
+    log::debug!("[COMMAND] Hiding Prompter window");
     window.hide().map_err(|e| format!("Hide error: {}", e))?;
     tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
 
     // Execute use case
-    log::info!("[COMMAND] Executing PastePromptUseCase");
-    let use_case = PastePromptUseCase::new(clipboard, window_manager, input_simulator);
-    let result = use_case.execute(&text, auto_paste).await;
+    log::debug!("[COMMAND] Executing PastePromptUseCase");
+    let result = paste_rendered(app, window_manager, &text, auto_paste, overrides).await;
 
     match &result {
         Ok(r) => log::info!("[COMMAND] copy_and_paste completed: {}", r.message),
-        Err(e) => log::error!("[COMMAND] copy_and_paste failed: {}", e),
+        Err(e) => log::error!("[COMMAND] copy_and_paste failed: {} ({})", e.code, redact(e)),
     }
 
     result
 }
 
+/// Paste a prompt straight into the focused app (per-prompt hotkeys)
+/// Same path as the launcher: remember window → render → record usage → paste;
+/// variables take their defaults, so prompts with a required empty variable are refused
+pub(crate) async fn paste_prompt_by_id(app: tauri::AppHandle, prompt_id: &str) -> CommandResult<CopyPasteResult> {
+    log::info!("[HOTKEY] paste_prompt_by_id: {}", redact(prompt_id));
+    let window_manager = window_manager();
+    // The launcher stays hidden, so the target app is still in the foreground
+    window_manager.remember_current_window()?;
+
+    let (text, overrides) = render_prompt_for_paste(prompt_id, window_manager.as_ref())?;
+    let result = paste_rendered(app, window_manager, &text, true, overrides).await?;
+    log::info!("[HOTKEY] paste_prompt_by_id completed: {}", result.message);
+    Ok(result)
+}
+
+/// A prompt's own paste settings; unset ones follow the per-app config
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PasteOverrides {
+    pub mode: Option<PasteMode>,
+    pub format: Option<PasteFormat>,
+    /// The prompt's macro, run instead of a single paste when auto-paste is on
+    pub steps: Vec<MacroAction>,
+}
+
+impl PasteOverrides {
+    /// `content` is the prompt's rendered text; macro segments are rendered here with the
+    /// variables' defaults and secrets filled in
+    pub(crate) fn for_prompt(prompt: &Prompt, content: &str) -> CommandResult<Self> {
+        let secrets = SecretService::new(EncryptedSecretStore::new()?);
+        let steps = prompt
+            .steps
+            .iter()
+            .map(|step| {
+                Ok(match step {
+                    MacroStep::Content => MacroAction::Paste(content.to_string()),
+                    MacroStep::Paste(text) => {
+                        let text = prompt.render_text_with_defaults(text).map_err(StorageError::Validation)?;
+                        MacroAction::Paste(secrets.resolve(&text)?)
+                    }
+                    MacroStep::Keys(chord) => MacroAction::Keys(KeyCode::parse_chord(chord).map_err(StorageError::Validation)?),
+                    MacroStep::WaitMs(ms) => MacroAction::Wait(Duration::from_millis(*ms)),
+                })
+            })
+            .collect::<CommandResult<Vec<_>>>()?;
+
+        Ok(Self {
+            mode: prompt.paste_mode,
+            format: prompt.paste_format,
+            steps,
+        })
+    }
+}
+
+/// Business rule: a prompt's macro keys must name known keys, checked when it is saved
+pub(crate) fn check_prompt_steps(prompt: &Prompt) -> Result<(), StorageError> {
+    for (i, step) in prompt.steps.iter().enumerate() {
+        if let MacroStep::Keys(chord) = step {
+            KeyCode::parse_chord(chord).map_err(|e| StorageError::Validation(format!("Step {}: {}", i + 1, e)))?;
+        }
+    }
+    Ok(())
+}
+
+/// Render a prompt with its variable defaults and secrets, and record its use
+/// in the remembered window's app; also returns the prompt's own paste settings
+pub(crate) fn render_prompt_for_paste(
+    prompt_id: &str,
+    window_manager: &dyn WindowManager,
+) -> CommandResult<(String, PasteOverrides)> {
+    let prompt = FilePromptRepository::new()?.find_by_id(&PromptId::new(prompt_id))?;
+    if prompt.locked {
+        return Err(StorageError::Locked(prompt.id).into());
+    }
+    let text = prompt.render_with_defaults().map_err(StorageError::Validation)?;
+    let text = SecretService::new(EncryptedSecretStore::new()?).resolve(&text)?;
+
+    let target_app = window_manager.remembered_app().map(TargetApp::new);
+    RecordUsageUseCase::new(FrecencyCalculator::new()?).execute(&PromptId::new(prompt_id), target_app.as_ref())?;
+    let overrides = PasteOverrides::for_prompt(&prompt, &text)?;
+    Ok((text, overrides))
+}
+
+/// Put rendered text into the remembered window: pasted as plain or rich text, or typed out,
+/// as the prompt or the target app (config `type_out_apps`, `rich_text_apps`) asks; the app's
+/// paste profile (config `paste_profiles`) sets the shortcut, delays and strategies;
+/// a prompt with macro steps runs them instead
+pub(crate) async fn paste_rendered(
+    app: tauri::AppHandle,
+    window_manager: Arc<dyn WindowManager>,
+    text: &str,
+    auto_paste: bool,
+    overrides: PasteOverrides,
+) -> CommandResult<CopyPasteResult> {
+    let config = load_config().unwrap_or_default();
+    let target_app = window_manager.remembered_app().map(TargetApp::new);
+    let apps = |names: &[String]| names.iter().map(TargetApp::new).collect::<Vec<_>>();
+    let mode = PasteMode::resolve(overrides.mode, target_app.as_ref(), &apps(&config.type_out_apps));
+    let format = PasteFormat::resolve(overrides.format, target_app.as_ref(), &apps(&config.rich_text_apps));
+    let window_class = window_manager.remembered_window_class();
+    let profile = PasteProfile::matching(&config.paste_profiles, target_app.as_ref(), window_class.as_deref()).cloned();
+    let interval = Duration::from_millis(config.type_out_delay_ms.into());
+
+    let clipboard = Arc::new(TauriClipboardAdapter::new(app));
+    let input_simulator = input_simulator();
+    if auto_paste && !overrides.steps.is_empty() {
+        let mut runner = RunMacroUseCase::new(clipboard, window_manager, input_simulator);
+        if let Some(profile) = profile {
+            runner = runner.with_profile(profile, interval);
+        }
+        if mode == PasteMode::Type {
+            runner = runner.with_type_out(interval);
+        }
+        if config.restore_clipboard {
+            runner = runner.with_clipboard_restore(Duration::from_millis(config.restore_clipboard_delay_ms.into()));
+        }
+        if format == PasteFormat::Rich {
+            runner = runner.with_rich_text();
+        }
+        return Ok(runner.execute(&overrides.steps).await?);
+    }
+
+    let mut use_case = PastePromptUseCase::new(clipboard, window_manager, input_simulator);
+    if config.restore_clipboard {
+        use_case = use_case.with_clipboard_restore(Duration::from_millis(config.restore_clipboard_delay_ms.into()));
+    }
+    if format == PasteFormat::Rich {
+        use_case = use_case.with_rich_text();
+    }
+    if let Some(profile) = profile {
+        log::debug!("Using paste profile {}", profile.name);
+        use_case = use_case.with_profile(profile, interval);
+    }
+    let result = match mode {
+        PasteMode::Type if auto_paste => use_case.type_out(text, interval).await?,
+        _ => use_case.execute(text, auto_paste).await?,
+    };
+    Ok(result)
+}
+
 /// Show window and remember current focus
 #[tauri::command(rename_all = "snake_case")]
-pub async fn show_window(app: tauri::AppHandle) -> Result<(), String> {
-    let window_manager = Arc::new(WindowsFocusTracker::new());
+pub async fn show_window(app: tauri::AppHandle) -> CommandResult<()> {
+    let window_manager = window_manager();
 
     // Use ShowWindowUseCase to remember current window
     let use_case = ShowWindowUseCase::new(window_manager);
@@ -67,7 +230,7 @@ pub async fn show_window(app: tauri::AppHandle) -> Result<(), String> {
 
 /// Just hide window and restore focus (for Escape key)
 #[tauri::command(rename_all = "snake_case")]
-pub async fn hide_window(app: tauri::AppHandle) -> Result<(), String> {
+pub async fn hide_window(app: tauri::AppHandle) -> CommandResult<()> {
     let window = app
         .get_webview_window("main")
         .ok_or("Could not find main window")?;
@@ -75,7 +238,7 @@ pub async fn hide_window(app: tauri::AppHandle) -> Result<(), String> {
     window.hide().map_err(|e| format!("Hide error: {}", e))?;
     tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
 
-    let window_manager = WindowsFocusTracker::new();
+    let window_manager = window_manager();
     window_manager.restore_previous_window()?;
     window_manager.clear_saved_window();
 
@@ -92,8 +255,12 @@ pub async fn open_editor_window(
     app: tauri::AppHandle,
     prompt_id: Option<String>,
     mode: Option<String>,
-) -> Result<(), String> {
-    log::info!("[COMMAND] open_editor_window: prompt_id={:?}, mode={:?}", prompt_id, mode);
+) -> CommandResult<()> {
+    log::info!(
+        "[COMMAND] open_editor_window: prompt_id={}, mode={:?}",
+        redact(prompt_id.as_deref().unwrap_or("none")),
+        mode
+    );
 
     // Build URL with query parameters
     let mut url = "/editor".to_string();
@@ -140,7 +307,7 @@ pub async fn open_editor_window(
 
 /// Open the settings window
 #[tauri::command(rename_all = "snake_case")]
-pub async fn open_settings_window(app: tauri::AppHandle) -> Result<(), String> {
+pub async fn open_settings_window(app: tauri::AppHandle) -> CommandResult<()> {
     log::info!("[COMMAND] open_settings_window");
 
     // Try to get existing settings window
@@ -168,7 +335,7 @@ pub async fn open_settings_window(app: tauri::AppHandle) -> Result<(), String> {
 
 /// Open the analytics window
 #[tauri::command(rename_all = "snake_case")]
-pub async fn open_analytics_window(app: tauri::AppHandle) -> Result<(), String> {
+pub async fn open_analytics_window(app: tauri::AppHandle) -> CommandResult<()> {
     log::info!("[COMMAND] open_analytics_window");
 
     // Try to get existing analytics window
@@ -196,7 +363,7 @@ pub async fn open_analytics_window(app: tauri::AppHandle) -> Result<(), String>
 
 /// Close a specific window by label
 #[tauri::command(rename_all = "snake_case")]
-pub async fn close_window(app: tauri::AppHandle, label: String) -> Result<(), String> {
+pub async fn close_window(app: tauri::AppHandle, label: String) -> CommandResult<()> {
     log::info!("[COMMAND] close_window: label={}", label);
 
     if let Some(window) = app.get_webview_window(&label) {
@@ -212,36 +379,36 @@ pub async fn close_window(app: tauri::AppHandle, label: String) -> Result<(), St
 
 /// Enable auto-start on system login
 #[tauri::command(rename_all = "snake_case")]
-pub async fn enable_autostart(app: tauri::AppHandle) -> Result<(), String> {
+pub async fn enable_autostart(app: tauri::AppHandle) -> CommandResult<()> {
     use tauri_plugin_autostart::ManagerExt;
 
     log::info!("[COMMAND] enable_autostart");
 
     app.autolaunch()
         .enable()
-        .map_err(|e| format!("Failed to enable autostart: {}", e))
+        .map_err(|e| format!("Failed to enable autostart: {}", e).into())
 }
 
 /// Disable auto-start on system login
 #[tauri::command(rename_all = "snake_case")]
-pub async fn disable_autostart(app: tauri::AppHandle) -> Result<(), String> {
+pub async fn disable_autostart(app: tauri::AppHandle) -> CommandResult<()> {
     use tauri_plugin_autostart::ManagerExt;
 
     log::info!("[COMMAND] disable_autostart");
 
     app.autolaunch()
         .disable()
-        .map_err(|e| format!("Failed to disable autostart: {}", e))
+        .map_err(|e| format!("Failed to disable autostart: {}", e).into())
 }
 
 /// Check if auto-start is enabled
 #[tauri::command(rename_all = "snake_case")]
-pub async fn is_autostart_enabled(app: tauri::AppHandle) -> Result<bool, String> {
+pub async fn is_autostart_enabled(app: tauri::AppHandle) -> CommandResult<bool> {
     use tauri_plugin_autostart::ManagerExt;
 
     log::info!("[COMMAND] is_autostart_enabled");
 
     app.autolaunch()
         .is_enabled()
-        .map_err(|e| format!("Failed to check autostart status: {}", e))
+        .map_err(|e| format!("Failed to check autostart status: {}", e).into())
 }
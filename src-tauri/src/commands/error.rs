@@ -0,0 +1,141 @@
+// Error type shared by all Tauri commands
+// Serialized to the frontend as `{ code, message, details }` so the UI can tell
+// "not found" from "permission denied" from "parse error"
+use crate::backup::BackupError;
+use crate::hotkey::HotkeyError;
+use crate::os::domain::OsError;
+use crate::storage::StorageError;
+use crate::sync::SyncError;
+use serde::Serialize;
+use std::fmt;
+
+pub type CommandResult<T> = Result<T, CommandError>;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommandError {
+    /// Stable machine-readable code (e.g. "not_found", "permission_denied", "parse_error")
+    pub code: String,
+    /// Human-readable message, safe to show as-is
+    pub message: String,
+    /// The prompt, file or line the error is about, when known
+    pub details: Option<String>,
+}
+
+impl CommandError {
+    pub fn new(code: &str, message: impl Into<String>) -> Self {
+        Self {
+            code: code.to_string(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// A bad request from the frontend (e.g. deleting a folder that still holds prompts)
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        Self::new("invalid_input", message)
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<StorageError> for CommandError {
+    fn from(error: StorageError) -> Self {
+        Self {
+            code: error.code().to_string(),
+            message: error.to_string(),
+            details: error.details(),
+        }
+    }
+}
+
+impl From<OsError> for CommandError {
+    fn from(error: OsError) -> Self {
+        Self::new(error.code(), error.to_string())
+    }
+}
+
+impl From<HotkeyError> for CommandError {
+    fn from(error: HotkeyError) -> Self {
+        Self {
+            code: error.code().to_string(),
+            message: error.to_string(),
+            details: error.details(),
+        }
+    }
+}
+
+impl From<BackupError> for CommandError {
+    fn from(error: BackupError) -> Self {
+        Self {
+            code: error.code().to_string(),
+            message: error.to_string(),
+            details: error.details(),
+        }
+    }
+}
+
+impl From<SyncError> for CommandError {
+    fn from(error: SyncError) -> Self {
+        Self {
+            code: error.code().to_string(),
+            message: error.to_string(),
+            details: error.details(),
+        }
+    }
+}
+
+/// Helpers that still report plain messages (config, window lookup)
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        Self::new("internal", message)
+    }
+}
+
+impl From<&str> for CommandError {
+    fn from(message: &str) -> Self {
+        Self::new("internal", message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serializes_code_message_and_details() {
+        let error = CommandError::from(StorageError::NotFound("Coding/review.md".to_string()));
+
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "code": "not_found",
+                "message": "Prompt not found: Coding/review.md",
+                "details": "Coding/review.md",
+            })
+        );
+    }
+
+    #[test]
+    fn test_subsystem_errors_keep_their_codes() {
+        let locked = CommandError::from(StorageError::VaultLocked("Unlock the vault to use secrets".to_string()));
+        assert_eq!(locked.code, "vault_locked");
+
+        let missing = CommandError::from(BackupError::NotFound("backup-1.zip".to_string()));
+        assert_eq!((missing.code.as_str(), missing.details.as_deref()), ("not_found", Some("backup-1.zip")));
+
+        assert_eq!(CommandError::from(SyncError::Git("git push failed".to_string())).code, "git_error");
+    }
+
+    #[test]
+    fn test_plain_messages_map_to_internal() {
+        let error = CommandError::from("Could not find main window");
+        assert_eq!(error.code, "internal");
+        assert_eq!(error.details, None);
+        assert_eq!(CommandError::from(OsError::NoSavedWindow).code, "no_saved_window");
+    }
+}
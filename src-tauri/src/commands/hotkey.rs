@@ -0,0 +1,220 @@
+// Global hotkey commands - Presentation layer (thin wrapper)
+use crate::commands::error::CommandResult;
+use crate::commands::prompts::load_config;
+use crate::hotkey::{
+    check_available, plan_bindings, to_shortcut, Accelerator, HotkeyError, HotkeyService, HotkeyStatus,
+    JsonRegistrationStore, PromptBinding, PromptHotkey, TauriShortcutRegistrar, DEFAULT_HOTKEY,
+};
+use crate::logging::redact;
+use crate::os::infrastructure::window_manager;
+use crate::storage::{FilePromptRepository, Prompt, PromptRepository};
+use once_cell::sync::{Lazy, OnceCell};
+use std::sync::Mutex;
+use tauri::{Emitter, Manager};
+use tauri_plugin_global_shortcut::Shortcut;
+
+/// What is registered right now; also serializes registration changes
+#[derive(Default)]
+struct HotkeyState {
+    status: HotkeyStatus,
+    prompt_bindings: Vec<PromptBinding>,
+}
+
+static STATE: Lazy<Mutex<HotkeyState>> = Lazy::new(|| Mutex::new(HotkeyState::default()));
+
+/// Copy of the registered hotkeys read by the shortcut handler
+/// Separate from STATE because registering waits on the main thread, where the handler runs
+static ROUTES: Lazy<Mutex<Routes>> = Lazy::new(|| Mutex::new(Routes::default()));
+
+#[derive(Default)]
+struct Routes {
+    launcher: Option<Accelerator>,
+    prompts: Vec<PromptBinding>,
+}
+
+fn publish_routes(state: &HotkeyState) {
+    *ROUTES.lock().unwrap() = Routes {
+        launcher: state.status.active.as_deref().and_then(|a| Accelerator::parse(a).ok()),
+        prompts: state.prompt_bindings.clone(),
+    };
+}
+
+/// Set at startup so prompt saves can re-register hotkeys without an AppHandle
+static APP_HANDLE: OnceCell<tauri::AppHandle> = OnceCell::new();
+
+fn hotkey_service(
+    app: &tauri::AppHandle,
+) -> Result<HotkeyService<TauriShortcutRegistrar, JsonRegistrationStore>, HotkeyError> {
+    Ok(HotkeyService::new(
+        TauriShortcutRegistrar::new(app.clone()),
+        JsonRegistrationStore::new()?,
+    ))
+}
+
+/// `hotkey` fields of every prompt in the library
+fn library_prompt_hotkeys() -> Result<Vec<PromptHotkey>, String> {
+    let prompts = FilePromptRepository::new()?.find_all()?;
+    Ok(prompts
+        .into_iter()
+        .filter_map(|p| {
+            p.hotkey.map(|hotkey| PromptHotkey {
+                prompt_id: p.id,
+                prompt_name: p.name,
+                hotkey,
+            })
+        })
+        .collect())
+}
+
+/// Register the configured hotkey and the prompt hotkeys at startup
+/// The launcher hotkey falls back to the default if it is invalid or taken
+pub fn register_configured_hotkey(app: &tauri::AppHandle) {
+    let _ = APP_HANDLE.set(app.clone());
+    let configured = load_config()
+        .map(|c| c.hotkey)
+        .unwrap_or_else(|_| DEFAULT_HOTKEY.to_string());
+
+    let status = match hotkey_service(app) {
+        Ok(service) => service.startup(&configured),
+        Err(e) => HotkeyStatus {
+            configured,
+            active: None,
+            error: Some(e.to_string()),
+            prompts: Vec::new(),
+        },
+    };
+    if let Some(error) = &status.error {
+        log::error!("Hotkey problem: {}. Use the system tray icon if no hotkey is active.", error);
+    }
+
+    let mut state = STATE.lock().unwrap();
+    state.status = status;
+    sync_prompt_hotkeys(app, &mut state);
+}
+
+/// Re-register prompt hotkeys after the library changed (runs in the background; failures are logged)
+pub fn refresh_prompt_hotkeys() {
+    let Some(app) = APP_HANDLE.get().cloned() else { return };
+    std::thread::spawn(move || {
+        let mut state = STATE.lock().unwrap();
+        sync_prompt_hotkeys(&app, &mut state);
+    });
+}
+
+fn sync_prompt_hotkeys(app: &tauri::AppHandle, state: &mut HotkeyState) {
+    let prompt_hotkeys = match library_prompt_hotkeys() {
+        Ok(prompt_hotkeys) => prompt_hotkeys,
+        Err(e) => {
+            log::warn!("Failed to load prompt hotkeys: {}", redact(e));
+            return;
+        }
+    };
+    let service = match hotkey_service(app) {
+        Ok(service) => service,
+        Err(e) => {
+            log::warn!("Failed to register prompt hotkeys: {}", e);
+            return;
+        }
+    };
+
+    let launcher = state.status.active.as_deref().and_then(|a| Accelerator::parse(a).ok());
+    let (next, mut rejected) = plan_bindings(launcher.as_ref(), &prompt_hotkeys);
+    let (registered, mut statuses) = service.sync_prompts(&state.prompt_bindings, next);
+
+    statuses.append(&mut rejected);
+    statuses.sort_by(|a, b| a.prompt_id.cmp(&b.prompt_id));
+    state.prompt_bindings = registered;
+    state.status.prompts = statuses;
+    publish_routes(state);
+}
+
+/// Validate and normalize a prompt's hotkey before it is saved
+/// Rejects invalid hotkeys and ones already used by the launcher or another prompt
+pub(crate) fn check_prompt_hotkey(prompt: &mut Prompt) -> Result<(), HotkeyError> {
+    let Some(hotkey) = prompt.hotkey.as_deref() else { return Ok(()) };
+    let accelerator = Accelerator::parse(hotkey)?;
+
+    let launcher = ROUTES.lock().unwrap().launcher.clone();
+    let prompt_hotkeys = library_prompt_hotkeys().map_err(HotkeyError::Record)?;
+    check_available(&accelerator, &prompt.id, launcher.as_ref(), &prompt_hotkeys)?;
+
+    prompt.hotkey = Some(accelerator.to_string());
+    Ok(())
+}
+
+/// Switch to `requested` without a restart; returns its canonical spelling
+/// On error the current hotkey stays registered
+pub(crate) fn change_hotkey(app: &tauri::AppHandle, requested: &str) -> Result<String, HotkeyError> {
+    let prompt_hotkeys = library_prompt_hotkeys().map_err(HotkeyError::Record)?;
+    let mut state = STATE.lock().unwrap();
+    let active = hotkey_service(app)?
+        .change(state.status.active.as_deref(), requested, &prompt_hotkeys)?
+        .to_string();
+    state.status.configured = active.clone();
+    state.status.active = Some(active.clone());
+    state.status.error = None;
+    publish_routes(&state);
+    drop(state);
+
+    // A prompt hotkey equal to the old launcher hotkey was refused and can be registered now
+    refresh_prompt_hotkeys();
+    Ok(active)
+}
+
+/// Release every hotkey this install registered (on exit)
+pub fn release_hotkeys(app: &tauri::AppHandle) {
+    let mut state = STATE.lock().unwrap();
+    match hotkey_service(app) {
+        Ok(service) => service.shutdown(),
+        Err(e) => log::warn!("Failed to release hotkeys on exit: {}", e),
+    }
+    state.prompt_bindings.clear();
+    state.status.active = None;
+    publish_routes(&state);
+}
+
+/// Route a global shortcut press from the plugin handler
+pub fn handle_shortcut(app: &tauri::AppHandle, shortcut: &Shortcut) {
+    let matches = |accelerator: &Accelerator| to_shortcut(accelerator).is_ok_and(|s| s == *shortcut);
+
+    let (is_launcher, prompt_id) = {
+        let routes = ROUTES.lock().unwrap();
+        let prompt_id = routes
+            .prompts
+            .iter()
+            .find(|b| matches(&b.accelerator))
+            .map(|b| b.prompt_id.clone());
+        (routes.launcher.as_ref().is_some_and(matches), prompt_id)
+    };
+
+    if is_launcher {
+        show_launcher(app);
+    } else if let Some(prompt_id) = prompt_id {
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = crate::commands::clipboard::paste_prompt_by_id(app, &prompt_id).await {
+                log::warn!("Prompt hotkey paste failed for {}: {} ({})", redact(&prompt_id), e.code, redact(&e));
+            }
+        });
+    }
+}
+
+fn show_launcher(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        // Remember current window before showing Prompter
+        let _ = window_manager().remember_current_window();
+
+        // Show and focus window
+        let _ = window.show();
+        let _ = window.set_focus();
+
+        // Emit event to frontend to focus search input
+        let _ = window.emit("focus-search", ());
+    }
+}
+
+/// Launcher and prompt hotkeys, with the reason they are not registered if they are not
+#[tauri::command(rename_all = "snake_case")]
+pub async fn get_hotkey_status() -> CommandResult<HotkeyStatus> {
+    Ok(STATE.lock().unwrap().status.clone())
+}
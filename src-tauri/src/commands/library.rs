@@ -0,0 +1,59 @@
+// Library import/export commands - Presentation layer (thin wrapper)
+use crate::commands::error::CommandResult;
+use crate::commands::prompts_changed;
+use crate::logging::redact;
+use crate::storage::{
+    import_source, BundleWriter, ConflictResolution, ExportFormat, ExportSelection, ExportSummary, FilePromptRepository,
+    FrecencyCalculator, ImportFormat, ImportReport, LibraryExportService, LibraryImportService,
+};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Import prompt files from a directory tree, a zip archive or a single file
+/// `format` selects the source tool (Prompter .md by default; Espanso, VS Code, Raycast, TextExpander, .txt)
+/// With `dry_run` (the default) only returns the plan; `resolutions` maps source paths
+/// of duplicates/conflicts to skip/overwrite/keep_both (unresolved ones are skipped)
+#[tauri::command(rename_all = "snake_case")]
+pub async fn import_library(
+    path: String,
+    format: Option<ImportFormat>,
+    dry_run: Option<bool>,
+    resolutions: Option<HashMap<String, ConflictResolution>>,
+) -> CommandResult<ImportReport> {
+    let format = format.unwrap_or_default();
+    let dry_run = dry_run.unwrap_or(true);
+    log::info!("[COMMAND] import_library: path={}, format={:?}, dry_run={}", redact(&path), format, dry_run);
+
+    let batch = import_source(format, PathBuf::from(path)).load()?;
+    let service = LibraryImportService::new(FilePromptRepository::new()?);
+    let report = service.import(batch, &resolutions.unwrap_or_default(), dry_run)?;
+    if !dry_run {
+        prompts_changed();
+    }
+    Ok(report)
+}
+
+/// Export a selection of prompts (folders, tags, favorites or IDs; empty = everything)
+/// as a zip of .md files, a JSON or YAML bundle, or a VS Code / JetBrains / Espanso snippet file
+/// Snippet exports list features they could not represent in `warnings`
+#[tauri::command(rename_all = "snake_case")]
+pub async fn export_library(
+    path: String,
+    format: ExportFormat,
+    selection: Option<ExportSelection>,
+    include_versions: Option<bool>,
+    include_usage: Option<bool>,
+) -> CommandResult<ExportSummary> {
+    log::info!("[COMMAND] export_library: path={}, format={:?}", redact(&path), format);
+
+    let repository = FilePromptRepository::new()?;
+    let prompts_dir = repository.get_prompts_dir().clone();
+    let service = LibraryExportService::new(repository, FrecencyCalculator::new()?, prompts_dir);
+
+    let bundle = service.bundle(
+        &selection.unwrap_or_default(),
+        include_versions.unwrap_or(false) && !format.is_snippet_format(),
+        include_usage.unwrap_or(false) && !format.is_snippet_format(),
+    )?;
+    Ok(BundleWriter::new().write(&bundle, format, &PathBuf::from(path))?)
+}
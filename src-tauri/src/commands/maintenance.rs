@@ -0,0 +1,47 @@
+// Library maintenance commands - Presentation layer (thin wrapper)
+use crate::commands::error::CommandResult;
+use crate::storage::{
+    FilePromptRepository, FrecencyCalculator, HealthReport, LibraryHealthService, LibraryMaintenanceService,
+    MaintenanceReport, StorageError,
+};
+
+/// Garbage-collect usage and version data of deleted or renamed prompts
+/// With `dry_run` (the default) only reports what would change
+#[tauri::command(rename_all = "snake_case")]
+pub async fn run_maintenance(dry_run: Option<bool>) -> CommandResult<MaintenanceReport> {
+    let dry_run = dry_run.unwrap_or(true);
+    log::info!("[COMMAND] run_maintenance: dry_run={}", dry_run);
+    Ok(run_library_maintenance(dry_run)?)
+}
+
+fn run_library_maintenance(dry_run: bool) -> Result<MaintenanceReport, StorageError> {
+    maintenance_service()?.run(dry_run)
+}
+
+/// Startup maintenance: re-associate renamed prompts and only report orphans, since
+/// files a sync hasn't pulled yet would otherwise lose their history
+pub fn reassociate_library() -> Result<MaintenanceReport, StorageError> {
+    maintenance_service()?.reassociate()
+}
+
+fn maintenance_service() -> Result<LibraryMaintenanceService<FilePromptRepository>, StorageError> {
+    let repository = FilePromptRepository::new()?;
+    let prompts_dir = repository.get_prompts_dir().clone();
+    let frecency = FrecencyCalculator::new()?;
+    Ok(LibraryMaintenanceService::new(repository, frecency, prompts_dir))
+}
+
+/// Validate every prompt file and report problems
+/// With `fix` also repairs the safe cases (folder, color, timestamps, names, undeclared variables)
+#[tauri::command(rename_all = "snake_case")]
+pub async fn check_library(fix: Option<bool>) -> CommandResult<HealthReport> {
+    let fix = fix.unwrap_or(false);
+    log::info!("[COMMAND] check_library: fix={}", fix);
+
+    let repository = FilePromptRepository::new()?;
+    let prompts_dir = repository.get_prompts_dir().clone();
+    let service = LibraryHealthService::new(repository, prompts_dir);
+
+    let report = if fix { service.repair()? } else { service.check()? };
+    Ok(report)
+}
@@ -1,5 +1,19 @@
 // Module contains all Tauri commands exposed to frontend
 // Commands are accessed via module path in generate_handler! macro
 
+pub mod error;
 pub mod prompts;
 pub mod clipboard;
+pub mod maintenance;
+pub mod backup;
+pub mod library;
+pub mod sync;
+pub mod vault;
+pub mod hotkey;
+pub mod text_expansion;
+
+/// Re-register prompt hotkeys and abbreviations after the library changed
+pub(crate) fn prompts_changed() {
+    hotkey::refresh_prompt_hotkeys();
+    text_expansion::refresh_abbreviations();
+}
@@ -1,11 +1,20 @@
 // Tauri commands - Presentation layer
 // Thin wrappers that delegate to use cases
 
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::clipboard::check_prompt_steps;
+use crate::commands::hotkey::check_prompt_hotkey;
+use crate::commands::prompts_changed;
+use crate::commands::text_expansion::{check_prompt_abbreviation, refresh_abbreviations};
+use crate::commands::sync::commit_on_save;
+use crate::os::domain::PasteProfile;
+use crate::os::infrastructure::window_manager;
 use crate::storage::{
-    FilePromptRepository, FrecencyCalculator, FuzzySearchService,
+    FilePromptRepository, FrecencyCalculator, FrecencyModel, FuzzySearchService, MacroStep, PasteFormat, PasteMode,
     Prompt, PromptId, PromptRepository, RecordUsageUseCase, SavePromptUseCase,
-    SearchPromptsUseCase, SearchResult,
+    SearchPromptsUseCase, SearchResult, StorageError, TargetApp,
 };
+use crate::sync::SyncMode;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -32,6 +41,18 @@ pub struct CreatePromptInput {
     pub variables: Option<Vec<VariableInput>>,
     #[serde(default)]
     pub auto_paste: Option<bool>,
+    #[serde(default)]
+    pub apps: Option<Vec<String>>,
+    #[serde(default)]
+    pub hotkey: Option<String>,
+    #[serde(default)]
+    pub abbreviation: Option<String>,
+    #[serde(default)]
+    pub paste_mode: Option<PasteMode>,
+    #[serde(default)]
+    pub paste_format: Option<PasteFormat>,
+    #[serde(default)]
+    pub steps: Option<Vec<MacroStep>>,
 }
 
 /// Input for updating an existing prompt
@@ -58,6 +79,23 @@ pub struct UpdatePromptInput {
     pub auto_paste: Option<bool>,
     #[serde(default)]
     pub is_favorite: Option<bool>,
+    #[serde(default)]
+    pub apps: Option<Vec<String>>,
+    /// Empty string removes the prompt's hotkey
+    #[serde(default)]
+    pub hotkey: Option<String>,
+    /// Empty string removes the prompt's abbreviation
+    #[serde(default)]
+    pub abbreviation: Option<String>,
+    /// "clipboard" or "type"; empty string follows the per-app setting again
+    #[serde(default)]
+    pub paste_mode: Option<String>,
+    /// "plain" or "rich"; empty string follows the per-app setting again
+    #[serde(default)]
+    pub paste_format: Option<String>,
+    /// Empty list removes the prompt's macro
+    #[serde(default)]
+    pub steps: Option<Vec<MacroStep>>,
 }
 
 /// Variable input (matches frontend PromptVariable)
@@ -73,7 +111,7 @@ pub struct VariableInput {
 // =============================================================================
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn get_all_prompts() -> Result<Vec<Prompt>, String> {
+pub async fn get_all_prompts() -> CommandResult<Vec<Prompt>> {
     log::info!("[get_all_prompts] Command invoked");
     let repository = FilePromptRepository::new()?;
     let prompts = repository.find_all()?;
@@ -82,13 +120,13 @@ pub async fn get_all_prompts() -> Result<Vec<Prompt>, String> {
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn get_prompt(id: String) -> Result<Prompt, String> {
+pub async fn get_prompt(id: String) -> CommandResult<Prompt> {
     let repository = FilePromptRepository::new()?;
-    repository.find_by_id(&PromptId::new(id))
+    Ok(repository.find_by_id(&PromptId::new(id))?)
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn create_prompt(input: CreatePromptInput) -> Result<Prompt, String> {
+pub async fn create_prompt(input: CreatePromptInput) -> CommandResult<Prompt> {
     let repository = FilePromptRepository::new()?;
     let now = Utc::now().to_rfc3339();
 
@@ -107,7 +145,7 @@ pub async fn create_prompt(input: CreatePromptInput) -> Result<Prompt, String> {
         })
         .collect();
 
-    let prompt = Prompt {
+    let mut prompt = Prompt {
         id: id.clone(),
         name: input.name,
         description: input.description,
@@ -121,19 +159,35 @@ pub async fn create_prompt(input: CreatePromptInput) -> Result<Prompt, String> {
         is_favorite: false,
         created_at: now.clone(),
         updated_at: now,
+        apps: input.apps.unwrap_or_default(),
+        private: false,
+        locked: false,
+        hotkey: input.hotkey.filter(|h| !h.trim().is_empty()),
+        abbreviation: input.abbreviation.filter(|a| !a.trim().is_empty()),
+        paste_mode: input.paste_mode,
+        paste_format: input.paste_format,
+        steps: input.steps.unwrap_or_default(),
     };
 
-    prompt.validate()?;
+    prompt.validate().map_err(StorageError::Validation)?;
+    check_prompt_hotkey(&mut prompt)?;
+    check_prompt_abbreviation(&prompt)?;
+    check_prompt_steps(&prompt)?;
     repository.save(&prompt)?;
+    commit_on_save();
+    prompts_changed();
     Ok(prompt)
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn update_prompt(input: UpdatePromptInput) -> Result<Prompt, String> {
+pub async fn update_prompt(input: UpdatePromptInput) -> CommandResult<Prompt> {
     let repository = FilePromptRepository::new()?;
 
     // Load existing prompt
     let mut prompt = repository.find_by_id(&PromptId::new(&input.id))?;
+    if prompt.locked && (input.description.is_some() || input.content.is_some() || input.variables.is_some()) {
+        return Err(StorageError::Locked(input.id.clone()).into());
+    }
 
     // Apply updates
     if let Some(name) = input.name {
@@ -172,23 +226,55 @@ pub async fn update_prompt(input: UpdatePromptInput) -> Result<Prompt, String> {
     if let Some(is_favorite) = input.is_favorite {
         prompt.is_favorite = is_favorite;
     }
+    if let Some(apps) = input.apps {
+        prompt.apps = apps;
+    }
+    if let Some(hotkey) = input.hotkey {
+        prompt.hotkey = Some(hotkey).filter(|h| !h.trim().is_empty());
+    }
+    if let Some(abbreviation) = input.abbreviation {
+        prompt.abbreviation = Some(abbreviation).filter(|a| !a.trim().is_empty());
+    }
+    if let Some(paste_mode) = input.paste_mode {
+        prompt.paste_mode = match paste_mode.trim() {
+            "" => None,
+            mode => Some(mode.parse().map_err(StorageError::Validation)?),
+        };
+    }
+    if let Some(paste_format) = input.paste_format {
+        prompt.paste_format = match paste_format.trim() {
+            "" => None,
+            format => Some(format.parse().map_err(StorageError::Validation)?),
+        };
+    }
+    if let Some(steps) = input.steps {
+        prompt.steps = steps;
+    }
 
     // Update timestamp
     prompt.updated_at = Utc::now().to_rfc3339();
 
-    prompt.validate()?;
+    prompt.validate().map_err(StorageError::Validation)?;
+    check_prompt_hotkey(&mut prompt)?;
+    check_prompt_abbreviation(&prompt)?;
+    check_prompt_steps(&prompt)?;
     repository.save(&prompt)?;
+    commit_on_save();
+    prompts_changed();
     Ok(prompt)
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn delete_prompt(id: String) -> Result<(), String> {
+pub async fn delete_prompt(id: String) -> CommandResult<()> {
     let repository = FilePromptRepository::new()?;
-    repository.delete(&PromptId::new(id))
+    repository.delete(&PromptId::new(id))?;
+    commit_on_save();
+    prompts_changed();
+    Ok(())
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn duplicate_prompt(id: String, new_name: Option<String>) -> Result<Prompt, String> {
+pub async fn duplicate_prompt(id: String, new_name: Option<String>) -> CommandResult<Prompt> {
     let repository = FilePromptRepository::new()?;
     let now = Utc::now().to_rfc3339();
 
@@ -215,9 +301,19 @@ pub async fn duplicate_prompt(id: String, new_name: Option<String>) -> Result<Pr
         is_favorite: false, // Duplicates start as non-favorite
         created_at: now.clone(),
         updated_at: now,
+        apps: original.apps,
+        private: original.private,
+        locked: original.locked,
+        // A hotkey or abbreviation can only belong to one prompt
+        hotkey: None,
+        abbreviation: None,
+        paste_mode: original.paste_mode,
+        paste_format: original.paste_format,
+        steps: original.steps,
     };
 
     repository.save(&duplicated)?;
+    commit_on_save();
     Ok(duplicated)
 }
 
@@ -226,17 +322,20 @@ pub async fn duplicate_prompt(id: String, new_name: Option<String>) -> Result<Pr
 // =============================================================================
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn search_prompts(query: String) -> Result<Vec<SearchResult>, String> {
+pub async fn search_prompts(query: String) -> CommandResult<Vec<SearchResult>> {
     let repository = FilePromptRepository::new()?;
     let search_service = FuzzySearchService::new();
-    let frecency = FrecencyCalculator::new()?;
+    let frecency = FrecencyCalculator::new()?.with_model(load_config()?.frecency_model);
+
+    // App the launcher was opened from (remembered before the window was shown)
+    let target_app = remembered_target_app();
 
     let use_case = SearchPromptsUseCase::new(repository, search_service, frecency);
-    use_case.execute(&query)
+    Ok(use_case.execute(&query, target_app.as_ref())?)
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn get_prompts_by_folder(folder: String) -> Result<Vec<Prompt>, String> {
+pub async fn get_prompts_by_folder(folder: String) -> CommandResult<Vec<Prompt>> {
     let repository = FilePromptRepository::new()?;
     let all_prompts = repository.find_all()?;
 
@@ -249,7 +348,7 @@ pub async fn get_prompts_by_folder(folder: String) -> Result<Vec<Prompt>, String
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn get_prompts_by_tag(tag: String) -> Result<Vec<Prompt>, String> {
+pub async fn get_prompts_by_tag(tag: String) -> CommandResult<Vec<Prompt>> {
     let repository = FilePromptRepository::new()?;
     let all_prompts = repository.find_all()?;
 
@@ -262,7 +361,7 @@ pub async fn get_prompts_by_tag(tag: String) -> Result<Vec<Prompt>, String> {
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn get_favorite_prompts() -> Result<Vec<Prompt>, String> {
+pub async fn get_favorite_prompts() -> CommandResult<Vec<Prompt>> {
     let repository = FilePromptRepository::new()?;
     let all_prompts = repository.find_all()?;
 
@@ -295,7 +394,7 @@ pub struct TagInfo {
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn get_folders() -> Result<Vec<FolderInfo>, String> {
+pub async fn get_folders() -> CommandResult<Vec<FolderInfo>> {
     let repository = FilePromptRepository::new()?;
     let all_prompts = repository.find_all()?;
 
@@ -319,7 +418,7 @@ pub async fn get_folders() -> Result<Vec<FolderInfo>, String> {
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn get_tags() -> Result<Vec<TagInfo>, String> {
+pub async fn get_tags() -> CommandResult<Vec<TagInfo>> {
     let repository = FilePromptRepository::new()?;
     let all_prompts = repository.find_all()?;
 
@@ -343,7 +442,7 @@ pub async fn get_tags() -> Result<Vec<TagInfo>, String> {
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn toggle_favorite(id: String) -> Result<bool, String> {
+pub async fn toggle_favorite(id: String) -> CommandResult<bool> {
     let repository = FilePromptRepository::new()?;
 
     // Load existing prompt
@@ -354,6 +453,7 @@ pub async fn toggle_favorite(id: String) -> Result<bool, String> {
     prompt.updated_at = Utc::now().to_rfc3339();
 
     repository.save(&prompt)?;
+    commit_on_save();
     Ok(prompt.is_favorite)
 }
 
@@ -362,7 +462,7 @@ pub async fn toggle_favorite(id: String) -> Result<bool, String> {
 // =============================================================================
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn create_folder(name: String, parent_id: Option<String>) -> Result<FolderInfo, String> {
+pub async fn create_folder(name: String, parent_id: Option<String>) -> CommandResult<FolderInfo> {
     use std::fs;
 
     let repository = FilePromptRepository::new()?;
@@ -376,8 +476,7 @@ pub async fn create_folder(name: String, parent_id: Option<String>) -> Result<Fo
     };
 
     // Create the directory
-    fs::create_dir_all(&folder_path)
-        .map_err(|e| format!("Failed to create folder: {}", e))?;
+    fs::create_dir_all(&folder_path).map_err(|e| StorageError::io(&folder_path, e))?;
 
     Ok(FolderInfo {
         id: name.clone(),
@@ -388,7 +487,7 @@ pub async fn create_folder(name: String, parent_id: Option<String>) -> Result<Fo
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn delete_folder(id: String) -> Result<(), String> {
+pub async fn delete_folder(id: String) -> CommandResult<()> {
     use std::fs;
 
     let repository = FilePromptRepository::new()?;
@@ -403,17 +502,16 @@ pub async fn delete_folder(id: String) -> Result<(), String> {
         .collect();
 
     if !prompts_in_folder.is_empty() {
-        return Err(format!(
+        return Err(CommandError::invalid_input(format!(
             "Cannot delete folder '{}': contains {} prompts",
             id,
             prompts_in_folder.len()
-        ));
+        )));
     }
 
     // Delete the folder
     if folder_path.exists() {
-        fs::remove_dir(&folder_path)
-            .map_err(|e| format!("Failed to delete folder: {}", e))?;
+        fs::remove_dir(&folder_path).map_err(|e| StorageError::io(&folder_path, e))?;
     }
 
     Ok(())
@@ -438,12 +536,17 @@ pub struct VersionInfo {
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn get_version_history(prompt_id: String) -> Result<Vec<VersionInfo>, String> {
+pub async fn get_version_history(prompt_id: String) -> CommandResult<Vec<VersionInfo>> {
     use std::fs;
 
     let repository = FilePromptRepository::new()?;
     let prompts_dir = repository.get_prompts_dir();
 
+    // Snapshots are stored in the clear; a private prompt's must not be readable while it is locked
+    if repository.find_by_id(&PromptId::new(&prompt_id)).is_ok_and(|prompt| prompt.private) {
+        return Err(CommandError::invalid_input("Version history is not kept for private prompts"));
+    }
+
     // Version history is stored in .versions/<prompt_id>/
     let versions_dir = prompts_dir.join(".versions").join(&prompt_id);
 
@@ -453,18 +556,12 @@ pub async fn get_version_history(prompt_id: String) -> Result<Vec<VersionInfo>,
 
     let mut versions: Vec<VersionInfo> = Vec::new();
 
-    for entry in fs::read_dir(&versions_dir)
-        .map_err(|e| format!("Failed to read versions directory: {}", e))?
-    {
-        let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+    for entry in fs::read_dir(&versions_dir).map_err(|e| StorageError::io(&versions_dir, e))? {
+        let entry = entry.map_err(|e| StorageError::io(&versions_dir, e))?;
         let path = entry.path();
 
         if path.extension().map_or(false, |ext| ext == "json") {
-            let content = fs::read_to_string(&path)
-                .map_err(|e| format!("Failed to read version file: {}", e))?;
-            let version: VersionInfo = serde_json::from_str(&content)
-                .map_err(|e| format!("Failed to parse version: {}", e))?;
-            versions.push(version);
+            versions.push(read_version(&path)?);
         }
     }
 
@@ -475,9 +572,7 @@ pub async fn get_version_history(prompt_id: String) -> Result<Vec<VersionInfo>,
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn restore_version(prompt_id: String, version_id: String) -> Result<Prompt, String> {
-    use std::fs;
-
+pub async fn restore_version(prompt_id: String, version_id: String) -> CommandResult<Prompt> {
     let repository = FilePromptRepository::new()?;
     let prompts_dir = repository.get_prompts_dir();
 
@@ -487,13 +582,14 @@ pub async fn restore_version(prompt_id: String, version_id: String) -> Result<Pr
         .join(&prompt_id)
         .join(format!("{}.json", version_id));
 
-    let version_content = fs::read_to_string(&version_path)
-        .map_err(|e| format!("Failed to read version: {}", e))?;
-    let version: VersionInfo = serde_json::from_str(&version_content)
-        .map_err(|e| format!("Failed to parse version: {}", e))?;
+    let version = read_version(&version_path)?;
 
     // Load current prompt
     let mut prompt = repository.find_by_id(&PromptId::new(&prompt_id))?;
+    // Snapshots are stored in the clear, so private prompts have no history to restore into
+    if prompt.private {
+        return Err(CommandError::invalid_input("Version history is not kept for private prompts"));
+    }
 
     // Save current state as new version before restoring
     save_version(&prompts_dir, &prompt, Some("Before restore".to_string()))?;
@@ -505,21 +601,36 @@ pub async fn restore_version(prompt_id: String, version_id: String) -> Result<Pr
     prompt.updated_at = Utc::now().to_rfc3339();
 
     repository.save(&prompt)?;
+    commit_on_save();
 
     Ok(prompt)
 }
 
+/// Read one version snapshot; a missing file is not_found and a damaged one a parse error
+fn read_version(path: &std::path::Path) -> Result<VersionInfo, StorageError> {
+    let content = std::fs::read_to_string(path).map_err(|e| StorageError::io(path, e))?;
+    serde_json::from_str(&content).map_err(|e| json_parse_error(path, e))
+}
+
+/// Delete a prompt's version snapshots when it becomes private, since they are stored in the clear
+pub(crate) fn discard_versions(prompts_dir: &std::path::Path, prompt_id: &str) -> Result<(), StorageError> {
+    let versions_dir = prompts_dir.join(".versions").join(prompt_id);
+    if versions_dir.exists() {
+        std::fs::remove_dir_all(&versions_dir).map_err(|e| StorageError::io(&versions_dir, e))?;
+    }
+    Ok(())
+}
+
 /// Helper to save a version of a prompt
 fn save_version(
     prompts_dir: &std::path::Path,
     prompt: &Prompt,
     change_summary: Option<String>,
-) -> Result<(), String> {
+) -> Result<(), StorageError> {
     use std::fs;
 
     let versions_dir = prompts_dir.join(".versions").join(&prompt.id);
-    fs::create_dir_all(&versions_dir)
-        .map_err(|e| format!("Failed to create versions directory: {}", e))?;
+    fs::create_dir_all(&versions_dir).map_err(|e| StorageError::io(&versions_dir, e))?;
 
     // Count existing versions
     let version_count = fs::read_dir(&versions_dir)
@@ -539,15 +650,21 @@ fn save_version(
     };
 
     let version_path = versions_dir.join(format!("{}.json", version.id));
-    let content = serde_json::to_string_pretty(&version)
-        .map_err(|e| format!("Failed to serialize version: {}", e))?;
-
-    fs::write(&version_path, content)
-        .map_err(|e| format!("Failed to write version: {}", e))?;
+    let content = serde_json::to_string_pretty(&version).map_err(|e| StorageError::Serialization(e.to_string()))?;
+    fs::write(&version_path, content).map_err(|e| StorageError::io(&version_path, e))?;
 
     Ok(())
 }
 
+/// A JSON data file (version snapshot, config) that doesn't parse, with the failing line
+fn json_parse_error(path: &std::path::Path, error: serde_json::Error) -> StorageError {
+    StorageError::Parse {
+        id: path.display().to_string(),
+        message: error.to_string(),
+        line: Some(error.line()),
+    }
+}
+
 // =============================================================================
 // USAGE COMMANDS
 // =============================================================================
@@ -561,14 +678,16 @@ pub struct UsageStatsInfo {
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn record_usage(prompt_id: String) -> Result<(), String> {
+pub async fn record_usage(prompt_id: String) -> CommandResult<()> {
     let frecency = FrecencyCalculator::new()?;
     let mut use_case = RecordUsageUseCase::new(frecency);
-    use_case.execute(&PromptId::new(prompt_id))
+    // Called before copy_and_paste, so the target window is still remembered
+    let target_app = remembered_target_app();
+    Ok(use_case.execute(&PromptId::new(prompt_id), target_app.as_ref())?)
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn get_usage_stats(prompt_id: String) -> Result<UsageStatsInfo, String> {
+pub async fn get_usage_stats(prompt_id: String) -> CommandResult<UsageStatsInfo> {
     let frecency = FrecencyCalculator::new()?;
 
     // Get usage data from frecency calculator
@@ -588,10 +707,16 @@ pub async fn get_usage_stats(prompt_id: String) -> Result<UsageStatsInfo, String
 
 // Legacy alias for save_prompt (used by older code)
 #[tauri::command(rename_all = "snake_case")]
-pub async fn save_prompt(prompt: Prompt) -> Result<(), String> {
+pub async fn save_prompt(mut prompt: Prompt) -> CommandResult<()> {
+    check_prompt_hotkey(&mut prompt)?;
+    check_prompt_abbreviation(&prompt)?;
+    check_prompt_steps(&prompt)?;
     let repository = FilePromptRepository::new()?;
     let use_case = SavePromptUseCase::new(repository);
-    use_case.execute(&prompt)
+    use_case.execute(&prompt)?;
+    commit_on_save();
+    prompts_changed();
+    Ok(())
 }
 
 // =============================================================================
@@ -630,13 +755,102 @@ pub struct AppConfigInfo {
     // Advanced
     pub backup_enabled: bool,
     pub backup_interval_hours: i32,
+    /// Number of backups kept before the oldest are deleted
+    #[serde(default = "default_backup_retention_count")]
+    pub backup_retention_count: i32,
     pub analytics_enabled: bool,
+    /// Sync the library with a git remote or a shared folder
+    #[serde(default)]
+    pub sync_enabled: bool,
+    #[serde(default)]
+    pub sync_mode: SyncMode,
+    #[serde(default = "default_sync_interval_minutes")]
+    pub sync_interval_minutes: i32,
+    #[serde(default = "default_sync_remote")]
+    pub sync_remote: String,
+    /// Shared folder (OneDrive, Syncthing, ...) used in folder mode
+    #[serde(default)]
+    pub sync_folder: String,
+    /// Commit the library after every save while sync is enabled
+    #[serde(default = "default_sync_commit_on_save")]
+    pub sync_commit_on_save: bool,
+    /// Frecency algorithm used to rank the launcher list
+    #[serde(default)]
+    pub frecency_model: FrecencyModel,
+    /// Lock private prompts after this many idle minutes (0 = only when locked by hand)
+    /// Applies from the next unlock
+    #[serde(default = "default_vault_auto_lock_minutes")]
+    pub vault_auto_lock_minutes: i32,
+    /// Write prompt content, names and clipboard text to the log (off by default)
+    #[serde(default)]
+    pub log_prompt_content: bool,
+    /// Expand prompt abbreviations typed in any app
+    #[serde(default = "default_text_expansion_enabled")]
+    pub text_expansion_enabled: bool,
+    /// Apps where abbreviations are never expanded (e.g. "KeePass.exe")
+    #[serde(default)]
+    pub text_expansion_excluded_apps: Vec<String>,
+    /// Apps that get prompts typed as keystrokes instead of pasted (e.g. "mstsc.exe")
+    #[serde(default)]
+    pub type_out_apps: Vec<String>,
+    /// Pause between typed characters; raise it for slow remote sessions
+    #[serde(default = "default_type_out_delay_ms")]
+    pub type_out_delay_ms: u32,
+    /// Put the previous clipboard text back after pasting a prompt
+    #[serde(default)]
+    pub restore_clipboard: bool,
+    /// Wait before restoring; slow apps read the clipboard late after Ctrl+V
+    #[serde(default = "default_restore_clipboard_delay_ms")]
+    pub restore_clipboard_delay_ms: u32,
+    /// Apps that get prompts as rich text (markdown rendered to HTML/RTF)
+    #[serde(default = "default_rich_text_apps")]
+    pub rich_text_apps: Vec<String>,
+    /// Per-app paste shortcut, delays, strategy order and post-paste keys; the first
+    /// profile matching the target's process or window class applies
+    #[serde(default = "PasteProfile::built_in")]
+    pub paste_profiles: Vec<PasteProfile>,
+}
+
+fn default_backup_retention_count() -> i32 {
+    10
+}
+
+fn default_sync_interval_minutes() -> i32 {
+    15
+}
+
+fn default_sync_remote() -> String {
+    "origin".to_string()
+}
+
+fn default_sync_commit_on_save() -> bool {
+    true
+}
+
+fn default_vault_auto_lock_minutes() -> i32 {
+    15
+}
+
+fn default_text_expansion_enabled() -> bool {
+    true
+}
+
+fn default_type_out_delay_ms() -> u32 {
+    10
+}
+
+fn default_restore_clipboard_delay_ms() -> u32 {
+    500
+}
+
+fn default_rich_text_apps() -> Vec<String> {
+    vec!["outlook.exe".to_string(), "winword.exe".to_string(), "slack.exe".to_string()]
 }
 
 impl Default for AppConfigInfo {
     fn default() -> Self {
         Self {
-            hotkey: "F9".to_string(),
+            hotkey: crate::hotkey::DEFAULT_HOTKEY.to_string(),
             prompts_dir: "~/.prompter/prompts".to_string(),
             theme: "dark".to_string(),
             language: "en".to_string(),
@@ -654,13 +868,36 @@ impl Default for AppConfigInfo {
             editor_word_wrap: true,
             backup_enabled: true,
             backup_interval_hours: 24,
+            backup_retention_count: default_backup_retention_count(),
             analytics_enabled: true,
+            sync_enabled: false,
+            sync_mode: SyncMode::default(),
+            sync_interval_minutes: default_sync_interval_minutes(),
+            sync_remote: default_sync_remote(),
+            sync_folder: String::new(),
+            sync_commit_on_save: default_sync_commit_on_save(),
+            frecency_model: FrecencyModel::default(),
+            vault_auto_lock_minutes: default_vault_auto_lock_minutes(),
+            log_prompt_content: false,
+            text_expansion_enabled: default_text_expansion_enabled(),
+            text_expansion_excluded_apps: Vec::new(),
+            type_out_apps: Vec::new(),
+            type_out_delay_ms: default_type_out_delay_ms(),
+            restore_clipboard: false,
+            restore_clipboard_delay_ms: default_restore_clipboard_delay_ms(),
+            rich_text_apps: default_rich_text_apps(),
+            paste_profiles: PasteProfile::built_in(),
         }
     }
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn get_config() -> Result<AppConfigInfo, String> {
+pub async fn get_config() -> CommandResult<AppConfigInfo> {
+    Ok(load_config()?)
+}
+
+/// Load config from disk (defaults if no config file exists yet)
+pub(crate) fn load_config() -> Result<AppConfigInfo, StorageError> {
     use std::fs;
 
     let config_path = get_config_path()?;
@@ -670,38 +907,42 @@ pub async fn get_config() -> Result<AppConfigInfo, String> {
         return Ok(AppConfigInfo::default());
     }
 
-    let content = fs::read_to_string(&config_path)
-        .map_err(|e| format!("Failed to read config: {}", e))?;
-
-    serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse config: {}", e))
+    let content = fs::read_to_string(&config_path).map_err(|e| StorageError::io(&config_path, e))?;
+    serde_json::from_str(&content).map_err(|e| json_parse_error(&config_path, e))
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn update_config(config: AppConfigInfo) -> Result<AppConfigInfo, String> {
+pub async fn update_config(app: tauri::AppHandle, mut config: AppConfigInfo) -> CommandResult<AppConfigInfo> {
     use std::fs;
 
     let config_path = get_config_path()?;
 
+    for profile in &config.paste_profiles {
+        profile.validate().map_err(StorageError::Validation)?;
+    }
+
+    // Re-register before saving, so a conflicting hotkey is reported and never persisted
+    if config.hotkey != load_config()?.hotkey {
+        config.hotkey = crate::commands::hotkey::change_hotkey(&app, &config.hotkey)?;
+    }
+
     // Ensure parent directory exists
     if let Some(parent) = config_path.parent() {
-        fs::create_dir_all(parent)
-            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        fs::create_dir_all(parent).map_err(|e| StorageError::io(parent, e))?;
     }
 
-    let content = serde_json::to_string_pretty(&config)
-        .map_err(|e| format!("Failed to serialize config: {}", e))?;
-
-    fs::write(&config_path, content)
-        .map_err(|e| format!("Failed to write config: {}", e))?;
+    let content = serde_json::to_string_pretty(&config).map_err(|e| StorageError::Serialization(e.to_string()))?;
+    fs::write(&config_path, content).map_err(|e| StorageError::io(&config_path, e))?;
+    crate::logging::set_log_prompt_content(config.log_prompt_content);
+    refresh_abbreviations();
 
     Ok(config)
 }
 
-fn get_config_path() -> Result<std::path::PathBuf, String> {
+fn get_config_path() -> Result<std::path::PathBuf, StorageError> {
     dirs::home_dir()
         .map(|home| home.join(".prompter").join("config.json"))
-        .ok_or_else(|| "Could not determine home directory".to_string())
+        .ok_or_else(|| StorageError::Other("Could not determine home directory".to_string()))
 }
 
 // =============================================================================
@@ -709,21 +950,21 @@ fn get_config_path() -> Result<std::path::PathBuf, String> {
 // =============================================================================
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn export_prompt(id: String) -> Result<String, String> {
+pub async fn export_prompt(id: String) -> CommandResult<String> {
     let repository = FilePromptRepository::new()?;
     let prompt = repository.find_by_id(&PromptId::new(&id))?;
 
     // Export as YAML (matches file format)
     serde_yaml::to_string(&prompt)
-        .map_err(|e| format!("Failed to export prompt: {}", e))
+        .map_err(|e| StorageError::Serialization(e.to_string()).into())
 }
 
 #[tauri::command(rename_all = "snake_case")]
-pub async fn import_prompt(content: String) -> Result<Prompt, String> {
+pub async fn import_prompt(content: String) -> CommandResult<Prompt> {
     // Try parsing as YAML first, then JSON
     let mut prompt: Prompt = serde_yaml::from_str(&content)
         .or_else(|_| serde_json::from_str(&content))
-        .map_err(|e| format!("Failed to parse prompt: {}", e))?;
+        .map_err(|e| CommandError::new("parse_error", format!("Failed to parse prompt: {}", e)))?;
 
     // Generate new ID to avoid conflicts
     let now = Utc::now().to_rfc3339();
@@ -732,10 +973,25 @@ pub async fn import_prompt(content: String) -> Result<Prompt, String> {
     prompt.id = format!("{}/{}-{}.md", prompt.folder, sanitized_name, uuid_suffix);
     prompt.created_at = now.clone();
     prompt.updated_at = now;
+    // An imported hotkey or abbreviation that is invalid or already taken is dropped rather than failing the import
+    if let Err(e) = check_prompt_hotkey(&mut prompt) {
+        log::warn!("[import_prompt] Dropping hotkey: {}", e);
+        prompt.hotkey = None;
+    }
+    if let Err(e) = check_prompt_abbreviation(&prompt) {
+        log::warn!("[import_prompt] Dropping abbreviation: {}", e);
+        prompt.abbreviation = None;
+    }
+    if let Err(e) = check_prompt_steps(&prompt) {
+        log::warn!("[import_prompt] Dropping macro steps: {}", e);
+        prompt.steps.clear();
+    }
 
     // Save the imported prompt
     let repository = FilePromptRepository::new()?;
     repository.save(&prompt)?;
+    commit_on_save();
+    prompts_changed();
 
     Ok(prompt)
 }
@@ -744,6 +1000,13 @@ pub async fn import_prompt(content: String) -> Result<Prompt, String> {
 // HELPER FUNCTIONS
 // =============================================================================
 
+/// App owning the window Prompter was opened from, if known
+fn remembered_target_app() -> Option<TargetApp> {
+    window_manager()
+        .remembered_app()
+        .map(TargetApp::new)
+}
+
 /// Sanitize a string to be used as a filename
 fn sanitize_filename(name: &str) -> String {
     name.chars()
@@ -0,0 +1,173 @@
+// Sync commands - Presentation layer (thin wrapper)
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::prompts_changed;
+use crate::commands::prompts::load_config;
+use crate::logging::redact;
+use crate::storage::FilePromptRepository;
+use crate::sync::{
+    host_name, ConflictChoice, DirectoryTree, FolderSyncReport, FolderSyncService, FolderSyncStatus,
+    GitCliVersionControl, JsonManifestStore, SyncConflict, SyncError, SyncMode, SyncPolicy, SyncReport, SyncService,
+    SyncStatus,
+};
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use std::path::PathBuf;
+use tokio::sync::{Mutex, MutexGuard};
+
+/// Time of the last scheduled or manual sync
+/// Held for the duration of every sync operation so saves, the scheduler and
+/// commands never sync the library concurrently (a tokio mutex: it can't be poisoned)
+static LAST_SYNC: Lazy<Mutex<Option<DateTime<Utc>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Take the sync lock, or report that another sync holds it
+fn sync_lock() -> CommandResult<MutexGuard<'static, Option<DateTime<Utc>>>> {
+    LAST_SYNC
+        .try_lock()
+        .map_err(|_| CommandError::new("sync_busy", "A sync is already running - try again when it finishes"))
+}
+
+/// Run git or folder work on the blocking pool so network round-trips don't stall async workers
+async fn run_blocking<T: Send + 'static>(
+    operation: impl FnOnce() -> Result<T, SyncError> + Send + 'static,
+) -> CommandResult<T> {
+    let result = tauri::async_runtime::spawn_blocking(operation)
+        .await
+        .map_err(|e| CommandError::new("internal", format!("Sync task failed: {}", e)))?;
+    Ok(result?)
+}
+
+fn load_policy() -> Result<SyncPolicy, String> {
+    let config = load_config().map_err(|e| e.to_string())?;
+    Ok(SyncPolicy::new(
+        config.sync_enabled,
+        config.sync_mode,
+        config.sync_interval_minutes,
+        &config.sync_remote,
+        &config.sync_folder,
+        config.sync_commit_on_save,
+    ))
+}
+
+fn sync_service(policy: &SyncPolicy) -> Result<SyncService<GitCliVersionControl>, SyncError> {
+    let prompts_dir = FilePromptRepository::new()?.get_prompts_dir().clone();
+    Ok(SyncService::new(GitCliVersionControl::new(prompts_dir), &policy.remote))
+}
+
+fn folder_sync_service(
+    policy: &SyncPolicy,
+) -> Result<FolderSyncService<DirectoryTree, DirectoryTree, JsonManifestStore>, SyncError> {
+    if policy.folder.is_empty() {
+        return Err(SyncError::NotConfigured("No sync folder configured".to_string()));
+    }
+    let prompts_dir = FilePromptRepository::new()?.get_prompts_dir().clone();
+    Ok(FolderSyncService::new(
+        DirectoryTree::new(prompts_dir),
+        DirectoryTree::new(PathBuf::from(&policy.folder)),
+        JsonManifestStore::new()?,
+        &host_name(),
+    ))
+}
+
+/// State of the prompts directory's git working tree (no network access)
+#[tauri::command(rename_all = "snake_case")]
+pub async fn get_sync_status() -> CommandResult<SyncStatus> {
+    log::info!("[COMMAND] get_sync_status");
+    let _guard = sync_lock()?;
+    run_blocking(|| sync_service(&load_policy()?)?.status()).await
+}
+
+/// Commit, pull (rebase) and push right now
+/// The report lists conflicts if the sync had to pause
+#[tauri::command(rename_all = "snake_case")]
+pub async fn sync_now() -> CommandResult<SyncReport> {
+    log::info!("[COMMAND] sync_now");
+    let mut last_sync = sync_lock()?;
+    let report = run_blocking(|| sync_service(&load_policy()?)?.sync()).await?;
+    *last_sync = Some(Utc::now());
+    prompts_changed();
+    Ok(report)
+}
+
+/// Unresolved conflicts of a paused sync, with base, local and remote versions
+#[tauri::command(rename_all = "snake_case")]
+pub async fn get_sync_conflicts() -> CommandResult<Vec<SyncConflict>> {
+    log::info!("[COMMAND] get_sync_conflicts");
+    let _guard = sync_lock()?;
+    run_blocking(|| sync_service(&load_policy()?)?.conflicts()).await
+}
+
+/// Resolve one conflict of a paused sync; the sync resumes once none remain
+#[tauri::command(rename_all = "snake_case")]
+pub async fn resolve_sync_conflict(path: String, choice: ConflictChoice) -> CommandResult<SyncReport> {
+    log::info!("[COMMAND] resolve_sync_conflict: path={}", redact(&path));
+    let _guard = sync_lock()?;
+    run_blocking(move || sync_service(&load_policy()?)?.resolve(&path, choice)).await
+}
+
+/// Give up a paused sync and keep the local library as it was
+#[tauri::command(rename_all = "snake_case")]
+pub async fn abort_sync() -> CommandResult<()> {
+    log::info!("[COMMAND] abort_sync");
+    let _guard = sync_lock()?;
+    run_blocking(|| sync_service(&load_policy()?)?.abort()).await
+}
+
+/// Pending changes between the library and the shared sync folder (nothing is written)
+#[tauri::command(rename_all = "snake_case")]
+pub async fn get_folder_sync_status() -> CommandResult<FolderSyncStatus> {
+    log::info!("[COMMAND] get_folder_sync_status");
+    let _guard = sync_lock()?;
+    run_blocking(|| folder_sync_service(&load_policy()?)?.status()).await
+}
+
+/// Sync the library with the shared folder right now
+/// Prompts edited on both sides are kept twice, as "name (conflict from HOST).md"
+#[tauri::command(rename_all = "snake_case")]
+pub async fn sync_folder_now() -> CommandResult<FolderSyncReport> {
+    log::info!("[COMMAND] sync_folder_now");
+    let mut last_sync = sync_lock()?;
+    let report = run_blocking(|| folder_sync_service(&load_policy()?)?.sync()).await?;
+    *last_sync = Some(Utc::now());
+    prompts_changed();
+    Ok(report)
+}
+
+/// Commit the library after a save, if enabled (runs in the background; failures are logged)
+pub fn commit_on_save() {
+    std::thread::spawn(|| {
+        let result = load_policy().map_err(SyncError::from).and_then(|policy| {
+            if !policy.commits_on_save() {
+                return Ok(None);
+            }
+            // Waits for a running sync rather than dropping the commit
+            let _guard = LAST_SYNC.blocking_lock();
+            sync_service(&policy)?.commit_changes()
+        });
+        if let Err(e) = result {
+            log::warn!("Commit on save failed: {}", e);
+        }
+    });
+}
+
+/// Sync in the configured mode if enabled and the interval has passed (used by the background scheduler)
+/// Skipped while another sync runs; the next check picks it up
+pub fn run_scheduled_sync() -> Result<(), SyncError> {
+    let policy = load_policy()?;
+    let Ok(mut last_sync) = LAST_SYNC.try_lock() else {
+        return Ok(());
+    };
+    let now = Utc::now();
+    if !policy.is_due(*last_sync, now) {
+        return Ok(());
+    }
+
+    *last_sync = Some(now);
+    let conflicts = match policy.mode {
+        SyncMode::Git => sync_service(&policy)?.sync()?.conflicts.len(),
+        SyncMode::Folder => folder_sync_service(&policy)?.sync()?.conflicts.len(),
+    };
+    if conflicts > 0 {
+        log::warn!("Scheduled sync found {} conflicts", conflicts);
+    }
+    Ok(())
+}
@@ -0,0 +1,173 @@
+// Text expansion commands - Presentation layer (thin wrapper)
+// Typing a prompt's abbreviation (e.g. ";;review") in any app replaces it with the prompt
+use crate::commands::clipboard::{paste_rendered, render_prompt_for_paste};
+use crate::commands::prompts::load_config;
+use crate::logging::redact;
+use crate::os::application::use_cases::ExpandAbbreviationUseCase;
+use crate::os::domain::ports::{KeyEvent, KeyboardEventSource};
+use crate::os::domain::Abbreviation;
+use crate::os::infrastructure::{input_simulator, window_manager, WindowsKeyboardHook};
+use crate::storage::{FilePromptRepository, Prompt, PromptRepository, StorageError, TargetApp};
+use once_cell::sync::{Lazy, OnceCell};
+use std::sync::mpsc::Receiver;
+use std::sync::Mutex;
+
+/// Set while the keyboard hook is running
+static EXPANDER: Lazy<Mutex<Option<ExpandAbbreviationUseCase>>> = Lazy::new(|| Mutex::new(None));
+
+/// Set at startup so library changes can restart expansion without an AppHandle
+static APP_HANDLE: OnceCell<tauri::AppHandle> = OnceCell::new();
+
+/// Abbreviations of every valid prompt in the library
+/// When prompts share an abbreviation the first by ID keeps it, like prompt hotkeys
+fn library_abbreviations() -> Result<Vec<Abbreviation>, String> {
+    let mut prompts = FilePromptRepository::new()?.find_all()?;
+    prompts.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut abbreviations: Vec<Abbreviation> = Vec::new();
+    for prompt in prompts {
+        let Some(trigger) = prompt.abbreviation.clone() else { continue };
+        if prompt.validate().is_err() || abbreviations.iter().any(|a| a.trigger == trigger) {
+            continue;
+        }
+        abbreviations.push(Abbreviation {
+            trigger,
+            prompt_id: prompt.id,
+        });
+    }
+    Ok(abbreviations)
+}
+
+/// Start watching for abbreviations at startup (if enabled and any prompt has one)
+pub fn start_text_expansion(app: &tauri::AppHandle) {
+    let _ = APP_HANDLE.set(app.clone());
+    apply_settings();
+}
+
+/// Pick up changed abbreviations or settings (runs in the background; failures are logged)
+pub fn refresh_abbreviations() {
+    if APP_HANDLE.get().is_none() {
+        return;
+    }
+    std::thread::spawn(apply_settings);
+}
+
+/// Remove the keyboard hook (on exit)
+pub fn stop_text_expansion() {
+    if EXPANDER.lock().unwrap().take().is_some() {
+        WindowsKeyboardHook::new().stop();
+    }
+}
+
+fn apply_settings() {
+    let config = load_config().unwrap_or_default();
+    let abbreviations = match library_abbreviations() {
+        Ok(abbreviations) => abbreviations,
+        Err(e) => {
+            log::warn!("Failed to load abbreviations: {}", redact(e));
+            return;
+        }
+    };
+
+    let mut expander = EXPANDER.lock().unwrap();
+    let hook = WindowsKeyboardHook::new();
+    // No hook at all unless it has something to do
+    if !config.text_expansion_enabled || abbreviations.is_empty() {
+        if expander.take().is_some() {
+            hook.stop();
+            log::info!("Text expansion stopped");
+        }
+        return;
+    }
+
+    let excluded_apps: Vec<TargetApp> = config.text_expansion_excluded_apps.iter().map(TargetApp::new).collect();
+    if let Some(use_case) = expander.as_mut() {
+        use_case.configure(abbreviations, excluded_apps);
+        return;
+    }
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    if let Err(e) = hook.start(sender) {
+        log::warn!("Text expansion unavailable: {}", e);
+        return;
+    }
+    log::info!("Text expansion started with {} abbreviation(s)", abbreviations.len());
+    *expander = Some(ExpandAbbreviationUseCase::new(
+        window_manager(),
+        input_simulator(),
+        abbreviations,
+        excluded_apps,
+    ));
+    std::thread::spawn(move || run_expander(receiver));
+}
+
+/// Worker loop; ends when the hook is stopped and drops its sender
+/// Expanding blocks the loop, so keystrokes typed meanwhile are handled afterwards
+fn run_expander(receiver: Receiver<KeyEvent>) {
+    for event in receiver {
+        let matched = match EXPANDER.lock().unwrap().as_mut() {
+            Some(use_case) => use_case.handle(event),
+            None => break,
+        };
+        match matched {
+            Ok(Some(abbreviation)) => tauri::async_runtime::block_on(expand(abbreviation)),
+            Ok(None) => {}
+            Err(e) => log::warn!("Abbreviation not expanded: {}", e),
+        }
+    }
+}
+
+/// Render first, so a prompt that cannot be pasted leaves the typed trigger in place
+async fn expand(abbreviation: Abbreviation) {
+    let Some(app) = APP_HANDLE.get().cloned() else { return };
+    let window_manager = window_manager();
+
+    let (text, overrides) = match render_prompt_for_paste(&abbreviation.prompt_id, window_manager.as_ref()) {
+        Ok(rendered) => rendered,
+        Err(e) => {
+            let prompt_id = redact(&abbreviation.prompt_id);
+            log::warn!("Abbreviation for {} not expanded: {} ({})", prompt_id, e.code, redact(&e));
+            window_manager.clear_saved_window();
+            return;
+        }
+    };
+
+    let erased = match EXPANDER.lock().unwrap().as_ref() {
+        Some(use_case) => use_case.erase(&abbreviation),
+        None => return,
+    };
+    if let Err(e) = erased {
+        log::warn!("Failed to erase abbreviation for {}: {}", redact(&abbreviation.prompt_id), e.code());
+        window_manager.clear_saved_window();
+        return;
+    }
+
+    match paste_rendered(app, window_manager, &text, true, overrides).await {
+        Ok(result) => log::info!("Expanded abbreviation for {}: {}", redact(&abbreviation.prompt_id), result.message),
+        Err(e) => {
+            log::warn!("Abbreviation paste failed for {}: {} ({})", redact(&abbreviation.prompt_id), e.code, redact(&e))
+        }
+    }
+}
+
+/// Reject an abbreviation that another prompt's abbreviation equals, contains or is part of
+/// before the prompt is saved; the shorter one would always expand first
+pub(crate) fn check_prompt_abbreviation(prompt: &Prompt) -> Result<(), StorageError> {
+    let Some(abbreviation) = prompt.abbreviation.as_deref() else { return Ok(()) };
+    let prompts = FilePromptRepository::new()?.find_all()?;
+    let owner = prompts.iter().filter(|p| p.id != prompt.id).find_map(|p| {
+        let other = p.abbreviation.as_deref()?;
+        Prompt::abbreviations_clash(abbreviation, other).then_some((p, other))
+    });
+    match owner {
+        Some((owner, other)) if other == abbreviation => Err(StorageError::Validation(format!(
+            "Abbreviation {} is already used by {}",
+            abbreviation, owner.name
+        ))),
+        Some((owner, other)) => Err(StorageError::Validation(format!(
+            "Abbreviation {} overlaps {} used by {}",
+            abbreviation, other, owner.name
+        ))),
+        None => Ok(()),
+    }
+}
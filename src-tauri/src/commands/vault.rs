@@ -0,0 +1,135 @@
+// Vault and secrets commands - Presentation layer (thin wrapper)
+// Passphrases and secret values are never logged
+use crate::commands::error::{CommandError, CommandResult};
+use crate::commands::prompts::{discard_versions, load_config};
+use crate::commands::sync::commit_on_save;
+use crate::logging::redact;
+use crate::storage::{
+    EncryptedSecretStore, FilePromptRepository, PassphraseVault, Prompt, PromptId, PromptRepository, PromptVault,
+    SecretInfo, SecretService, StorageError, VaultStatus,
+};
+use chrono::Utc;
+use std::time::Duration;
+
+/// Idle time after which an unlocked vault locks itself (None = never)
+fn auto_lock_timeout() -> Result<Option<Duration>, StorageError> {
+    let minutes = load_config()?.vault_auto_lock_minutes;
+    Ok((minutes > 0).then(|| Duration::from_secs(minutes as u64 * 60)))
+}
+
+fn unlocked_vault() -> CommandResult<PassphraseVault> {
+    let vault = PassphraseVault::new()?;
+    if !vault.is_unlocked() {
+        return Err(CommandError::new("vault_locked", "Unlock private prompts first"));
+    }
+    Ok(vault)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn get_vault_status() -> CommandResult<VaultStatus> {
+    log::info!("[COMMAND] get_vault_status");
+    Ok(PassphraseVault::new()?.status()?)
+}
+
+/// Set the vault passphrase for the first time, then unlock
+#[tauri::command(rename_all = "snake_case")]
+pub async fn setup_vault(passphrase: String) -> CommandResult<VaultStatus> {
+    log::info!("[COMMAND] setup_vault");
+    let vault = PassphraseVault::new()?;
+    vault.setup(&passphrase)?;
+    vault.unlock(&passphrase, auto_lock_timeout()?)?;
+    Ok(vault.status()?)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn unlock_vault(passphrase: String) -> CommandResult<VaultStatus> {
+    log::info!("[COMMAND] unlock_vault");
+    let vault = PassphraseVault::new()?;
+    vault.unlock(&passphrase, auto_lock_timeout()?)?;
+    Ok(vault.status()?)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn lock_vault() -> CommandResult<VaultStatus> {
+    log::info!("[COMMAND] lock_vault");
+    let vault = PassphraseVault::new()?;
+    vault.lock();
+    Ok(vault.status()?)
+}
+
+/// Encrypt a prompt, or store it in the clear again (the vault must be unlocked)
+/// Prompts in a private folder stay encrypted either way
+#[tauri::command(rename_all = "snake_case")]
+pub async fn set_prompt_private(id: String, private: bool) -> CommandResult<Prompt> {
+    log::info!("[COMMAND] set_prompt_private: id={}, private={}", redact(&id), private);
+    unlocked_vault()?;
+    let repository = FilePromptRepository::new()?;
+    let mut prompt = repository.find_by_id(&PromptId::new(&id))?;
+    prompt.private = private;
+    prompt.updated_at = Utc::now().to_rfc3339();
+    repository.save(&prompt)?;
+    if private {
+        discard_versions(repository.get_prompts_dir(), &id)?;
+    }
+    commit_on_save();
+    Ok(repository.find_by_id(&PromptId::new(&id))?)
+}
+
+/// Mark a folder private, encrypting the prompts already in it, or stop encrypting new saves there
+/// Prompts already encrypted stay private until changed one by one
+#[tauri::command(rename_all = "snake_case")]
+pub async fn set_folder_private(folder: String, private: bool) -> CommandResult<VaultStatus> {
+    log::info!("[COMMAND] set_folder_private: folder={}, private={}", redact(&folder), private);
+    let vault = if private { unlocked_vault()? } else { PassphraseVault::new()? };
+    vault.set_folder_private(&folder, private)?;
+
+    if private {
+        let repository = FilePromptRepository::new()?;
+        let mut encrypted = 0;
+        for mut prompt in repository.find_all()? {
+            if !vault.is_private_folder(&prompt.directory()) {
+                continue;
+            }
+            if !prompt.private {
+                prompt.private = true;
+                repository.save(&prompt)?;
+                encrypted += 1;
+            }
+            discard_versions(repository.get_prompts_dir(), &prompt.id)?;
+        }
+        log::info!("[COMMAND] set_folder_private: encrypted {} prompts", encrypted);
+        commit_on_save();
+    }
+    Ok(vault.status()?)
+}
+
+/// Lock the vault if it has been idle for the auto-lock timeout (used by the background scheduler)
+pub fn run_auto_lock() -> Result<(), String> {
+    // Checking the state applies the timeout
+    PassphraseVault::new()?.is_unlocked();
+    Ok(())
+}
+
+/// Names of stored secrets (values are never returned)
+#[tauri::command(rename_all = "snake_case")]
+pub async fn list_secrets() -> CommandResult<Vec<SecretInfo>> {
+    log::info!("[COMMAND] list_secrets");
+    Ok(SecretService::new(EncryptedSecretStore::new()?).list()?)
+}
+
+/// Create or replace a secret used as `{{secret:name}}` (the vault must be unlocked)
+#[tauri::command(rename_all = "snake_case")]
+pub async fn set_secret(name: String, value: String) -> CommandResult<Vec<SecretInfo>> {
+    log::info!("[COMMAND] set_secret: name={}", redact(&name));
+    let service = SecretService::new(EncryptedSecretStore::new()?);
+    service.set(&name, &value)?;
+    Ok(service.list()?)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn delete_secret(name: String) -> CommandResult<Vec<SecretInfo>> {
+    log::info!("[COMMAND] delete_secret: name={}", redact(&name));
+    let service = SecretService::new(EncryptedSecretStore::new()?);
+    service.delete(&name)?;
+    Ok(service.list()?)
+}
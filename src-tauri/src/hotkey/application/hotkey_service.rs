@@ -0,0 +1,411 @@
+use crate::hotkey::domain::ports::{RegistrationStore, ShortcutRegistrar};
+use crate::hotkey::domain::{
+    check_available, Accelerator, HotkeyError, HotkeyStatus, PromptBinding, PromptHotkey, PromptHotkeyStatus,
+};
+use crate::logging::redact;
+
+/// Hotkey used when the configured one is invalid or taken
+pub const DEFAULT_HOTKEY: &str = "F9";
+
+/// Global hotkey use cases
+/// Orchestrates: release what earlier runs left registered → register the configured
+/// hotkey (falling back to the default) and prompt hotkeys → keep the registration record in step
+pub struct HotkeyService<R: ShortcutRegistrar, S: RegistrationStore> {
+    registrar: R,
+    store: S,
+}
+
+impl<R: ShortcutRegistrar, S: RegistrationStore> HotkeyService<R, S> {
+    pub fn new(registrar: R, store: S) -> Self {
+        Self { registrar, store }
+    }
+
+    /// Release every hotkey recorded by earlier runs, then register `configured`
+    /// Falls back to the default hotkey and reports why if `configured` is invalid or taken
+    pub fn startup(&self, configured: &str) -> HotkeyStatus {
+        self.release_recorded();
+
+        let error = match self.register(configured) {
+            Ok(active) => {
+                return HotkeyStatus {
+                    configured: configured.to_string(),
+                    active: Some(active.to_string()),
+                    error: None,
+                    prompts: Vec::new(),
+                }
+            }
+            Err(e) => e,
+        };
+        log::error!("[HotkeyService] {}", loggable(&error));
+
+        let fallback = if configured == DEFAULT_HOTKEY {
+            None
+        } else {
+            match self.register(DEFAULT_HOTKEY) {
+                Ok(active) => {
+                    log::warn!("[HotkeyService] Fell back to {}", active);
+                    Some(active.to_string())
+                }
+                Err(e) => {
+                    log::error!("[HotkeyService] Fallback failed: {}", loggable(&e));
+                    None
+                }
+            }
+        };
+
+        HotkeyStatus {
+            configured: configured.to_string(),
+            active: fallback,
+            error: Some(error.to_string()),
+            prompts: Vec::new(),
+        }
+    }
+
+    /// Switch the active hotkey to `requested` without a restart
+    /// The new hotkey is registered before the old one is released, so on any
+    /// error `current` stays registered and `requested` does not
+    pub fn change(
+        &self,
+        current: Option<&str>,
+        requested: &str,
+        prompts: &[PromptHotkey],
+    ) -> Result<Accelerator, HotkeyError> {
+        let requested = Accelerator::parse(requested)?;
+        let current = current.and_then(|c| Accelerator::parse(c).ok());
+        if current.as_ref() == Some(&requested) {
+            return Ok(requested);
+        }
+        check_available(&requested, "", None, prompts)?;
+
+        self.registrar.register(&requested)?;
+        if let Err(e) = self.remember(&requested) {
+            // An unrecorded hotkey would outlive a crash; don't keep it registered
+            if let Err(e) = self.registrar.unregister(&requested) {
+                log::warn!("[HotkeyService] Failed to release {}: {}", requested, e);
+            }
+            return Err(e);
+        }
+
+        if let Some(current) = current {
+            if let Err(e) = self.registrar.unregister(&current) {
+                log::warn!("[HotkeyService] Failed to release {}: {}", current, e);
+            }
+            self.forget(&current)?;
+        }
+
+        log::info!("[HotkeyService] Hotkey is now {}", requested);
+        Ok(requested)
+    }
+
+    /// Register the `next` prompt hotkeys in place of `current`, leaving unchanged ones alone
+    /// Returns the bindings now registered and the outcome for each prompt
+    pub fn sync_prompts(
+        &self,
+        current: &[PromptBinding],
+        next: Vec<PromptBinding>,
+    ) -> (Vec<PromptBinding>, Vec<PromptHotkeyStatus>) {
+        for old in current {
+            if next.iter().any(|b| b.accelerator == old.accelerator) {
+                continue;
+            }
+            if let Err(e) = self.registrar.unregister(&old.accelerator) {
+                log::warn!("[HotkeyService] Failed to release {}: {}", old.accelerator, e);
+            }
+            if let Err(e) = self.forget(&old.accelerator) {
+                log::warn!("[HotkeyService] {}", e);
+            }
+        }
+
+        let mut registered = Vec::new();
+        let mut statuses = Vec::new();
+        for binding in next {
+            let result = if current.iter().any(|b| b.accelerator == binding.accelerator) {
+                Ok(())
+            } else {
+                self.registrar
+                    .register(&binding.accelerator)
+                    .and_then(|_| self.remember(&binding.accelerator))
+            };
+            match result {
+                Ok(()) => {
+                    statuses.push(PromptHotkeyStatus::active(&binding));
+                    registered.push(binding);
+                }
+                Err(e) => {
+                    log::warn!("[HotkeyService] Prompt hotkey for {}: {}", redact(&binding.prompt_id), loggable(&e));
+                    statuses.push(PromptHotkeyStatus::failed(&binding.prompt_id, &binding.accelerator.to_string(), &e));
+                }
+            }
+        }
+
+        log::info!("[HotkeyService] {} prompt hotkeys registered", registered.len());
+        (registered, statuses)
+    }
+
+    /// Release every recorded hotkey (on exit)
+    pub fn shutdown(&self) {
+        self.release_recorded();
+    }
+
+    fn register(&self, hotkey: &str) -> Result<Accelerator, HotkeyError> {
+        let accelerator = Accelerator::parse(hotkey)?;
+        self.registrar.register(&accelerator)?;
+        self.remember(&accelerator)?;
+        log::info!("[HotkeyService] Registered global hotkey {}", accelerator);
+        Ok(accelerator)
+    }
+
+    /// Unregister and clear everything in the record; failures are only logged
+    /// (a hotkey from a crashed run is already gone along with its process)
+    fn release_recorded(&self) {
+        let recorded = match self.store.load() {
+            Ok(recorded) => recorded,
+            Err(e) => {
+                log::warn!("[HotkeyService] {}", e);
+                return;
+            }
+        };
+
+        for hotkey in &recorded {
+            match Accelerator::parse(hotkey) {
+                Ok(accelerator) => {
+                    if let Err(e) = self.registrar.unregister(&accelerator) {
+                        log::debug!("[HotkeyService] {} was not registered: {}", accelerator, e);
+                    }
+                }
+                Err(e) => log::warn!("[HotkeyService] Skipping recorded hotkey: {}", e),
+            }
+        }
+
+        if !recorded.is_empty() {
+            log::info!("[HotkeyService] Released {} recorded hotkeys", recorded.len());
+            if let Err(e) = self.store.save(&[]) {
+                log::warn!("[HotkeyService] {}", e);
+            }
+        }
+    }
+
+    fn remember(&self, accelerator: &Accelerator) -> Result<(), HotkeyError> {
+        let mut recorded = self.store.load()?;
+        let hotkey = accelerator.to_string();
+        if !recorded.contains(&hotkey) {
+            recorded.push(hotkey);
+            self.store.save(&recorded)?;
+        }
+        Ok(())
+    }
+
+    fn forget(&self, accelerator: &Accelerator) -> Result<(), HotkeyError> {
+        let mut recorded = self.store.load()?;
+        let hotkey = accelerator.to_string();
+        recorded.retain(|r| *r != hotkey);
+        self.store.save(&recorded)
+    }
+}
+
+/// The error as it may be logged: a duplicate names the prompt that owns the hotkey
+fn loggable(error: &HotkeyError) -> String {
+    match error {
+        HotkeyError::Duplicate { hotkey, owner } => format!("{} is already used by {}", hotkey, redact(owner)),
+        error => error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    /// Registrar where `taken` hotkeys belong to another application
+    #[derive(Default)]
+    struct FakeRegistrar {
+        registered: Mutex<HashSet<String>>,
+        taken: Vec<&'static str>,
+    }
+
+    impl FakeRegistrar {
+        fn taken(taken: Vec<&'static str>) -> Self {
+            Self { taken, ..Default::default() }
+        }
+
+        fn registered(&self) -> Vec<String> {
+            let mut registered: Vec<String> = self.registered.lock().unwrap().iter().cloned().collect();
+            registered.sort();
+            registered
+        }
+    }
+
+    impl ShortcutRegistrar for FakeRegistrar {
+        fn register(&self, accelerator: &Accelerator) -> Result<(), HotkeyError> {
+            let hotkey = accelerator.to_string();
+            if self.taken.contains(&hotkey.as_str()) {
+                return Err(HotkeyError::Conflict { hotkey });
+            }
+            self.registered.lock().unwrap().insert(hotkey);
+            Ok(())
+        }
+
+        fn unregister(&self, accelerator: &Accelerator) -> Result<(), HotkeyError> {
+            let hotkey = accelerator.to_string();
+            if !self.registered.lock().unwrap().remove(&hotkey) {
+                return Err(HotkeyError::Registration { hotkey, reason: "not registered".to_string() });
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryStore(Mutex<Vec<String>>);
+
+    impl RegistrationStore for MemoryStore {
+        fn load(&self) -> Result<Vec<String>, HotkeyError> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+
+        fn save(&self, accelerators: &[String]) -> Result<(), HotkeyError> {
+            *self.0.lock().unwrap() = accelerators.to_vec();
+            Ok(())
+        }
+    }
+
+    /// Store whose record can be read but not written
+    struct ReadOnlyStore;
+
+    impl RegistrationStore for ReadOnlyStore {
+        fn load(&self) -> Result<Vec<String>, HotkeyError> {
+            Ok(Vec::new())
+        }
+
+        fn save(&self, _accelerators: &[String]) -> Result<(), HotkeyError> {
+            Err(HotkeyError::Record("read-only".to_string()))
+        }
+    }
+
+    #[test]
+    fn test_startup_releases_recorded_and_registers_configured() {
+        let registrar = FakeRegistrar::default();
+        registrar.registered.lock().unwrap().insert("F10".to_string());
+        let store = MemoryStore(Mutex::new(vec!["F10".to_string(), "Ctrl+Alt+Space".to_string()]));
+        let service = HotkeyService::new(registrar, store);
+
+        let status = service.startup("ctrl+shift+space");
+
+        assert_eq!(status.active.as_deref(), Some("Ctrl+Shift+Space"));
+        assert_eq!(status.error, None);
+        assert_eq!(service.registrar.registered(), vec!["Ctrl+Shift+Space"]);
+        assert_eq!(service.store.load().unwrap(), vec!["Ctrl+Shift+Space"]);
+    }
+
+    #[test]
+    fn test_startup_falls_back_to_default_on_conflict() {
+        let service = HotkeyService::new(FakeRegistrar::taken(vec!["Ctrl+Space"]), MemoryStore::default());
+
+        let status = service.startup("Ctrl+Space");
+
+        assert_eq!(status.configured, "Ctrl+Space");
+        assert_eq!(status.active.as_deref(), Some(DEFAULT_HOTKEY));
+        assert_eq!(status.error.as_deref(), Some("Ctrl+Space is already in use by another application"));
+    }
+
+    #[test]
+    fn test_startup_reports_invalid_hotkey() {
+        let service = HotkeyService::new(FakeRegistrar::taken(vec!["F9"]), MemoryStore::default());
+
+        let status = service.startup("Ctrl+Nope");
+
+        assert_eq!(status.active, None);
+        assert_eq!(status.error.as_deref(), Some("Invalid hotkey 'Ctrl+Nope': unknown key 'Nope'"));
+    }
+
+    #[test]
+    fn test_change_swaps_registration() {
+        let service = HotkeyService::new(FakeRegistrar::default(), MemoryStore::default());
+        service.startup("F9");
+
+        let active = service.change(Some("F9"), "Alt+Space", &[]).unwrap();
+
+        assert_eq!(active.to_string(), "Alt+Space");
+        assert_eq!(service.registrar.registered(), vec!["Alt+Space"]);
+        assert_eq!(service.store.load().unwrap(), vec!["Alt+Space"]);
+    }
+
+    #[test]
+    fn test_change_keeps_current_hotkey_on_conflict() {
+        let service = HotkeyService::new(FakeRegistrar::taken(vec!["Alt+Space"]), MemoryStore::default());
+        service.startup("F9");
+
+        let error = service.change(Some("F9"), "alt+space", &[]).unwrap_err();
+
+        assert_eq!(error.code(), "hotkey_conflict");
+        assert_eq!(service.registrar.registered(), vec!["F9"]);
+        assert_eq!(service.store.load().unwrap(), vec!["F9"]);
+    }
+
+    #[test]
+    fn test_change_releases_requested_hotkey_when_it_cannot_be_recorded() {
+        let registrar = FakeRegistrar::default();
+        registrar.registered.lock().unwrap().insert("F9".to_string());
+        let service = HotkeyService::new(registrar, ReadOnlyStore);
+
+        let error = service.change(Some("F9"), "Alt+Space", &[]).unwrap_err();
+
+        assert_eq!(error.code(), "hotkey_record_error");
+        assert_eq!(service.registrar.registered(), vec!["F9"]);
+    }
+
+    #[test]
+    fn test_change_rejects_a_prompt_hotkey() {
+        let service = HotkeyService::new(FakeRegistrar::default(), MemoryStore::default());
+        service.startup("F9");
+        let prompts = vec![PromptHotkey {
+            prompt_id: "review.md".to_string(),
+            prompt_name: "Review".to_string(),
+            hotkey: "Ctrl+Alt+1".to_string(),
+        }];
+
+        let error = service.change(Some("F9"), "Ctrl+Alt+1", &prompts).unwrap_err();
+
+        assert_eq!(error.code(), "hotkey_duplicate");
+        assert_eq!(service.registrar.registered(), vec!["F9"]);
+    }
+
+    #[test]
+    fn test_sync_prompts_registers_new_and_releases_removed() {
+        let service = HotkeyService::new(FakeRegistrar::taken(vec!["Ctrl+Alt+3"]), MemoryStore::default());
+        let binding = |id: &str, hotkey: &str| PromptBinding {
+            prompt_id: id.to_string(),
+            accelerator: Accelerator::parse(hotkey).unwrap(),
+        };
+        let (current, _) = service.sync_prompts(&[], vec![binding("a.md", "Ctrl+Alt+1"), binding("b.md", "Ctrl+Alt+2")]);
+
+        let (registered, statuses) =
+            service.sync_prompts(&current, vec![binding("a.md", "Ctrl+Alt+1"), binding("c.md", "Ctrl+Alt+3")]);
+
+        assert_eq!(registered, vec![binding("a.md", "Ctrl+Alt+1")]);
+        assert_eq!(service.registrar.registered(), vec!["Ctrl+Alt+1"]);
+        assert_eq!(service.store.load().unwrap(), vec!["Ctrl+Alt+1"]);
+        assert!(!statuses[1].active);
+        assert_eq!(statuses[1].error.as_deref(), Some("Ctrl+Alt+3 is already in use by another application"));
+    }
+
+    #[test]
+    fn test_shutdown_clears_record() {
+        let service = HotkeyService::new(FakeRegistrar::default(), MemoryStore::default());
+        service.startup("F9");
+
+        service.shutdown();
+
+        assert!(service.registrar.registered().is_empty());
+        assert!(service.store.load().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_loggable_redacts_duplicate_owner() {
+        let duplicate = HotkeyError::Duplicate { hotkey: "Ctrl+Alt+1".to_string(), owner: "Payroll notes".to_string() };
+        assert_eq!(loggable(&duplicate), "Ctrl+Alt+1 is already used by <redacted>");
+
+        let conflict = HotkeyError::Conflict { hotkey: "Ctrl+Alt+1".to_string() };
+        assert_eq!(loggable(&conflict), conflict.to_string());
+    }
+}
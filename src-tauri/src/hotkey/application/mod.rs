@@ -0,0 +1,4 @@
+// Application layer - orchestrates hotkey registration, fallback and the registration record
+pub mod hotkey_service;
+
+pub use hotkey_service::{HotkeyService, DEFAULT_HOTKEY};
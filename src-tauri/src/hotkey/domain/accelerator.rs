@@ -0,0 +1,203 @@
+use crate::hotkey::domain::HotkeyError;
+use std::fmt;
+
+/// Modifier keys, in the order they are written out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Modifier {
+    Ctrl,
+    Alt,
+    Shift,
+    Super,
+}
+
+impl Modifier {
+    fn parse(token: &str) -> Option<Self> {
+        match token.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => Some(Self::Ctrl),
+            "alt" | "option" => Some(Self::Alt),
+            "shift" => Some(Self::Shift),
+            "super" | "win" | "cmd" | "command" | "meta" => Some(Self::Super),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ctrl => "Ctrl",
+            Self::Alt => "Alt",
+            Self::Shift => "Shift",
+            Self::Super => "Super",
+        }
+    }
+}
+
+/// Named keys: (canonical name, accepted aliases)
+const NAMED_KEYS: &[(&str, &[&str])] = &[
+    ("Space", &["space"]),
+    ("Enter", &["enter", "return"]),
+    ("Tab", &["tab"]),
+    ("Escape", &["escape", "esc"]),
+    ("Backspace", &["backspace"]),
+    ("Delete", &["delete", "del"]),
+    ("Insert", &["insert", "ins"]),
+    ("Home", &["home"]),
+    ("End", &["end"]),
+    ("PageUp", &["pageup", "pgup"]),
+    ("PageDown", &["pagedown", "pgdn"]),
+    ("ArrowUp", &["arrowup", "up"]),
+    ("ArrowDown", &["arrowdown", "down"]),
+    ("ArrowLeft", &["arrowleft", "left"]),
+    ("ArrowRight", &["arrowright", "right"]),
+    ("Backquote", &["backquote", "`"]),
+    ("Minus", &["minus", "-"]),
+    ("Equal", &["equal", "="]),
+    ("BracketLeft", &["bracketleft", "["]),
+    ("BracketRight", &["bracketright", "]"]),
+    ("Backslash", &["backslash", "\\"]),
+    ("Semicolon", &["semicolon", ";"]),
+    ("Quote", &["quote", "'"]),
+    ("Comma", &["comma", ","]),
+    ("Period", &["period", "."]),
+    ("Slash", &["slash", "/"]),
+];
+
+/// A global hotkey such as "Ctrl+Shift+Space" or "F9"
+/// Always holds a valid combination; `Display` gives the canonical spelling
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Accelerator {
+    modifiers: Vec<Modifier>,
+    key: String,
+}
+
+impl Accelerator {
+    /// Parse and validate an accelerator string (case-insensitive, '+' separated)
+    pub fn parse(input: &str) -> Result<Self, HotkeyError> {
+        let invalid = |reason: String| HotkeyError::Invalid {
+            hotkey: input.to_string(),
+            reason,
+        };
+
+        let tokens: Vec<&str> = input.split('+').map(str::trim).collect();
+        if tokens.iter().all(|t| t.is_empty()) {
+            return Err(invalid("hotkey is empty".to_string()));
+        }
+        if tokens.iter().any(|t| t.is_empty()) {
+            return Err(invalid("empty key between '+' signs".to_string()));
+        }
+
+        let mut modifiers = Vec::new();
+        let mut key = None;
+        for token in tokens {
+            if let Some(modifier) = Modifier::parse(token) {
+                if modifiers.contains(&modifier) {
+                    return Err(invalid(format!("{} appears twice", modifier.as_str())));
+                }
+                modifiers.push(modifier);
+                continue;
+            }
+            let parsed = parse_key(token).ok_or_else(|| invalid(format!("unknown key '{}'", token)))?;
+            if key.replace(parsed).is_some() {
+                return Err(invalid("only one key besides modifiers is allowed".to_string()));
+            }
+        }
+
+        let key = key.ok_or_else(|| invalid("needs a key besides modifiers".to_string()))?;
+        modifiers.sort();
+
+        // A bare or Shift-only typing key would swallow normal typing system-wide
+        let only_shift = modifiers.iter().all(|m| *m == Modifier::Shift);
+        if only_shift && !is_function_key(&key) {
+            return Err(invalid(format!("{} needs Ctrl, Alt or Super", key)));
+        }
+
+        Ok(Self { modifiers, key })
+    }
+
+    pub fn modifiers(&self) -> &[Modifier] {
+        &self.modifiers
+    }
+
+    /// Canonical key name ("Space", "K", "F9", ...)
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl fmt::Display for Accelerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{}+", modifier.as_str())?;
+        }
+        f.write_str(&self.key)
+    }
+}
+
+fn parse_key(token: &str) -> Option<String> {
+    let lower = token.to_ascii_lowercase();
+
+    let mut chars = lower.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c.is_ascii_alphanumeric() {
+            return Some(c.to_ascii_uppercase().to_string());
+        }
+    }
+
+    if let Some(number) = lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+        if (1..=24).contains(&number) {
+            return Some(format!("F{}", number));
+        }
+    }
+
+    NAMED_KEYS
+        .iter()
+        .find(|(_, aliases)| aliases.contains(&lower.as_str()))
+        .map(|(name, _)| name.to_string())
+}
+
+fn is_function_key(key: &str) -> bool {
+    key.len() > 1 && key.starts_with('F') && key[1..].chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canonical(input: &str) -> String {
+        Accelerator::parse(input).unwrap().to_string()
+    }
+
+    fn reason(input: &str) -> String {
+        match Accelerator::parse(input) {
+            Err(HotkeyError::Invalid { reason, .. }) => reason,
+            other => panic!("expected invalid hotkey for {:?}, got {:?}", input, other),
+        }
+    }
+
+    #[test]
+    fn test_parse_normalizes_case_order_and_aliases() {
+        assert_eq!(canonical("F9"), "F9");
+        assert_eq!(canonical("ctrl+shift+space"), "Ctrl+Shift+Space");
+        assert_eq!(canonical("Shift + Control + k"), "Ctrl+Shift+K");
+        assert_eq!(canonical("Cmd+Alt+`"), "Alt+Super+Backquote");
+        assert_eq!(canonical("Alt+Up"), "Alt+ArrowUp");
+        assert_eq!(canonical("Shift+F12"), "Shift+F12");
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_combinations() {
+        assert_eq!(reason(""), "hotkey is empty");
+        assert_eq!(reason("Ctrl++K"), "empty key between '+' signs");
+        assert_eq!(reason("Ctrl+Hyper+K"), "unknown key 'Hyper'");
+        assert_eq!(reason("F25"), "unknown key 'F25'");
+        assert_eq!(reason("Ctrl+Ctrl+K"), "Ctrl appears twice");
+        assert_eq!(reason("Ctrl+Shift"), "needs a key besides modifiers");
+        assert_eq!(reason("Ctrl+K+L"), "only one key besides modifiers is allowed");
+    }
+
+    #[test]
+    fn test_typing_keys_need_a_real_modifier() {
+        assert_eq!(reason("Space"), "Space needs Ctrl, Alt or Super");
+        assert_eq!(reason("Shift+A"), "A needs Ctrl, Alt or Super");
+        assert!(Accelerator::parse("Alt+Space").is_ok());
+    }
+}
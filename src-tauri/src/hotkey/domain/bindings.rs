@@ -0,0 +1,162 @@
+use crate::hotkey::domain::{Accelerator, HotkeyError};
+use serde::{Deserialize, Serialize};
+
+/// Owner name used when a prompt hotkey collides with the launcher hotkey
+pub const LAUNCHER_OWNER: &str = "the launcher";
+
+/// A prompt's `hotkey` frontmatter field, as read from the library
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptHotkey {
+    pub prompt_id: String,
+    pub prompt_name: String,
+    pub hotkey: String,
+}
+
+/// A prompt hotkey that passed validation and can be registered
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptBinding {
+    pub prompt_id: String,
+    pub accelerator: Accelerator,
+}
+
+/// Registration outcome for one prompt hotkey, reported to the frontend
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptHotkeyStatus {
+    pub prompt_id: String,
+    pub hotkey: String,
+    /// Whether pressing the hotkey pastes the prompt right now
+    pub active: bool,
+    pub error: Option<String>,
+}
+
+impl PromptHotkeyStatus {
+    pub fn active(binding: &PromptBinding) -> Self {
+        Self {
+            prompt_id: binding.prompt_id.clone(),
+            hotkey: binding.accelerator.to_string(),
+            active: true,
+            error: None,
+        }
+    }
+
+    pub fn failed(prompt_id: &str, hotkey: &str, error: &HotkeyError) -> Self {
+        Self {
+            prompt_id: prompt_id.to_string(),
+            hotkey: hotkey.to_string(),
+            active: false,
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+/// Business rule: a hotkey belongs to the launcher or to one prompt, never both
+/// Fails with `HotkeyError::Duplicate` naming the current owner
+pub fn check_available(
+    accelerator: &Accelerator,
+    prompt_id: &str,
+    launcher: Option<&Accelerator>,
+    prompts: &[PromptHotkey],
+) -> Result<(), HotkeyError> {
+    let duplicate = |owner: &str| HotkeyError::Duplicate {
+        hotkey: accelerator.to_string(),
+        owner: owner.to_string(),
+    };
+
+    if launcher == Some(accelerator) {
+        return Err(duplicate(LAUNCHER_OWNER));
+    }
+    let owner = prompts.iter().find(|p| {
+        p.prompt_id != prompt_id && Accelerator::parse(&p.hotkey).is_ok_and(|a| a == *accelerator)
+    });
+    match owner {
+        Some(owner) => Err(duplicate(&owner.prompt_name)),
+        None => Ok(()),
+    }
+}
+
+/// Business rule: turn the library's prompt hotkeys into bindings
+/// Invalid hotkeys and hotkeys taken by the launcher are rejected; when prompts share
+/// a hotkey the first by ID keeps it, so the outcome does not depend on load order
+pub fn plan_bindings(
+    launcher: Option<&Accelerator>,
+    prompts: &[PromptHotkey],
+) -> (Vec<PromptBinding>, Vec<PromptHotkeyStatus>) {
+    let mut sorted: Vec<&PromptHotkey> = prompts.iter().collect();
+    sorted.sort_by(|a, b| a.prompt_id.cmp(&b.prompt_id));
+
+    let mut bindings: Vec<PromptBinding> = Vec::new();
+    let mut accepted: Vec<PromptHotkey> = Vec::new();
+    let mut rejected = Vec::new();
+    for prompt in sorted {
+        let result = Accelerator::parse(&prompt.hotkey)
+            .and_then(|a| check_available(&a, &prompt.prompt_id, launcher, &accepted).map(|_| a));
+        match result {
+            Ok(accelerator) => {
+                accepted.push(prompt.clone());
+                bindings.push(PromptBinding {
+                    prompt_id: prompt.prompt_id.clone(),
+                    accelerator,
+                });
+            }
+            Err(e) => rejected.push(PromptHotkeyStatus::failed(&prompt.prompt_id, &prompt.hotkey, &e)),
+        }
+    }
+    (bindings, rejected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prompt_hotkey(id: &str, hotkey: &str) -> PromptHotkey {
+        PromptHotkey {
+            prompt_id: id.to_string(),
+            prompt_name: id.trim_end_matches(".md").to_string(),
+            hotkey: hotkey.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_check_available_names_the_owner() {
+        let launcher = Accelerator::parse("F9").unwrap();
+        let prompts = vec![prompt_hotkey("review.md", "ctrl+alt+1")];
+        let taken = Accelerator::parse("Ctrl+Alt+1").unwrap();
+
+        let error = check_available(&launcher, "notes.md", Some(&launcher), &prompts).unwrap_err();
+        assert_eq!(error.to_string(), "F9 is already used by the launcher");
+
+        let error = check_available(&taken, "notes.md", Some(&launcher), &prompts).unwrap_err();
+        assert_eq!(error.to_string(), "Ctrl+Alt+1 is already used by review");
+
+        // A prompt keeping its own hotkey is not a duplicate
+        assert!(check_available(&taken, "review.md", Some(&launcher), &prompts).is_ok());
+    }
+
+    #[test]
+    fn test_plan_bindings_rejects_invalid_launcher_and_duplicate_hotkeys() {
+        let launcher = Accelerator::parse("Ctrl+Space").unwrap();
+        let prompts = vec![
+            prompt_hotkey("b.md", "Ctrl+Alt+1"),
+            prompt_hotkey("a.md", "ctrl+alt+1"),
+            prompt_hotkey("c.md", "Ctrl+Space"),
+            prompt_hotkey("d.md", "Q"),
+            prompt_hotkey("e.md", "Ctrl+Alt+2"),
+        ];
+
+        let (bindings, rejected) = plan_bindings(Some(&launcher), &prompts);
+
+        let bound: Vec<(&str, String)> = bindings
+            .iter()
+            .map(|b| (b.prompt_id.as_str(), b.accelerator.to_string()))
+            .collect();
+        assert_eq!(
+            bound,
+            vec![("a.md", "Ctrl+Alt+1".to_string()), ("e.md", "Ctrl+Alt+2".to_string())]
+        );
+
+        let rejected_ids: Vec<&str> = rejected.iter().map(|r| r.prompt_id.as_str()).collect();
+        assert_eq!(rejected_ids, vec!["b.md", "c.md", "d.md"]);
+        assert_eq!(rejected[0].error.as_deref(), Some("Ctrl+Alt+1 is already used by a"));
+        assert!(rejected.iter().all(|r| !r.active));
+    }
+}
@@ -0,0 +1,44 @@
+use thiserror::Error;
+
+/// Errors returned by hotkey parsing and registration
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum HotkeyError {
+    #[error("Invalid hotkey '{hotkey}': {reason}")]
+    Invalid { hotkey: String, reason: String },
+
+    #[error("{hotkey} is already in use by another application")]
+    Conflict { hotkey: String },
+
+    #[error("{hotkey} is already used by {owner}")]
+    Duplicate { hotkey: String, owner: String },
+
+    #[error("Failed to register {hotkey}: {reason}")]
+    Registration { hotkey: String, reason: String },
+
+    #[error("Hotkey record error: {0}")]
+    Record(String),
+}
+
+impl HotkeyError {
+    /// Stable machine-readable code sent to the frontend
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid { .. } => "invalid_hotkey",
+            Self::Conflict { .. } => "hotkey_conflict",
+            Self::Duplicate { .. } => "hotkey_duplicate",
+            Self::Registration { .. } => "hotkey_registration_failed",
+            Self::Record(_) => "hotkey_record_error",
+        }
+    }
+
+    /// The hotkey the error is about, when known
+    pub fn details(&self) -> Option<String> {
+        match self {
+            Self::Invalid { hotkey, .. }
+            | Self::Conflict { hotkey }
+            | Self::Duplicate { hotkey, .. }
+            | Self::Registration { hotkey, .. } => Some(hotkey.clone()),
+            Self::Record(_) => None,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// Domain layer - accelerator rules, registration status and ports
+pub mod accelerator;
+pub mod bindings;
+pub mod errors;
+pub mod ports;
+
+pub use accelerator::Accelerator;
+pub use bindings::{check_available, plan_bindings, PromptBinding, PromptHotkey, PromptHotkeyStatus};
+pub use errors::HotkeyError;
+
+use serde::{Deserialize, Serialize};
+
+/// Which hotkey is registered, and why it differs from the settings if it does
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HotkeyStatus {
+    /// Hotkey from the settings
+    pub configured: String,
+    /// Hotkey actually registered (None if nothing could be registered)
+    pub active: Option<String>,
+    /// Why the configured hotkey is not the active one
+    pub error: Option<String>,
+    /// Per-prompt hotkeys that paste without opening the launcher
+    #[serde(default)]
+    pub prompts: Vec<PromptHotkeyStatus>,
+}
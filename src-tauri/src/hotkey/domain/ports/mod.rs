@@ -0,0 +1,6 @@
+// Domain ports (interfaces) - infrastructure layer will implement these
+pub mod shortcut_registrar;
+pub mod registration_store;
+
+pub use shortcut_registrar::ShortcutRegistrar;
+pub use registration_store::RegistrationStore;
@@ -0,0 +1,11 @@
+use crate::hotkey::domain::HotkeyError;
+
+/// Record of the hotkeys this install has registered (Port)
+/// Survives crashes, so the next start can release whatever was left behind
+pub trait RegistrationStore: Send + Sync {
+    /// Accelerators recorded as registered, in their canonical form
+    fn load(&self) -> Result<Vec<String>, HotkeyError>;
+
+    /// Replace the record
+    fn save(&self, accelerators: &[String]) -> Result<(), HotkeyError>;
+}
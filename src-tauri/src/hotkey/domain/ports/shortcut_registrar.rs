@@ -0,0 +1,11 @@
+use crate::hotkey::domain::{Accelerator, HotkeyError};
+
+/// System-wide shortcut registration interface (Port)
+/// Infrastructure layer will implement this trait
+pub trait ShortcutRegistrar: Send + Sync {
+    /// Register a shortcut; `HotkeyError::Conflict` if another application holds it
+    fn register(&self, accelerator: &Accelerator) -> Result<(), HotkeyError>;
+
+    /// Release a shortcut registered by this process
+    fn unregister(&self, accelerator: &Accelerator) -> Result<(), HotkeyError>;
+}
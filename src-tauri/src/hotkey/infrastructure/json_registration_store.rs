@@ -0,0 +1,55 @@
+use crate::hotkey::domain::ports::RegistrationStore;
+use crate::hotkey::domain::HotkeyError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const RECORD_FILE_NAME: &str = "hotkeys.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RegistrationRecord {
+    registered: Vec<String>,
+}
+
+/// Hotkeys registered by this install, stored as JSON in the data directory (~/.prompter/hotkeys.json)
+pub struct JsonRegistrationStore {
+    path: PathBuf,
+}
+
+impl JsonRegistrationStore {
+    pub fn new() -> Result<Self, HotkeyError> {
+        let home = dirs::home_dir().ok_or_else(|| HotkeyError::Record("Could not determine home directory".to_string()))?;
+        Ok(Self::with_path(home.join(".prompter").join(RECORD_FILE_NAME)))
+    }
+
+    pub fn with_path(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl RegistrationStore for JsonRegistrationStore {
+    fn load(&self) -> Result<Vec<String>, HotkeyError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = fs::read_to_string(&self.path)
+            .map_err(|e| HotkeyError::Record(format!("Failed to read hotkey record: {}", e)))?;
+        serde_json::from_str::<RegistrationRecord>(&content)
+            .map(|record| record.registered)
+            .map_err(|e| HotkeyError::Record(format!("Failed to parse hotkey record: {}", e)))
+    }
+
+    fn save(&self, accelerators: &[String]) -> Result<(), HotkeyError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| HotkeyError::Record(format!("Failed to create data directory: {}", e)))?;
+        }
+        let record = RegistrationRecord {
+            registered: accelerators.to_vec(),
+        };
+        let content = serde_json::to_string_pretty(&record)
+            .map_err(|e| HotkeyError::Record(format!("Failed to serialize hotkey record: {}", e)))?;
+        fs::write(&self.path, content)
+            .map_err(|e| HotkeyError::Record(format!("Failed to write hotkey record: {}", e)))
+    }
+}
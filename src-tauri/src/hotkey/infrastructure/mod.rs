@@ -0,0 +1,6 @@
+// Infrastructure layer - Tauri global shortcut and registration record adapters
+pub mod tauri_registrar;
+pub mod json_registration_store;
+
+pub use tauri_registrar::{to_shortcut, TauriShortcutRegistrar};
+pub use json_registration_store::JsonRegistrationStore;
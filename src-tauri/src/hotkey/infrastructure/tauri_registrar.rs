@@ -0,0 +1,55 @@
+use crate::hotkey::domain::ports::ShortcutRegistrar;
+use crate::hotkey::domain::{Accelerator, HotkeyError};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+
+/// Convert a validated accelerator into the plugin's shortcut type
+pub fn to_shortcut(accelerator: &Accelerator) -> Result<Shortcut, HotkeyError> {
+    accelerator
+        .to_string()
+        .parse::<Shortcut>()
+        .map_err(|e| HotkeyError::Invalid {
+            hotkey: accelerator.to_string(),
+            reason: e.to_string(),
+        })
+}
+
+/// Tauri implementation of ShortcutRegistrar (Adapter)
+/// Key presses are routed by the handler given to the global shortcut plugin in lib.rs
+pub struct TauriShortcutRegistrar {
+    app_handle: tauri::AppHandle,
+}
+
+impl TauriShortcutRegistrar {
+    pub fn new(app_handle: tauri::AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+impl ShortcutRegistrar for TauriShortcutRegistrar {
+    fn register(&self, accelerator: &Accelerator) -> Result<(), HotkeyError> {
+        let shortcut = to_shortcut(accelerator)?;
+        let global_shortcut = self.app_handle.global_shortcut();
+        if global_shortcut.is_registered(shortcut) {
+            return Ok(());
+        }
+
+        // The OS refuses a registration when another application already owns the combination
+        global_shortcut.register(shortcut).map_err(|e| {
+            log::warn!("[Hotkey] Registering {} failed: {}", accelerator, e);
+            HotkeyError::Conflict {
+                hotkey: accelerator.to_string(),
+            }
+        })
+    }
+
+    fn unregister(&self, accelerator: &Accelerator) -> Result<(), HotkeyError> {
+        let shortcut = to_shortcut(accelerator)?;
+        self.app_handle
+            .global_shortcut()
+            .unregister(shortcut)
+            .map_err(|e| HotkeyError::Registration {
+                hotkey: accelerator.to_string(),
+                reason: e.to_string(),
+            })
+    }
+}
@@ -0,0 +1,18 @@
+// Hotkey module - Hexagonal Architecture
+// - domain/ (Accelerator parsing and validation, prompt bindings with duplicate detection,
+//   HotkeyStatus, HotkeyError; ShortcutRegistrar and RegistrationStore ports)
+// - application/ (HotkeyService: startup cleanup, live re-registration, fallback,
+//   prompt hotkey registration)
+// - infrastructure/ (TauriShortcutRegistrar, JsonRegistrationStore)
+
+pub mod domain;
+pub mod application;
+pub mod infrastructure;
+
+// Re-export types used by commands layer
+pub use domain::{
+    check_available, plan_bindings, Accelerator, HotkeyError, HotkeyStatus, PromptBinding, PromptHotkey,
+    PromptHotkeyStatus,
+};
+pub use application::{HotkeyService, DEFAULT_HOTKEY};
+pub use infrastructure::{to_shortcut, JsonRegistrationStore, TauriShortcutRegistrar};
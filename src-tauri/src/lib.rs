@@ -2,36 +2,25 @@
 mod commands;
 mod storage;
 mod os;
+mod backup;
+mod sync;
+mod logging;
+mod hotkey;
 
-use tauri::{Emitter, Manager, RunEvent, WindowEvent};
+use tauri::{Manager, RunEvent, WindowEvent};
 use tauri::menu::{Menu, MenuItem};
 use tauri::tray::{TrayIconBuilder, MouseButton, MouseButtonState};
-use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut};
-use os::infrastructure::WindowsFocusTracker;
-use os::domain::ports::WindowManager;
-use std::sync::Arc;
+use tauri_plugin_global_shortcut::ShortcutState;
+use os::infrastructure::window_manager;
 
-/// The ONE hotkey for Prompter - F9
-/// Professional apps use a single, memorable hotkey rather than fallback chains
-const PROMPTER_HOTKEY: Code = Code::F9;
-const PROMPTER_HOTKEY_NAME: &str = "F9";
+/// How often the backup scheduler checks whether a backup is due
+const BACKUP_CHECK_INTERVAL_SECS: u64 = 15 * 60;
 
-/// Legacy hotkeys that may have been registered by previous versions
-/// We unregister ALL of these on startup to ensure clean state
-fn get_legacy_hotkeys() -> Vec<Shortcut> {
-    vec![
-        Shortcut::new(None, Code::F9),
-        Shortcut::new(None, Code::F10),
-        Shortcut::new(None, Code::F11),
-        Shortcut::new(None, Code::F12),
-        Shortcut::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Space),
-        Shortcut::new(Some(Modifiers::CONTROL | Modifiers::ALT), Code::Space),
-        Shortcut::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyK),
-        Shortcut::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::Semicolon),
-        Shortcut::new(Some(Modifiers::ALT), Code::KeyP),
-        Shortcut::new(Some(Modifiers::CONTROL), Code::Backquote),
-    ]
-}
+/// How often the sync scheduler checks whether a sync is due
+const SYNC_CHECK_INTERVAL_SECS: u64 = 60;
+
+/// How often the vault is checked for the auto-lock timeout
+const VAULT_CHECK_INTERVAL_SECS: u64 = 30;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -47,7 +36,15 @@ pub fn run() {
     }))
     .plugin(tauri_plugin_shell::init())
     .plugin(tauri_plugin_clipboard_manager::init())
-    .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+    .plugin(
+      tauri_plugin_global_shortcut::Builder::new()
+        .with_handler(|app, shortcut, event| {
+          if event.state == ShortcutState::Pressed {
+            commands::hotkey::handle_shortcut(app, shortcut);
+          }
+        })
+        .build(),
+    )
     .plugin(tauri_plugin_autostart::init(
       tauri_plugin_autostart::MacosLauncher::LaunchAgent,
       Some(vec!["--hidden"]),
@@ -83,9 +80,37 @@ pub fn run() {
       // Configuration
       commands::prompts::get_config,
       commands::prompts::update_config,
+      commands::hotkey::get_hotkey_status,
       // Import/Export
       commands::prompts::export_prompt,
       commands::prompts::import_prompt,
+      commands::library::import_library,
+      commands::library::export_library,
+      // Maintenance
+      commands::maintenance::run_maintenance,
+      commands::maintenance::check_library,
+      // Backups
+      commands::backup::list_backups,
+      commands::backup::create_backup,
+      commands::backup::restore_backup,
+      // Sync (git or shared folder)
+      commands::sync::get_sync_status,
+      commands::sync::sync_now,
+      commands::sync::get_sync_conflicts,
+      commands::sync::resolve_sync_conflict,
+      commands::sync::abort_sync,
+      commands::sync::get_folder_sync_status,
+      commands::sync::sync_folder_now,
+      // Private prompts
+      commands::vault::get_vault_status,
+      commands::vault::setup_vault,
+      commands::vault::unlock_vault,
+      commands::vault::lock_vault,
+      commands::vault::set_prompt_private,
+      commands::vault::set_folder_private,
+      commands::vault::list_secrets,
+      commands::vault::set_secret,
+      commands::vault::delete_secret,
       // Clipboard and window management
       commands::clipboard::copy_and_paste,
       commands::clipboard::show_window,
@@ -101,13 +126,11 @@ pub fn run() {
       commands::clipboard::is_autostart_enabled,
     ])
     .setup(|app| {
-      if cfg!(debug_assertions) {
-        app.handle().plugin(
-          tauri_plugin_log::Builder::default()
-            .level(log::LevelFilter::Info)
-            .build(),
-        )?;
-      }
+      // Rotating log file in every build; prompt text stays out of it unless enabled in settings
+      app.handle().plugin(logging::plugin())?;
+      logging::set_log_prompt_content(
+        commands::prompts::load_config().map(|c| c.log_prompt_content).unwrap_or(false),
+      );
 
       // Create system tray icon with menu
       let show_item = MenuItem::with_id(app, "show", "Show Prompter", true, None::<&str>)?;
@@ -117,7 +140,7 @@ pub fn run() {
       let menu = Menu::with_items(app, &[&show_item, &new_prompt_item, &settings_item, &quit_item])?;
 
       let app_handle = app.handle().clone();
-      let wm_for_tray = Arc::new(WindowsFocusTracker::new());
+      let wm_for_tray = window_manager();
       let wm_clone = wm_for_tray.clone();
 
       let _tray = TrayIconBuilder::new()
@@ -197,48 +220,57 @@ pub fn run() {
         })
         .build(app)?;
 
-      // CLEANUP: Unregister ALL legacy hotkeys from previous versions
-      // This ensures a clean slate and prevents hotkey conflicts
-      log::info!("Cleaning up legacy hotkeys...");
-      for legacy_shortcut in get_legacy_hotkeys() {
-        let _ = app.global_shortcut().unregister(legacy_shortcut);
-      }
-      log::info!("Legacy hotkey cleanup complete");
-
-      // Register the ONE hotkey: F9
-      let app_handle = app.handle().clone();
-      let window_manager = Arc::new(WindowsFocusTracker::new());
-      let shortcut = Shortcut::new(None, PROMPTER_HOTKEY);
-
-      // Set up the hotkey handler
-      let handle_clone = app_handle.clone();
-      let wm_clone = window_manager.clone();
+      // Release hotkeys left registered by earlier runs, then register the configured one
+      // and the prompts' own hotkeys; the launcher hotkey falls back to F9 (reported via
+      // get_hotkey_status) if it is invalid or taken
+      commands::hotkey::register_configured_hotkey(app.handle());
 
-      if let Err(e) = app.global_shortcut().on_shortcut(shortcut, move |_app, _shortcut, _event| {
-        if let Some(window) = handle_clone.get_webview_window("main") {
-          // Remember current window before showing Prompter
-          let _ = wm_clone.remember_current_window();
+      // Watch for prompt abbreviations typed in other apps (only if any prompt has one)
+      commands::text_expansion::start_text_expansion(app.handle());
 
-          // Show and focus window
-          let _ = window.show();
-          let _ = window.set_focus();
+      // Carry usage/version data over to prompts renamed outside the app; orphans are only
+      // pruned by an explicit run_maintenance
+      std::thread::spawn(|| {
+        if let Err(e) = commands::maintenance::reassociate_library() {
+          log::warn!("Startup maintenance failed: {} ({})", e.code(), logging::redact(&e));
+        }
+      });
 
-          // Emit event to frontend to focus search input
-          let _ = window.emit("focus-search", ());
+      // Scheduled backups: check periodically so interval/enabled changes apply without restart
+      tauri::async_runtime::spawn(async {
+        loop {
+          match tauri::async_runtime::spawn_blocking(commands::backup::run_scheduled_backup).await {
+            Ok(Err(e)) => log::warn!("Scheduled backup failed: {}", e),
+            Err(e) => log::warn!("Scheduled backup task panicked: {}", e),
+            Ok(Ok(_)) => {}
+          }
+          tokio::time::sleep(std::time::Duration::from_secs(BACKUP_CHECK_INTERVAL_SECS)).await;
         }
-      }) {
-        log::error!("Failed to set up hotkey handler for {}: {}", PROMPTER_HOTKEY_NAME, e);
-      }
+      });
 
-      // Register the hotkey
-      match app.global_shortcut().register(shortcut) {
-        Ok(_) => {
-          log::info!("Successfully registered global hotkey: {}", PROMPTER_HOTKEY_NAME);
+      // Sync (git or shared folder) on the configured interval while sync is enabled
+      tauri::async_runtime::spawn(async {
+        loop {
+          match tauri::async_runtime::spawn_blocking(commands::sync::run_scheduled_sync).await {
+            Ok(Err(e)) => log::warn!("Scheduled sync failed: {}", e),
+            Err(e) => log::warn!("Scheduled sync task panicked: {}", e),
+            Ok(Ok(())) => {}
+          }
+          tokio::time::sleep(std::time::Duration::from_secs(SYNC_CHECK_INTERVAL_SECS)).await;
         }
-        Err(e) => {
-          log::error!("Failed to register hotkey {}: {}. Use the system tray icon instead.", PROMPTER_HOTKEY_NAME, e);
+      });
+
+      // Auto-lock: wipe the vault key from memory once it has been idle long enough
+      tauri::async_runtime::spawn(async {
+        loop {
+          match tauri::async_runtime::spawn_blocking(commands::vault::run_auto_lock).await {
+            Ok(Err(e)) => log::warn!("Vault auto-lock check failed: {}", e),
+            Err(e) => log::warn!("Vault auto-lock task panicked: {}", e),
+            Ok(Ok(())) => {}
+          }
+          tokio::time::sleep(std::time::Duration::from_secs(VAULT_CHECK_INTERVAL_SECS)).await;
         }
-      }
+      });
 
       // App starts minimized to tray. Use hotkey or tray icon to show.
       // Note: No HWND registration needed - we use process-based detection to distinguish
@@ -251,15 +283,11 @@ pub fn run() {
     .expect("error while building tauri application")
     .run(|app_handle, event| {
       match event {
-        // Clean up hotkey on app exit
+        // Clean up hotkeys and the keyboard hook on app exit
         RunEvent::ExitRequested { .. } | RunEvent::Exit => {
-          log::info!("App exiting - unregistering hotkey...");
-          let shortcut = Shortcut::new(None, PROMPTER_HOTKEY);
-          if let Err(e) = app_handle.global_shortcut().unregister(shortcut) {
-            log::warn!("Failed to unregister hotkey on exit: {}", e);
-          } else {
-            log::info!("Hotkey {} unregistered successfully", PROMPTER_HOTKEY_NAME);
-          }
+          log::info!("App exiting - unregistering hotkeys...");
+          commands::hotkey::release_hotkeys(app_handle);
+          commands::text_expansion::stop_text_expansion();
         }
         // Handle window events to track focus changes
         RunEvent::WindowEvent { label, event: window_event, .. } => {
@@ -267,10 +295,10 @@ pub fn run() {
           // Only update saved HWND if focus goes to an EXTERNAL app (not another Prompter window)
           if let WindowEvent::Focused(false) = window_event {
             let window_label = label.clone();
-            // Small delay to let Windows update the foreground window
+            // Small delay to let the OS update the foreground window
             std::thread::spawn(move || {
               std::thread::sleep(std::time::Duration::from_millis(50));
-              let wm = WindowsFocusTracker::new();
+              let wm = window_manager();
               match wm.remember_if_external() {
                 Ok(true) => {
                   log::info!("Updated saved window on {} blur (external focus change)", window_label);
@@ -0,0 +1,82 @@
+// Logging policy - what may reach the log and where the log goes
+// - Prompt content, names, ids and clipboard text are wrapped in `redact()` and print as
+//   `<redacted>` unless `log_prompt_content` is switched on in the config
+// - Release builds write a size-rotated file in the app log directory; debug builds also log to stdout
+
+use log::LevelFilter;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::plugin::TauriPlugin;
+use tauri::Runtime;
+use tauri_plugin_log::{RotationStrategy, Target, TargetKind};
+
+/// Placeholder written in place of redacted values
+pub const REDACTED: &str = "<redacted>";
+
+/// Rotate the log file once it reaches 1 MB
+const MAX_LOG_FILE_BYTES: u128 = 1024 * 1024;
+
+/// Rotated log files kept next to the current one
+const KEPT_LOG_FILES: usize = 5;
+
+static LOG_PROMPT_CONTENT: AtomicBool = AtomicBool::new(false);
+
+/// Allow prompt content and names in the log (the `log_prompt_content` config flag)
+pub fn set_log_prompt_content(enabled: bool) {
+    LOG_PROMPT_CONTENT.store(enabled, Ordering::Relaxed);
+}
+
+pub fn log_prompt_content() -> bool {
+    LOG_PROMPT_CONTENT.load(Ordering::Relaxed)
+}
+
+/// A value that only reaches the log when prompt content logging is on
+pub struct Redacted<T>(T);
+
+impl<T: fmt::Display> fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if log_prompt_content() {
+            self.0.fmt(f)
+        } else {
+            f.write_str(REDACTED)
+        }
+    }
+}
+
+/// Wrap prompt content, names, ids or paths before passing them to a log macro
+pub fn redact<T: fmt::Display>(value: T) -> Redacted<T> {
+    Redacted(value)
+}
+
+/// Log plugin with the rotating file target (and stdout in debug builds)
+pub fn plugin<R: Runtime>() -> TauriPlugin<R> {
+    let mut builder = tauri_plugin_log::Builder::new()
+        .clear_targets()
+        .target(Target::new(TargetKind::LogDir { file_name: None }))
+        .max_file_size(MAX_LOG_FILE_BYTES)
+        .rotation_strategy(RotationStrategy::KeepSome(KEPT_LOG_FILES))
+        .level(LevelFilter::Info);
+    if cfg!(debug_assertions) {
+        // Step-by-step paste and parse logs from our own crate only
+        builder = builder
+            .target(Target::new(TargetKind::Stdout))
+            .level_for("prompter_lib", LevelFilter::Debug);
+    }
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_hides_value_unless_content_logging_is_on() {
+        assert_eq!(format!("{}", redact("secret prompt")), REDACTED);
+
+        set_log_prompt_content(true);
+        let shown = format!("{}", redact("secret prompt"));
+        set_log_prompt_content(false);
+
+        assert_eq!(shown, "secret prompt");
+    }
+}
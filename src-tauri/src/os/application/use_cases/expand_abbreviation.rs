@@ -0,0 +1,64 @@
+use crate::os::domain::ports::{InputSimulator, KeyCode, KeyEvent, WindowManager};
+use crate::os::domain::{Abbreviation, KeystrokeBuffer, OsError};
+use crate::storage::TargetApp;
+use std::sync::Arc;
+
+/// Expand abbreviation use case
+/// Orchestrates: Watch keystrokes → Match trigger → Check app exclusions → Erase trigger
+/// Pasting the prompt is left to the caller, which owns rendering
+pub struct ExpandAbbreviationUseCase {
+    window_manager: Arc<dyn WindowManager>,
+    input_simulator: Arc<dyn InputSimulator>,
+    abbreviations: Vec<Abbreviation>,
+    excluded_apps: Vec<TargetApp>,
+    buffer: KeystrokeBuffer,
+}
+
+impl ExpandAbbreviationUseCase {
+    pub fn new(
+        window_manager: Arc<dyn WindowManager>,
+        input_simulator: Arc<dyn InputSimulator>,
+        abbreviations: Vec<Abbreviation>,
+        excluded_apps: Vec<TargetApp>,
+    ) -> Self {
+        Self {
+            window_manager,
+            input_simulator,
+            abbreviations,
+            excluded_apps,
+            buffer: KeystrokeBuffer::new(),
+        }
+    }
+
+    /// Replace the abbreviations and exclusions after the library or settings changed
+    pub fn configure(&mut self, abbreviations: Vec<Abbreviation>, excluded_apps: Vec<TargetApp>) {
+        self.abbreviations = abbreviations;
+        self.excluded_apps = excluded_apps;
+        self.buffer.clear();
+    }
+
+    /// Feed one keystroke; returns the abbreviation to expand, if any
+    /// On a match the focused window is remembered so the paste lands in it
+    pub fn handle(&mut self, event: KeyEvent) -> Result<Option<Abbreviation>, OsError> {
+        let Some(abbreviation) = self.buffer.feed(event, &self.abbreviations) else {
+            return Ok(None);
+        };
+
+        self.window_manager.remember_current_window()?;
+        let app = self.window_manager.remembered_app().map(TargetApp::new);
+        if app.is_some_and(|app| self.excluded_apps.contains(&app)) {
+            log::debug!("ExpandAbbreviationUseCase: Skipping expansion in excluded app");
+            self.window_manager.clear_saved_window();
+            return Ok(None);
+        }
+        Ok(Some(abbreviation))
+    }
+
+    /// Delete the typed trigger with one Backspace per character
+    pub fn erase(&self, abbreviation: &Abbreviation) -> Result<(), OsError> {
+        for _ in abbreviation.trigger.chars() {
+            self.input_simulator.simulate_keys(&[KeyCode::Backspace])?;
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,10 @@
 // Application layer use cases
+pub mod expand_abbreviation;
 pub mod paste_prompt;
+pub mod run_macro;
 pub mod show_window;
 
+pub use expand_abbreviation::ExpandAbbreviationUseCase;
 pub use paste_prompt::PastePromptUseCase;
+pub use run_macro::RunMacroUseCase;
 pub use show_window::ShowWindowUseCase;
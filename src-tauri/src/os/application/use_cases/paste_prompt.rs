@@ -1,13 +1,30 @@
-use crate::os::domain::ports::{ClipboardService, InputSimulator, WindowManager};
-use crate::os::domain::CopyPasteResult;
+use crate::os::domain::ports::{ClipboardService, InputSimulator, KeyCode, PasteStrategy, WindowManager};
+use crate::os::domain::{ClipboardContent, CopyPasteResult, OsError, PasteProfile, PasteReason};
 use std::sync::Arc;
+use std::time::Duration;
+
+/// Wait before pasting when no profile applies
+/// 100ms is needed for Electron apps (VS Code, ChatGPT desktop) which have
+/// complex window hierarchies and slower focus handling than native apps.
+/// macOS PromptLight uses 50ms but Windows needs more time for Electron.
+pub(super) const DEFAULT_PRE_PASTE_DELAY: Duration = Duration::from_millis(100);
+
+/// Most keys typed between two focus checks; lines end a chunk early
+const TYPE_CHUNK_KEYS: usize = 64;
 
 /// Paste prompt use case
-/// Orchestrates: Copy → Hide → Restore focus → Paste
+/// Orchestrates: Copy → Check target → Restore focus → Paste → Verify (→ Restore previous clipboard)
+/// or, for apps that ignore clipboard paste: Check target → Restore focus → Type → Verify
 pub struct PastePromptUseCase {
     clipboard: Arc<dyn ClipboardService>,
     window_manager: Arc<dyn WindowManager>,
     input_simulator: Arc<dyn InputSimulator>,
+    /// Put the previous clipboard text back this long after a paste
+    restore_clipboard_after: Option<Duration>,
+    /// Copy markdown as HTML/RTF as well as plain text
+    rich_text: bool,
+    /// How the target app wants to be pasted into, and the pause between typed keys
+    profile: Option<(PasteProfile, Duration)>,
 }
 
 impl PastePromptUseCase {
@@ -20,29 +37,61 @@ impl PastePromptUseCase {
             clipboard,
             window_manager,
             input_simulator,
+            restore_clipboard_after: None,
+            rich_text: false,
+            profile: None,
         }
     }
 
-    pub async fn execute(&self, text: &str, auto_paste: bool) -> Result<CopyPasteResult, String> {
-        log::info!("PastePromptUseCase: Starting (auto_paste={})", auto_paste);
+    /// Paste as the target app's profile says (chord, delays, strategy order, post-paste keys)
+    /// instead of trying every strategy with Ctrl+V; `type_interval` paces the type-out strategy
+    pub fn with_profile(mut self, profile: PasteProfile, type_interval: Duration) -> Self {
+        self.profile = Some((profile, type_interval));
+        self
+    }
+
+    /// Also copy the text rendered from markdown to HTML and RTF, for rich editors
+    pub fn with_rich_text(mut self) -> Self {
+        self.rich_text = true;
+        self
+    }
+
+    /// Restore the user's clipboard text `delay` after a paste was sent, so a paste doesn't
+    /// permanently replace what they had copied (or leave resolved secrets on the clipboard)
+    pub fn with_clipboard_restore(mut self, delay: Duration) -> Self {
+        self.restore_clipboard_after = Some(delay);
+        self
+    }
+
+    pub async fn execute(&self, text: &str, auto_paste: bool) -> Result<CopyPasteResult, OsError> {
+        log::debug!("PastePromptUseCase: Starting (auto_paste={})", auto_paste);
+
+        // Step 0: Snapshot the clipboard if it is to be restored after pasting
+        // Only text can be restored; images and files on the clipboard read as errors
+        let snapshot = match self.restore_clipboard_after {
+            Some(_) if auto_paste => self.clipboard.read_text().ok(),
+            _ => None,
+        };
 
         // Step 1: Copy text to clipboard
-        log::info!("PastePromptUseCase: Copying text to clipboard ({} chars)", text.len());
-        if let Err(e) = self.clipboard.write_text(text) {
-            return Ok(CopyPasteResult {
-                clipboard_success: false,
-                paste_attempted: false,
-                paste_likely_success: false,
-                message: format!("Failed to copy to clipboard: {}", e),
-            });
+        // The text may contain resolved secrets, so neither it nor its length is logged
+        log::debug!("PastePromptUseCase: Copying text to clipboard");
+        let written = if self.rich_text {
+            self.clipboard.write_content(&ClipboardContent::from_markdown(text))
+        } else {
+            self.clipboard.write_text(text)
+        };
+        if let Err(e) = written {
+            log::warn!("PastePromptUseCase: Clipboard write failed: {}", e);
+            return Ok(CopyPasteResult::new(PasteReason::ClipboardFailed, false));
         }
-        log::info!("PastePromptUseCase: Clipboard write successful");
+        log::debug!("PastePromptUseCase: Clipboard write successful");
 
         // Step 2: Small delay for clipboard sync (20ms matches macOS PromptLight)
         tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
 
         // Step 2a: Verify clipboard content
-        log::info!("PastePromptUseCase: Verifying clipboard content");
+        log::debug!("PastePromptUseCase: Verifying clipboard content");
         let verification = match self.clipboard.read_text() {
             Ok(v) => v,
             Err(e) => {
@@ -56,93 +105,277 @@ impl PastePromptUseCase {
         if !clipboard_verified {
             log::warn!("PastePromptUseCase: Clipboard content mismatch (may still work)");
         } else {
-            log::info!("PastePromptUseCase: Clipboard verified successfully ({} chars)", verification.len());
+            log::debug!("PastePromptUseCase: Clipboard verified successfully");
         }
 
-        // Step 3: Restore focus to previous window (in blocking context)
-        log::info!("PastePromptUseCase: Restoring previous window focus");
-        let window_manager = Arc::clone(&self.window_manager);
-        let focus_result = tokio::task::spawn_blocking(move || {
-            window_manager.restore_previous_window()
-        }).await;
+        // Step 3: Check the target up front; Windows drops input sent to elevated apps
+        // without reporting an error
+        let elevated = auto_paste && self.window_manager.remembered_window_elevated();
 
-        let focus_restored = match focus_result {
-            Ok(Ok(())) => {
-                log::info!("PastePromptUseCase: Window focus restored");
-                true
-            }
-            Ok(Err(e)) => {
-                log::warn!("PastePromptUseCase: Focus restore failed: {}", e);
-                false
-            }
-            Err(e) => {
-                log::warn!("PastePromptUseCase: Focus restore task failed: {}", e);
-                false
-            }
-        };
+        // Step 4: Restore focus to previous window (in blocking context)
+        let focus_restored = restore_focus(&self.window_manager).await;
 
-        // Step 4: Optionally simulate paste
-        let paste_attempted = auto_paste;
-        let mut paste_result_ok = false;
-
-        if auto_paste {
+        // Step 5: Optionally simulate paste, then verify the target still has focus
+        let reason = if !auto_paste {
+            log::debug!("PastePromptUseCase: Auto-paste disabled, skipping paste simulation");
+            PasteReason::Copied
+        } else if !focus_restored {
+            PasteReason::NoTargetWindow
+        } else if elevated {
+            log::warn!("PastePromptUseCase: Target app is elevated, not pasting");
+            PasteReason::TargetElevated
+        } else {
             // Wait for target window to be ready
-            // 100ms is needed for Electron apps (VS Code, ChatGPT desktop) which have
-            // complex window hierarchies and slower focus handling than native apps.
-            // macOS PromptLight uses 50ms but Windows needs more time for Electron.
-            log::info!("PastePromptUseCase: Waiting for target window to be ready...");
-            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+            log::debug!("PastePromptUseCase: Waiting for target window to be ready...");
+            tokio::time::sleep(self.pre_paste_delay()).await;
 
-            log::info!("PastePromptUseCase: Simulating paste (Ctrl+V) in blocking context");
+            log::debug!("PastePromptUseCase: Simulating paste in blocking context");
             let input_simulator = Arc::clone(&self.input_simulator);
-            let paste_result = tokio::task::spawn_blocking(move || {
-                input_simulator.simulate_paste()
+            let profile = self.profile.clone();
+            let pasted_text = text.to_string();
+            let paste_result = tokio::task::spawn_blocking(move || match profile {
+                Some((profile, type_interval)) => Self::paste_with_profile(
+                    input_simulator.as_ref(),
+                    &profile,
+                    &pasted_text,
+                    type_interval,
+                ),
+                None => input_simulator.simulate_paste(),
             }).await;
 
-            paste_result_ok = match paste_result {
+            match paste_result {
                 Ok(Ok(())) => {
-                    log::info!("PastePromptUseCase: Paste simulation completed");
-                    true
+                    log::debug!("PastePromptUseCase: Paste simulation completed");
+                    let reason = verify(self.window_manager.as_ref(), PasteReason::Pasted);
+                    // Never send the post-paste keys to a window that took focus meanwhile
+                    if reason != PasteReason::FocusChanged {
+                        self.press_post_paste_keys().await;
+                    }
+                    reason
                 }
                 Ok(Err(e)) => {
                     log::warn!("PastePromptUseCase: Paste simulation failed: {}", e);
-                    false
+                    PasteReason::InputFailed
                 }
                 Err(e) => {
                     log::warn!("PastePromptUseCase: Paste simulation task failed: {}", e);
-                    false
+                    PasteReason::InputFailed
                 }
-            };
-        } else {
-            log::info!("PastePromptUseCase: Auto-paste disabled, skipping paste simulation");
-        }
+            }
+        };
 
-        // Step 5: Cleanup
-        log::info!("PastePromptUseCase: Cleaning up saved window");
+        // Step 6: Cleanup
+        log::debug!("PastePromptUseCase: Cleaning up saved window");
         self.window_manager.clear_saved_window();
 
-        // Build result based on what happened
-        // Note: paste_result_ok means "no error was returned" but due to UIPI restrictions,
-        // the paste may not have actually worked. We're honest about this uncertainty.
-        let paste_likely_success = paste_attempted && paste_result_ok && focus_restored;
-
-        let message = if !auto_paste {
-            "Copied to clipboard".to_string()
-        } else if paste_likely_success {
-            "Copied and pasted".to_string()
-        } else if paste_attempted && !focus_restored {
-            "Copied to clipboard - press Ctrl+V to paste".to_string()
+        // Step 7: Restore the previous clipboard once the paste was sent, even unconfirmed, since
+        // the text may hold secrets; when nothing was sent the user still needs it to paste by hand
+        let mut result = CopyPasteResult::new(reason, true);
+        if let (Some(delay), Some(previous), true) = (self.restore_clipboard_after, snapshot, reason.input_sent()) {
+            restore_clipboard(self.clipboard.as_ref(), text, &previous, delay).await;
+            // Don't suggest pasting by hand what is no longer on the clipboard
+            result.message = reason.message(false);
+        }
+
+        log::debug!("PastePromptUseCase: Completed - {:?}", reason);
+        Ok(result)
+    }
+
+    /// Type the text as keystrokes instead of pasting it
+    /// Keys go out a line (or TYPE_CHUNK_KEYS) at a time and typing stops as soon as the target
+    /// loses focus; if focus can't be restored or typing fails, the text is copied to the
+    /// clipboard instead, so keystrokes never land in the wrong window
+    pub async fn type_out(&self, text: &str, interval: Duration) -> Result<CopyPasteResult, OsError> {
+        log::debug!("PastePromptUseCase: Starting type-out");
+
+        let elevated = self.window_manager.remembered_window_elevated();
+        let reason = if !restore_focus(&self.window_manager).await {
+            PasteReason::NoTargetWindow
+        } else if elevated {
+            log::warn!("PastePromptUseCase: Target app is elevated, not typing");
+            PasteReason::TargetElevated
         } else {
-            // Paste was attempted but may not have worked
-            "Copied to clipboard - press Ctrl+V if not pasted".to_string()
+            // Same settle time as pasting (Electron apps need it)
+            tokio::time::sleep(self.pre_paste_delay()).await;
+
+            self.type_chunks(KeyCode::for_text(text), interval).await
         };
+        self.window_manager.clear_saved_window();
+
+        if matches!(reason, PasteReason::Typed | PasteReason::Unverified) {
+            log::debug!("PastePromptUseCase: Type-out completed");
+            self.press_post_paste_keys().await;
+            return Ok(CopyPasteResult::new(reason, false));
+        }
 
-        log::info!("PastePromptUseCase: Completed - {}", message);
-        Ok(CopyPasteResult {
-            clipboard_success: true,
-            paste_attempted,
-            paste_likely_success,
-            message,
-        })
+        log::warn!("PastePromptUseCase: Type-out not confirmed ({:?}), copying instead", reason);
+        let clipboard_success = self.clipboard.write_text(text).is_ok();
+        Ok(CopyPasteResult::new(reason, clipboard_success))
+    }
+
+    /// Type `keys` chunk by chunk, checking before each one that the target still has focus
+    async fn type_chunks(&self, keys: Vec<KeyCode>, interval: Duration) -> PasteReason {
+        for (i, chunk) in key_chunks(&keys).into_iter().enumerate() {
+            if self.window_manager.remembered_window_focused() == Some(false) {
+                log::warn!("PastePromptUseCase: Focus moved away before chunk {}, typing stopped", i + 1);
+                return PasteReason::FocusChanged;
+            }
+
+            let input_simulator = Arc::clone(&self.input_simulator);
+            let chunk = chunk.to_vec();
+            match tokio::task::spawn_blocking(move || input_simulator.type_keys(&chunk, interval)).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => {
+                    log::warn!("PastePromptUseCase: Typing failed: {}", e);
+                    return PasteReason::InputFailed;
+                }
+                Err(e) => {
+                    log::warn!("PastePromptUseCase: Typing task failed: {}", e);
+                    return PasteReason::InputFailed;
+                }
+            }
+        }
+        verify(self.window_manager.as_ref(), PasteReason::Typed)
+    }
+
+    fn pre_paste_delay(&self) -> Duration {
+        self.profile
+            .as_ref()
+            .map(|(profile, _)| Duration::from_millis(profile.pre_paste_delay_ms))
+            .unwrap_or(DEFAULT_PRE_PASTE_DELAY)
+    }
+
+    /// Business rule: strategies are tried in the profile's order and the first one that
+    /// succeeds wins; a chord that doesn't parse falls back to the default paste
+    pub(super) fn paste_with_profile(
+        input_simulator: &dyn InputSimulator,
+        profile: &PasteProfile,
+        text: &str,
+        type_interval: Duration,
+    ) -> Result<(), OsError> {
+        let chord = match profile.chord_keys() {
+            Ok(chord) => chord,
+            Err(e) => {
+                log::warn!("PastePromptUseCase: {} (profile {}), using Ctrl+V", e, profile.name);
+                return input_simulator.simulate_paste();
+            }
+        };
+
+        let mut last_error = OsError::Input(format!("paste profile {} has no strategies", profile.name));
+        for strategy in &profile.strategies {
+            let result = match strategy {
+                PasteStrategy::TypeOut => input_simulator.type_keys(&KeyCode::for_text(text), type_interval),
+                strategy => input_simulator.paste_with(*strategy, &chord),
+            };
+            match result {
+                Ok(()) => {
+                    log::debug!("PastePromptUseCase: Pasted with {:?} (profile {})", strategy, profile.name);
+                    return Ok(());
+                }
+                Err(e) => {
+                    log::warn!("PastePromptUseCase: {:?} failed: {}", strategy, e);
+                    last_error = e;
+                }
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Press the profile's post-paste chords (e.g. Enter to submit a chat message)
+    async fn press_post_paste_keys(&self) {
+        let Some((profile, _)) = &self.profile else {
+            return;
+        };
+        if profile.post_paste_keys.is_empty() {
+            return;
+        }
+        let chords = match profile.post_paste_chords() {
+            Ok(chords) => chords,
+            Err(e) => {
+                log::warn!("PastePromptUseCase: Skipping post-paste keys: {}", e);
+                return;
+            }
+        };
+
+        tokio::time::sleep(Duration::from_millis(profile.post_paste_delay_ms)).await;
+        let input_simulator = Arc::clone(&self.input_simulator);
+        let pressed = tokio::task::spawn_blocking(move || {
+            chords.iter().try_for_each(|chord| input_simulator.simulate_keys(chord))
+        }).await;
+        match pressed {
+            Ok(Ok(())) => log::debug!("PastePromptUseCase: Post-paste keys pressed"),
+            Ok(Err(e)) => log::warn!("PastePromptUseCase: Post-paste keys failed: {}", e),
+            Err(e) => log::warn!("PastePromptUseCase: Post-paste keys task failed: {}", e),
+        }
+    }
+}
+
+/// Put `previous` back on the clipboard `delay` after `pasted` was pasted
+/// Business rule: only restore if the clipboard still holds our text; anything else
+/// was copied after the paste and must not be overwritten
+pub(super) async fn restore_clipboard(clipboard: &dyn ClipboardService, pasted: &str, previous: &str, delay: Duration) {
+    // The target app reads the clipboard asynchronously after Ctrl+V
+    tokio::time::sleep(delay).await;
+
+    match clipboard.read_text() {
+        Ok(current) if current == pasted => match clipboard.write_text(previous) {
+            Ok(()) => log::debug!("restore_clipboard: Previous clipboard restored"),
+            Err(e) => log::warn!("restore_clipboard: Clipboard restore failed: {}", e),
+        },
+        Ok(_) => log::debug!("restore_clipboard: Clipboard changed since paste, not restoring"),
+        Err(e) => log::warn!("restore_clipboard: Clipboard read before restore failed: {}", e),
+    }
+}
+
+/// Business rule: input only counts as delivered if the target window still has focus
+/// afterwards; `done` is the outcome to report when it does
+pub(super) fn verify(window_manager: &dyn WindowManager, done: PasteReason) -> PasteReason {
+    match window_manager.remembered_window_focused() {
+        Some(true) => done,
+        Some(false) => {
+            log::warn!("verify: Focus moved away from the target window");
+            PasteReason::FocusChanged
+        }
+        None => PasteReason::Unverified,
+    }
+}
+
+/// Split keystrokes after each Enter and every TYPE_CHUNK_KEYS keys
+fn key_chunks(keys: &[KeyCode]) -> Vec<&[KeyCode]> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    for (i, key) in keys.iter().enumerate() {
+        if *key == KeyCode::Enter || i + 1 - start == TYPE_CHUNK_KEYS {
+            chunks.push(&keys[start..=i]);
+            start = i + 1;
+        }
+    }
+    if start < keys.len() {
+        chunks.push(&keys[start..]);
+    }
+    chunks
+}
+
+/// Give focus back to the remembered window (in blocking context)
+pub(super) async fn restore_focus(window_manager: &Arc<dyn WindowManager>) -> bool {
+    log::debug!("restore_focus: Restoring previous window focus");
+    let window_manager = Arc::clone(window_manager);
+    let focus_result = tokio::task::spawn_blocking(move || {
+        window_manager.restore_previous_window()
+    }).await;
+
+    match focus_result {
+        Ok(Ok(())) => {
+            log::debug!("restore_focus: Window focus restored");
+            true
+        }
+        Ok(Err(e)) => {
+            log::warn!("restore_focus: Focus restore failed: {}", e);
+            false
+        }
+        Err(e) => {
+            log::warn!("restore_focus: Focus restore task failed: {}", e);
+            false
+        }
     }
 }
@@ -0,0 +1,196 @@
+use super::paste_prompt::{restore_clipboard, restore_focus, verify, PastePromptUseCase, DEFAULT_PRE_PASTE_DELAY};
+use crate::os::domain::ports::{ClipboardService, InputSimulator, KeyCode, WindowManager};
+use crate::os::domain::{ClipboardContent, CopyPasteResult, MacroAction, OsError, PasteProfile, PasteReason};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Run macro use case
+/// Orchestrates: Check target → Restore focus → for each step: check focus, then paste a
+/// segment, press keys or wait
+pub struct RunMacroUseCase {
+    clipboard: Arc<dyn ClipboardService>,
+    window_manager: Arc<dyn WindowManager>,
+    input_simulator: Arc<dyn InputSimulator>,
+    /// How the target app wants to be pasted into, and the pause between typed keys
+    profile: Option<(PasteProfile, Duration)>,
+    /// Type paste segments instead of pasting them, pausing this long between keys
+    type_interval: Option<Duration>,
+    /// Put the previous clipboard text back this long after the last pasted segment
+    restore_clipboard_after: Option<Duration>,
+    /// Copy paste segments as HTML/RTF as well as plain text
+    rich_text: bool,
+}
+
+impl RunMacroUseCase {
+    pub fn new(
+        clipboard: Arc<dyn ClipboardService>,
+        window_manager: Arc<dyn WindowManager>,
+        input_simulator: Arc<dyn InputSimulator>,
+    ) -> Self {
+        Self {
+            clipboard,
+            window_manager,
+            input_simulator,
+            profile: None,
+            type_interval: None,
+            restore_clipboard_after: None,
+            rich_text: false,
+        }
+    }
+
+    /// Paste segments as the target app's profile says; its post-paste keys are not
+    /// pressed, since the macro's own steps say what follows each paste
+    pub fn with_profile(mut self, profile: PasteProfile, type_interval: Duration) -> Self {
+        self.profile = Some((profile, type_interval));
+        self
+    }
+
+    /// Type paste segments as keystrokes, for apps that ignore clipboard paste
+    pub fn with_type_out(mut self, interval: Duration) -> Self {
+        self.type_interval = Some(interval);
+        self
+    }
+
+    /// Render paste segments from markdown to HTML and RTF too, for rich editors
+    pub fn with_rich_text(mut self) -> Self {
+        self.rich_text = true;
+        self
+    }
+
+    /// Restore the user's clipboard text `delay` after the macro, if it pasted any segment
+    pub fn with_clipboard_restore(mut self, delay: Duration) -> Self {
+        self.restore_clipboard_after = Some(delay);
+        self
+    }
+
+    pub async fn execute(&self, actions: &[MacroAction]) -> Result<CopyPasteResult, OsError> {
+        log::debug!("RunMacroUseCase: Starting ({} steps)", actions.len());
+
+        // Business rule: never press keys into the wrong window, or into one that drops
+        // them; the first segment is copied instead so the user can paste it by hand
+        let elevated = self.window_manager.remembered_window_elevated();
+        let blocked = if !restore_focus(&self.window_manager).await {
+            Some(PasteReason::NoTargetWindow)
+        } else if elevated {
+            log::warn!("RunMacroUseCase: Target app is elevated, not running");
+            Some(PasteReason::TargetElevated)
+        } else {
+            None
+        };
+        if let Some(reason) = blocked {
+            self.window_manager.clear_saved_window();
+            let first = actions.iter().find_map(|action| match action {
+                MacroAction::Paste(text) => Some(text.as_str()),
+                _ => None,
+            });
+            let clipboard_success = first.is_some_and(|text| self.clipboard.write_text(text).is_ok());
+            return Ok(CopyPasteResult::new(reason, clipboard_success));
+        }
+
+        let pre_paste_delay = self
+            .profile
+            .as_ref()
+            .map(|(profile, _)| Duration::from_millis(profile.pre_paste_delay_ms))
+            .unwrap_or(DEFAULT_PRE_PASTE_DELAY);
+        tokio::time::sleep(pre_paste_delay).await;
+
+        // Only text can be restored; images and files on the clipboard read as errors
+        let snapshot = match self.restore_clipboard_after {
+            Some(_) if self.type_interval.is_none() => self.clipboard.read_text().ok(),
+            _ => None,
+        };
+
+        let mut clipboard_success = false;
+        let mut last_pasted = None;
+        let mut failure = None;
+        for (i, action) in actions.iter().enumerate() {
+            let sends_input = !matches!(action, MacroAction::Wait(_));
+            if sends_input && self.window_manager.remembered_window_focused() == Some(false) {
+                log::warn!("RunMacroUseCase: Focus moved away before step {}", i + 1);
+                failure = Some((PasteReason::FocusChanged, format!("Macro stopped at step {}: focus moved to another window", i + 1)));
+                break;
+            }
+
+            let result = match action {
+                MacroAction::Paste(text) => {
+                    let copied = self.type_interval.is_none();
+                    let result = self.paste_segment(text).await;
+                    if copied && result.is_ok() {
+                        clipboard_success = true;
+                        last_pasted = Some(text.as_str());
+                    }
+                    result
+                }
+                MacroAction::Keys(chord) => {
+                    let input_simulator = Arc::clone(&self.input_simulator);
+                    let chord = chord.clone();
+                    Self::blocking(move || input_simulator.simulate_keys(&chord)).await
+                }
+                MacroAction::Wait(duration) => {
+                    tokio::time::sleep(*duration).await;
+                    Ok(())
+                }
+            };
+            if let Err(e) = result {
+                log::warn!("RunMacroUseCase: Step {} failed: {}", i + 1, e);
+                failure = Some((PasteReason::MacroStopped, format!("Macro stopped at step {}: {}", i + 1, e)));
+                break;
+            }
+        }
+
+        // Segments may hold secrets, so the clipboard is restored even when the macro stopped
+        // part-way; it only still holds a segment if one was pasted
+        if let (Some(delay), Some(previous), Some(pasted)) = (self.restore_clipboard_after, snapshot, last_pasted) {
+            restore_clipboard(self.clipboard.as_ref(), pasted, &previous, delay).await;
+            clipboard_success = false;
+        }
+
+        let result = match failure {
+            Some((reason, message)) => CopyPasteResult {
+                message,
+                ..CopyPasteResult::new(reason, clipboard_success)
+            },
+            None => CopyPasteResult::new(verify(self.window_manager.as_ref(), PasteReason::RanMacro), clipboard_success),
+        };
+        self.window_manager.clear_saved_window();
+
+        log::debug!("RunMacroUseCase: Completed - {:?}", result.reason);
+        Ok(result)
+    }
+
+    /// Paste (or type) one segment; the text is never logged
+    async fn paste_segment(&self, text: &str) -> Result<(), OsError> {
+        let input_simulator = Arc::clone(&self.input_simulator);
+        if let Some(interval) = self.type_interval {
+            let keys = KeyCode::for_text(text);
+            return Self::blocking(move || input_simulator.type_keys(&keys, interval)).await;
+        }
+
+        if self.rich_text {
+            self.clipboard.write_content(&ClipboardContent::from_markdown(text))?;
+        } else {
+            self.clipboard.write_text(text)?;
+        }
+        // Same clipboard sync delay as a single paste
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let profile = self.profile.clone();
+        let text = text.to_string();
+        Self::blocking(move || match profile {
+            Some((profile, type_interval)) => {
+                PastePromptUseCase::paste_with_profile(input_simulator.as_ref(), &profile, &text, type_interval)
+            }
+            None => input_simulator.simulate_paste(),
+        })
+        .await
+    }
+
+    async fn blocking<F>(f: F) -> Result<(), OsError>
+    where
+        F: FnOnce() -> Result<(), OsError> + Send + 'static,
+    {
+        tokio::task::spawn_blocking(f)
+            .await
+            .unwrap_or_else(|e| Err(OsError::Input(format!("input task failed: {}", e))))
+    }
+}
@@ -1,4 +1,5 @@
 use crate::os::domain::ports::WindowManager;
+use crate::os::domain::OsError;
 use std::sync::Arc;
 
 /// Show window use case
@@ -12,7 +13,7 @@ impl ShowWindowUseCase {
         Self { window_manager }
     }
 
-    pub fn execute(&self) -> Result<(), String> {
+    pub fn execute(&self) -> Result<(), OsError> {
         // Remember the window that's currently focused
         self.window_manager.remember_current_window()?;
         Ok(())
@@ -0,0 +1,127 @@
+use crate::os::domain::ports::KeyEvent;
+
+/// A trigger that expands into a prompt when typed (e.g. ";;review")
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Abbreviation {
+    pub trigger: String,
+    pub prompt_id: String,
+}
+
+/// Recently typed characters, matched against abbreviation triggers
+/// Only holds as many characters as the longest trigger needs
+#[derive(Debug, Default)]
+pub struct KeystrokeBuffer {
+    typed: Vec<char>,
+}
+
+impl KeystrokeBuffer {
+    /// Upper bound on the buffer so a long trigger list cannot make it grow unbounded
+    const MAX_CHARS: usize = 64;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a keystroke and return the abbreviation whose trigger was just completed
+    /// Business rule: the longest matching trigger wins; the buffer empties after a match
+    /// so the expansion itself cannot re-trigger
+    pub fn feed(&mut self, event: KeyEvent, abbreviations: &[Abbreviation]) -> Option<Abbreviation> {
+        match event {
+            KeyEvent::Char(c) => self.typed.push(c),
+            KeyEvent::Backspace => {
+                self.typed.pop();
+                return None;
+            }
+            KeyEvent::Reset => {
+                self.clear();
+                return None;
+            }
+        }
+
+        let longest = abbreviations
+            .iter()
+            .map(|a| a.trigger.chars().count())
+            .max()
+            .unwrap_or(0)
+            .min(Self::MAX_CHARS);
+        if self.typed.len() > longest {
+            self.typed.drain(..self.typed.len() - longest);
+        }
+
+        let matched = abbreviations
+            .iter()
+            .filter(|a| self.ends_with(&a.trigger))
+            .max_by_key(|a| a.trigger.chars().count())
+            .cloned();
+        if matched.is_some() {
+            self.clear();
+        }
+        matched
+    }
+
+    pub fn clear(&mut self) {
+        self.typed.clear();
+    }
+
+    fn ends_with(&self, trigger: &str) -> bool {
+        let trigger: Vec<char> = trigger.chars().collect();
+        !trigger.is_empty() && self.typed.ends_with(&trigger)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abbreviation(trigger: &str, prompt_id: &str) -> Abbreviation {
+        Abbreviation {
+            trigger: trigger.to_string(),
+            prompt_id: prompt_id.to_string(),
+        }
+    }
+
+    fn type_text(buffer: &mut KeystrokeBuffer, text: &str, abbreviations: &[Abbreviation]) -> Option<Abbreviation> {
+        text.chars()
+            .map(|c| buffer.feed(KeyEvent::Char(c), abbreviations))
+            .last()
+            .flatten()
+    }
+
+    #[test]
+    fn test_feed_matches_trigger_at_end_of_typing() {
+        let abbreviations = vec![abbreviation(";;review", "review.md")];
+        let mut buffer = KeystrokeBuffer::new();
+
+        assert_eq!(type_text(&mut buffer, "please ;;revie", &abbreviations), None);
+        let matched = buffer.feed(KeyEvent::Char('w'), &abbreviations);
+        assert_eq!(matched, Some(abbreviations[0].clone()));
+
+        // Buffer is emptied so the next keystroke starts fresh
+        assert_eq!(buffer.feed(KeyEvent::Char('w'), &abbreviations), None);
+    }
+
+    #[test]
+    fn test_feed_prefers_longest_trigger() {
+        let abbreviations = vec![abbreviation(";;r", "short.md"), abbreviation("x;;r", "long.md")];
+        let mut buffer = KeystrokeBuffer::new();
+
+        let matched = type_text(&mut buffer, "x;;r", &abbreviations).unwrap();
+        assert_eq!(matched.prompt_id, "long.md");
+    }
+
+    #[test]
+    fn test_backspace_and_reset_edit_the_buffer() {
+        let abbreviations = vec![abbreviation(";;fix", "fix.md")];
+        let mut buffer = KeystrokeBuffer::new();
+
+        // Typo corrected with backspace still matches
+        type_text(&mut buffer, ";;fiz", &abbreviations);
+        buffer.feed(KeyEvent::Backspace, &abbreviations);
+        assert!(buffer.feed(KeyEvent::Char('x'), &abbreviations).is_some());
+
+        // Moving the caret in between breaks the trigger
+        type_text(&mut buffer, ";;f", &abbreviations);
+        buffer.feed(KeyEvent::Reset, &abbreviations);
+        assert_eq!(type_text(&mut buffer, "ix", &abbreviations), None);
+    }
+}
@@ -0,0 +1,41 @@
+use thiserror::Error;
+
+/// Errors returned by the clipboard, window and input ports
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum OsError {
+    #[error("Clipboard error: {0}")]
+    Clipboard(String),
+
+    #[error("No previous window saved")]
+    NoSavedWindow,
+
+    #[error("Could not get foreground window")]
+    NoForegroundWindow,
+
+    #[error("Window is no longer valid (it may have been closed)")]
+    WindowClosed,
+
+    #[error("Focus error: {0}")]
+    Focus(String),
+
+    #[error("Input simulation failed: {0}")]
+    Input(String),
+
+    #[error("{0} is not supported on this desktop")]
+    Unsupported(&'static str),
+}
+
+impl OsError {
+    /// Stable machine-readable code sent to the frontend
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Clipboard(_) => "clipboard_error",
+            Self::NoSavedWindow => "no_saved_window",
+            Self::NoForegroundWindow => "no_foreground_window",
+            Self::WindowClosed => "window_closed",
+            Self::Focus(_) => "focus_failed",
+            Self::Input(_) => "input_failed",
+            Self::Unsupported(_) => "unsupported_platform",
+        }
+    }
+}
@@ -0,0 +1,14 @@
+// Domain - Macro actions: prompt steps resolved into text to paste, chords and pauses
+use crate::os::domain::ports::KeyCode;
+use std::time::Duration;
+
+/// One step of a macro, ready to run: text is rendered and chords are parsed
+/// The text is prompt content (possibly with secrets), so actions are never logged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MacroAction {
+    /// Put text on the clipboard and paste it (or type it, in type-out mode)
+    Paste(String),
+    /// Press a chord, keys released in reverse order
+    Keys(Vec<KeyCode>),
+    Wait(Duration),
+}
@@ -1,5 +1,18 @@
 // Domain layer - contains business logic interfaces (ports)
+pub mod abbreviation;
+pub mod errors;
+pub mod macro_action;
+pub mod paste_profile;
+pub mod paste_reason;
 pub mod ports;
+pub mod rich_text;
+
+pub use abbreviation::{Abbreviation, KeystrokeBuffer};
+pub use errors::OsError;
+pub use macro_action::MacroAction;
+pub use paste_profile::PasteProfile;
+pub use paste_reason::PasteReason;
+pub use rich_text::ClipboardContent;
 
 use serde::{Deserialize, Serialize};
 
@@ -9,10 +22,24 @@ use serde::{Deserialize, Serialize};
 pub struct CopyPasteResult {
     /// Whether text was successfully copied to clipboard
     pub clipboard_success: bool,
-    /// Whether auto-paste was attempted
+    /// Whether input was sent to the target app
     pub paste_attempted: bool,
-    /// Whether paste is likely to have succeeded (best guess)
-    pub paste_likely_success: bool,
-    /// User-friendly message describing what happened
+    /// Whether the input reached the target window (it still had focus afterwards)
+    pub paste_verified: bool,
+    /// Why the operation ended the way it did
+    pub reason: PasteReason,
+    /// User-friendly message for `reason`
     pub message: String,
 }
+
+impl CopyPasteResult {
+    pub fn new(reason: PasteReason, clipboard_success: bool) -> Self {
+        Self {
+            clipboard_success,
+            paste_attempted: reason.paste_attempted(),
+            paste_verified: reason.is_verified(),
+            reason,
+            message: reason.message(clipboard_success),
+        }
+    }
+}
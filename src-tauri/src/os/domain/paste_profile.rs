@@ -0,0 +1,201 @@
+// Domain - Per-application paste profiles (key chord, delays, strategy order, post-paste keys)
+use crate::os::domain::ports::{KeyCode, PasteStrategy};
+use crate::storage::TargetApp;
+use serde::{Deserialize, Serialize};
+
+/// How to paste into a group of apps, matched by process name or window class
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasteProfile {
+    pub name: String,
+    /// Process names (e.g. "WindowsTerminal.exe"); "*" matches every app
+    pub apps: Vec<String>,
+    /// Window classes (e.g. "ConsoleWindowClass"), compared case-insensitively
+    pub window_classes: Vec<String>,
+    /// Shortcut that pastes in these apps
+    pub chord: String,
+    /// Wait after focus is restored, before pasting
+    pub pre_paste_delay_ms: u64,
+    /// Wait after pasting, before the post-paste keys
+    pub post_paste_delay_ms: u64,
+    /// Tried in order until one succeeds
+    pub strategies: Vec<PasteStrategy>,
+    /// Chords pressed after pasting (e.g. "Enter" to submit)
+    pub post_paste_keys: Vec<String>,
+}
+
+impl Default for PasteProfile {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            apps: Vec::new(),
+            window_classes: Vec::new(),
+            chord: "Ctrl+V".to_string(),
+            // Electron apps (VS Code, ChatGPT desktop) need 100ms to settle after focus changes
+            pre_paste_delay_ms: 100,
+            post_paste_delay_ms: 0,
+            strategies: vec![PasteStrategy::SendInput, PasteStrategy::MessageKeys, PasteStrategy::WmPaste],
+            post_paste_keys: Vec::new(),
+        }
+    }
+}
+
+impl PasteProfile {
+    /// Profiles shipped in the default config
+    pub fn built_in() -> Vec<PasteProfile> {
+        vec![
+            PasteProfile {
+                name: "Terminals".to_string(),
+                apps: names(&[
+                    "WindowsTerminal.exe",
+                    "alacritty.exe",
+                    "wezterm-gui.exe",
+                    "conhost.exe",
+                    "gnome-terminal-server",
+                    "konsole",
+                    "kitty",
+                    "xfce4-terminal",
+                    "tilix",
+                ]),
+                window_classes: names(&[
+                    "CASCADIA_HOSTING_WINDOW_CLASS",
+                    "ConsoleWindowClass",
+                    "Gnome-terminal",
+                    "konsole",
+                    "kitty",
+                    "Alacritty",
+                ]),
+                chord: "Ctrl+Shift+V".to_string(),
+                pre_paste_delay_ms: 50,
+                strategies: vec![PasteStrategy::SendInput],
+                ..Default::default()
+            },
+            PasteProfile {
+                name: "mintty (Git Bash, Cygwin)".to_string(),
+                apps: names(&["mintty.exe"]),
+                window_classes: names(&["mintty"]),
+                chord: "Shift+Insert".to_string(),
+                pre_paste_delay_ms: 50,
+                strategies: vec![PasteStrategy::SendInput],
+                ..Default::default()
+            },
+            PasteProfile {
+                name: "Electron and Chromium apps".to_string(),
+                apps: names(&["Code.exe", "ChatGPT.exe", "Discord.exe", "Teams.exe"]),
+                window_classes: names(&["Chrome_WidgetWin_1"]),
+                pre_paste_delay_ms: 100,
+                strategies: vec![PasteStrategy::SendInput],
+                ..Default::default()
+            },
+        ]
+    }
+
+    /// Business rule: the first profile naming the app wins, then the first naming the
+    /// window class; `None` means the default paste behavior applies
+    pub fn matching<'a>(
+        profiles: &'a [PasteProfile],
+        target_app: Option<&TargetApp>,
+        window_class: Option<&str>,
+    ) -> Option<&'a PasteProfile> {
+        let by_app = target_app.and_then(|app| {
+            profiles
+                .iter()
+                .find(|profile| profile.apps.iter().any(|name| name != "*" && TargetApp::new(name) == *app))
+        });
+        let by_class = || {
+            window_class.and_then(|class| {
+                profiles
+                    .iter()
+                    .find(|profile| profile.window_classes.iter().any(|c| c.eq_ignore_ascii_case(class)))
+            })
+        };
+        let wildcard = || profiles.iter().find(|profile| profile.apps.iter().any(|name| name == "*"));
+
+        by_app.or_else(by_class).or_else(wildcard)
+    }
+
+    pub fn chord_keys(&self) -> Result<Vec<KeyCode>, String> {
+        KeyCode::parse_chord(&self.chord)
+    }
+
+    pub fn post_paste_chords(&self) -> Result<Vec<Vec<KeyCode>>, String> {
+        self.post_paste_keys.iter().map(|chord| KeyCode::parse_chord(chord)).collect()
+    }
+
+    /// Check that the chords parse and a strategy is given, so mistakes surface when the
+    /// config is saved rather than on the next paste
+    pub fn validate(&self) -> Result<(), String> {
+        let label = if self.name.is_empty() { "unnamed" } else { self.name.as_str() };
+        self.chord_keys().map_err(|e| format!("Paste profile {}: {}", label, e))?;
+        self.post_paste_chords().map_err(|e| format!("Paste profile {}: {}", label, e))?;
+        if self.strategies.is_empty() {
+            return Err(format!("Paste profile {}: at least one strategy is required", label));
+        }
+        Ok(())
+    }
+}
+
+fn names(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_prefers_app_then_window_class() {
+        let profiles = PasteProfile::built_in();
+        let terminal = TargetApp::new("C:\\Program Files\\WindowsApps\\WindowsTerminal.EXE");
+        let matched = PasteProfile::matching(&profiles, Some(&terminal), Some("Chrome_WidgetWin_1")).unwrap();
+        assert_eq!(matched.chord, "Ctrl+Shift+V");
+
+        let gnome_terminal = TargetApp::new("/usr/libexec/gnome-terminal-server");
+        assert_eq!(PasteProfile::matching(&profiles, Some(&gnome_terminal), None).unwrap().chord, "Ctrl+Shift+V");
+
+        let unknown = TargetApp::new("electron-app.exe");
+        let matched = PasteProfile::matching(&profiles, Some(&unknown), Some("chrome_widgetwin_1")).unwrap();
+        assert_eq!(matched.name, "Electron and Chromium apps");
+
+        let notepad = TargetApp::new("notepad.exe");
+        assert!(PasteProfile::matching(&profiles, Some(&notepad), Some("Notepad")).is_none());
+    }
+
+    #[test]
+    fn test_wildcard_profile_applies_last() {
+        let mut profiles = PasteProfile::built_in();
+        profiles.insert(0, PasteProfile { name: "Everything".to_string(), apps: names(&["*"]), ..Default::default() });
+
+        let notepad = TargetApp::new("notepad.exe");
+        assert_eq!(PasteProfile::matching(&profiles, Some(&notepad), None).unwrap().name, "Everything");
+        let mintty = TargetApp::new("mintty.exe");
+        assert_eq!(PasteProfile::matching(&profiles, Some(&mintty), None).unwrap().chord, "Shift+Insert");
+    }
+
+    #[test]
+    fn test_built_in_profiles_are_valid() {
+        for profile in PasteProfile::built_in() {
+            assert_eq!(profile.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_chords_and_missing_strategies() {
+        let bad_chord = PasteProfile { chord: "Ctrl+Hyper+V".to_string(), ..Default::default() };
+        assert!(bad_chord.validate().is_err());
+
+        let bad_post_keys = PasteProfile { post_paste_keys: vec!["Enter".to_string(), "Ctrl+".to_string()], ..Default::default() };
+        assert!(bad_post_keys.validate().is_err());
+
+        let no_strategy = PasteProfile { strategies: Vec::new(), ..Default::default() };
+        assert!(no_strategy.validate().is_err());
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        let profile: PasteProfile = serde_json::from_str(r#"{"name":"Slack","apps":["slack.exe"],"post_paste_keys":["Enter"]}"#).unwrap();
+        assert_eq!(profile.chord, "Ctrl+V");
+        assert_eq!(profile.pre_paste_delay_ms, 100);
+        assert_eq!(profile.post_paste_chords(), Ok(vec![vec![KeyCode::Enter]]));
+    }
+}
@@ -0,0 +1,94 @@
+// Domain - Why a copy/paste ended the way it did, as a stable code for the frontend
+use serde::{Deserialize, Serialize};
+
+/// Outcome of a copy, paste, type-out or macro run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasteReason {
+    /// Copied only; auto-paste was off
+    Copied,
+    /// Pasted, and the target window still had focus afterwards
+    Pasted,
+    /// Typed as keystrokes, and the target window still had focus afterwards
+    Typed,
+    /// Every macro step ran, and the target window still had focus afterwards
+    RanMacro,
+    /// Input was sent, but this desktop can't tell which window received it
+    Unverified,
+    /// Focus moved away from the target while input was sent
+    FocusChanged,
+    /// The target runs at a higher integrity level (e.g. as administrator), which blocks
+    /// synthesized input, so none was sent
+    TargetElevated,
+    /// Focus could not be returned to the target, so no input was sent
+    NoTargetWindow,
+    /// Sending the paste or keys failed
+    InputFailed,
+    /// A macro step failed; later steps were skipped
+    MacroStopped,
+    /// The clipboard could not be written
+    ClipboardFailed,
+}
+
+impl PasteReason {
+    /// Business rule: only input that reached a window still focused afterwards counts as done
+    pub fn is_verified(self) -> bool {
+        matches!(self, Self::Pasted | Self::Typed | Self::RanMacro)
+    }
+
+    /// Whether input went out without an error, whether or not it reached the target
+    pub fn input_sent(self) -> bool {
+        matches!(self, Self::Pasted | Self::Typed | Self::RanMacro | Self::Unverified | Self::FocusChanged)
+    }
+
+    /// Whether input was actually sent to the target
+    pub fn paste_attempted(self) -> bool {
+        matches!(
+            self,
+            Self::Pasted | Self::Typed | Self::RanMacro | Self::Unverified | Self::FocusChanged | Self::InputFailed | Self::MacroStopped
+        )
+    }
+
+    /// Text shown to the user; `clipboard_success` says whether they can paste by hand
+    pub fn message(self, clipboard_success: bool) -> String {
+        let message = match (self, clipboard_success) {
+            (Self::Copied, _) => "Copied to clipboard",
+            (Self::Pasted, _) => "Copied and pasted",
+            (Self::Typed, _) => "Typed into the app",
+            (Self::RanMacro, _) => "Ran macro",
+            (Self::Unverified, true) => "Pasted, but it could not be confirmed - press Ctrl+V if not pasted",
+            (Self::Unverified, false) => "Sent to the app, but it could not be confirmed",
+            (Self::FocusChanged, true) => "Focus moved during the paste - press Ctrl+V if not pasted",
+            (Self::FocusChanged, false) => "Focus moved while sending keys - check the app",
+            (Self::TargetElevated, true) => "The app is running as administrator, so paste is blocked - press Ctrl+V to paste",
+            (Self::TargetElevated, false) => "The app is running as administrator, so input is blocked",
+            (Self::NoTargetWindow, true) => "Copied to clipboard - press Ctrl+V to paste",
+            (Self::NoTargetWindow, false) => "Could not return to the app",
+            (Self::InputFailed, true) => "Paste failed - copied to clipboard, press Ctrl+V to paste",
+            (Self::InputFailed, false) => "Sending keys failed and the clipboard could not be written",
+            (Self::MacroStopped, _) => "Macro stopped",
+            (Self::ClipboardFailed, _) => "Failed to copy to clipboard",
+        };
+        message.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_focused_outcomes_are_verified() {
+        assert!(PasteReason::Pasted.is_verified());
+        assert!(!PasteReason::Unverified.is_verified());
+        assert!(!PasteReason::FocusChanged.is_verified());
+        assert!(PasteReason::FocusChanged.paste_attempted());
+        assert!(!PasteReason::TargetElevated.paste_attempted());
+    }
+
+    #[test]
+    fn test_serializes_as_snake_case_code() {
+        assert_eq!(serde_json::to_string(&PasteReason::TargetElevated).unwrap(), "\"target_elevated\"");
+        assert_eq!(serde_json::to_string(&PasteReason::NoTargetWindow).unwrap(), "\"no_target_window\"");
+    }
+}
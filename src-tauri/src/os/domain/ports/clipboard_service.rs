@@ -1,10 +1,14 @@
+use crate::os::domain::{ClipboardContent, OsError};
+
 /// Clipboard operations interface (Port)
 pub trait ClipboardService: Send + Sync {
     /// Write text to system clipboard
-    fn write_text(&self, text: &str) -> Result<(), String>;
+    fn write_text(&self, text: &str) -> Result<(), OsError>;
 
     /// Read text from system clipboard
-    /// Part of complete clipboard interface - reserved for future use
-    #[allow(dead_code)]
-    fn read_text(&self) -> Result<String, String>;
+    fn read_text(&self) -> Result<String, OsError>;
+
+    /// Write plain text plus any HTML/RTF renderings in one go
+    /// Formats the platform can't hold are dropped; the plain text is always written
+    fn write_content(&self, content: &ClipboardContent) -> Result<(), OsError>;
 }
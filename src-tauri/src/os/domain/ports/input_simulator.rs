@@ -1,19 +1,165 @@
+use crate::os::domain::OsError;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
 /// Keyboard input simulation interface (Port)
 pub trait InputSimulator: Send + Sync {
     /// Simulate Ctrl+V keystroke
-    fn simulate_paste(&self) -> Result<(), String>;
+    fn simulate_paste(&self) -> Result<(), OsError>;
+
+    /// Simulate a key combination (keys pressed in order, released in reverse)
+    fn simulate_keys(&self, keys: &[KeyCode]) -> Result<(), OsError>;
 
-    /// Simulate arbitrary key combination
-    /// Part of extensible input interface - reserved for future use
-    #[allow(dead_code)]
-    fn simulate_keys(&self, keys: &[KeyCode]) -> Result<(), String>;
+    /// Press and release each key in turn, pausing `interval` after each one
+    fn type_keys(&self, keys: &[KeyCode], interval: Duration) -> Result<(), OsError>;
+
+    /// Paste with one strategy, sending `chord` (e.g. Ctrl+Shift+V) where the strategy uses keys
+    /// `PasteStrategy::TypeOut` needs the text and is handled with `type_keys` instead
+    fn paste_with(&self, strategy: PasteStrategy, chord: &[KeyCode]) -> Result<(), OsError>;
+}
+
+/// Ways of making the target app paste, tried in a paste profile's order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasteStrategy {
+    /// Synthesized key events: SendInput on Windows, XTest or a virtual keyboard on Linux (works for most apps)
+    SendInput,
+    /// WM_KEYDOWN/WM_KEYUP posted to the focused control (bypasses some UIPI issues)
+    MessageKeys,
+    /// WM_PASTE to the foreground window (standard edit controls only)
+    WmPaste,
+    /// Type the text as keystrokes (apps that ignore the clipboard)
+    TypeOut,
 }
 
 /// Key codes for input simulation
-/// Extensible for future keyboard shortcut features
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(dead_code)]
 pub enum KeyCode {
     Control,
     V,
+    Backspace,
+    Enter,
+    Tab,
+    Shift,
+    Alt,
+    Insert,
+    Escape,
+    /// Windows/Super key
+    Win,
+    Space,
+    Delete,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Up,
+    Down,
+    Left,
+    Right,
+    /// Function key F1-F24
+    F(u8),
+    /// A letter or digit key (A-Z, 0-9), for shortcuts
+    Letter(char),
+    /// A character typed as-is, independent of the keyboard layout
+    Char(char),
+}
+
+impl KeyCode {
+    /// Keystrokes that type `text`: line breaks become Enter, tabs become Tab,
+    /// and other control characters are dropped
+    pub fn for_text(text: &str) -> Vec<KeyCode> {
+        text.replace("\r\n", "\n")
+            .chars()
+            .filter_map(|c| match c {
+                '\n' | '\r' => Some(KeyCode::Enter),
+                '\t' => Some(KeyCode::Tab),
+                c if c.is_control() => None,
+                c => Some(KeyCode::Char(c)),
+            })
+            .collect()
+    }
+
+    /// Parse a key chord such as "Ctrl+Shift+V" or "Enter" (case-insensitive)
+    pub fn parse_chord(chord: &str) -> Result<Vec<KeyCode>, String> {
+        chord
+            .split('+')
+            .map(|token| {
+                let token = token.trim();
+                let key = match token.to_ascii_lowercase().as_str() {
+                    "ctrl" | "control" => KeyCode::Control,
+                    "shift" => KeyCode::Shift,
+                    "alt" => KeyCode::Alt,
+                    "enter" | "return" => KeyCode::Enter,
+                    "tab" => KeyCode::Tab,
+                    "insert" | "ins" => KeyCode::Insert,
+                    "escape" | "esc" => KeyCode::Escape,
+                    "backspace" => KeyCode::Backspace,
+                    "win" | "super" | "meta" => KeyCode::Win,
+                    "space" => KeyCode::Space,
+                    "delete" | "del" => KeyCode::Delete,
+                    "home" => KeyCode::Home,
+                    "end" => KeyCode::End,
+                    "pageup" | "pgup" => KeyCode::PageUp,
+                    "pagedown" | "pgdn" => KeyCode::PageDown,
+                    "up" => KeyCode::Up,
+                    "down" => KeyCode::Down,
+                    "left" => KeyCode::Left,
+                    "right" => KeyCode::Right,
+                    name => match Self::function_key(name) {
+                        Some(n) => KeyCode::F(n),
+                        None => {
+                            let mut chars = token.chars();
+                            match (chars.next(), chars.next()) {
+                                (Some(c), None) if c.is_ascii_alphanumeric() => KeyCode::Letter(c.to_ascii_uppercase()),
+                                _ => return Err(format!("Unknown key '{}' in \"{}\"", token, chord)),
+                            }
+                        }
+                    },
+                };
+                Ok(key)
+            })
+            .collect()
+    }
+
+    /// "f1" to "f24"
+    fn function_key(name: &str) -> Option<u8> {
+        name.strip_prefix('f')?.parse().ok().filter(|n| (1..=24).contains(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_text_maps_line_breaks_and_tabs() {
+        assert_eq!(
+            KeyCode::for_text("a\r\nb\tç\u{7}"),
+            vec![
+                KeyCode::Char('a'),
+                KeyCode::Enter,
+                KeyCode::Char('b'),
+                KeyCode::Tab,
+                KeyCode::Char('ç'),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_chord() {
+        assert_eq!(
+            KeyCode::parse_chord("ctrl+Shift+v"),
+            Ok(vec![KeyCode::Control, KeyCode::Shift, KeyCode::Letter('V')])
+        );
+        assert_eq!(KeyCode::parse_chord("Shift + Insert"), Ok(vec![KeyCode::Shift, KeyCode::Insert]));
+        assert!(KeyCode::parse_chord("Ctrl+").is_err());
+        assert_eq!(
+            KeyCode::parse_chord("Ctrl+Shift+Left"),
+            Ok(vec![KeyCode::Control, KeyCode::Shift, KeyCode::Left])
+        );
+        assert_eq!(KeyCode::parse_chord("F12"), Ok(vec![KeyCode::F(12)]));
+        assert!(KeyCode::parse_chord("F25").is_err());
+        assert!(KeyCode::parse_chord("Hyper+V").is_err());
+    }
 }
@@ -0,0 +1,22 @@
+use crate::os::domain::OsError;
+use std::sync::mpsc::Sender;
+
+/// A keystroke as seen by the text expander
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyEvent {
+    /// A typed character (after keyboard layout and Shift are applied)
+    Char(char),
+    Backspace,
+    /// Anything that moves the caret or changes focus (arrows, Enter, clicks, shortcuts)
+    Reset,
+}
+
+/// System-wide keystroke source interface (Port)
+/// Implementations must not deliver keystrokes that Prompter itself injected
+pub trait KeyboardEventSource: Send + Sync {
+    /// Start delivering keystrokes to `sink` until `stop` is called
+    fn start(&self, sink: Sender<KeyEvent>) -> Result<(), OsError>;
+
+    /// Stop delivering keystrokes; does nothing if not started
+    fn stop(&self);
+}
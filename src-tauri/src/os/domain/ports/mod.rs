@@ -2,7 +2,9 @@
 pub mod window_manager;
 pub mod clipboard_service;
 pub mod input_simulator;
+pub mod keyboard_hook;
 
 pub use window_manager::WindowManager;
 pub use clipboard_service::ClipboardService;
-pub use input_simulator::{InputSimulator, KeyCode};
+pub use input_simulator::{InputSimulator, KeyCode, PasteStrategy};
+pub use keyboard_hook::{KeyEvent, KeyboardEventSource};
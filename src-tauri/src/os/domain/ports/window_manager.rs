@@ -1,12 +1,34 @@
+use crate::os::domain::OsError;
+
 /// Window management interface (Port)
 /// Platform-specific implementations will be in Infrastructure layer
 pub trait WindowManager: Send + Sync {
     /// Remember the currently focused window
-    fn remember_current_window(&self) -> Result<(), String>;
+    fn remember_current_window(&self) -> Result<(), OsError>;
+
+    /// Remember the focused window only if it belongs to another app, for when a Prompter
+    /// window loses focus; Ok(false) means focus moved to another Prompter window
+    fn remember_if_external(&self) -> Result<bool, OsError>;
 
     /// Restore focus to previously remembered window
-    fn restore_previous_window(&self) -> Result<(), String>;
+    fn restore_previous_window(&self) -> Result<(), OsError>;
 
     /// Clear saved window reference
     fn clear_saved_window(&self);
+
+    /// Executable name of the application owning the remembered window
+    /// (e.g. "Code.exe"), used for per-app suggestions
+    fn remembered_app(&self) -> Option<String>;
+
+    /// Window class of the remembered window (e.g. "ConsoleWindowClass"),
+    /// used to pick a paste profile when the process name is too generic
+    fn remembered_window_class(&self) -> Option<String>;
+
+    /// Whether the remembered window's process runs at a higher integrity level than
+    /// Prompter (e.g. as administrator); the OS drops input synthesized into it
+    fn remembered_window_elevated(&self) -> bool;
+
+    /// Whether the remembered window has focus right now, to verify input reached it;
+    /// `None` when the desktop doesn't expose the focused window
+    fn remembered_window_focused(&self) -> Option<bool>;
 }
@@ -0,0 +1,444 @@
+/// Clipboard payload in every format the target app may understand
+/// Apps pick the richest format they support; `text` is always present
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ClipboardContent {
+    pub text: String,
+    pub html: Option<String>,
+    pub rtf: Option<String>,
+}
+
+impl ClipboardContent {
+    pub fn plain(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// The markdown as plain text, plus HTML and RTF renderings for rich editors
+    /// (Outlook, Word, Slack) that would otherwise show raw asterisks
+    pub fn from_markdown(markdown: &str) -> Self {
+        let blocks = parse_blocks(markdown);
+        Self {
+            text: markdown.to_string(),
+            html: Some(to_html(&blocks)),
+            rtf: Some(to_rtf(&blocks)),
+        }
+    }
+}
+
+// =============================================================================
+// MARKDOWN PARSING (the subset prompts use)
+// =============================================================================
+
+#[derive(Debug, PartialEq)]
+enum Block {
+    Heading(usize, Vec<Span>),
+    /// Lines of one paragraph; line breaks are kept
+    Paragraph(Vec<Vec<Span>>),
+    List { ordered: bool, items: Vec<Vec<Span>> },
+    Quote(Vec<Vec<Span>>),
+    Code(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Style {
+    bold: bool,
+    italic: bool,
+    code: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Span {
+    text: String,
+    style: Style,
+    link: Option<String>,
+}
+
+fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut lines = markdown.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if trimmed.starts_with("```") {
+            let mut code = Vec::new();
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with("```") {
+                    break;
+                }
+                code.push(line.to_string());
+            }
+            blocks.push(Block::Code(code));
+        } else if let Some((level, text)) = heading(trimmed) {
+            blocks.push(Block::Heading(level, parse_inline(text)));
+        } else if let Some((ordered, text)) = list_item(trimmed) {
+            let mut items = vec![parse_inline(text)];
+            while let Some((next_ordered, text)) = lines.peek().and_then(|l| list_item(l.trim_start())) {
+                if next_ordered != ordered {
+                    break;
+                }
+                items.push(parse_inline(text));
+                lines.next();
+            }
+            blocks.push(Block::List { ordered, items });
+        } else if let Some(text) = quote_line(trimmed) {
+            let mut quoted = vec![parse_inline(text)];
+            while let Some(text) = lines.peek().and_then(|l| quote_line(l.trim_start())) {
+                quoted.push(parse_inline(text));
+                lines.next();
+            }
+            blocks.push(Block::Quote(quoted));
+        } else {
+            let mut paragraph = vec![parse_inline(line.trim())];
+            while let Some(next) = lines.peek() {
+                let next = next.trim_start();
+                if next.is_empty() || starts_block(next) {
+                    break;
+                }
+                paragraph.push(parse_inline(next.trim_end()));
+                lines.next();
+            }
+            blocks.push(Block::Paragraph(paragraph));
+        }
+    }
+    blocks
+}
+
+fn starts_block(line: &str) -> bool {
+    line.starts_with("```") || heading(line).is_some() || list_item(line).is_some() || quote_line(line).is_some()
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let text = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then_some((level, text.trim()))
+}
+
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(text) = line.strip_prefix(bullet) {
+            return Some((false, text.trim()));
+        }
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let text = line[digits..].strip_prefix(". ")?;
+    (digits > 0).then_some((true, text.trim()))
+}
+
+fn quote_line(line: &str) -> Option<&str> {
+    line.strip_prefix('>').map(str::trim)
+}
+
+fn parse_inline(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    parse_styled(text, Style::default(), &mut spans);
+    spans
+}
+
+/// Emphasis markers only count when a matching closing marker follows,
+/// so "2 * 3" and snake_case names stay literal
+fn parse_styled(text: &str, style: Style, spans: &mut Vec<Span>) {
+    let mut literal = String::new();
+    let mut i = 0;
+
+    'scan: while i < text.len() {
+        let rest = &text[i..];
+
+        if let Some(after) = rest.strip_prefix('`') {
+            if let Some(end) = after.find('`') {
+                flush(&mut literal, style, spans);
+                spans.push(Span {
+                    text: after[..end].to_string(),
+                    style: Style { code: true, ..style },
+                    link: None,
+                });
+                i += end + 2;
+                continue;
+            }
+        }
+
+        for marker in ["**", "__", "*", "_"] {
+            if !rest.starts_with(marker) || !opens_emphasis(text, i, marker) {
+                continue;
+            }
+            let inner = &rest[marker.len()..];
+            if let Some(end) = closing_marker(inner, marker) {
+                flush(&mut literal, style, spans);
+                let inner_style = if marker.len() == 2 {
+                    Style { bold: true, ..style }
+                } else {
+                    Style { italic: true, ..style }
+                };
+                parse_styled(&inner[..end], inner_style, spans);
+                i += end + marker.len() * 2;
+                continue 'scan;
+            }
+        }
+
+        if let Some((label, url, len)) = link(rest) {
+            flush(&mut literal, style, spans);
+            spans.push(Span {
+                text: label.to_string(),
+                style,
+                link: Some(url.to_string()),
+            });
+            i += len;
+            continue;
+        }
+
+        let c = rest.chars().next().unwrap_or_default();
+        literal.push(c);
+        i += c.len_utf8();
+    }
+    flush(&mut literal, style, spans);
+}
+
+fn flush(literal: &mut String, style: Style, spans: &mut Vec<Span>) {
+    if !literal.is_empty() {
+        spans.push(Span {
+            text: std::mem::take(literal),
+            style,
+            link: None,
+        });
+    }
+}
+
+fn opens_emphasis(text: &str, at: usize, marker: &str) -> bool {
+    let next = text[at + marker.len()..].chars().next();
+    let previous = text[..at].chars().next_back();
+    let word_inside = marker.starts_with('_') && previous.is_some_and(char::is_alphanumeric);
+    next.is_some_and(|c| !c.is_whitespace()) && !word_inside
+}
+
+/// Byte offset of the marker closing an emphasis, if any
+fn closing_marker(inner: &str, marker: &str) -> Option<usize> {
+    let single = marker.len() == 1;
+    let mut from = 0;
+    while let Some(offset) = inner[from..].find(marker) {
+        let at = from + offset;
+        let after = &inner[at + marker.len()..];
+        // A single marker never closes on half of a double one
+        if single && after.starts_with(marker) {
+            from = at + 2;
+            continue;
+        }
+        let previous = inner[..at].chars().next_back();
+        if at > 0 && previous.is_some_and(|c| !c.is_whitespace()) {
+            return Some(at);
+        }
+        from = at + marker.len();
+    }
+    None
+}
+
+/// `[label](url)` at the start of `text`: (label, url, bytes consumed)
+fn link(text: &str) -> Option<(&str, &str, usize)> {
+    let label_end = text.strip_prefix('[')?.find("](")? + 1;
+    let url_start = label_end + 2;
+    let url_end = url_start + text[url_start..].find(')')?;
+    Some((&text[1..label_end], &text[url_start..url_end], url_end + 1))
+}
+
+// =============================================================================
+// HTML
+// =============================================================================
+
+fn to_html(blocks: &[Block]) -> String {
+    let mut html = String::new();
+    for block in blocks {
+        match block {
+            Block::Heading(level, spans) => {
+                html.push_str(&format!("<h{0}>{1}</h{0}>", level, html_spans(spans)));
+            }
+            Block::Paragraph(lines) => {
+                let lines: Vec<String> = lines.iter().map(|l| html_spans(l)).collect();
+                html.push_str(&format!("<p>{}</p>", lines.join("<br>")));
+            }
+            Block::List { ordered, items } => {
+                let tag = if *ordered { "ol" } else { "ul" };
+                html.push_str(&format!("<{}>", tag));
+                for item in items {
+                    html.push_str(&format!("<li>{}</li>", html_spans(item)));
+                }
+                html.push_str(&format!("</{}>", tag));
+            }
+            Block::Quote(lines) => {
+                let lines: Vec<String> = lines.iter().map(|l| html_spans(l)).collect();
+                html.push_str(&format!("<blockquote>{}</blockquote>", lines.join("<br>")));
+            }
+            Block::Code(lines) => {
+                html.push_str(&format!("<pre><code>{}</code></pre>", escape_html(&lines.join("\n"))));
+            }
+        }
+    }
+    html
+}
+
+fn html_spans(spans: &[Span]) -> String {
+    let mut html = String::new();
+    for span in spans {
+        let mut piece = escape_html(&span.text);
+        if span.style.code {
+            piece = format!("<code>{}</code>", piece);
+        }
+        if span.style.italic {
+            piece = format!("<em>{}</em>", piece);
+        }
+        if span.style.bold {
+            piece = format!("<strong>{}</strong>", piece);
+        }
+        if let Some(url) = &span.link {
+            piece = format!("<a href=\"{}\">{}</a>", escape_html(url), piece);
+        }
+        html.push_str(&piece);
+    }
+    html
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// =============================================================================
+// RTF
+// =============================================================================
+
+/// Heading font sizes in half-points, by level
+const RTF_HEADING_SIZES: [u32; 6] = [36, 32, 28, 26, 24, 22];
+
+fn to_rtf(blocks: &[Block]) -> String {
+    let mut rtf = String::from("{\\rtf1\\ansi\\deff0{\\fonttbl{\\f0\\fswiss Calibri;}{\\f1\\fmodern Consolas;}}\\fs22 ");
+    for block in blocks {
+        match block {
+            Block::Heading(level, spans) => {
+                let size = RTF_HEADING_SIZES[level - 1];
+                rtf.push_str(&format!("{{\\b\\fs{} {}}}\\par ", size, rtf_spans(spans)));
+            }
+            Block::Paragraph(lines) => {
+                let lines: Vec<String> = lines.iter().map(|l| rtf_spans(l)).collect();
+                rtf.push_str(&format!("{}\\par ", lines.join("\\line ")));
+            }
+            Block::List { ordered, items } => {
+                for (n, item) in items.iter().enumerate() {
+                    let marker = if *ordered { format!("{}.", n + 1) } else { "\\bullet".to_string() };
+                    rtf.push_str(&format!("{{\\li360\\fi-360 {}\\tab {}\\par}}", marker, rtf_spans(item)));
+                }
+            }
+            Block::Quote(lines) => {
+                let lines: Vec<String> = lines.iter().map(|l| rtf_spans(l)).collect();
+                rtf.push_str(&format!("{{\\li720\\i {}\\par}}", lines.join("\\line ")));
+            }
+            Block::Code(lines) => {
+                let lines: Vec<String> = lines.iter().map(|l| escape_rtf(l)).collect();
+                rtf.push_str(&format!("{{\\f1 {}\\par}}", lines.join("\\line ")));
+            }
+        }
+    }
+    rtf.push('}');
+    rtf
+}
+
+fn rtf_spans(spans: &[Span]) -> String {
+    let mut rtf = String::new();
+    for span in spans {
+        let mut controls = String::new();
+        if span.style.bold {
+            controls.push_str("\\b");
+        }
+        if span.style.italic {
+            controls.push_str("\\i");
+        }
+        if span.style.code {
+            controls.push_str("\\f1");
+        }
+        let mut piece = escape_rtf(&span.text);
+        if !controls.is_empty() {
+            piece = format!("{{{} {}}}", controls, piece);
+        }
+        if let Some(url) = &span.link {
+            piece = format!(
+                "{{\\field{{\\*\\fldinst HYPERLINK \"{}\"}}{{\\fldrslt {}}}}}",
+                escape_rtf(url),
+                piece
+            );
+        }
+        rtf.push_str(&piece);
+    }
+    rtf
+}
+
+/// RTF is 7-bit: escape control characters and write everything else as \uN? (UTF-16, signed)
+fn escape_rtf(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '\\' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c if c.is_ascii() && !c.is_ascii_control() => escaped.push(c),
+            c => {
+                let mut units = [0u16; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    escaped.push_str(&format!("\\u{}?", *unit as i16));
+                }
+            }
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn html(markdown: &str) -> String {
+        ClipboardContent::from_markdown(markdown).html.unwrap()
+    }
+
+    #[test]
+    fn test_html_renders_blocks() {
+        let markdown = "# Review\n\nCheck the **diff**\nand *tests*.\n\n- one\n- `two`\n\n1. first\n\n> quoted\n\n```\nlet x = 1 < 2;\n```";
+        assert_eq!(
+            html(markdown),
+            "<h1>Review</h1>\
+             <p>Check the <strong>diff</strong><br>and <em>tests</em>.</p>\
+             <ul><li>one</li><li><code>two</code></li></ul>\
+             <ol><li>first</li></ol>\
+             <blockquote>quoted</blockquote>\
+             <pre><code>let x = 1 &lt; 2;</code></pre>"
+        );
+    }
+
+    #[test]
+    fn test_inline_markers_need_a_closing_match() {
+        assert_eq!(html("2 * 3 = 6"), "<p>2 * 3 = 6</p>");
+        assert_eq!(html("use snake_case_names"), "<p>use snake_case_names</p>");
+        assert_eq!(
+            html("**bold *both* bold**"),
+            "<p><strong>bold </strong><strong><em>both</em></strong><strong> bold</strong></p>"
+        );
+        assert_eq!(
+            html("see [docs](https://example.com/?a=1&b=2)"),
+            "<p>see <a href=\"https://example.com/?a=1&amp;b=2\">docs</a></p>"
+        );
+    }
+
+    #[test]
+    fn test_rtf_escapes_and_styles() {
+        let content = ClipboardContent::from_markdown("**Hi** {x} café");
+        let rtf = content.rtf.unwrap();
+        assert!(rtf.starts_with("{\\rtf1"));
+        assert!(rtf.contains("{\\b Hi} \\{x\\} caf\\u233?\\par "));
+        assert!(rtf.ends_with('}'));
+        assert_eq!(content.text, "**Hi** {x} café");
+    }
+}
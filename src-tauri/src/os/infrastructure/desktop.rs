@@ -0,0 +1,98 @@
+// Infrastructure - Picks the focus and input adapters for the desktop session at runtime
+use crate::os::domain::ports::{InputSimulator, WindowManager};
+use once_cell::sync::Lazy;
+use std::sync::Arc;
+
+#[cfg(target_os = "linux")]
+use super::{WaylandFocusTracker, WaylandInputSimulator, X11FocusTracker, X11InputSimulator};
+use super::{WindowsFocusTracker, WindowsInputSimulator};
+
+/// The kind of desktop Prompter runs on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DesktopSession {
+    Windows,
+    X11,
+    Wayland,
+    /// No display found (e.g. a headless session); focus and paste report Unsupported
+    Unsupported,
+}
+
+static SESSION: Lazy<DesktopSession> = Lazy::new(|| {
+    let session = DesktopSession::from_env(|name| std::env::var(name).ok());
+    log::info!("Desktop session: {:?}", session);
+    session
+});
+
+impl DesktopSession {
+    /// The session this process runs in, detected once
+    pub fn current() -> Self {
+        *SESSION
+    }
+
+    /// Business rule: a Wayland session uses the Wayland adapters unless the app was
+    /// forced onto XWayland (GDK_BACKEND=x11), where X11 sees the other apps' windows
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        if cfg!(target_os = "windows") {
+            return Self::Windows;
+        }
+        let set = |name: &str| var(name).is_some_and(|value| !value.is_empty());
+        let forced_x11 = var("GDK_BACKEND").is_some_and(|backend| backend == "x11") && set("DISPLAY");
+        let wayland = set("WAYLAND_DISPLAY") || var("XDG_SESSION_TYPE").is_some_and(|kind| kind == "wayland");
+        if wayland && !forced_x11 {
+            Self::Wayland
+        } else if set("DISPLAY") {
+            Self::X11
+        } else {
+            Self::Unsupported
+        }
+    }
+}
+
+/// Focus tracking for the current desktop
+pub fn window_manager() -> Arc<dyn WindowManager> {
+    match DesktopSession::current() {
+        #[cfg(target_os = "linux")]
+        DesktopSession::X11 => Arc::new(X11FocusTracker::new()),
+        #[cfg(target_os = "linux")]
+        DesktopSession::Wayland => Arc::new(WaylandFocusTracker::new()),
+        // Off Windows this is the stub that reports Unsupported
+        _ => Arc::new(WindowsFocusTracker::new()),
+    }
+}
+
+/// Key synthesis for the current desktop
+pub fn input_simulator() -> Arc<dyn InputSimulator> {
+    match DesktopSession::current() {
+        #[cfg(target_os = "linux")]
+        DesktopSession::X11 => Arc::new(X11InputSimulator::new()),
+        #[cfg(target_os = "linux")]
+        DesktopSession::Wayland => Arc::new(WaylandInputSimulator::new()),
+        _ => Arc::new(WindowsInputSimulator::new()),
+    }
+}
+
+#[cfg(all(test, not(target_os = "windows")))]
+mod tests {
+    use super::*;
+
+    fn session(vars: &[(&str, &str)]) -> DesktopSession {
+        DesktopSession::from_env(|name| vars.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string()))
+    }
+
+    #[test]
+    fn test_detects_session_from_environment() {
+        assert_eq!(session(&[("DISPLAY", ":0")]), DesktopSession::X11);
+        assert_eq!(session(&[("WAYLAND_DISPLAY", "wayland-0"), ("DISPLAY", ":0")]), DesktopSession::Wayland);
+        assert_eq!(session(&[("XDG_SESSION_TYPE", "wayland")]), DesktopSession::Wayland);
+        assert_eq!(session(&[("DISPLAY", "")]), DesktopSession::Unsupported);
+        assert_eq!(session(&[]), DesktopSession::Unsupported);
+    }
+
+    #[test]
+    fn test_xwayland_backend_uses_x11() {
+        let vars = [("WAYLAND_DISPLAY", "wayland-0"), ("DISPLAY", ":0"), ("GDK_BACKEND", "x11")];
+        assert_eq!(session(&vars), DesktopSession::X11);
+        // Without an X display to talk to, Wayland is still the only option
+        assert_eq!(session(&[("WAYLAND_DISPLAY", "wayland-0"), ("GDK_BACKEND", "x11")]), DesktopSession::Wayland);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::os::domain::ports::ClipboardService;
+use crate::os::domain::{ClipboardContent, OsError};
+use std::sync::Mutex;
+
+/// In-memory implementation of ClipboardService (Adapter for tests)
+/// Keeps every written format so tests can check what a rich paste produced
+#[derive(Default)]
+pub struct InMemoryClipboard {
+    content: Mutex<ClipboardContent>,
+}
+
+impl InMemoryClipboard {
+    pub fn with_text(text: &str) -> Self {
+        Self {
+            content: Mutex::new(ClipboardContent::plain(text)),
+        }
+    }
+
+    pub fn content(&self) -> ClipboardContent {
+        self.content.lock().unwrap().clone()
+    }
+}
+
+impl ClipboardService for InMemoryClipboard {
+    fn write_text(&self, text: &str) -> Result<(), OsError> {
+        *self.content.lock().unwrap() = ClipboardContent::plain(text);
+        Ok(())
+    }
+
+    fn read_text(&self) -> Result<String, OsError> {
+        Ok(self.content.lock().unwrap().text.clone())
+    }
+
+    fn write_content(&self, content: &ClipboardContent) -> Result<(), OsError> {
+        *self.content.lock().unwrap() = content.clone();
+        Ok(())
+    }
+}
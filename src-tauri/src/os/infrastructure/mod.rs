@@ -2,7 +2,31 @@
 pub mod windows_focus;
 pub mod tauri_clipboard;
 pub mod windows_input;
+pub mod windows_keyboard_hook;
+#[cfg(target_os = "linux")]
+pub mod x11_focus;
+#[cfg(target_os = "linux")]
+pub mod x11_input;
+#[cfg(target_os = "linux")]
+pub mod wayland_focus;
+#[cfg(target_os = "linux")]
+pub mod wayland_input;
+pub mod desktop;
+#[cfg(test)]
+pub mod memory_clipboard;
 
 pub use windows_focus::WindowsFocusTracker;
 pub use tauri_clipboard::TauriClipboardAdapter;
 pub use windows_input::WindowsInputSimulator;
+pub use windows_keyboard_hook::WindowsKeyboardHook;
+#[cfg(target_os = "linux")]
+pub use x11_focus::X11FocusTracker;
+#[cfg(target_os = "linux")]
+pub use x11_input::X11InputSimulator;
+#[cfg(target_os = "linux")]
+pub use wayland_focus::WaylandFocusTracker;
+#[cfg(target_os = "linux")]
+pub use wayland_input::WaylandInputSimulator;
+pub use desktop::{input_simulator, window_manager, DesktopSession};
+#[cfg(test)]
+pub use memory_clipboard::InMemoryClipboard;
@@ -1,8 +1,10 @@
 use crate::os::domain::ports::ClipboardService;
+use crate::os::domain::{ClipboardContent, OsError};
 use tauri_plugin_clipboard_manager::ClipboardExt;
 
 /// Tauri implementation of ClipboardService (Adapter)
-/// Note: Uses Tauri's clipboard plugin which is cross-platform
+/// Note: Uses Tauri's clipboard plugin which is cross-platform; the plugin can't write RTF,
+/// so on Windows rich content is written with the Win32 clipboard API instead
 pub struct TauriClipboardAdapter {
     app_handle: tauri::AppHandle,
 }
@@ -14,17 +16,125 @@ impl TauriClipboardAdapter {
 }
 
 impl ClipboardService for TauriClipboardAdapter {
-    fn write_text(&self, text: &str) -> Result<(), String> {
+    fn write_text(&self, text: &str) -> Result<(), OsError> {
         self.app_handle
             .clipboard()
             .write_text(text)
-            .map_err(|e| format!("Clipboard write error: {}", e))
+            .map_err(|e| OsError::Clipboard(format!("write failed: {}", e)))
     }
 
-    fn read_text(&self) -> Result<String, String> {
+    fn read_text(&self) -> Result<String, OsError> {
         self.app_handle
             .clipboard()
             .read_text()
-            .map_err(|e| format!("Clipboard read error: {}", e))
+            .map_err(|e| OsError::Clipboard(format!("read failed: {}", e)))
+    }
+
+    fn write_content(&self, content: &ClipboardContent) -> Result<(), OsError> {
+        #[cfg(target_os = "windows")]
+        if content.rtf.is_some() {
+            return win32::write_all(content);
+        }
+
+        match &content.html {
+            Some(html) => self
+                .app_handle
+                .clipboard()
+                .write_html(html.as_str(), Some(content.text.as_str()))
+                .map_err(|e| OsError::Clipboard(format!("write failed: {}", e))),
+            None => self.write_text(&content.text),
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod win32 {
+    use crate::os::domain::{ClipboardContent, OsError};
+    use windows::core::w;
+    use windows::Win32::Foundation::{HANDLE, HWND};
+    use windows::Win32::System::DataExchange::{
+        CloseClipboard, EmptyClipboard, OpenClipboard, RegisterClipboardFormatW, SetClipboardData,
+    };
+    use windows::Win32::System::Memory::{GlobalAlloc, GlobalFree, GlobalLock, GlobalUnlock, GMEM_MOVEABLE};
+
+    /// Standard clipboard format for UTF-16 text
+    const CF_UNICODETEXT: u32 = 13;
+
+    /// Write every format in one clipboard session so apps see them together
+    pub fn write_all(content: &ClipboardContent) -> Result<(), OsError> {
+        unsafe {
+            OpenClipboard(HWND::default()).map_err(|e| OsError::Clipboard(format!("open failed: {}", e)))?;
+            let result = write_formats(content);
+            let _ = CloseClipboard();
+            result
+        }
+    }
+
+    unsafe fn write_formats(content: &ClipboardContent) -> Result<(), OsError> {
+        EmptyClipboard().map_err(|e| OsError::Clipboard(format!("empty failed: {}", e)))?;
+
+        let text: Vec<u16> = content.text.encode_utf16().chain(std::iter::once(0)).collect();
+        let text_bytes = std::slice::from_raw_parts(text.as_ptr() as *const u8, text.len() * 2);
+        set_data(CF_UNICODETEXT, text_bytes)?;
+
+        if let Some(html) = &content.html {
+            let format = RegisterClipboardFormatW(w!("HTML Format"));
+            set_data(format, nul_terminated(&cf_html(html)).as_slice())?;
+        }
+        if let Some(rtf) = &content.rtf {
+            let format = RegisterClipboardFormatW(w!("Rich Text Format"));
+            set_data(format, nul_terminated(rtf).as_slice())?;
+        }
+        Ok(())
+    }
+
+    unsafe fn set_data(format: u32, bytes: &[u8]) -> Result<(), OsError> {
+        let memory = GlobalAlloc(GMEM_MOVEABLE, bytes.len())
+            .map_err(|e| OsError::Clipboard(format!("allocation failed: {}", e)))?;
+        let target = GlobalLock(memory) as *mut u8;
+        if target.is_null() {
+            let _ = GlobalFree(memory);
+            return Err(OsError::Clipboard("allocation failed: could not lock memory".to_string()));
+        }
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), target, bytes.len());
+        let _ = GlobalUnlock(memory);
+
+        // The clipboard owns the memory once SetClipboardData succeeds
+        if let Err(e) = SetClipboardData(format, HANDLE(memory.0)) {
+            let _ = GlobalFree(memory);
+            return Err(OsError::Clipboard(format!("write failed: {}", e)));
+        }
+        Ok(())
+    }
+
+    fn nul_terminated(text: &str) -> Vec<u8> {
+        let mut bytes = text.as_bytes().to_vec();
+        bytes.push(0);
+        bytes
+    }
+
+    /// Wrap an HTML fragment in the CF_HTML envelope: a header with UTF-8 byte offsets
+    fn cf_html(fragment: &str) -> String {
+        const PREFIX: &str = "<html><body>\r\n<!--StartFragment-->";
+        const SUFFIX: &str = "<!--EndFragment-->\r\n</body></html>";
+        let header = |start_html: usize, end_html: usize, start_fragment: usize, end_fragment: usize| {
+            format!(
+                "Version:0.9\r\nStartHTML:{:010}\r\nEndHTML:{:010}\r\nStartFragment:{:010}\r\nEndFragment:{:010}\r\n",
+                start_html, end_html, start_fragment, end_fragment
+            )
+        };
+
+        // Offsets are zero-padded, so the header length doesn't depend on them
+        let start_html = header(0, 0, 0, 0).len();
+        let start_fragment = start_html + PREFIX.len();
+        let end_fragment = start_fragment + fragment.len();
+        let end_html = end_fragment + SUFFIX.len();
+        format!(
+            "{}{}{}{}",
+            header(start_html, end_html, start_fragment, end_fragment),
+            PREFIX,
+            fragment,
+            SUFFIX
+        )
     }
 }
@@ -0,0 +1,64 @@
+use crate::os::domain::ports::WindowManager;
+use crate::os::domain::OsError;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static REMEMBERED: AtomicBool = AtomicBool::new(false);
+
+/// Wayland implementation of WindowManager (Adapter)
+///
+/// Wayland doesn't let clients see or activate other apps' windows. It doesn't need
+/// to: when Prompter hides, the compositor hands focus back to the window that had it.
+/// So this only tracks whether there is a window to return to; the target app is
+/// unknown, and per-app paste settings fall back to the defaults
+pub struct WaylandFocusTracker;
+
+impl WaylandFocusTracker {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl WindowManager for WaylandFocusTracker {
+    fn remember_current_window(&self) -> Result<(), OsError> {
+        REMEMBERED.store(true, Ordering::SeqCst);
+        log::info!("WaylandFocusTracker: Remembered focus (compositor restores it on hide)");
+        Ok(())
+    }
+
+    /// Prompter's own windows can't be told apart from other apps'; focus leaving the
+    /// launcher is treated as an external switch
+    fn remember_if_external(&self) -> Result<bool, OsError> {
+        self.remember_current_window().map(|()| true)
+    }
+
+    fn restore_previous_window(&self) -> Result<(), OsError> {
+        if !REMEMBERED.load(Ordering::SeqCst) {
+            log::error!("WaylandFocusTracker: No previous window saved");
+            return Err(OsError::NoSavedWindow);
+        }
+        Ok(())
+    }
+
+    fn clear_saved_window(&self) {
+        REMEMBERED.store(false, Ordering::SeqCst);
+        log::info!("WaylandFocusTracker: Cleared saved window");
+    }
+
+    fn remembered_app(&self) -> Option<String> {
+        None
+    }
+
+    fn remembered_window_class(&self) -> Option<String> {
+        None
+    }
+
+    /// Wayland has no integrity levels that block input between apps
+    fn remembered_window_elevated(&self) -> bool {
+        false
+    }
+
+    /// Clients can't see which window has focus
+    fn remembered_window_focused(&self) -> Option<bool> {
+        None
+    }
+}
@@ -0,0 +1,167 @@
+use crate::os::domain::ports::{InputSimulator, KeyCode, PasteStrategy};
+use crate::os::domain::OsError;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// The chord `simulate_paste` sends
+const CTRL_V: [KeyCode; 2] = [KeyCode::Control, KeyCode::V];
+
+/// Wayland implementation of InputSimulator (Adapter)
+///
+/// Wayland has no XTest; keys go through the compositor's virtual-keyboard protocol
+/// using the `wtype` tool (wlroots compositors such as Sway and Hyprland, and KDE).
+/// Where it's missing or unsupported the paste fails and the text stays on the clipboard
+pub struct WaylandInputSimulator;
+
+impl WaylandInputSimulator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// wtype's name for a modifier key, if `key` is one
+    fn modifier(key: KeyCode) -> Option<&'static str> {
+        match key {
+            KeyCode::Control => Some("ctrl"),
+            KeyCode::Shift => Some("shift"),
+            KeyCode::Alt => Some("alt"),
+            KeyCode::Win => Some("logo"),
+            _ => None,
+        }
+    }
+
+    /// XKB keysym name of a non-modifier key; `None` for modifiers and for characters, which are typed as text
+    fn key_name(key: KeyCode) -> Option<String> {
+        let name = match key {
+            KeyCode::V => "v",
+            KeyCode::Backspace => "BackSpace",
+            KeyCode::Enter => "Return",
+            KeyCode::Tab => "Tab",
+            KeyCode::Insert => "Insert",
+            KeyCode::Escape => "Escape",
+            KeyCode::Space => "space",
+            KeyCode::Delete => "Delete",
+            KeyCode::Home => "Home",
+            KeyCode::End => "End",
+            KeyCode::PageUp => "Prior",
+            KeyCode::PageDown => "Next",
+            KeyCode::Up => "Up",
+            KeyCode::Down => "Down",
+            KeyCode::Left => "Left",
+            KeyCode::Right => "Right",
+            KeyCode::F(n) => return Some(format!("F{}", n.clamp(1, 24))),
+            KeyCode::Letter(c) => return Some(c.to_ascii_lowercase().to_string()),
+            KeyCode::Control | KeyCode::Shift | KeyCode::Alt | KeyCode::Win | KeyCode::Char(_) => return None,
+        };
+        Some(name.to_string())
+    }
+
+    /// wtype arguments for a chord: modifiers held with -M/-m, other keys pressed and released
+    fn chord_args(keys: &[KeyCode]) -> Vec<String> {
+        let mut args = Vec::new();
+        for key in keys {
+            match (Self::modifier(*key), Self::key_name(*key)) {
+                (Some(modifier), _) => args.extend(["-M".to_string(), modifier.to_string()]),
+                (None, Some(name)) => args.extend(["-P".to_string(), name]),
+                (None, None) => {}
+            }
+        }
+        for key in keys.iter().rev() {
+            match (Self::modifier(*key), Self::key_name(*key)) {
+                (Some(modifier), _) => args.extend(["-m".to_string(), modifier.to_string()]),
+                (None, Some(name)) => args.extend(["-p".to_string(), name]),
+                (None, None) => {}
+            }
+        }
+        args
+    }
+
+    /// Run wtype; `stdin` is text to type, passed on stdin so it never shows up in the process list
+    fn wtype(args: &[String], stdin: Option<&str>) -> Result<(), OsError> {
+        let mut child = Command::new("wtype")
+            .args(args)
+            .stdin(if stdin.is_some() { Stdio::piped() } else { Stdio::null() })
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => OsError::Input("wtype is not installed".to_string()),
+                _ => OsError::Input(format!("could not run wtype: {}", e)),
+            })?;
+        if let (Some(text), Some(mut pipe)) = (stdin, child.stdin.take()) {
+            pipe.write_all(text.as_bytes()).map_err(|e| OsError::Input(format!("wtype: {}", e)))?;
+        }
+        let output = child.wait_with_output().map_err(|e| OsError::Input(format!("wtype: {}", e)))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(OsError::Input(format!("wtype failed: {}", stderr.trim())));
+        }
+        Ok(())
+    }
+}
+
+impl InputSimulator for WaylandInputSimulator {
+    fn simulate_paste(&self) -> Result<(), OsError> {
+        log::info!("simulate_paste: Sending Ctrl+V through wtype");
+        Self::wtype(&Self::chord_args(&CTRL_V), None)
+    }
+
+    fn simulate_keys(&self, keys: &[KeyCode]) -> Result<(), OsError> {
+        Self::wtype(&Self::chord_args(keys), None)
+    }
+
+    /// Runs of characters are typed in one wtype call with `interval` between keys;
+    /// named keys (Enter, Tab, ...) are tapped on their own
+    fn type_keys(&self, keys: &[KeyCode], interval: Duration) -> Result<(), OsError> {
+        // No count logged: it would reveal the length of the (possibly secret) text
+        log::info!("type_keys: Typing text");
+        let delay = ["-d".to_string(), interval.as_millis().to_string(), "-".to_string()];
+        let mut text = String::new();
+        for key in keys {
+            match key {
+                KeyCode::Char(c) => text.push(*c),
+                key => {
+                    if !text.is_empty() {
+                        Self::wtype(&delay, Some(&std::mem::take(&mut text)))?;
+                        std::thread::sleep(interval);
+                    }
+                    Self::wtype(&Self::chord_args(&[*key]), None)?;
+                    std::thread::sleep(interval);
+                }
+            }
+        }
+        if !text.is_empty() {
+            Self::wtype(&delay, Some(&text))?;
+        }
+        Ok(())
+    }
+
+    fn paste_with(&self, strategy: PasteStrategy, chord: &[KeyCode]) -> Result<(), OsError> {
+        log::info!("paste_with: {:?} with {:?}", strategy, chord);
+        match strategy {
+            PasteStrategy::SendInput => Self::wtype(&Self::chord_args(chord), None),
+            // Window messages only exist on Windows; the profile's next strategy is tried
+            PasteStrategy::MessageKeys | PasteStrategy::WmPaste => Err(OsError::Unsupported("Message-based paste")),
+            PasteStrategy::TypeOut => Err(OsError::Input("type-out needs the text; use type_keys".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chord_args_hold_modifiers_around_keys() {
+        let chord = KeyCode::parse_chord("Ctrl+Shift+V").unwrap();
+        assert_eq!(
+            WaylandInputSimulator::chord_args(&chord),
+            ["-M", "ctrl", "-M", "shift", "-P", "v", "-p", "v", "-m", "shift", "-m", "ctrl"]
+        );
+        assert_eq!(
+            WaylandInputSimulator::chord_args(&[KeyCode::Shift, KeyCode::Insert]),
+            ["-M", "shift", "-P", "Insert", "-p", "Insert", "-m", "shift"]
+        );
+        assert_eq!(WaylandInputSimulator::chord_args(&[KeyCode::F(5)]), ["-P", "F5", "-p", "F5"]);
+    }
+}
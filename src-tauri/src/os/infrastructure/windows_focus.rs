@@ -1,13 +1,25 @@
 use crate::os::domain::ports::WindowManager;
+use crate::os::domain::OsError;
 #[cfg(target_os = "windows")]
 use once_cell::sync::Lazy;
 #[cfg(target_os = "windows")]
 use std::sync::Mutex;
 
 #[cfg(target_os = "windows")]
-use windows::Win32::Foundation::{GetLastError, HWND};
+use windows::core::PWSTR;
 #[cfg(target_os = "windows")]
-use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, IsWindow, SetForegroundWindow, GetWindowThreadProcessId};
+use windows::Win32::Foundation::{CloseHandle, GetLastError, ERROR_ACCESS_DENIED, HANDLE, HWND};
+#[cfg(target_os = "windows")]
+use windows::Win32::Security::{
+    GetSidSubAuthority, GetSidSubAuthorityCount, GetTokenInformation, TokenIntegrityLevel, TOKEN_MANDATORY_LABEL, TOKEN_QUERY,
+};
+#[cfg(target_os = "windows")]
+use windows::Win32::System::Threading::{
+    GetCurrentProcess, OpenProcess, OpenProcessToken, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+    PROCESS_QUERY_LIMITED_INFORMATION,
+};
+#[cfg(target_os = "windows")]
+use windows::Win32::UI::WindowsAndMessaging::{GetClassNameW, GetForegroundWindow, IsWindow, SetForegroundWindow, GetWindowThreadProcessId};
 
 #[cfg(target_os = "windows")]
 static PREVIOUS_WINDOW: Lazy<Mutex<Option<isize>>> = Lazy::new(|| Mutex::new(None));
@@ -36,6 +48,82 @@ impl WindowsFocusTracker {
         process_id
     }
 
+    /// Get the executable file name (e.g. "Code.exe") of the process owning a window
+    #[cfg(target_os = "windows")]
+    fn get_window_process_name(hwnd: HWND) -> Option<String> {
+        let process_id = Self::get_window_process_id(hwnd);
+        if process_id == 0 {
+            return None;
+        }
+
+        unsafe {
+            let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id).ok()?;
+
+            let mut buffer = [0u16; 260];
+            let mut len = buffer.len() as u32;
+            let result = QueryFullProcessImageNameW(
+                process,
+                PROCESS_NAME_WIN32,
+                PWSTR(buffer.as_mut_ptr()),
+                &mut len,
+            );
+            let _ = CloseHandle(process);
+            result.ok()?;
+
+            let full_path = String::from_utf16_lossy(&buffer[..len as usize]);
+            full_path.rsplit('\\').next().map(|name| name.to_string())
+        }
+    }
+
+    /// Mandatory integrity level of a process (e.g. 0x2000 medium, 0x3000 high)
+    #[cfg(target_os = "windows")]
+    fn process_integrity_level(process: HANDLE) -> windows::core::Result<u32> {
+        unsafe {
+            let mut token = HANDLE::default();
+            OpenProcessToken(process, TOKEN_QUERY, &mut token)?;
+
+            let mut len = 0u32;
+            let _ = GetTokenInformation(token, TokenIntegrityLevel, None, 0, &mut len);
+            // u64 elements keep the label's SID pointer aligned
+            let mut buffer = vec![0u64; (len as usize + 7) / 8];
+            let result = GetTokenInformation(token, TokenIntegrityLevel, Some(buffer.as_mut_ptr().cast()), len, &mut len);
+            let _ = CloseHandle(token);
+            result?;
+
+            // The level is the last sub-authority of the label's SID
+            let label = &*(buffer.as_ptr() as *const TOKEN_MANDATORY_LABEL);
+            let count = *GetSidSubAuthorityCount(label.Label.Sid);
+            Ok(*GetSidSubAuthority(label.Label.Sid, u32::from(count).saturating_sub(1)))
+        }
+    }
+
+    /// Whether a window's process runs at a higher integrity level than Prompter, so UIPI
+    /// drops the input Prompter sends it
+    /// A token that can't be read is how an elevated process looks from a non-elevated one
+    #[cfg(target_os = "windows")]
+    fn is_window_elevated(hwnd: HWND) -> bool {
+        let process_id = Self::get_window_process_id(hwnd);
+        if process_id == 0 {
+            return false;
+        }
+
+        unsafe {
+            let Ok(own_level) = Self::process_integrity_level(GetCurrentProcess()) else {
+                return false;
+            };
+            let Ok(process) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id) else {
+                return false;
+            };
+            let target_level = Self::process_integrity_level(process);
+            let _ = CloseHandle(process);
+
+            match target_level {
+                Ok(level) => level > own_level,
+                Err(e) => e.code() == ERROR_ACCESS_DENIED.to_hresult(),
+            }
+        }
+    }
+
     /// Check if a window belongs to the current process (i.e., is a Prompter window)
     #[cfg(target_os = "windows")]
     pub fn is_same_process(hwnd: HWND) -> bool {
@@ -43,21 +131,33 @@ impl WindowsFocusTracker {
         let our_pid = std::process::id();
         window_pid == our_pid
     }
+}
 
-    /// Remember the current foreground window ONLY if it's an external app (not a Prompter window)
-    /// This is called when any Prompter window loses focus to track the last external app.
-    ///
-    /// Returns:
-    /// - Ok(true) if an external window was saved
-    /// - Ok(false) if the new foreground is another Prompter window (internal switch)
-    /// - Err if we couldn't get the foreground window
-    #[cfg(target_os = "windows")]
-    pub fn remember_if_external(&self) -> Result<bool, String> {
+#[cfg(target_os = "windows")]
+impl WindowManager for WindowsFocusTracker {
+    fn remember_current_window(&self) -> Result<(), OsError> {
+        unsafe {
+            let hwnd = GetForegroundWindow();
+            if !hwnd.is_invalid() {
+                let hwnd_val = hwnd.0 as isize;
+                *PREVIOUS_WINDOW.lock().unwrap() = Some(hwnd_val);
+                log::info!("WindowsFocusTracker: Remembered window handle: 0x{:X}", hwnd_val);
+                Ok(())
+            } else {
+                log::error!("WindowsFocusTracker: Could not get foreground window");
+                Err(OsError::NoForegroundWindow)
+            }
+        }
+    }
+
+    /// Only an external app (different process) is remembered; another Prompter window
+    /// taking focus leaves the saved window alone
+    fn remember_if_external(&self) -> Result<bool, OsError> {
         unsafe {
             let hwnd = GetForegroundWindow();
             if hwnd.is_invalid() {
                 log::warn!("WindowsFocusTracker: Could not get foreground window for external check");
-                return Err("Could not get foreground window".to_string());
+                return Err(OsError::NoForegroundWindow);
             }
 
             let hwnd_val = hwnd.0 as isize;
@@ -82,31 +182,7 @@ impl WindowsFocusTracker {
         }
     }
 
-    // Non-Windows stubs
-    #[cfg(not(target_os = "windows"))]
-    pub fn remember_if_external(&self) -> Result<bool, String> {
-        Err("WindowsFocusTracker is only supported on Windows".to_string())
-    }
-}
-
-#[cfg(target_os = "windows")]
-impl WindowManager for WindowsFocusTracker {
-    fn remember_current_window(&self) -> Result<(), String> {
-        unsafe {
-            let hwnd = GetForegroundWindow();
-            if !hwnd.is_invalid() {
-                let hwnd_val = hwnd.0 as isize;
-                *PREVIOUS_WINDOW.lock().unwrap() = Some(hwnd_val);
-                log::info!("WindowsFocusTracker: Remembered window handle: 0x{:X}", hwnd_val);
-                Ok(())
-            } else {
-                log::error!("WindowsFocusTracker: Could not get foreground window");
-                Err("Could not get foreground window".to_string())
-            }
-        }
-    }
-
-    fn restore_previous_window(&self) -> Result<(), String> {
+    fn restore_previous_window(&self) -> Result<(), OsError> {
         let handle = PREVIOUS_WINDOW.lock().unwrap();
         if let Some(hwnd_val) = *handle {
             log::info!("WindowsFocusTracker: Attempting to restore window handle: 0x{:X}", hwnd_val);
@@ -116,7 +192,7 @@ impl WindowManager for WindowsFocusTracker {
                 // Validate HWND before attempting to use it
                 if !IsWindow(hwnd).as_bool() {
                     log::error!("WindowsFocusTracker: Invalid window handle (window may have been closed)");
-                    return Err(format!("Invalid window handle: 0x{:X}", hwnd_val));
+                    return Err(OsError::WindowClosed);
                 }
                 log::info!("WindowsFocusTracker: HWND validated successfully");
 
@@ -125,7 +201,7 @@ impl WindowManager for WindowsFocusTracker {
                 if !result.as_bool() {
                     let error = GetLastError();
                     log::error!("WindowsFocusTracker: SetForegroundWindow failed with error: {:?}", error);
-                    return Err(format!("SetForegroundWindow failed: {:?}", error));
+                    return Err(OsError::Focus(format!("SetForegroundWindow failed: {:?}", error)));
                 }
                 log::info!("WindowsFocusTracker: SetForegroundWindow returned success");
 
@@ -143,11 +219,11 @@ impl WindowManager for WindowsFocusTracker {
 
                 // Focus verification failed after all retries
                 log::error!("WindowsFocusTracker: Focus verification failed after 3 attempts");
-                Err("Focus verification failed - window didn't gain focus".to_string())
+                Err(OsError::Focus("window didn't gain focus".to_string()))
             }
         } else {
             log::error!("WindowsFocusTracker: No previous window saved");
-            Err("No previous window saved".to_string())
+            Err(OsError::NoSavedWindow)
         }
     }
 
@@ -155,19 +231,84 @@ impl WindowManager for WindowsFocusTracker {
         *PREVIOUS_WINDOW.lock().unwrap() = None;
         log::info!("WindowsFocusTracker: Cleared saved window");
     }
+
+    fn remembered_app(&self) -> Option<String> {
+        let hwnd_val = (*PREVIOUS_WINDOW.lock().unwrap())?;
+        let hwnd = HWND(hwnd_val as *mut _);
+        unsafe {
+            if !IsWindow(hwnd).as_bool() {
+                return None;
+            }
+        }
+        Self::get_window_process_name(hwnd)
+    }
+
+    fn remembered_window_class(&self) -> Option<String> {
+        let hwnd_val = (*PREVIOUS_WINDOW.lock().unwrap())?;
+        let hwnd = HWND(hwnd_val as *mut _);
+        unsafe {
+            if !IsWindow(hwnd).as_bool() {
+                return None;
+            }
+            // Class names are at most 256 characters
+            let mut buffer = [0u16; 257];
+            let len = GetClassNameW(hwnd, &mut buffer);
+            if len <= 0 {
+                return None;
+            }
+            Some(String::from_utf16_lossy(&buffer[..len as usize]))
+        }
+    }
+
+    fn remembered_window_elevated(&self) -> bool {
+        let Some(hwnd_val) = *PREVIOUS_WINDOW.lock().unwrap() else {
+            return false;
+        };
+        let elevated = Self::is_window_elevated(HWND(hwnd_val as *mut _));
+        if elevated {
+            log::warn!("WindowsFocusTracker: Window 0x{:X} belongs to an elevated process", hwnd_val);
+        }
+        elevated
+    }
+
+    fn remembered_window_focused(&self) -> Option<bool> {
+        let hwnd_val = (*PREVIOUS_WINDOW.lock().unwrap())?;
+        let foreground = unsafe { GetForegroundWindow() };
+        Some(foreground.0 as isize == hwnd_val)
+    }
 }
 
 #[cfg(not(target_os = "windows"))]
 impl WindowManager for WindowsFocusTracker {
-    fn remember_current_window(&self) -> Result<(), String> {
-        Err("WindowsFocusTracker is only supported on Windows".to_string())
+    fn remember_current_window(&self) -> Result<(), OsError> {
+        Err(OsError::Unsupported("WindowsFocusTracker"))
+    }
+
+    fn remember_if_external(&self) -> Result<bool, OsError> {
+        Err(OsError::Unsupported("WindowsFocusTracker"))
     }
 
-    fn restore_previous_window(&self) -> Result<(), String> {
-        Err("WindowsFocusTracker is only supported on Windows".to_string())
+    fn restore_previous_window(&self) -> Result<(), OsError> {
+        Err(OsError::Unsupported("WindowsFocusTracker"))
     }
 
     fn clear_saved_window(&self) {
         // No-op on non-Windows
     }
+
+    fn remembered_app(&self) -> Option<String> {
+        None
+    }
+
+    fn remembered_window_class(&self) -> Option<String> {
+        None
+    }
+
+    fn remembered_window_elevated(&self) -> bool {
+        false
+    }
+
+    fn remembered_window_focused(&self) -> Option<bool> {
+        None
+    }
 }
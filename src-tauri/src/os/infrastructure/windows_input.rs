@@ -1,4 +1,6 @@
-use crate::os::domain::ports::{InputSimulator, KeyCode};
+use crate::os::domain::ports::{InputSimulator, KeyCode, PasteStrategy};
+use crate::os::domain::OsError;
+use std::time::Duration;
 
 #[cfg(target_os = "windows")]
 use windows::Win32::Foundation::{LPARAM, WPARAM};
@@ -6,8 +8,10 @@ use windows::Win32::Foundation::{LPARAM, WPARAM};
 use windows::Win32::System::Threading::{AttachThreadInput, GetCurrentThreadId};
 #[cfg(target_os = "windows")]
 use windows::Win32::UI::Input::KeyboardAndMouse::{
-    GetFocus, MapVirtualKeyW, SendInput, INPUT, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP,
-    MAPVK_VK_TO_VSC, VIRTUAL_KEY, VK_CONTROL, VK_V,
+    GetFocus, MapVirtualKeyW, SendInput, INPUT, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_EXTENDEDKEY,
+    KEYEVENTF_KEYUP, KEYEVENTF_UNICODE, MAPVK_VK_TO_VSC, VIRTUAL_KEY, VK_BACK, VK_CONTROL, VK_DELETE,
+    VK_DOWN, VK_END, VK_ESCAPE, VK_F1, VK_HOME, VK_INSERT, VK_LEFT, VK_LWIN, VK_MENU, VK_NEXT, VK_PRIOR,
+    VK_RETURN, VK_RIGHT, VK_SHIFT, VK_SPACE, VK_TAB, VK_UP, VK_V,
 };
 #[cfg(target_os = "windows")]
 use windows::Win32::UI::WindowsAndMessaging::{
@@ -15,6 +19,9 @@ use windows::Win32::UI::WindowsAndMessaging::{
     WM_KEYDOWN, WM_KEYUP, WM_PASTE,
 };
 
+/// The chord `simulate_paste` sends
+const CTRL_V: [KeyCode; 2] = [KeyCode::Control, KeyCode::V];
+
 /// Windows implementation of InputSimulator (Adapter)
 pub struct WindowsInputSimulator;
 
@@ -28,9 +35,86 @@ impl WindowsInputSimulator {
         match key {
             KeyCode::Control => VK_CONTROL,
             KeyCode::V => VK_V,
+            KeyCode::Backspace => VK_BACK,
+            KeyCode::Enter => VK_RETURN,
+            KeyCode::Tab => VK_TAB,
+            KeyCode::Shift => VK_SHIFT,
+            KeyCode::Alt => VK_MENU,
+            KeyCode::Insert => VK_INSERT,
+            KeyCode::Escape => VK_ESCAPE,
+            KeyCode::Win => VK_LWIN,
+            KeyCode::Space => VK_SPACE,
+            KeyCode::Delete => VK_DELETE,
+            KeyCode::Home => VK_HOME,
+            KeyCode::End => VK_END,
+            KeyCode::PageUp => VK_PRIOR,
+            KeyCode::PageDown => VK_NEXT,
+            KeyCode::Up => VK_UP,
+            KeyCode::Down => VK_DOWN,
+            KeyCode::Left => VK_LEFT,
+            KeyCode::Right => VK_RIGHT,
+            // VK_F1..VK_F24 are consecutive
+            KeyCode::F(n) => VIRTUAL_KEY(VK_F1.0 + n.clamp(1, 24) as u16 - 1),
+            // Virtual key codes of A-Z and 0-9 are their ASCII codes
+            KeyCode::Letter(c) => VIRTUAL_KEY(c.to_ascii_uppercase() as u16),
+            // Sent as Unicode input by send_keycode, never as a virtual key
+            KeyCode::Char(_) => VIRTUAL_KEY(0),
         }
     }
 
+    /// Send a key event; characters go through KEYEVENTF_UNICODE so the layout doesn't matter
+    #[cfg(target_os = "windows")]
+    fn send_keycode(&self, key: KeyCode, key_up: bool) -> Result<(), OsError> {
+        match key {
+            KeyCode::Char(c) => self.send_unicode_event(c, key_up),
+            key => self.send_key_event(self.keycode_to_vk(key), key_up),
+        }
+    }
+
+    /// Send one character as Unicode key events (two UTF-16 units outside the BMP)
+    /// The character is prompt content, so it is never logged
+    #[cfg(target_os = "windows")]
+    fn send_unicode_event(&self, c: char, key_up: bool) -> Result<(), OsError> {
+        let flags = if key_up {
+            KEYEVENTF_UNICODE | KEYEVENTF_KEYUP
+        } else {
+            KEYEVENTF_UNICODE
+        };
+
+        let mut units = [0u16; 2];
+        let inputs: Vec<INPUT> = c
+            .encode_utf16(&mut units)
+            .iter()
+            .map(|unit| INPUT {
+                r#type: INPUT_KEYBOARD,
+                Anonymous: windows::Win32::UI::Input::KeyboardAndMouse::INPUT_0 {
+                    ki: KEYBDINPUT {
+                        wVk: VIRTUAL_KEY(0),
+                        wScan: *unit,
+                        dwFlags: flags,
+                        time: 0,
+                        dwExtraInfo: 0,
+                    },
+                },
+            })
+            .collect();
+
+        let sent = unsafe { SendInput(&inputs, std::mem::size_of::<INPUT>() as i32) };
+        if sent as usize != inputs.len() {
+            return Err(OsError::Input("SendInput failed for typed character".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Navigation keys share scan codes with the numpad; the extended flag tells them apart
+    #[cfg(target_os = "windows")]
+    fn is_extended_key(vk: VIRTUAL_KEY) -> bool {
+        matches!(
+            vk,
+            VK_INSERT | VK_DELETE | VK_HOME | VK_END | VK_PRIOR | VK_NEXT | VK_UP | VK_DOWN | VK_LEFT | VK_RIGHT | VK_LWIN
+        )
+    }
+
     /// Get scan code for a virtual key
     #[cfg(target_os = "windows")]
     fn get_scan_code(&self, vk: VIRTUAL_KEY) -> u16 {
@@ -39,11 +123,11 @@ impl WindowsInputSimulator {
 
     /// Try to send WM_PASTE directly to the foreground window
     #[cfg(target_os = "windows")]
-    fn try_wm_paste(&self) -> Result<(), String> {
+    fn try_wm_paste(&self) -> Result<(), OsError> {
         unsafe {
             let hwnd = GetForegroundWindow();
             if hwnd.0.is_null() {
-                return Err("No foreground window".to_string());
+                return Err(OsError::NoForegroundWindow);
             }
 
             log::info!("try_wm_paste: Sending WM_PASTE to HWND {:?}", hwnd);
@@ -65,11 +149,11 @@ impl WindowsInputSimulator {
     /// Strategy: Send WM_KEYDOWN/WM_KEYUP messages to the focused control
     /// This bypasses SendInput UIPI issues by sending messages directly
     #[cfg(target_os = "windows")]
-    fn try_send_message_keys(&self) -> Result<(), String> {
+    fn try_send_message_keys(&self, chord: &[KeyCode]) -> Result<(), OsError> {
         unsafe {
             let hwnd = GetForegroundWindow();
             if hwnd.0.is_null() {
-                return Err("No foreground window".to_string());
+                return Err(OsError::NoForegroundWindow);
             }
 
             // Attach to target thread to be able to call GetFocus
@@ -104,35 +188,30 @@ impl WindowsInputSimulator {
 
             log::info!("try_send_message_keys: Sending WM_KEYDOWN/WM_KEYUP to {:?}", focus_hwnd);
 
-            // Get scan codes for building lParam
-            let ctrl_scan = self.get_scan_code(VK_CONTROL) as u32;
-            let v_scan = self.get_scan_code(VK_V) as u32;
-
-            // Build lParam: bits 0-15 = repeat count (1), bits 16-23 = scan code
-            let ctrl_lparam_down = LPARAM((1 | (ctrl_scan << 16)) as isize);
-            let v_lparam_down = LPARAM((1 | (v_scan << 16)) as isize);
-            // For key up: bit 30 = previous key state (1), bit 31 = transition state (1)
-            let ctrl_lparam_up = LPARAM((1 | (ctrl_scan << 16) | (1 << 30) | (1 << 31)) as isize);
-            let v_lparam_up = LPARAM((1 | (v_scan << 16) | (1 << 30) | (1 << 31)) as isize);
-
-            // Send Ctrl down
-            let _ = SendMessageW(focus_hwnd, WM_KEYDOWN, WPARAM(VK_CONTROL.0 as usize), ctrl_lparam_down);
-            std::thread::sleep(std::time::Duration::from_millis(10));
-
-            // Send V down
-            let _ = SendMessageW(focus_hwnd, WM_KEYDOWN, WPARAM(VK_V.0 as usize), v_lparam_down);
-            std::thread::sleep(std::time::Duration::from_millis(10));
-
-            // Send V character (some controls need WM_CHAR)
-            let _ = SendMessageW(focus_hwnd, WM_CHAR, WPARAM('v' as usize), v_lparam_down);
-            std::thread::sleep(std::time::Duration::from_millis(10));
+            for key in chord {
+                let vk = self.keycode_to_vk(*key);
+                // Build lParam: bits 0-15 = repeat count (1), bits 16-23 = scan code
+                let scan = self.get_scan_code(vk) as u32;
+                let lparam_down = LPARAM((1 | (scan << 16)) as isize);
+                let _ = SendMessageW(focus_hwnd, WM_KEYDOWN, WPARAM(vk.0 as usize), lparam_down);
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
 
-            // Send V up
-            let _ = SendMessageW(focus_hwnd, WM_KEYUP, WPARAM(VK_V.0 as usize), v_lparam_up);
-            std::thread::sleep(std::time::Duration::from_millis(10));
+            // Send V character (some controls need WM_CHAR for Ctrl+V)
+            if chord == CTRL_V {
+                let v_scan = self.get_scan_code(VK_V) as u32;
+                let _ = SendMessageW(focus_hwnd, WM_CHAR, WPARAM('v' as usize), LPARAM((1 | (v_scan << 16)) as isize));
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
 
-            // Send Ctrl up
-            let _ = SendMessageW(focus_hwnd, WM_KEYUP, WPARAM(VK_CONTROL.0 as usize), ctrl_lparam_up);
+            for key in chord.iter().rev() {
+                let vk = self.keycode_to_vk(*key);
+                // For key up: bit 30 = previous key state (1), bit 31 = transition state (1)
+                let scan = self.get_scan_code(vk) as u32;
+                let lparam_up = LPARAM((1 | (scan << 16) | (1 << 30) | (1 << 31)) as isize);
+                let _ = SendMessageW(focus_hwnd, WM_KEYUP, WPARAM(vk.0 as usize), lparam_up);
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
 
             // Detach thread input
             if attached && target_thread != our_thread {
@@ -146,17 +225,20 @@ impl WindowsInputSimulator {
 
     /// Send a single key event using virtual key code (most compatible)
     #[cfg(target_os = "windows")]
-    fn send_key_event(&self, vk: VIRTUAL_KEY, key_up: bool) -> Result<(), String> {
+    fn send_key_event(&self, vk: VIRTUAL_KEY, key_up: bool) -> Result<(), OsError> {
         unsafe {
             let scan_code = self.get_scan_code(vk);
 
             // Use virtual key code approach (NOT KEYEVENTF_SCANCODE)
             // Many apps ignore wVk when KEYEVENTF_SCANCODE is set
-            let flags = if key_up {
+            let mut flags = if key_up {
                 KEYEVENTF_KEYUP
             } else {
                 windows::Win32::UI::Input::KeyboardAndMouse::KEYBD_EVENT_FLAGS(0)
             };
+            if Self::is_extended_key(vk) {
+                flags |= KEYEVENTF_EXTENDEDKEY;
+            }
 
             log::info!(
                 "send_key_event: VK={:?} scan={} key_up={} flags={:?}",
@@ -178,44 +260,43 @@ impl WindowsInputSimulator {
 
             let result = SendInput(&[input], std::mem::size_of::<INPUT>() as i32);
             if result != 1 {
-                return Err(format!("SendInput failed for key {:?}", vk));
+                return Err(OsError::Input(format!("SendInput failed for key {:?}", vk)));
             }
 
             Ok(())
         }
     }
 
-    /// Simulate Ctrl+V with individual key events and delays
+    /// Simulate a chord (e.g. Ctrl+V) with individual key events and delays
     #[cfg(target_os = "windows")]
-    fn simulate_ctrl_v_sequential(&self) -> Result<(), String> {
-        log::info!("simulate_ctrl_v_sequential: Starting sequential Ctrl+V simulation");
-
-        // Press Ctrl
-        self.send_key_event(VK_CONTROL, false)?;
-        std::thread::sleep(std::time::Duration::from_millis(20));
+    fn simulate_chord_sequential(&self, chord: &[KeyCode]) -> Result<(), OsError> {
+        log::info!("simulate_chord_sequential: Starting sequential {:?} simulation", chord);
 
-        // Press V
-        self.send_key_event(VK_V, false)?;
-        std::thread::sleep(std::time::Duration::from_millis(20));
-
-        // Release V
-        self.send_key_event(VK_V, true)?;
-        std::thread::sleep(std::time::Duration::from_millis(20));
+        // Press keys in order
+        for key in chord {
+            self.send_keycode(*key, false)?;
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
 
-        // Release Ctrl
-        self.send_key_event(VK_CONTROL, true)?;
+        // Release in reverse order
+        for (i, key) in chord.iter().rev().enumerate() {
+            self.send_keycode(*key, true)?;
+            if i + 1 < chord.len() {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+            }
+        }
 
-        log::info!("simulate_ctrl_v_sequential: Sequential simulation complete");
+        log::info!("simulate_chord_sequential: Sequential simulation complete");
         Ok(())
     }
 
     /// Simulate keyboard input with thread attachment for better reliability
     #[cfg(target_os = "windows")]
-    fn simulate_with_attachment(&self) -> Result<(), String> {
+    fn simulate_with_attachment(&self, chord: &[KeyCode]) -> Result<(), OsError> {
         unsafe {
             let hwnd = GetForegroundWindow();
             if hwnd.0.is_null() {
-                return Err("No foreground window".to_string());
+                return Err(OsError::NoForegroundWindow);
             }
 
             let target_thread = GetWindowThreadProcessId(hwnd, None);
@@ -245,8 +326,8 @@ impl WindowsInputSimulator {
             // Small delay after attachment (reduced from 50ms to match PromptLight timing)
             std::thread::sleep(std::time::Duration::from_millis(20));
 
-            // Send Ctrl+V with individual events and delays
-            let result = self.simulate_ctrl_v_sequential();
+            // Send the chord with individual events and delays
+            let result = self.simulate_chord_sequential(chord);
 
             // Small delay before detachment
             std::thread::sleep(std::time::Duration::from_millis(20));
@@ -264,7 +345,7 @@ impl WindowsInputSimulator {
 
 #[cfg(target_os = "windows")]
 impl InputSimulator for WindowsInputSimulator {
-    fn simulate_paste(&self) -> Result<(), String> {
+    fn simulate_paste(&self) -> Result<(), OsError> {
         log::info!("simulate_paste: Starting paste simulation with multiple strategies");
 
         let mut sendinput_success = false;
@@ -274,7 +355,7 @@ impl InputSimulator for WindowsInputSimulator {
         // Strategy 1: Try SendInput with thread attachment
         // Best for apps that process raw input
         log::info!("simulate_paste: Strategy 1 - SendInput with thread attachment");
-        match self.simulate_with_attachment() {
+        match self.simulate_with_attachment(&CTRL_V) {
             Ok(()) => {
                 log::info!("simulate_paste: SendInput completed");
                 sendinput_success = true;
@@ -288,7 +369,7 @@ impl InputSimulator for WindowsInputSimulator {
         // Strategy 2: Try SendMessage WM_KEYDOWN/WM_KEYUP to focused control
         // Bypasses UIPI issues, works for many apps that ignore SendInput
         log::info!("simulate_paste: Strategy 2 - SendMessage WM_KEYDOWN/WM_KEYUP");
-        match self.try_send_message_keys() {
+        match self.try_send_message_keys(&CTRL_V) {
             Ok(()) => {
                 log::info!("simulate_paste: SendMessage keys completed");
                 sendmessage_success = true;
@@ -315,7 +396,7 @@ impl InputSimulator for WindowsInputSimulator {
         // Return error only if ALL strategies failed
         if !sendinput_success && !sendmessage_success && !wm_paste_success {
             log::error!("simulate_paste: All paste strategies failed");
-            return Err("Paste simulation failed: all strategies failed".to_string());
+            return Err(OsError::Input("all paste strategies failed".to_string()));
         }
 
         log::info!(
@@ -325,31 +406,59 @@ impl InputSimulator for WindowsInputSimulator {
         Ok(())
     }
 
-    fn simulate_keys(&self, keys: &[KeyCode]) -> Result<(), String> {
+    fn simulate_keys(&self, keys: &[KeyCode]) -> Result<(), OsError> {
         // For general key simulation, use sequential approach with scan codes
         for key in keys {
-            let vk = self.keycode_to_vk(*key);
-            self.send_key_event(vk, false)?;
+            self.send_keycode(*key, false)?;
             std::thread::sleep(std::time::Duration::from_millis(20));
         }
 
         for key in keys.iter().rev() {
-            let vk = self.keycode_to_vk(*key);
-            self.send_key_event(vk, true)?;
+            self.send_keycode(*key, true)?;
             std::thread::sleep(std::time::Duration::from_millis(20));
         }
 
         Ok(())
     }
+
+    fn type_keys(&self, keys: &[KeyCode], interval: Duration) -> Result<(), OsError> {
+        // No count logged: it would reveal the length of the (possibly secret) text
+        log::info!("type_keys: Typing text");
+        for key in keys {
+            self.send_keycode(*key, false)?;
+            self.send_keycode(*key, true)?;
+            std::thread::sleep(interval);
+        }
+        Ok(())
+    }
+
+    fn paste_with(&self, strategy: PasteStrategy, chord: &[KeyCode]) -> Result<(), OsError> {
+        log::info!("paste_with: {:?} with {:?}", strategy, chord);
+        match strategy {
+            PasteStrategy::SendInput => self.simulate_with_attachment(chord),
+            PasteStrategy::MessageKeys => self.try_send_message_keys(chord),
+            // WM_PASTE has no shortcut; the control pastes however it normally would
+            PasteStrategy::WmPaste => self.try_wm_paste(),
+            PasteStrategy::TypeOut => Err(OsError::Input("type-out needs the text; use type_keys".to_string())),
+        }
+    }
 }
 
 #[cfg(not(target_os = "windows"))]
 impl InputSimulator for WindowsInputSimulator {
-    fn simulate_paste(&self) -> Result<(), String> {
-        Err("WindowsInputSimulator is only supported on Windows".to_string())
+    fn simulate_paste(&self) -> Result<(), OsError> {
+        Err(OsError::Unsupported("WindowsInputSimulator"))
+    }
+
+    fn simulate_keys(&self, _keys: &[KeyCode]) -> Result<(), OsError> {
+        Err(OsError::Unsupported("WindowsInputSimulator"))
+    }
+
+    fn type_keys(&self, _keys: &[KeyCode], _interval: Duration) -> Result<(), OsError> {
+        Err(OsError::Unsupported("WindowsInputSimulator"))
     }
 
-    fn simulate_keys(&self, _keys: &[KeyCode]) -> Result<(), String> {
-        Err("WindowsInputSimulator is only supported on Windows".to_string())
+    fn paste_with(&self, _strategy: PasteStrategy, _chord: &[KeyCode]) -> Result<(), OsError> {
+        Err(OsError::Unsupported("WindowsInputSimulator"))
     }
 }
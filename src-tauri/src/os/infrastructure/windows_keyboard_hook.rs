@@ -0,0 +1,185 @@
+use crate::os::domain::ports::{KeyEvent, KeyboardEventSource};
+use crate::os::domain::OsError;
+use std::sync::mpsc::Sender;
+#[cfg(target_os = "windows")]
+use once_cell::sync::Lazy;
+#[cfg(target_os = "windows")]
+use std::sync::Mutex;
+
+#[cfg(target_os = "windows")]
+use windows::core::PCWSTR;
+#[cfg(target_os = "windows")]
+use windows::Win32::Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM};
+#[cfg(target_os = "windows")]
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+#[cfg(target_os = "windows")]
+use windows::Win32::System::Threading::GetCurrentThreadId;
+#[cfg(target_os = "windows")]
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    GetAsyncKeyState, GetKeyState, GetKeyboardLayout, ToUnicodeEx, VIRTUAL_KEY, VK_BACK, VK_CAPITAL,
+    VK_CONTROL, VK_LCONTROL, VK_LMENU, VK_LSHIFT, VK_LWIN, VK_MENU, VK_RCONTROL, VK_RMENU, VK_RSHIFT,
+    VK_RWIN, VK_SHIFT,
+};
+#[cfg(target_os = "windows")]
+use windows::Win32::UI::WindowsAndMessaging::{
+    CallNextHookEx, GetForegroundWindow, GetMessageW, GetWindowThreadProcessId, PostThreadMessageW,
+    SetWindowsHookExW, UnhookWindowsHookEx, HHOOK, KBDLLHOOKSTRUCT, LLKHF_INJECTED, MSG, WH_KEYBOARD_LL,
+    WM_KEYDOWN, WM_QUIT, WM_SYSKEYDOWN,
+};
+
+/// Where the hook callback sends keystrokes; the callback cannot capture state
+#[cfg(target_os = "windows")]
+static SINK: Lazy<Mutex<Option<Sender<KeyEvent>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Thread running the hook's message loop, if started
+#[cfg(target_os = "windows")]
+static HOOK_THREAD: Lazy<Mutex<Option<u32>>> = Lazy::new(|| Mutex::new(None));
+
+/// Windows implementation of KeyboardEventSource (Adapter)
+///
+/// Installs a low-level keyboard hook (WH_KEYBOARD_LL) on a dedicated thread.
+/// Keystrokes injected by SendInput (including our own backspaces and paste) are skipped.
+/// Typed characters are never logged.
+pub struct WindowsKeyboardHook;
+
+impl WindowsKeyboardHook {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Translate a key press into a KeyEvent using the foreground app's keyboard layout
+/// Returns None for lone modifiers and dead keys, which don't change the typed text
+#[cfg(target_os = "windows")]
+unsafe fn translate(info: &KBDLLHOOKSTRUCT) -> Option<KeyEvent> {
+    let vk = VIRTUAL_KEY(info.vkCode as u16);
+    let modifiers = [
+        VK_SHIFT, VK_LSHIFT, VK_RSHIFT, VK_CAPITAL, VK_CONTROL, VK_LCONTROL, VK_RCONTROL, VK_MENU,
+        VK_LMENU, VK_RMENU, VK_LWIN, VK_RWIN,
+    ];
+    if vk == VK_BACK {
+        return Some(KeyEvent::Backspace);
+    }
+    if modifiers.contains(&vk) {
+        return None;
+    }
+
+    let pressed = |key: VIRTUAL_KEY| GetAsyncKeyState(key.0 as i32) < 0;
+    let ctrl = pressed(VK_CONTROL);
+    let alt = pressed(VK_MENU);
+    // Shortcuts don't type text; Ctrl+Alt together is AltGr, which does
+    if pressed(VK_LWIN) || pressed(VK_RWIN) || ctrl != alt {
+        return Some(KeyEvent::Reset);
+    }
+
+    // The hook thread has no keyboard state of its own, so build it from the async state
+    let mut state = [0u8; 256];
+    if pressed(VK_SHIFT) {
+        state[VK_SHIFT.0 as usize] = 0x80;
+    }
+    if GetKeyState(VK_CAPITAL.0 as i32) & 1 != 0 {
+        state[VK_CAPITAL.0 as usize] = 0x01;
+    }
+    if ctrl && alt {
+        state[VK_CONTROL.0 as usize] = 0x80;
+        state[VK_MENU.0 as usize] = 0x80;
+    }
+
+    let foreground_thread = GetWindowThreadProcessId(GetForegroundWindow(), None);
+    let layout = GetKeyboardLayout(foreground_thread);
+    let mut buffer = [0u16; 8];
+    // Flag 0x4 leaves the keyboard state (e.g. pending dead keys) untouched for the target app
+    let count = ToUnicodeEx(info.vkCode, info.scanCode, &state, &mut buffer, 0x4, layout);
+    if count < 0 {
+        return None;
+    }
+
+    // Keys that type nothing (arrows, Home, F-keys) or control characters (Enter, Tab, Esc)
+    // move the caret or leave the field
+    match String::from_utf16_lossy(&buffer[..count as usize]).chars().last() {
+        Some(c) if !c.is_control() => Some(KeyEvent::Char(c)),
+        _ => Some(KeyEvent::Reset),
+    }
+}
+
+#[cfg(target_os = "windows")]
+unsafe extern "system" fn keyboard_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    let message = wparam.0 as u32;
+    if code >= 0 && (message == WM_KEYDOWN || message == WM_SYSKEYDOWN) {
+        let info = &*(lparam.0 as *const KBDLLHOOKSTRUCT);
+        if info.flags.0 & LLKHF_INJECTED.0 == 0 {
+            if let Some(event) = translate(info) {
+                if let Some(sink) = SINK.lock().unwrap().as_ref() {
+                    let _ = sink.send(event);
+                }
+            }
+        }
+    }
+    CallNextHookEx(HHOOK::default(), code, wparam, lparam)
+}
+
+#[cfg(target_os = "windows")]
+impl KeyboardEventSource for WindowsKeyboardHook {
+    fn start(&self, sink: Sender<KeyEvent>) -> Result<(), OsError> {
+        let mut hook_thread = HOOK_THREAD.lock().unwrap();
+        *SINK.lock().unwrap() = Some(sink);
+        if hook_thread.is_some() {
+            return Ok(());
+        }
+
+        // The hook only receives events while its thread pumps messages
+        let (ready, started) = std::sync::mpsc::channel();
+        std::thread::spawn(move || unsafe {
+            let hook = GetModuleHandleW(PCWSTR::null()).and_then(|module| {
+                SetWindowsHookExW(WH_KEYBOARD_LL, Some(keyboard_proc), HINSTANCE(module.0), 0)
+            });
+            let hook = match hook {
+                Ok(hook) => hook,
+                Err(e) => {
+                    let _ = ready.send(Err(OsError::Input(format!("keyboard hook failed: {}", e))));
+                    return;
+                }
+            };
+            let _ = ready.send(Ok(GetCurrentThreadId()));
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, HWND::default(), 0, 0).as_bool() {}
+            let _ = UnhookWindowsHookEx(hook);
+            log::debug!("WindowsKeyboardHook: Hook removed");
+        });
+
+        let thread_id = started
+            .recv()
+            .map_err(|_| OsError::Input("keyboard hook thread exited".to_string()))?;
+        match thread_id {
+            Ok(thread_id) => {
+                *hook_thread = Some(thread_id);
+                log::debug!("WindowsKeyboardHook: Hook installed");
+                Ok(())
+            }
+            Err(e) => {
+                *SINK.lock().unwrap() = None;
+                Err(e)
+            }
+        }
+    }
+
+    fn stop(&self) {
+        if let Some(thread_id) = HOOK_THREAD.lock().unwrap().take() {
+            unsafe {
+                let _ = PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+            }
+        }
+        // Dropping the sender ends the receiver's loop
+        *SINK.lock().unwrap() = None;
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+impl KeyboardEventSource for WindowsKeyboardHook {
+    fn start(&self, _sink: Sender<KeyEvent>) -> Result<(), OsError> {
+        Err(OsError::Unsupported("WindowsKeyboardHook"))
+    }
+
+    fn stop(&self) {}
+}
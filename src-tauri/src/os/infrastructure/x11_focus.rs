@@ -0,0 +1,262 @@
+use crate::os::domain::ports::WindowManager;
+use crate::os::domain::OsError;
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{Atom, AtomEnum, ClientMessageEvent, ConnectionExt as _, EventMask, InputFocus, Window};
+use x11rb::rust_connection::RustConnection;
+
+static PREVIOUS_WINDOW: Lazy<Mutex<Option<Window>>> = Lazy::new(|| Mutex::new(None));
+
+/// Core-protocol focus value meaning "whichever window is under the pointer"
+const POINTER_ROOT: Window = 1;
+
+/// X11 implementation of WindowManager (Adapter)
+///
+/// Reads and sets the active window through EWMH (`_NET_ACTIVE_WINDOW`), which every
+/// mainstream window manager supports; without a window manager (e.g. Xvfb) it falls
+/// back to the core input focus. Like the Windows tracker, Prompter's own windows are
+/// recognized by process ID (`_NET_WM_PID`)
+pub struct X11FocusTracker;
+
+impl X11FocusTracker {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Open a connection to the display in `$DISPLAY`
+    pub(super) fn connect() -> Result<(RustConnection, Window), OsError> {
+        let (conn, screen) = x11rb::connect(None).map_err(|e| OsError::Focus(format!("X11 connection failed: {}", e)))?;
+        let root = conn.setup().roots[screen].root;
+        Ok((conn, root))
+    }
+
+    fn atom(conn: &RustConnection, name: &[u8]) -> Result<Atom, OsError> {
+        Ok(conn.intern_atom(false, name).map_err(focus_error)?.reply().map_err(focus_error)?.atom)
+    }
+
+    /// First 32-bit value of a window property, if set
+    fn property32(conn: &RustConnection, window: Window, property: Atom, kind: AtomEnum) -> Option<u32> {
+        let reply = conn.get_property(false, window, property, kind, 0, 1).ok()?.reply().ok()?;
+        let value = reply.value32()?.next();
+        value
+    }
+
+    /// Whether the window manager handles `_NET_ACTIVE_WINDOW` requests
+    fn wm_supports(conn: &RustConnection, root: Window, atom: Atom) -> bool {
+        let Ok(supported) = Self::atom(conn, b"_NET_SUPPORTED") else {
+            return false;
+        };
+        conn.get_property(false, root, supported, AtomEnum::ATOM, 0, 1024)
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .and_then(|reply| reply.value32().map(|mut atoms| atoms.any(|a| a == atom)))
+            .unwrap_or(false)
+    }
+
+    fn active_window(conn: &RustConnection, root: Window) -> Result<Window, OsError> {
+        let net_active_window = Self::atom(conn, b"_NET_ACTIVE_WINDOW")?;
+        if let Some(window) = Self::property32(conn, root, net_active_window, AtomEnum::WINDOW) {
+            if window != x11rb::NONE {
+                return Ok(window);
+            }
+        }
+
+        // No EWMH window manager: use the core input focus
+        let focus = conn.get_input_focus().map_err(focus_error)?.reply().map_err(focus_error)?.focus;
+        if focus == x11rb::NONE || focus == POINTER_ROOT || focus == root {
+            return Err(OsError::NoForegroundWindow);
+        }
+        Ok(focus)
+    }
+
+    fn activate(conn: &RustConnection, root: Window, window: Window) -> Result<(), OsError> {
+        let net_active_window = Self::atom(conn, b"_NET_ACTIVE_WINDOW")?;
+        if Self::wm_supports(conn, root, net_active_window) {
+            // Source indication 2 ("pager") so focus-stealing prevention lets it through
+            let event = ClientMessageEvent::new(32, window, net_active_window, [2, x11rb::CURRENT_TIME, 0, 0, 0]);
+            conn.send_event(false, root, EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY, event)
+                .map_err(focus_error)?;
+        } else {
+            conn.set_input_focus(InputFocus::PARENT, window, x11rb::CURRENT_TIME).map_err(focus_error)?;
+        }
+        conn.flush().map_err(focus_error)
+    }
+
+    fn window_pid(conn: &RustConnection, window: Window) -> Option<u32> {
+        let net_wm_pid = Self::atom(conn, b"_NET_WM_PID").ok()?;
+        Self::property32(conn, window, net_wm_pid, AtomEnum::CARDINAL)
+    }
+
+    /// Executable name of a process (e.g. "code"), from /proc
+    fn process_name(pid: u32) -> Option<String> {
+        std::fs::read_link(format!("/proc/{}/exe", pid))
+            .ok()
+            .and_then(|path| path.file_name().map(|name| name.to_string_lossy().into_owned()))
+            .or_else(|| std::fs::read_to_string(format!("/proc/{}/comm", pid)).ok().map(|comm| comm.trim().to_string()))
+    }
+
+    fn remembered() -> Option<Window> {
+        *PREVIOUS_WINDOW.lock().unwrap()
+    }
+}
+
+impl WindowManager for X11FocusTracker {
+    fn remember_current_window(&self) -> Result<(), OsError> {
+        let (conn, root) = Self::connect()?;
+        let window = Self::active_window(&conn, root)?;
+        *PREVIOUS_WINDOW.lock().unwrap() = Some(window);
+        log::info!("X11FocusTracker: Remembered window 0x{:X}", window);
+        Ok(())
+    }
+
+    /// Only an external app (different process) is remembered; another Prompter window
+    /// taking focus leaves the saved window alone
+    fn remember_if_external(&self) -> Result<bool, OsError> {
+        let (conn, root) = Self::connect()?;
+        let window = Self::active_window(&conn, root)?;
+        if Self::window_pid(&conn, window) == Some(std::process::id()) {
+            log::info!("X11FocusTracker: Window 0x{:X} is a Prompter window, NOT updating saved window", window);
+            return Ok(false);
+        }
+        *PREVIOUS_WINDOW.lock().unwrap() = Some(window);
+        log::info!("X11FocusTracker: Remembered external window 0x{:X}", window);
+        Ok(true)
+    }
+
+    fn restore_previous_window(&self) -> Result<(), OsError> {
+        let window = Self::remembered().ok_or(OsError::NoSavedWindow)?;
+        let (conn, root) = Self::connect()?;
+
+        // The window may have been closed since it was remembered
+        if conn.get_window_attributes(window).map_err(focus_error)?.reply().is_err() {
+            log::error!("X11FocusTracker: Window 0x{:X} no longer exists", window);
+            return Err(OsError::WindowClosed);
+        }
+
+        log::info!("X11FocusTracker: Activating window 0x{:X}", window);
+        Self::activate(&conn, root, window)?;
+
+        // Window managers activate asynchronously; verify like the Windows tracker does
+        for attempt in 0..5 {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            if Self::active_window(&conn, root).ok() == Some(window) {
+                log::info!("X11FocusTracker: Focus verified after {} attempt(s)", attempt + 1);
+                return Ok(());
+            }
+        }
+        log::error!("X11FocusTracker: Focus verification failed after 5 attempts");
+        Err(OsError::Focus("window didn't gain focus".to_string()))
+    }
+
+    fn clear_saved_window(&self) {
+        *PREVIOUS_WINDOW.lock().unwrap() = None;
+        log::info!("X11FocusTracker: Cleared saved window");
+    }
+
+    fn remembered_app(&self) -> Option<String> {
+        let window = Self::remembered()?;
+        let (conn, _) = Self::connect().ok()?;
+        Self::window_pid(&conn, window).and_then(Self::process_name)
+    }
+
+    /// The class part of WM_CLASS (e.g. "Gnome-terminal")
+    fn remembered_window_class(&self) -> Option<String> {
+        let window = Self::remembered()?;
+        let (conn, _) = Self::connect().ok()?;
+        let reply = conn
+            .get_property(false, window, AtomEnum::WM_CLASS, AtomEnum::STRING, 0, 256)
+            .ok()?
+            .reply()
+            .ok()?;
+        // WM_CLASS holds "instance\0class\0"
+        let class = reply.value.split(|b| *b == 0).nth(1)?;
+        (!class.is_empty()).then(|| String::from_utf8_lossy(class).into_owned())
+    }
+
+    /// X11 has no integrity levels; any client may send input to any window
+    fn remembered_window_elevated(&self) -> bool {
+        false
+    }
+
+    fn remembered_window_focused(&self) -> Option<bool> {
+        let window = Self::remembered()?;
+        let (conn, root) = Self::connect().ok()?;
+        Some(Self::active_window(&conn, root).ok() == Some(window))
+    }
+}
+
+fn focus_error(e: impl std::fmt::Display) -> OsError {
+    OsError::Focus(format!("X11: {}", e))
+}
+
+/// Map a top-level window with the given WM_CLASS class on the test display
+#[cfg(test)]
+pub(super) fn create_test_window(conn: &RustConnection, root: Window, class: &[u8], event_mask: EventMask) -> Window {
+    use x11rb::protocol::xproto::{CreateWindowAux, PropMode, WindowClass};
+    use x11rb::wrapper::ConnectionExt as _;
+
+    let window = conn.generate_id().unwrap();
+    conn.create_window(
+        x11rb::COPY_DEPTH_FROM_PARENT,
+        window,
+        root,
+        0,
+        0,
+        100,
+        100,
+        0,
+        WindowClass::INPUT_OUTPUT,
+        x11rb::COPY_FROM_PARENT,
+        &CreateWindowAux::new().event_mask(event_mask),
+    )
+    .unwrap();
+    let wm_class = [b"prompter-test\0".as_slice(), class, b"\0"].concat();
+    conn.change_property8(PropMode::REPLACE, window, AtomEnum::WM_CLASS, AtomEnum::STRING, &wm_class)
+        .unwrap();
+    conn.map_window(window).unwrap();
+    conn.sync().unwrap();
+    window
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "needs an X server: xvfb-run cargo test -- --ignored --test-threads=1"]
+    fn test_remember_and_restore_focus() {
+        let (conn, root) = X11FocusTracker::connect().unwrap();
+        let target = create_test_window(&conn, root, b"PrompterTest", EventMask::NO_EVENT);
+        let other = create_test_window(&conn, root, b"Other", EventMask::NO_EVENT);
+        conn.set_input_focus(InputFocus::PARENT, target, x11rb::CURRENT_TIME).unwrap();
+        conn.flush().unwrap();
+
+        let tracker = X11FocusTracker::new();
+        tracker.remember_current_window().unwrap();
+        assert_eq!(tracker.remembered_window_class().as_deref(), Some("PrompterTest"));
+
+        conn.set_input_focus(InputFocus::PARENT, other, x11rb::CURRENT_TIME).unwrap();
+        conn.flush().unwrap();
+        tracker.restore_previous_window().unwrap();
+        assert_eq!(conn.get_input_focus().unwrap().reply().unwrap().focus, target);
+
+        tracker.clear_saved_window();
+        assert_eq!(tracker.restore_previous_window(), Err(OsError::NoSavedWindow));
+    }
+
+    #[test]
+    #[ignore = "needs an X server: xvfb-run cargo test -- --ignored --test-threads=1"]
+    fn test_restore_closed_window_fails() {
+        let (conn, root) = X11FocusTracker::connect().unwrap();
+        let target = create_test_window(&conn, root, b"PrompterTest", EventMask::NO_EVENT);
+        conn.set_input_focus(InputFocus::PARENT, target, x11rb::CURRENT_TIME).unwrap();
+        conn.flush().unwrap();
+
+        let tracker = X11FocusTracker::new();
+        tracker.remember_current_window().unwrap();
+        conn.destroy_window(target).unwrap();
+        conn.flush().unwrap();
+        assert_eq!(tracker.restore_previous_window(), Err(OsError::WindowClosed));
+    }
+}
@@ -0,0 +1,330 @@
+use crate::os::domain::ports::{InputSimulator, KeyCode, PasteStrategy};
+use crate::os::domain::OsError;
+use std::time::Duration;
+use x11rb::connection::{Connection, RequestConnection};
+use x11rb::protocol::xproto::{ConnectionExt as _, Keycode, Keysym, KEY_PRESS_EVENT, KEY_RELEASE_EVENT};
+use x11rb::protocol::xtest::{self, ConnectionExt as _};
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+
+/// The chord `simulate_paste` sends
+const CTRL_V: [KeyCode; 2] = [KeyCode::Control, KeyCode::V];
+
+/// Keysym of Shift_L, held for characters on a key's shifted level
+const XK_SHIFT_L: Keysym = 0xffe1;
+
+/// How long clients get to read a temporary key mapping before it is undone
+const REMAP_SETTLE: Duration = Duration::from_millis(50);
+
+/// X11 implementation of InputSimulator (Adapter)
+///
+/// Synthesizes key events with the XTest extension. Characters missing from the
+/// keyboard layout are typed by briefly binding them to an unused keycode
+pub struct X11InputSimulator;
+
+impl X11InputSimulator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Keysym for a named key, from X11/keysymdef.h
+    fn keysym(key: KeyCode) -> Keysym {
+        match key {
+            KeyCode::Control => 0xffe3,
+            KeyCode::V => 'v' as Keysym,
+            KeyCode::Backspace => 0xff08,
+            KeyCode::Enter => 0xff0d,
+            KeyCode::Tab => 0xff09,
+            KeyCode::Shift => XK_SHIFT_L,
+            KeyCode::Alt => 0xffe9,
+            KeyCode::Insert => 0xff63,
+            KeyCode::Escape => 0xff1b,
+            KeyCode::Win => 0xffeb,
+            KeyCode::Space => 0x20,
+            KeyCode::Delete => 0xffff,
+            KeyCode::Home => 0xff50,
+            KeyCode::End => 0xff57,
+            KeyCode::PageUp => 0xff55,
+            KeyCode::PageDown => 0xff56,
+            KeyCode::Up => 0xff52,
+            KeyCode::Down => 0xff54,
+            KeyCode::Left => 0xff51,
+            KeyCode::Right => 0xff53,
+            // XK_F1..XK_F24 are consecutive
+            KeyCode::F(n) => 0xffbe + Keysym::from(n.clamp(1, 24)) - 1,
+            // Shortcuts use the unshifted key, so Ctrl+A is Ctrl with 'a'
+            KeyCode::Letter(c) => c.to_ascii_lowercase() as Keysym,
+            KeyCode::Char(c) => Self::char_keysym(c),
+        }
+    }
+
+    /// Latin-1 characters are their own keysym; every other character has a Unicode keysym
+    fn char_keysym(c: char) -> Keysym {
+        match c as u32 {
+            cp @ (0x20..=0x7e | 0xa0..=0xff) => cp,
+            cp => 0x0100_0000 + cp,
+        }
+    }
+
+    fn input_error(e: impl std::fmt::Display) -> OsError {
+        OsError::Input(format!("X11: {}", e))
+    }
+}
+
+/// A connection with the keyboard mapping, for turning keysyms into keycodes
+struct Keyboard {
+    conn: RustConnection,
+    min_keycode: Keycode,
+    keysyms_per_keycode: usize,
+    keysyms: Vec<Keysym>,
+    /// Keycodes bound to a missing character; the layout had nothing on them
+    remapped: Vec<Keycode>,
+}
+
+impl Keyboard {
+    fn open() -> Result<Self, OsError> {
+        let (conn, _) = x11rb::connect(None).map_err(X11InputSimulator::input_error)?;
+        if conn.extension_information(xtest::X11_EXTENSION_NAME).map_err(X11InputSimulator::input_error)?.is_none() {
+            return Err(OsError::Input("the X server has no XTEST extension".to_string()));
+        }
+        let setup = conn.setup();
+        let (min_keycode, max_keycode) = (setup.min_keycode, setup.max_keycode);
+        let mapping = conn
+            .get_keyboard_mapping(min_keycode, max_keycode - min_keycode + 1)
+            .map_err(X11InputSimulator::input_error)?
+            .reply()
+            .map_err(X11InputSimulator::input_error)?;
+        Ok(Self {
+            conn,
+            min_keycode,
+            keysyms_per_keycode: mapping.keysyms_per_keycode.into(),
+            keysyms: mapping.keysyms,
+            remapped: Vec::new(),
+        })
+    }
+
+    fn keycode_keysyms(&self, index: usize) -> &[Keysym] {
+        &self.keysyms[index * self.keysyms_per_keycode..(index + 1) * self.keysyms_per_keycode]
+    }
+
+    fn keycode_count(&self) -> usize {
+        self.keysyms.len() / self.keysyms_per_keycode.max(1)
+    }
+
+    /// Keycode producing `keysym`, and whether it's on the shifted level
+    fn lookup(&self, keysym: Keysym) -> Option<(Keycode, bool)> {
+        (0..self.keycode_count()).find_map(|index| {
+            let syms = self.keycode_keysyms(index);
+            let keycode = self.min_keycode + index as u8;
+            syms.iter().take(2).position(|sym| *sym == keysym).map(|level| (keycode, level == 1))
+        })
+    }
+
+    /// Keycode producing `keysym`, binding it to an unused keycode if the layout lacks it
+    fn keycode_for(&mut self, keysym: Keysym) -> Result<(Keycode, bool), OsError> {
+        if let Some(found) = self.lookup(keysym) {
+            return Ok(found);
+        }
+
+        let keycode = match self.spare_keycode() {
+            Some(keycode) => keycode,
+            // Every spare keycode is in use: free them once the typed ones have been read
+            None => {
+                self.restore_remapped()?;
+                self.spare_keycode()
+                    .ok_or_else(|| OsError::Input("no spare keycode to type a character".to_string()))?
+            }
+        };
+        self.bind(keycode, keysym)?;
+        self.remapped.push(keycode);
+        Ok((keycode, false))
+    }
+
+    /// A keycode with nothing bound to it
+    fn spare_keycode(&self) -> Option<Keycode> {
+        (0..self.keycode_count())
+            .find(|index| self.keycode_keysyms(*index).iter().all(|sym| *sym == x11rb::NO_SYMBOL))
+            .map(|index| self.min_keycode + index as u8)
+    }
+
+    fn bind(&mut self, keycode: Keycode, keysym: Keysym) -> Result<(), OsError> {
+        let syms = vec![keysym; self.keysyms_per_keycode];
+        self.conn
+            .change_keyboard_mapping(1, keycode, self.keysyms_per_keycode as u8, &syms)
+            .map_err(X11InputSimulator::input_error)?;
+        self.conn.sync().map_err(X11InputSimulator::input_error)?;
+        self.set_local(keycode, keysym);
+        // Clients reload the mapping when they see MappingNotify
+        std::thread::sleep(REMAP_SETTLE);
+        Ok(())
+    }
+
+    /// Put the remapped keycodes back to unused
+    fn restore_remapped(&mut self) -> Result<(), OsError> {
+        if self.remapped.is_empty() {
+            return Ok(());
+        }
+        std::thread::sleep(REMAP_SETTLE);
+        let empty = vec![x11rb::NO_SYMBOL; self.keysyms_per_keycode];
+        for keycode in &self.remapped {
+            self.conn
+                .change_keyboard_mapping(1, *keycode, self.keysyms_per_keycode as u8, &empty)
+                .map_err(X11InputSimulator::input_error)?;
+        }
+        for keycode in std::mem::take(&mut self.remapped) {
+            self.set_local(keycode, x11rb::NO_SYMBOL);
+        }
+        self.conn.sync().map_err(X11InputSimulator::input_error)
+    }
+
+    fn set_local(&mut self, keycode: Keycode, keysym: Keysym) {
+        let start = usize::from(keycode - self.min_keycode) * self.keysyms_per_keycode;
+        self.keysyms[start..start + self.keysyms_per_keycode].fill(keysym);
+    }
+
+    fn send(&self, keycode: Keycode, key_up: bool) -> Result<(), OsError> {
+        let kind = if key_up { KEY_RELEASE_EVENT } else { KEY_PRESS_EVENT };
+        self.conn
+            .xtest_fake_input(kind, keycode, x11rb::CURRENT_TIME, x11rb::NONE, 0, 0, 0)
+            .map_err(X11InputSimulator::input_error)?;
+        Ok(())
+    }
+
+    /// Press the keys in order and release them in reverse
+    fn chord(&mut self, keys: &[KeyCode]) -> Result<(), OsError> {
+        let keycodes = keys
+            .iter()
+            .map(|key| self.keycode_for(X11InputSimulator::keysym(*key)).map(|(keycode, _)| keycode))
+            .collect::<Result<Vec<_>, _>>()?;
+        for keycode in &keycodes {
+            self.send(*keycode, false)?;
+        }
+        for keycode in keycodes.iter().rev() {
+            self.send(*keycode, true)?;
+        }
+        self.conn.sync().map_err(X11InputSimulator::input_error)
+    }
+
+    /// Tap one key, holding Shift if the character is on the shifted level
+    /// The key is prompt content, so it is never logged
+    fn tap(&mut self, key: KeyCode) -> Result<(), OsError> {
+        let (keycode, shifted) = self.keycode_for(X11InputSimulator::keysym(key))?;
+        let shift = if shifted { Some(self.keycode_for(XK_SHIFT_L)?.0) } else { None };
+        if let Some(shift) = shift {
+            self.send(shift, false)?;
+        }
+        self.send(keycode, false)?;
+        self.send(keycode, true)?;
+        if let Some(shift) = shift {
+            self.send(shift, true)?;
+        }
+        self.conn.sync().map_err(X11InputSimulator::input_error)
+    }
+}
+
+impl Drop for Keyboard {
+    fn drop(&mut self) {
+        if let Err(e) = self.restore_remapped() {
+            log::warn!("X11InputSimulator: Could not restore the keyboard mapping: {}", e);
+        }
+    }
+}
+
+impl InputSimulator for X11InputSimulator {
+    fn simulate_paste(&self) -> Result<(), OsError> {
+        log::info!("simulate_paste: Sending Ctrl+V through XTest");
+        Keyboard::open()?.chord(&CTRL_V)
+    }
+
+    fn simulate_keys(&self, keys: &[KeyCode]) -> Result<(), OsError> {
+        Keyboard::open()?.chord(keys)
+    }
+
+    fn type_keys(&self, keys: &[KeyCode], interval: Duration) -> Result<(), OsError> {
+        // No count logged: it would reveal the length of the (possibly secret) text
+        log::info!("type_keys: Typing text");
+        let mut keyboard = Keyboard::open()?;
+        for key in keys {
+            keyboard.tap(*key)?;
+            std::thread::sleep(interval);
+        }
+        Ok(())
+    }
+
+    fn paste_with(&self, strategy: PasteStrategy, chord: &[KeyCode]) -> Result<(), OsError> {
+        log::info!("paste_with: {:?} with {:?}", strategy, chord);
+        match strategy {
+            PasteStrategy::SendInput => Keyboard::open()?.chord(chord),
+            // Window messages only exist on Windows; the profile's next strategy is tried
+            PasteStrategy::MessageKeys | PasteStrategy::WmPaste => Err(OsError::Unsupported("Message-based paste")),
+            PasteStrategy::TypeOut => Err(OsError::Input("type-out needs the text; use type_keys".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::os::infrastructure::x11_focus::{create_test_window, X11FocusTracker};
+    use x11rb::protocol::xproto::{EventMask, InputFocus};
+    use x11rb::protocol::Event;
+
+    #[test]
+    fn test_keysyms() {
+        assert_eq!(X11InputSimulator::keysym(KeyCode::Letter('A')), 0x61);
+        assert_eq!(X11InputSimulator::keysym(KeyCode::F(12)), 0xffc9);
+        assert_eq!(X11InputSimulator::keysym(KeyCode::Char('é')), 0xe9);
+        assert_eq!(X11InputSimulator::keysym(KeyCode::Char('€')), 0x0100_20ac);
+    }
+
+    /// Keysyms of the key presses `window` received
+    fn pressed_keysyms(conn: &RustConnection, window: u32) -> Vec<Keysym> {
+        let setup = conn.setup();
+        let mapping = conn
+            .get_keyboard_mapping(setup.min_keycode, setup.max_keycode - setup.min_keycode + 1)
+            .unwrap()
+            .reply()
+            .unwrap();
+        let per = usize::from(mapping.keysyms_per_keycode);
+        let mut pressed = Vec::new();
+        std::thread::sleep(Duration::from_millis(100));
+        while let Some(event) = conn.poll_for_event().unwrap() {
+            if let Event::KeyPress(key) = event {
+                if key.event == window {
+                    let index = usize::from(key.detail - setup.min_keycode) * per;
+                    pressed.push(mapping.keysyms[index]);
+                }
+            }
+        }
+        pressed
+    }
+
+    #[test]
+    #[ignore = "needs an X server: xvfb-run cargo test -- --ignored --test-threads=1"]
+    fn test_chord_reaches_focused_window() {
+        let (conn, root) = X11FocusTracker::connect().unwrap();
+        let window = create_test_window(&conn, root, b"PrompterTest", EventMask::KEY_PRESS);
+        conn.set_input_focus(InputFocus::PARENT, window, x11rb::CURRENT_TIME).unwrap();
+        conn.sync().unwrap();
+
+        X11InputSimulator::new().simulate_paste().unwrap();
+        assert_eq!(pressed_keysyms(&conn, window), vec![0xffe3, 'v' as Keysym]);
+    }
+
+    #[test]
+    #[ignore = "needs an X server: xvfb-run cargo test -- --ignored --test-threads=1"]
+    fn test_type_keys_remaps_missing_characters() {
+        let (conn, root) = X11FocusTracker::connect().unwrap();
+        let window = create_test_window(&conn, root, b"PrompterTest", EventMask::KEY_PRESS);
+        conn.set_input_focus(InputFocus::PARENT, window, x11rb::CURRENT_TIME).unwrap();
+        conn.sync().unwrap();
+
+        let keys = KeyCode::for_text("a€");
+        X11InputSimulator::new().type_keys(&keys, Duration::ZERO).unwrap();
+        // The mapping is read after the remap is undone, so the € key reads as unbound
+        let pressed = pressed_keysyms(&conn, window);
+        assert_eq!(pressed.len(), 2);
+        assert_eq!(pressed[0], 'a' as Keysym);
+        assert!(Keyboard::open().unwrap().lookup(0x0100_20ac).is_none());
+    }
+}
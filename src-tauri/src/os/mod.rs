@@ -1,6 +1,6 @@
 // OS integration module - Hexagonal Architecture
-// - domain/ports/ (WindowManager, ClipboardService, InputSimulator traits)
-// - application/use_cases/ (PastePromptUseCase, ShowWindowUseCase)
+// - domain/ports/ (WindowManager, ClipboardService, InputSimulator, KeyboardEventSource traits)
+// - application/use_cases/ (PastePromptUseCase, ShowWindowUseCase, ExpandAbbreviationUseCase)
 // - infrastructure/ (Windows adapters)
 
 pub mod domain;
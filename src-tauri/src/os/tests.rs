@@ -1,62 +1,205 @@
 #[cfg(test)]
 mod domain_tests {
     use super::super::domain::ports::*;
+    use super::super::domain::{ClipboardContent, OsError, PasteReason};
+    use super::super::infrastructure::InMemoryClipboard;
     use std::sync::Arc;
 
     // Mock implementations for testing
     struct MockWindowManager {
         remembered: std::sync::Mutex<bool>,
+        /// The remembered window's process runs elevated
+        elevated: bool,
+        /// What the focus check reports after input is sent; `None` means it can't tell
+        focused: Option<bool>,
+        /// Focus checks that pass before focus moves to another window, if it ever does
+        focus_lost_after: Option<usize>,
+        focus_checks: std::sync::Mutex<usize>,
     }
 
     impl MockWindowManager {
         fn new() -> Self {
             Self {
                 remembered: std::sync::Mutex::new(false),
+                elevated: false,
+                focused: Some(true),
+                focus_lost_after: None,
+                focus_checks: std::sync::Mutex::new(0),
             }
         }
     }
 
     impl WindowManager for MockWindowManager {
-        fn remember_current_window(&self) -> Result<(), String> {
+        fn remember_current_window(&self) -> Result<(), OsError> {
             *self.remembered.lock().unwrap() = true;
             Ok(())
         }
 
-        fn restore_previous_window(&self) -> Result<(), String> {
+        fn remember_if_external(&self) -> Result<bool, OsError> {
+            self.remember_current_window().map(|()| true)
+        }
+
+        fn restore_previous_window(&self) -> Result<(), OsError> {
             if *self.remembered.lock().unwrap() {
                 Ok(())
             } else {
-                Err("No window remembered".to_string())
+                Err(OsError::NoSavedWindow)
             }
         }
 
         fn clear_saved_window(&self) {
             *self.remembered.lock().unwrap() = false;
         }
+
+        fn remembered_app(&self) -> Option<String> {
+            if *self.remembered.lock().unwrap() {
+                Some("mock.exe".to_string())
+            } else {
+                None
+            }
+        }
+
+        fn remembered_window_class(&self) -> Option<String> {
+            None
+        }
+
+        fn remembered_window_elevated(&self) -> bool {
+            self.elevated
+        }
+
+        fn remembered_window_focused(&self) -> Option<bool> {
+            let mut checks = self.focus_checks.lock().unwrap();
+            *checks += 1;
+            match self.focus_lost_after {
+                Some(passed) if *checks > passed => Some(false),
+                _ => self.focused,
+            }
+        }
     }
 
     struct MockClipboardService;
 
     impl ClipboardService for MockClipboardService {
-        fn write_text(&self, _text: &str) -> Result<(), String> {
+        fn write_text(&self, _text: &str) -> Result<(), OsError> {
             Ok(())
         }
 
-        fn read_text(&self) -> Result<String, String> {
+        fn read_text(&self) -> Result<String, OsError> {
             Ok("test".to_string())
         }
+
+        fn write_content(&self, _content: &ClipboardContent) -> Result<(), OsError> {
+            Ok(())
+        }
+    }
+
+    /// Simulates the user copying something else right as the paste happens
+    struct CopyingInputSimulator {
+        clipboard: Arc<InMemoryClipboard>,
+    }
+
+    impl InputSimulator for CopyingInputSimulator {
+        fn simulate_paste(&self) -> Result<(), OsError> {
+            self.clipboard.write_text("copied meanwhile")
+        }
+
+        fn simulate_keys(&self, _keys: &[KeyCode]) -> Result<(), OsError> {
+            Ok(())
+        }
+
+        fn type_keys(&self, _keys: &[KeyCode], _interval: std::time::Duration) -> Result<(), OsError> {
+            Ok(())
+        }
+
+        fn paste_with(&self, _strategy: PasteStrategy, _chord: &[KeyCode]) -> Result<(), OsError> {
+            Ok(())
+        }
     }
 
     struct MockInputSimulator;
 
     impl InputSimulator for MockInputSimulator {
-        fn simulate_paste(&self) -> Result<(), String> {
+        fn simulate_paste(&self) -> Result<(), OsError> {
+            Ok(())
+        }
+
+        fn simulate_keys(&self, _keys: &[KeyCode]) -> Result<(), OsError> {
+            Ok(())
+        }
+
+        fn type_keys(&self, _keys: &[KeyCode], _interval: std::time::Duration) -> Result<(), OsError> {
+            Ok(())
+        }
+
+        fn paste_with(&self, _strategy: PasteStrategy, _chord: &[KeyCode]) -> Result<(), OsError> {
+            Ok(())
+        }
+    }
+
+    /// Counts simulated Backspace presses and records typed keys, pastes and other chords
+    #[derive(Default)]
+    struct RecordingInputSimulator {
+        backspaces: std::sync::Mutex<usize>,
+        typed: std::sync::Mutex<Vec<KeyCode>>,
+        pastes: std::sync::Mutex<Vec<(PasteStrategy, Vec<KeyCode>)>>,
+        pressed: std::sync::Mutex<Vec<Vec<KeyCode>>>,
+        /// Clipboard pastes (Ctrl+V) simulated without a profile
+        default_pastes: std::sync::Mutex<usize>,
+        /// Strategies that fail when tried
+        failing: Vec<PasteStrategy>,
+    }
+
+    impl InputSimulator for RecordingInputSimulator {
+        fn simulate_paste(&self) -> Result<(), OsError> {
+            *self.default_pastes.lock().unwrap() += 1;
+            Ok(())
+        }
+
+        fn simulate_keys(&self, keys: &[KeyCode]) -> Result<(), OsError> {
+            if matches!(keys, [KeyCode::Backspace]) {
+                *self.backspaces.lock().unwrap() += 1;
+            } else {
+                self.pressed.lock().unwrap().push(keys.to_vec());
+            }
+            Ok(())
+        }
+
+        fn type_keys(&self, keys: &[KeyCode], _interval: std::time::Duration) -> Result<(), OsError> {
+            self.typed.lock().unwrap().extend_from_slice(keys);
+            Ok(())
+        }
+
+        fn paste_with(&self, strategy: PasteStrategy, chord: &[KeyCode]) -> Result<(), OsError> {
+            self.pastes.lock().unwrap().push((strategy, chord.to_vec()));
+            if self.failing.contains(&strategy) {
+                return Err(OsError::Input("blocked".to_string()));
+            }
             Ok(())
         }
+    }
+
+    /// Replays scripted keystrokes instead of hooking the keyboard
+    struct FakeKeyboardSource {
+        script: Vec<KeyEvent>,
+    }
 
-        fn simulate_keys(&self, _keys: &[KeyCode]) -> Result<(), String> {
+    impl FakeKeyboardSource {
+        fn typing(text: &str) -> Self {
+            Self {
+                script: text.chars().map(KeyEvent::Char).collect(),
+            }
+        }
+    }
+
+    impl KeyboardEventSource for FakeKeyboardSource {
+        fn start(&self, sink: std::sync::mpsc::Sender<KeyEvent>) -> Result<(), OsError> {
+            for event in &self.script {
+                let _ = sink.send(*event);
+            }
             Ok(())
         }
+
+        fn stop(&self) {}
     }
 
     #[test]
@@ -69,7 +212,17 @@ mod domain_tests {
     #[test]
     fn test_window_manager_restore_without_remember_fails() {
         let wm = MockWindowManager::new();
-        assert!(wm.restore_previous_window().is_err());
+        assert_eq!(wm.restore_previous_window(), Err(OsError::NoSavedWindow));
+    }
+
+    #[test]
+    fn test_window_manager_remembered_app_cleared_with_window() {
+        let wm = MockWindowManager::new();
+        wm.remember_current_window().unwrap();
+        assert_eq!(wm.remembered_app(), Some("mock.exe".to_string()));
+
+        wm.clear_saved_window();
+        assert_eq!(wm.remembered_app(), None);
     }
 
     #[test]
@@ -106,6 +259,439 @@ mod domain_tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_paste_prompt_use_case_restores_previous_clipboard() {
+        use super::super::application::use_cases::PastePromptUseCase;
+
+        let clipboard = Arc::new(InMemoryClipboard::with_text("user text"));
+        let window_manager = Arc::new(MockWindowManager::new());
+        let use_case = PastePromptUseCase::new(clipboard.clone(), window_manager.clone(), Arc::new(MockInputSimulator))
+            .with_clipboard_restore(std::time::Duration::ZERO);
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case.execute("prompt", true).await.unwrap();
+        assert!(result.paste_verified);
+        assert_eq!(clipboard.read_text().unwrap(), "user text");
+
+        // Copy-only keeps the prompt on the clipboard
+        use_case.execute("prompt", false).await.unwrap();
+        assert_eq!(clipboard.read_text().unwrap(), "prompt");
+    }
+
+    #[tokio::test]
+    async fn test_paste_prompt_use_case_restores_clipboard_after_unconfirmed_paste() {
+        use super::super::application::use_cases::PastePromptUseCase;
+
+        // The paste was sent but focus can't be checked, or moved away; the rendered text
+        // (possibly with secrets) must not stay on the clipboard either way
+        for focused in [None, Some(false)] {
+            let clipboard = Arc::new(InMemoryClipboard::with_text("user text"));
+            let window_manager = Arc::new(MockWindowManager { focused, ..MockWindowManager::new() });
+            let input_simulator = Arc::new(MockInputSimulator);
+            let use_case = PastePromptUseCase::new(clipboard.clone(), window_manager.clone(), input_simulator)
+                .with_clipboard_restore(std::time::Duration::ZERO);
+
+            window_manager.remember_current_window().unwrap();
+            let result = use_case.execute("secret prompt", true).await.unwrap();
+            assert!(!result.paste_verified);
+            assert!(!result.message.contains("Ctrl+V"));
+            assert_eq!(clipboard.read_text().unwrap(), "user text");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paste_prompt_use_case_keeps_clipboard_copied_meanwhile() {
+        use super::super::application::use_cases::PastePromptUseCase;
+
+        let clipboard = Arc::new(InMemoryClipboard::with_text("user text"));
+        let window_manager = Arc::new(MockWindowManager::new());
+        let input_simulator = Arc::new(CopyingInputSimulator { clipboard: clipboard.clone() });
+        let use_case = PastePromptUseCase::new(clipboard.clone(), window_manager.clone(), input_simulator)
+            .with_clipboard_restore(std::time::Duration::ZERO);
+
+        window_manager.remember_current_window().unwrap();
+        use_case.execute("prompt", true).await.unwrap();
+        assert_eq!(clipboard.read_text().unwrap(), "copied meanwhile");
+    }
+
+    #[tokio::test]
+    async fn test_paste_prompt_use_case_skips_elevated_target() {
+        use super::super::application::use_cases::PastePromptUseCase;
+
+        let clipboard = Arc::new(InMemoryClipboard::with_text("user text"));
+        let window_manager = Arc::new(MockWindowManager { elevated: true, ..MockWindowManager::new() });
+        let input_simulator = Arc::new(RecordingInputSimulator::default());
+        let use_case = PastePromptUseCase::new(clipboard.clone(), window_manager.clone(), input_simulator.clone())
+            .with_clipboard_restore(std::time::Duration::ZERO);
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case.execute("prompt", true).await.unwrap();
+        assert_eq!(result.reason, PasteReason::TargetElevated);
+        assert!(!result.paste_attempted);
+        assert!(result.clipboard_success);
+        assert_eq!(*input_simulator.default_pastes.lock().unwrap(), 0);
+        // The user pastes by hand, so the prompt stays on the clipboard
+        assert_eq!(clipboard.read_text().unwrap(), "prompt");
+    }
+
+    #[tokio::test]
+    async fn test_paste_prompt_use_case_verifies_focus_after_paste() {
+        use super::super::application::use_cases::PastePromptUseCase;
+        use super::super::domain::PasteProfile;
+
+        let profile = PasteProfile {
+            pre_paste_delay_ms: 0,
+            post_paste_keys: vec!["Enter".to_string()],
+            ..Default::default()
+        };
+        let clipboard = Arc::new(InMemoryClipboard::with_text("user text"));
+        let window_manager = Arc::new(MockWindowManager { focused: Some(false), ..MockWindowManager::new() });
+        let input_simulator = Arc::new(RecordingInputSimulator::default());
+        let use_case = PastePromptUseCase::new(clipboard.clone(), window_manager.clone(), input_simulator.clone())
+            .with_profile(profile, std::time::Duration::ZERO)
+            .with_clipboard_restore(std::time::Duration::ZERO);
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case.execute("prompt", true).await.unwrap();
+        assert_eq!(result.reason, PasteReason::FocusChanged);
+        assert!(result.paste_attempted);
+        assert!(!result.paste_verified);
+        // No Enter into whatever took focus
+        assert!(input_simulator.pressed.lock().unwrap().is_empty());
+
+        // A desktop that can't report focus leaves the paste unverified
+        let window_manager = Arc::new(MockWindowManager { focused: None, ..MockWindowManager::new() });
+        let use_case = PastePromptUseCase::new(clipboard.clone(), window_manager.clone(), Arc::new(MockInputSimulator));
+        window_manager.remember_current_window().unwrap();
+        let result = use_case.execute("prompt", true).await.unwrap();
+        assert_eq!(result.reason, PasteReason::Unverified);
+        assert!(!result.paste_verified);
+    }
+
+    #[tokio::test]
+    async fn test_paste_prompt_use_case_rich_text() {
+        use super::super::application::use_cases::PastePromptUseCase;
+
+        let clipboard = Arc::new(InMemoryClipboard::default());
+        let window_manager = Arc::new(MockWindowManager::new());
+        let use_case =
+            PastePromptUseCase::new(clipboard.clone(), window_manager.clone(), Arc::new(MockInputSimulator)).with_rich_text();
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case.execute("Fix **this**", true).await.unwrap();
+        assert!(result.paste_verified);
+
+        let content = clipboard.content();
+        assert_eq!(content.text, "Fix **this**");
+        assert_eq!(content.html.as_deref(), Some("<p>Fix <strong>this</strong></p>"));
+        assert!(content.rtf.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_paste_prompt_use_case_type_out() {
+        use super::super::application::use_cases::PastePromptUseCase;
+
+        let window_manager = Arc::new(MockWindowManager::new());
+        let input_simulator = Arc::new(RecordingInputSimulator::default());
+        let use_case = PastePromptUseCase::new(Arc::new(MockClipboardService), window_manager.clone(), input_simulator.clone());
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case.type_out("hi\n", std::time::Duration::ZERO).await.unwrap();
+        assert!(result.paste_verified);
+        assert!(!result.clipboard_success);
+        assert_eq!(
+            *input_simulator.typed.lock().unwrap(),
+            vec![KeyCode::Char('h'), KeyCode::Char('i'), KeyCode::Enter]
+        );
+
+        // Without a window to return to nothing is typed; the text is copied instead
+        let result = use_case.type_out("again", std::time::Duration::ZERO).await.unwrap();
+        assert_eq!(result.reason, PasteReason::NoTargetWindow);
+        assert!(!result.paste_attempted);
+        assert!(result.clipboard_success);
+        assert_eq!(input_simulator.typed.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_paste_prompt_use_case_type_out_stops_when_focus_moves() {
+        use super::super::application::use_cases::PastePromptUseCase;
+
+        let window_manager = Arc::new(MockWindowManager { focus_lost_after: Some(1), ..MockWindowManager::new() });
+        let input_simulator = Arc::new(RecordingInputSimulator::default());
+        let clipboard = Arc::new(InMemoryClipboard::default());
+        let use_case = PastePromptUseCase::new(clipboard.clone(), window_manager.clone(), input_simulator.clone());
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case.type_out("ok\nrm -rf ~\n", std::time::Duration::ZERO).await.unwrap();
+        assert_eq!(result.reason, PasteReason::FocusChanged);
+        // Only the first line reached the target; the rest was never typed into the other window
+        assert_eq!(
+            *input_simulator.typed.lock().unwrap(),
+            vec![KeyCode::Char('o'), KeyCode::Char('k'), KeyCode::Enter]
+        );
+        assert!(result.clipboard_success);
+        assert_eq!(clipboard.read_text().unwrap(), "ok\nrm -rf ~\n");
+    }
+
+    #[tokio::test]
+    async fn test_paste_prompt_use_case_types_long_lines_in_chunks() {
+        use super::super::application::use_cases::PastePromptUseCase;
+
+        let window_manager = Arc::new(MockWindowManager { focus_lost_after: Some(1), ..MockWindowManager::new() });
+        let input_simulator = Arc::new(RecordingInputSimulator::default());
+        let use_case = PastePromptUseCase::new(Arc::new(MockClipboardService), window_manager.clone(), input_simulator.clone());
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case.type_out(&"x".repeat(100), std::time::Duration::ZERO).await.unwrap();
+        assert_eq!(result.reason, PasteReason::FocusChanged);
+        assert_eq!(input_simulator.typed.lock().unwrap().len(), 64);
+    }
+
+    #[tokio::test]
+    async fn test_paste_prompt_use_case_follows_profile() {
+        use super::super::application::use_cases::PastePromptUseCase;
+        use super::super::domain::PasteProfile;
+
+        let profile = PasteProfile {
+            name: "Chat".to_string(),
+            chord: "Ctrl+Shift+V".to_string(),
+            pre_paste_delay_ms: 0,
+            strategies: vec![PasteStrategy::SendInput, PasteStrategy::MessageKeys, PasteStrategy::WmPaste],
+            post_paste_keys: vec!["Enter".to_string()],
+            ..Default::default()
+        };
+        let window_manager = Arc::new(MockWindowManager::new());
+        let input_simulator = Arc::new(RecordingInputSimulator {
+            failing: vec![PasteStrategy::SendInput],
+            ..Default::default()
+        });
+        let use_case = PastePromptUseCase::new(Arc::new(MockClipboardService), window_manager.clone(), input_simulator.clone())
+            .with_profile(profile, std::time::Duration::ZERO);
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case.execute("test", true).await.unwrap();
+        assert!(result.paste_verified);
+
+        // The failed strategy is followed by the next one; the rest are never tried
+        let chord = vec![KeyCode::Control, KeyCode::Shift, KeyCode::Letter('V')];
+        assert_eq!(
+            *input_simulator.pastes.lock().unwrap(),
+            vec![(PasteStrategy::SendInput, chord.clone()), (PasteStrategy::MessageKeys, chord)]
+        );
+        assert_eq!(*input_simulator.pressed.lock().unwrap(), vec![vec![KeyCode::Enter]]);
+    }
+
+    #[tokio::test]
+    async fn test_paste_prompt_use_case_profile_type_out_strategy() {
+        use super::super::application::use_cases::PastePromptUseCase;
+        use super::super::domain::PasteProfile;
+
+        let profile = PasteProfile {
+            pre_paste_delay_ms: 0,
+            strategies: vec![PasteStrategy::WmPaste, PasteStrategy::TypeOut],
+            ..Default::default()
+        };
+        let window_manager = Arc::new(MockWindowManager::new());
+        let input_simulator = Arc::new(RecordingInputSimulator {
+            failing: vec![PasteStrategy::WmPaste],
+            ..Default::default()
+        });
+        let use_case = PastePromptUseCase::new(Arc::new(MockClipboardService), window_manager.clone(), input_simulator.clone())
+            .with_profile(profile, std::time::Duration::ZERO);
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case.execute("ok", true).await.unwrap();
+        assert!(result.paste_verified);
+        assert_eq!(*input_simulator.typed.lock().unwrap(), vec![KeyCode::Char('o'), KeyCode::Char('k')]);
+        assert!(input_simulator.pressed.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_macro_use_case_runs_steps_in_order() {
+        use super::super::application::use_cases::RunMacroUseCase;
+        use super::super::domain::MacroAction;
+
+        let clipboard = Arc::new(InMemoryClipboard::default());
+        let window_manager = Arc::new(MockWindowManager::new());
+        let input_simulator = Arc::new(RecordingInputSimulator::default());
+        let use_case = RunMacroUseCase::new(clipboard.clone(), window_manager.clone(), input_simulator.clone());
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case
+            .execute(&[
+                MacroAction::Paste("Jane".to_string()),
+                MacroAction::Keys(vec![KeyCode::Tab]),
+                MacroAction::Wait(std::time::Duration::ZERO),
+                MacroAction::Paste("Doe".to_string()),
+                MacroAction::Keys(vec![KeyCode::Enter]),
+            ])
+            .await
+            .unwrap();
+
+        assert!(result.paste_verified);
+        assert!(result.clipboard_success);
+        assert_eq!(*input_simulator.default_pastes.lock().unwrap(), 2);
+        assert_eq!(*input_simulator.pressed.lock().unwrap(), vec![vec![KeyCode::Tab], vec![KeyCode::Enter]]);
+        assert_eq!(clipboard.read_text().unwrap(), "Doe");
+        assert_eq!(window_manager.remembered_app(), None);
+    }
+
+    #[tokio::test]
+    async fn test_run_macro_use_case_restores_clipboard() {
+        use super::super::application::use_cases::RunMacroUseCase;
+        use super::super::domain::MacroAction;
+
+        let clipboard = Arc::new(InMemoryClipboard::with_text("user text"));
+        let window_manager = Arc::new(MockWindowManager::new());
+        let input_simulator = Arc::new(RecordingInputSimulator::default());
+        let use_case = RunMacroUseCase::new(clipboard.clone(), window_manager.clone(), input_simulator.clone())
+            .with_clipboard_restore(std::time::Duration::ZERO);
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case
+            .execute(&[MacroAction::Paste("**Jane**".to_string()), MacroAction::Paste("token".to_string())])
+            .await
+            .unwrap();
+
+        assert!(result.paste_verified);
+        assert!(!result.clipboard_success);
+        assert_eq!(*input_simulator.default_pastes.lock().unwrap(), 2);
+        assert_eq!(clipboard.read_text().unwrap(), "user text");
+    }
+
+    #[tokio::test]
+    async fn test_run_macro_use_case_pastes_rich_text() {
+        use super::super::application::use_cases::RunMacroUseCase;
+        use super::super::domain::MacroAction;
+
+        let clipboard = Arc::new(InMemoryClipboard::default());
+        let window_manager = Arc::new(MockWindowManager::new());
+        let use_case = RunMacroUseCase::new(clipboard.clone(), window_manager.clone(), Arc::new(MockInputSimulator))
+            .with_rich_text();
+
+        window_manager.remember_current_window().unwrap();
+        use_case.execute(&[MacroAction::Paste("Fix **this**".to_string())]).await.unwrap();
+
+        let content = clipboard.content();
+        assert_eq!(content.text, "Fix **this**");
+        assert_eq!(content.html.as_deref(), Some("<p>Fix <strong>this</strong></p>"));
+        assert!(content.rtf.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_run_macro_use_case_types_segments_in_type_out_mode() {
+        use super::super::application::use_cases::RunMacroUseCase;
+        use super::super::domain::MacroAction;
+
+        let clipboard = Arc::new(InMemoryClipboard::default());
+        let window_manager = Arc::new(MockWindowManager::new());
+        let input_simulator = Arc::new(RecordingInputSimulator::default());
+        let use_case = RunMacroUseCase::new(clipboard.clone(), window_manager.clone(), input_simulator.clone())
+            .with_type_out(std::time::Duration::ZERO);
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case.execute(&[MacroAction::Paste("ok".to_string())]).await.unwrap();
+
+        assert!(result.paste_verified);
+        assert!(!result.clipboard_success);
+        assert_eq!(*input_simulator.typed.lock().unwrap(), vec![KeyCode::Char('o'), KeyCode::Char('k')]);
+        assert_eq!(*input_simulator.default_pastes.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_run_macro_use_case_without_focus_copies_first_segment() {
+        use super::super::application::use_cases::RunMacroUseCase;
+        use super::super::domain::MacroAction;
+
+        let clipboard = Arc::new(InMemoryClipboard::default());
+        let input_simulator = Arc::new(RecordingInputSimulator::default());
+        let use_case = RunMacroUseCase::new(clipboard.clone(), Arc::new(MockWindowManager::new()), input_simulator.clone());
+
+        let result = use_case
+            .execute(&[MacroAction::Keys(vec![KeyCode::Tab]), MacroAction::Paste("first".to_string())])
+            .await
+            .unwrap();
+
+        assert_eq!(result.reason, PasteReason::NoTargetWindow);
+        assert!(result.clipboard_success);
+        assert_eq!(clipboard.read_text().unwrap(), "first");
+        assert!(input_simulator.pressed.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_macro_use_case_stops_when_focus_moves() {
+        use super::super::application::use_cases::RunMacroUseCase;
+        use super::super::domain::MacroAction;
+
+        let window_manager = Arc::new(MockWindowManager { focused: Some(false), ..MockWindowManager::new() });
+        let input_simulator = Arc::new(RecordingInputSimulator::default());
+        let use_case = RunMacroUseCase::new(Arc::new(InMemoryClipboard::default()), window_manager.clone(), input_simulator.clone());
+
+        window_manager.remember_current_window().unwrap();
+        let result = use_case
+            .execute(&[MacroAction::Wait(std::time::Duration::ZERO), MacroAction::Keys(vec![KeyCode::Tab])])
+            .await
+            .unwrap();
+
+        assert_eq!(result.reason, PasteReason::FocusChanged);
+        assert_eq!(result.message, "Macro stopped at step 2: focus moved to another window");
+        assert!(input_simulator.pressed.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_expand_abbreviation_use_case_erases_trigger() {
+        use super::super::application::use_cases::ExpandAbbreviationUseCase;
+        use super::super::domain::Abbreviation;
+
+        let window_manager = Arc::new(MockWindowManager::new());
+        let input_simulator = Arc::new(RecordingInputSimulator::default());
+        let abbreviations = vec![Abbreviation {
+            trigger: ";;review".to_string(),
+            prompt_id: "review.md".to_string(),
+        }];
+        let mut use_case =
+            ExpandAbbreviationUseCase::new(window_manager.clone(), input_simulator.clone(), abbreviations, Vec::new());
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        FakeKeyboardSource::typing("see ;;review").start(sender).unwrap();
+
+        let mut matched = Vec::new();
+        for event in receiver.iter() {
+            if let Some(abbreviation) = use_case.handle(event).unwrap() {
+                use_case.erase(&abbreviation).unwrap();
+                matched.push(abbreviation.prompt_id);
+            }
+        }
+
+        assert_eq!(matched, vec!["review.md".to_string()]);
+        assert_eq!(*input_simulator.backspaces.lock().unwrap(), ";;review".len());
+        // Paste target is remembered for the caller
+        assert_eq!(window_manager.remembered_app(), Some("mock.exe".to_string()));
+    }
+
+    #[test]
+    fn test_expand_abbreviation_use_case_skips_excluded_apps() {
+        use super::super::application::use_cases::ExpandAbbreviationUseCase;
+        use super::super::domain::Abbreviation;
+        use crate::storage::TargetApp;
+
+        let window_manager = Arc::new(MockWindowManager::new());
+        let abbreviations = vec![Abbreviation {
+            trigger: ";;x".to_string(),
+            prompt_id: "x.md".to_string(),
+        }];
+        let mut use_case = ExpandAbbreviationUseCase::new(
+            window_manager.clone(),
+            Arc::new(RecordingInputSimulator::default()),
+            abbreviations,
+            vec![TargetApp::new("Mock.exe")],
+        );
+
+        let results: Vec<_> = ";;x".chars().map(|c| use_case.handle(KeyEvent::Char(c)).unwrap()).collect();
+        assert!(results.iter().all(Option::is_none));
+        assert_eq!(window_manager.remembered_app(), None);
+    }
+
     #[test]
     fn test_show_window_use_case() {
         use super::super::application::use_cases::ShowWindowUseCase;
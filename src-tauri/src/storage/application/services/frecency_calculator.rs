@@ -1,16 +1,24 @@
 use crate::storage::domain::entities::Prompt;
-use crate::storage::domain::value_objects::{FrecencyScore, PromptId};
+use crate::storage::domain::value_objects::{ContentHash, FrecencyModel, FrecencyScore, PromptId, TargetApp};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+/// Weight of the per-app frecency relative to the global frecency
+/// A prompt used a few times in the current app outranks a globally popular one
+const APP_AFFINITY_WEIGHT: f64 = 3.0;
+
+/// Number of individual use timestamps kept per prompt (and per prompt per app)
+const MAX_RECORDED_USES: usize = 30;
+
 /// Application service for frecency calculations
 /// Manages usage tracking and scoring
 #[derive(Debug, Clone)]
 pub struct FrecencyCalculator {
     usage_data: HashMap<String, InternalUsageData>,
     storage_path: PathBuf,
+    model: FrecencyModel,
 }
 
 /// Public usage data returned to API consumers
@@ -23,8 +31,54 @@ pub struct UsageData {
 /// Internal storage format with DateTime
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct InternalUsageData {
+    #[serde(flatten)]
+    record: UsageRecord,
+    /// Usage per target application, keyed by normalized app name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    apps: HashMap<String, UsageRecord>,
+    /// Content fingerprint stamped by library maintenance (finds renamed files)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_hash: Option<ContentHash>,
+}
+
+/// Use count plus the most recent use timestamps
+/// `uses` is absent in usage files written before per-use tracking
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct UsageRecord {
     use_count: u32,
     last_used: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    uses: Vec<DateTime<Utc>>,
+}
+
+impl UsageRecord {
+    fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            use_count: 0,
+            last_used: now,
+            uses: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, now: DateTime<Utc>) {
+        self.use_count += 1;
+        self.last_used = now;
+        self.uses.push(now);
+        if self.uses.len() > MAX_RECORDED_USES {
+            let excess = self.uses.len() - MAX_RECORDED_USES;
+            self.uses.drain(..excess);
+        }
+    }
+
+    fn score_at(&self, model: FrecencyModel, now: DateTime<Utc>) -> f64 {
+        // Legacy records only know the last use
+        let uses = if self.uses.is_empty() {
+            vec![self.last_used]
+        } else {
+            self.uses.clone()
+        };
+        FrecencyScore::new(self.use_count, uses).calculate_at(model, now)
+    }
 }
 
 impl FrecencyCalculator {
@@ -35,9 +89,16 @@ impl FrecencyCalculator {
         Ok(Self {
             usage_data,
             storage_path,
+            model: FrecencyModel::default(),
         })
     }
 
+    /// Use the frecency algorithm selected in config
+    pub fn with_model(mut self, model: FrecencyModel) -> Self {
+        self.model = model;
+        self
+    }
+
     fn get_storage_path() -> Result<PathBuf, String> {
         let home = dirs::home_dir().ok_or("Could not find home directory")?;
         let storage_dir = home.join(".prompter");
@@ -61,7 +122,19 @@ impl FrecencyCalculator {
             .map_err(|e| format!("Failed to parse usage file: {}", e))
     }
 
-    fn save_to_disk(&self) -> Result<(), String> {
+    /// Create a calculator backed by a custom usage file (for testing)
+    #[cfg(test)]
+    pub fn with_storage_path(storage_path: PathBuf) -> Result<Self, String> {
+        let usage_data = Self::load_from_disk(&storage_path)?;
+
+        Ok(Self {
+            usage_data,
+            storage_path,
+            model: FrecencyModel::default(),
+        })
+    }
+
+    pub fn save_to_disk(&self) -> Result<(), String> {
         let content = serde_json::to_string_pretty(&self.usage_data)
             .map_err(|e| format!("Failed to serialize usage data: {}", e))?;
 
@@ -69,47 +142,116 @@ impl FrecencyCalculator {
             .map_err(|e| format!("Failed to write usage file: {}", e))
     }
 
-    pub fn record_usage(&mut self, id: &PromptId) -> Result<(), String> {
+    /// Record a use of a prompt, optionally attributed to the app it was pasted into
+    pub fn record_usage(&mut self, id: &PromptId, target_app: Option<&TargetApp>) -> Result<(), String> {
+        let now = Utc::now();
         let entry = self
             .usage_data
             .entry(id.as_str().to_string())
-            .or_insert(InternalUsageData {
-                use_count: 0,
-                last_used: Utc::now(),
+            .or_insert_with(|| InternalUsageData {
+                record: UsageRecord::new(now),
+                apps: HashMap::new(),
+                content_hash: None,
             });
 
-        entry.use_count += 1;
-        entry.last_used = Utc::now();
+        entry.record.record(now);
+
+        if let Some(app) = target_app {
+            entry
+                .apps
+                .entry(app.as_str().to_string())
+                .or_insert_with(|| UsageRecord::new(now))
+                .record(now);
+        }
 
         // Persist to disk
         self.save_to_disk()
     }
 
     pub fn get_score(&self, id: &PromptId) -> f64 {
+        self.get_score_at(id, Utc::now())
+    }
+
+    /// Score at a specific point in time (deterministic evaluation)
+    pub fn get_score_at(&self, id: &PromptId, now: DateTime<Utc>) -> f64 {
         self.usage_data
             .get(id.as_str())
-            .map(|data| {
-                let score = FrecencyScore::new(data.use_count, data.last_used);
-                score.calculate()
-            })
+            .map(|data| data.record.score_at(self.model, now))
+            .unwrap_or(0.0)
+    }
+
+    /// Frecency of a prompt within a single target application (0.0 if never used there)
+    pub fn get_app_score(&self, id: &PromptId, app: &TargetApp) -> f64 {
+        self.usage_data
+            .get(id.as_str())
+            .and_then(|data| data.apps.get(app.as_str()))
+            .map(|data| data.score_at(self.model, Utc::now()))
             .unwrap_or(0.0)
     }
 
+    /// Global frecency plus the weighted app-affinity component
+    pub fn get_score_for_app(&self, id: &PromptId, app: Option<&TargetApp>) -> f64 {
+        let base = self.get_score(id);
+        match app {
+            Some(app) => base + APP_AFFINITY_WEIGHT * self.get_app_score(id, app),
+            None => base,
+        }
+    }
+
     /// Get usage data for a prompt (public API)
     pub fn get_usage(&self, id: &str) -> Option<UsageData> {
         self.usage_data.get(id).map(|data| UsageData {
-            use_count: data.use_count,
-            last_used: data.last_used.to_rfc3339(),
+            use_count: data.record.use_count,
+            last_used: data.record.last_used.to_rfc3339(),
         })
     }
 
+    /// IDs of all prompts with recorded usage
+    pub fn tracked_ids(&self) -> Vec<String> {
+        self.usage_data.keys().cloned().collect()
+    }
+
+    /// Content fingerprint last stamped for a prompt
+    pub fn content_hash(&self, id: &str) -> Option<&ContentHash> {
+        self.usage_data.get(id).and_then(|data| data.content_hash.as_ref())
+    }
+
+    /// Stamp a prompt's content fingerprint (call `save_to_disk` to persist)
+    pub fn set_content_hash(&mut self, id: &str, hash: ContentHash) {
+        if let Some(data) = self.usage_data.get_mut(id) {
+            data.content_hash = Some(hash);
+        }
+    }
+
+    /// Move usage from one prompt ID to another (call `save_to_disk` to persist)
+    pub fn rename_usage(&mut self, old_id: &str, new_id: &str) {
+        if let Some(data) = self.usage_data.remove(old_id) {
+            self.usage_data.insert(new_id.to_string(), data);
+        }
+    }
+
+    /// Forget a prompt's usage (call `save_to_disk` to persist)
+    pub fn remove_usage(&mut self, id: &str) {
+        self.usage_data.remove(id);
+    }
+
     pub fn sort_by_frecency(&self, prompts: &mut [Prompt]) {
+        self.sort_by_frecency_for_app(prompts, None);
+    }
+
+    /// Sort for the launcher list: prompts pinned to the target app first,
+    /// then by frecency including app affinity
+    pub fn sort_by_frecency_for_app(&self, prompts: &mut [Prompt], app: Option<&TargetApp>) {
         prompts.sort_by(|a, b| {
-            let score_a = self.get_score(&PromptId::new(&a.id));
-            let score_b = self.get_score(&PromptId::new(&b.id));
-            score_b
-                .partial_cmp(&score_a)
-                .unwrap_or(std::cmp::Ordering::Equal)
+            let pinned_a = app.is_some_and(|app| a.is_pinned_to(app));
+            let pinned_b = app.is_some_and(|app| b.is_pinned_to(app));
+            let score_a = self.get_score_for_app(&PromptId::new(&a.id), app);
+            let score_b = self.get_score_for_app(&PromptId::new(&b.id), app);
+            pinned_b.cmp(&pinned_a).then_with(|| {
+                score_b
+                    .partial_cmp(&score_a)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
         });
     }
 }
@@ -119,6 +261,7 @@ impl Default for FrecencyCalculator {
         Self::new().unwrap_or_else(|_| Self {
             usage_data: HashMap::new(),
             storage_path: PathBuf::from(".prompter/usage.json"),
+            model: FrecencyModel::default(),
         })
     }
 }
@@ -140,7 +283,7 @@ mod tests {
         let mut calculator = FrecencyCalculator::default();
         let id = PromptId::new("test-prompt");
 
-        calculator.record_usage(&id).unwrap();
+        calculator.record_usage(&id, None).unwrap();
         let score = calculator.get_score(&id);
         assert!(score > 0.0);
     }
@@ -150,10 +293,10 @@ mod tests {
         let mut calculator = FrecencyCalculator::default();
         let id = PromptId::new("test-prompt");
 
-        calculator.record_usage(&id).unwrap();
+        calculator.record_usage(&id, None).unwrap();
         let score1 = calculator.get_score(&id);
 
-        calculator.record_usage(&id).unwrap();
+        calculator.record_usage(&id, None).unwrap();
         let score2 = calculator.get_score(&id);
 
         assert!(score2 > score1);
@@ -168,12 +311,12 @@ mod tests {
         let id3 = PromptId::new("prompt3");
 
         // Record different usage patterns
-        calculator.record_usage(&id1).unwrap();
-        calculator.record_usage(&id2).unwrap();
-        calculator.record_usage(&id2).unwrap();
-        calculator.record_usage(&id3).unwrap();
-        calculator.record_usage(&id3).unwrap();
-        calculator.record_usage(&id3).unwrap();
+        calculator.record_usage(&id1, None).unwrap();
+        calculator.record_usage(&id2, None).unwrap();
+        calculator.record_usage(&id2, None).unwrap();
+        calculator.record_usage(&id3, None).unwrap();
+        calculator.record_usage(&id3, None).unwrap();
+        calculator.record_usage(&id3, None).unwrap();
 
         let mut prompts = vec![
             create_test_prompt("prompt1"),
@@ -189,21 +332,119 @@ mod tests {
         assert_eq!(prompts[2].id, "prompt1");
     }
 
+    #[test]
+    fn test_app_usage_only_boosts_matching_app() {
+        let mut calculator = FrecencyCalculator::default();
+        let id = PromptId::new("app-affinity-prompt");
+        let code = TargetApp::new("code");
+        let slack = TargetApp::new("slack");
+
+        calculator.record_usage(&id, Some(&code)).unwrap();
+
+        assert!(calculator.get_app_score(&id, &code) > 0.0);
+        assert_eq!(calculator.get_app_score(&id, &slack), 0.0);
+        assert!(calculator.get_score_for_app(&id, Some(&code)) > calculator.get_score_for_app(&id, Some(&slack)));
+    }
+
+    #[test]
+    fn test_sort_for_app_prefers_prompts_used_in_that_app() {
+        let mut calculator = FrecencyCalculator::default();
+        let popular = PromptId::new("affinity-popular");
+        let local = PromptId::new("affinity-local");
+        let terminal = TargetApp::new("wt");
+
+        // Globally popular prompt used elsewhere, local prompt used once in the terminal
+        calculator.record_usage(&popular, None).unwrap();
+        calculator.record_usage(&popular, None).unwrap();
+        calculator.record_usage(&local, Some(&terminal)).unwrap();
+
+        let mut prompts = vec![
+            create_test_prompt("affinity-popular"),
+            create_test_prompt("affinity-local"),
+        ];
+
+        calculator.sort_by_frecency_for_app(&mut prompts, Some(&terminal));
+        assert_eq!(prompts[0].id, "affinity-local");
+
+        calculator.sort_by_frecency_for_app(&mut prompts, None);
+        assert_eq!(prompts[0].id, "affinity-popular");
+    }
+
+    #[test]
+    fn test_sort_for_app_puts_pinned_prompts_first() {
+        let mut calculator = FrecencyCalculator::default();
+        let used = PromptId::new("pin-used");
+        calculator.record_usage(&used, None).unwrap();
+
+        let mut pinned = create_test_prompt("pin-never-used");
+        pinned.apps = vec!["Outlook.exe".to_string()];
+        let mut prompts = vec![create_test_prompt("pin-used"), pinned];
+
+        calculator.sort_by_frecency_for_app(&mut prompts, Some(&TargetApp::new("outlook")));
+        assert_eq!(prompts[0].id, "pin-never-used");
+    }
+
+    #[test]
+    fn test_recorded_uses_are_capped() {
+        let mut record = UsageRecord::new(Utc::now());
+        for _ in 0..(MAX_RECORDED_USES + 5) {
+            record.record(Utc::now());
+        }
+
+        assert_eq!(record.use_count as usize, MAX_RECORDED_USES + 5);
+        assert_eq!(record.uses.len(), MAX_RECORDED_USES);
+    }
+
+    #[test]
+    fn test_legacy_usage_record_without_timestamps_still_scores() {
+        let now = Utc::now();
+        let json = format!(r#"{{"use_count": 4, "last_used": "{}"}}"#, now.to_rfc3339());
+        let data: InternalUsageData = serde_json::from_str(&json).unwrap();
+
+        assert!(data.apps.is_empty());
+        assert!(data.content_hash.is_none());
+        assert!((data.record.score_at(FrecencyModel::default(), now) - 4.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_score_at_depends_on_model() {
+        let now = Utc::now();
+        let mut record = UsageRecord::new(now);
+        record.use_count = 1;
+        record.uses = vec![now - Duration::days(14)];
+
+        let half_life = record.score_at(FrecencyModel::HalfLife { half_life_days: 14.0 }, now);
+        let buckets = record.score_at(FrecencyModel::VisitBuckets, now);
+
+        assert!((half_life - 0.5).abs() < 1e-9);
+        assert!((buckets - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rename_and_remove_usage_persist() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("usage.json");
+        let mut calculator = FrecencyCalculator::with_storage_path(path.clone()).unwrap();
+
+        calculator.record_usage(&PromptId::new("old.md"), None).unwrap();
+        calculator.record_usage(&PromptId::new("gone.md"), None).unwrap();
+        calculator.set_content_hash("old.md", ContentHash::of("content"));
+        calculator.rename_usage("old.md", "new.md");
+        calculator.remove_usage("gone.md");
+        calculator.save_to_disk().unwrap();
+
+        let reloaded = FrecencyCalculator::with_storage_path(path).unwrap();
+        assert_eq!(reloaded.tracked_ids(), vec!["new.md".to_string()]);
+        assert_eq!(reloaded.content_hash("new.md"), Some(&ContentHash::of("content")));
+    }
+
     fn create_test_prompt(id: &str) -> Prompt {
         Prompt {
-            id: id.to_string(),
             name: format!("Prompt {}", id),
             description: "Test".to_string(),
-            content: "Test content".to_string(),
             folder: "test".to_string(),
-            icon: "📝".to_string(),
             color: "#000000".to_string(),
-            tags: vec![],
-            variables: vec![],
-            auto_paste: false,
-            is_favorite: false,
-            created_at: "2025-01-01T00:00:00Z".to_string(),
-            updated_at: "2025-01-01T00:00:00Z".to_string(),
+            ..Prompt::for_test(id)
         }
     }
 }
@@ -0,0 +1,183 @@
+use crate::logging::redact;
+use crate::storage::application::services::{FrecencyCalculator, VERSIONS_DIR_NAME};
+use crate::storage::domain::entities::{ExportBundle, ExportSelection, ExportedPrompt, ExportedUsage, EXPORT_SCHEMA_VERSION};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::PromptRepository;
+use chrono::Utc;
+use std::fs;
+use std::path::PathBuf;
+
+/// Application service that gathers a selection of prompts (plus optional
+/// version history and usage stats) into a format-independent bundle
+pub struct LibraryExportService<R: PromptRepository> {
+    repository: R,
+    frecency: FrecencyCalculator,
+    prompts_dir: PathBuf,
+}
+
+impl<R: PromptRepository> LibraryExportService<R> {
+    pub fn new(repository: R, frecency: FrecencyCalculator, prompts_dir: PathBuf) -> Self {
+        Self {
+            repository,
+            frecency,
+            prompts_dir,
+        }
+    }
+
+    /// Build the bundle for the selected prompts, sorted by ID
+    pub fn bundle(
+        &self,
+        selection: &ExportSelection,
+        include_versions: bool,
+        include_usage: bool,
+    ) -> Result<ExportBundle, StorageError> {
+        let mut prompts: Vec<_> = self
+            .repository
+            .find_all()?
+            .into_iter()
+            // Locked private prompts have no content to export
+            .filter(|p| !p.locked && selection.matches(p))
+            .collect();
+        prompts.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut exported = Vec::with_capacity(prompts.len());
+        for prompt in prompts {
+            let versions = if include_versions {
+                self.load_versions(&prompt.id)?
+            } else {
+                Vec::new()
+            };
+            let usage = include_usage
+                .then(|| self.frecency.get_usage(&prompt.id))
+                .flatten()
+                .map(|u| ExportedUsage {
+                    use_count: u.use_count,
+                    last_used: u.last_used,
+                });
+            exported.push(ExportedPrompt { prompt, versions, usage });
+        }
+
+        Ok(ExportBundle {
+            schema_version: EXPORT_SCHEMA_VERSION,
+            exported_at: Utc::now().to_rfc3339(),
+            prompts: exported,
+        })
+    }
+
+    /// Version snapshots of a prompt, oldest first
+    fn load_versions(&self, id: &str) -> Result<Vec<serde_json::Value>, String> {
+        let versions_dir = self.prompts_dir.join(VERSIONS_DIR_NAME).join(id);
+        if !versions_dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut versions = Vec::new();
+        for entry in fs::read_dir(&versions_dir)
+            .map_err(|e| format!("Failed to read versions directory: {}", e))?
+            .filter_map(|e| e.ok())
+        {
+            let path = entry.path();
+            if path.extension().and_then(|s| s.to_str()) != Some("json") {
+                continue;
+            }
+            let content = fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read version file: {}", e))?;
+            match serde_json::from_str::<serde_json::Value>(&content) {
+                Ok(version) => versions.push(version),
+                Err(e) => log::warn!("[LibraryExportService] Skipping unreadable version {}: {}", redact(path.display()), e),
+            }
+        }
+
+        versions.sort_by_key(|v| v.get("version_number").and_then(|n| n.as_i64()).unwrap_or(0));
+        Ok(versions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::domain::entities::Prompt;
+    use crate::storage::domain::value_objects::PromptId;
+    use crate::storage::infrastructure::persistence::FilePromptRepository;
+    use tempfile::TempDir;
+
+    fn create_prompt(id: &str, tags: &[&str]) -> Prompt {
+        Prompt {
+            name: "Test".to_string(),
+            content: format!("Content of {}", id),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..Prompt::for_test(id)
+        }
+    }
+
+    fn setup() -> (TempDir, LibraryExportService<FilePromptRepository>) {
+        let temp_dir = TempDir::new().unwrap();
+        let prompts_dir = temp_dir.path().join("prompts");
+        let repository = FilePromptRepository::with_directory(prompts_dir.clone()).unwrap();
+        repository.save(&create_prompt("Coding/review.md", &["code"])).unwrap();
+        repository.save(&create_prompt("Writing/intro.md", &["blog"])).unwrap();
+
+        let versions_dir = prompts_dir.join(VERSIONS_DIR_NAME).join("Coding/review.md");
+        fs::create_dir_all(&versions_dir).unwrap();
+        fs::write(versions_dir.join("b.json"), r#"{"version_number": 2, "content": "v2"}"#).unwrap();
+        fs::write(versions_dir.join("a.json"), r#"{"version_number": 1, "content": "v1"}"#).unwrap();
+
+        let mut frecency = FrecencyCalculator::with_storage_path(temp_dir.path().join("usage.json")).unwrap();
+        frecency.record_usage(&PromptId::new("Coding/review.md"), None).unwrap();
+
+        (temp_dir, LibraryExportService::new(repository, frecency, prompts_dir))
+    }
+
+    #[test]
+    fn test_bundle_filters_by_selection() {
+        let (_temp_dir, service) = setup();
+        let selection = ExportSelection {
+            tags: vec!["blog".to_string()],
+            ..Default::default()
+        };
+
+        let bundle = service.bundle(&selection, false, false).unwrap();
+        assert_eq!(bundle.schema_version, EXPORT_SCHEMA_VERSION);
+        assert_eq!(bundle.prompts.len(), 1);
+        assert_eq!(bundle.prompts[0].prompt.id, "Writing/intro.md");
+    }
+
+    #[test]
+    fn test_bundle_includes_versions_and_usage_on_request() {
+        let (_temp_dir, service) = setup();
+
+        let bundle = service.bundle(&ExportSelection::default(), true, true).unwrap();
+        let review = &bundle.prompts[0];
+        assert_eq!(review.prompt.id, "Coding/review.md");
+        assert_eq!(review.versions.len(), 2);
+        assert_eq!(review.versions[0]["content"], "v1");
+        assert_eq!(review.usage.as_ref().unwrap().use_count, 1);
+
+        let intro = &bundle.prompts[1];
+        assert!(intro.versions.is_empty());
+        assert!(intro.usage.is_none());
+    }
+
+    #[test]
+    fn test_bundle_omits_versions_and_usage_by_default() {
+        let (_temp_dir, service) = setup();
+
+        let bundle = service.bundle(&ExportSelection::default(), false, false).unwrap();
+        assert!(bundle.prompts.iter().all(|p| p.versions.is_empty() && p.usage.is_none()));
+    }
+
+    #[test]
+    fn test_bundle_skips_locked_private_prompts() {
+        let (temp_dir, service) = setup();
+        // No vault in tests, so a private prompt always loads locked
+        fs::write(
+            temp_dir.path().join("prompts/secret.md"),
+            "---\nname: Secret\nprivate: true\n---\n\nencrypted",
+        )
+        .unwrap();
+
+        let bundle = service.bundle(&ExportSelection::default(), false, false).unwrap();
+        let ids: Vec<&str> = bundle.prompts.iter().map(|p| p.prompt.id.as_str()).collect();
+        assert_eq!(ids, vec!["Coding/review.md", "Writing/intro.md"]);
+    }
+}
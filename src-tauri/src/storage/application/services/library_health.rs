@@ -0,0 +1,426 @@
+use crate::storage::domain::entities::{ParseFailure, Prompt, Variable};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::PromptRepository;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Category of a library problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthIssueKind {
+    /// File could not be parsed at all
+    ParseError,
+    /// Required field missing or empty (name, content, timestamps)
+    MissingField,
+    /// Another prompt in the same folder has the same name
+    DuplicateName,
+    /// `folder` field disagrees with the file's directory
+    FolderMismatch,
+    /// `{{name}}` used in content but not declared in `variables`
+    UndeclaredVariable,
+    /// Declared in `variables` but never used in content
+    UnusedVariable,
+    /// `color` is not a hex color
+    InvalidColor,
+}
+
+/// A single problem found in the library
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthIssue {
+    pub prompt_id: String,
+    pub kind: HealthIssueKind,
+    pub message: String,
+    /// 1-based line in the file, for parse errors
+    pub line: Option<usize>,
+    /// Whether `repair` can fix this without losing data
+    pub fixable: bool,
+}
+
+/// Result of a library health check
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// Number of prompt files inspected
+    pub files_checked: usize,
+    /// Problems still present
+    pub issues: Vec<HealthIssue>,
+    /// Problems fixed by this run (empty for a plain check)
+    pub fixed: Vec<HealthIssue>,
+}
+
+impl HealthReport {
+    pub fn is_healthy(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Application service that validates prompt files and repairs the safe cases
+pub struct LibraryHealthService<R: PromptRepository> {
+    repository: R,
+    prompts_dir: PathBuf,
+}
+
+impl<R: PromptRepository> LibraryHealthService<R> {
+    pub fn new(repository: R, prompts_dir: PathBuf) -> Self {
+        Self {
+            repository,
+            prompts_dir,
+        }
+    }
+
+    /// Inspect every prompt file without changing anything
+    pub fn check(&self) -> Result<HealthReport, StorageError> {
+        let (prompts, failures) = self.repository.find_all_with_failures()?;
+
+        let mut issues: Vec<HealthIssue> = failures.iter().map(Self::failure_issue).collect();
+        for prompt in &prompts {
+            issues.extend(Self::prompt_issues(prompt));
+        }
+        issues.extend(Self::duplicate_name_issues(&prompts));
+        issues.sort_by(|a, b| a.prompt_id.cmp(&b.prompt_id));
+
+        Ok(HealthReport {
+            files_checked: prompts.len() + failures.len(),
+            issues,
+            fixed: Vec::new(),
+        })
+    }
+
+    /// Fix every fixable issue, then check again
+    pub fn repair(&self) -> Result<HealthReport, StorageError> {
+        let before = self.check()?;
+        let (prompts, _) = self.repository.find_all_with_failures()?;
+        let mut prompts: HashMap<String, Prompt> = prompts.into_iter().map(|p| (p.id.clone(), p)).collect();
+
+        let mut fixed = Vec::new();
+        let mut changed: BTreeMap<String, Prompt> = BTreeMap::new();
+        for issue in before.issues.into_iter().filter(|i| i.fixable) {
+            let applied = match (issue.kind, prompts.get_mut(&issue.prompt_id)) {
+                // Unparseable file: only a missing name is fixed, directly in the file
+                (HealthIssueKind::MissingField, None) => self.insert_name_from_file_stem(&issue.prompt_id)?,
+                (_, Some(prompt)) => {
+                    let applied = Self::fix_prompt(prompt, &issue);
+                    if applied {
+                        changed.insert(prompt.id.clone(), prompt.clone());
+                    }
+                    applied
+                }
+                _ => false,
+            };
+            if applied {
+                fixed.push(issue);
+            }
+        }
+
+        for prompt in changed.values() {
+            self.repository.save(prompt)?;
+        }
+        log::info!("[LibraryHealthService] Repaired {} issues in {} prompts", fixed.len(), changed.len());
+
+        let mut report = self.check()?;
+        report.fixed = fixed;
+        Ok(report)
+    }
+
+    fn failure_issue(failure: &ParseFailure) -> HealthIssue {
+        let missing_name = failure.message.contains("missing field `name`");
+        HealthIssue {
+            prompt_id: failure.id.clone(),
+            kind: if missing_name { HealthIssueKind::MissingField } else { HealthIssueKind::ParseError },
+            message: failure.message.clone(),
+            line: failure.line,
+            fixable: missing_name,
+        }
+    }
+
+    fn prompt_issues(prompt: &Prompt) -> Vec<HealthIssue> {
+        let mut issues = Vec::new();
+        let mut issue = |kind, message: String, fixable| {
+            issues.push(HealthIssue {
+                prompt_id: prompt.id.clone(),
+                kind,
+                message,
+                line: None,
+                fixable,
+            })
+        };
+
+        if prompt.name.trim().is_empty() {
+            issue(HealthIssueKind::MissingField, "Name is empty".to_string(), true);
+        }
+        // Locked private prompts load without content; that is not a problem with the file
+        if !prompt.locked && prompt.content.trim().is_empty() {
+            issue(HealthIssueKind::MissingField, "Content is empty".to_string(), false);
+        }
+        if prompt.created_at.is_empty() || prompt.updated_at.is_empty() {
+            issue(HealthIssueKind::MissingField, "Timestamps are missing".to_string(), true);
+        }
+
+        let directory = prompt.directory();
+        if !prompt.folder.eq_ignore_ascii_case(&directory) {
+            issue(
+                HealthIssueKind::FolderMismatch,
+                format!("Folder is '{}' but the file is in '{}'", prompt.folder, directory),
+                true,
+            );
+        }
+
+        if !prompt.has_valid_color() {
+            issue(
+                HealthIssueKind::InvalidColor,
+                format!("'{}' is not a hex color", prompt.color),
+                true,
+            );
+        }
+
+        let placeholders = prompt.placeholders();
+        for name in &placeholders {
+            if !prompt.variables.iter().any(|v| &v.name == name) {
+                issue(
+                    HealthIssueKind::UndeclaredVariable,
+                    format!("{{{{{}}}}} is used but not declared", name),
+                    true,
+                );
+            }
+        }
+        // Removing a declaration would lose its default, so unused ones are report-only
+        for variable in &prompt.variables {
+            if !placeholders.contains(&variable.name) {
+                issue(
+                    HealthIssueKind::UnusedVariable,
+                    format!("Variable '{}' is declared but never used", variable.name),
+                    false,
+                );
+            }
+        }
+
+        issues
+    }
+
+    fn duplicate_name_issues(prompts: &[Prompt]) -> Vec<HealthIssue> {
+        let mut groups: BTreeMap<(String, String), Vec<&Prompt>> = BTreeMap::new();
+        for prompt in prompts.iter().filter(|p| !p.name.trim().is_empty()) {
+            let key = (prompt.directory().to_lowercase(), prompt.name.trim().to_lowercase());
+            groups.entry(key).or_default().push(prompt);
+        }
+
+        groups
+            .into_values()
+            .filter(|group| group.len() > 1)
+            .flat_map(|group| {
+                group
+                    .iter()
+                    .map(|prompt| {
+                        let others: Vec<&str> = group
+                            .iter()
+                            .filter(|p| p.id != prompt.id)
+                            .map(|p| p.id.as_str())
+                            .collect();
+                        HealthIssue {
+                            prompt_id: prompt.id.clone(),
+                            kind: HealthIssueKind::DuplicateName,
+                            message: format!("Name '{}' is also used by {}", prompt.name.trim(), others.join(", ")),
+                            line: None,
+                            fixable: false,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Apply the fix for one issue to the in-memory prompt; returns whether anything changed
+    fn fix_prompt(prompt: &mut Prompt, issue: &HealthIssue) -> bool {
+        match issue.kind {
+            HealthIssueKind::MissingField if prompt.name.trim().is_empty() => {
+                prompt.name = file_stem(&prompt.id);
+                true
+            }
+            HealthIssueKind::MissingField if prompt.created_at.is_empty() || prompt.updated_at.is_empty() => {
+                let now = chrono::Utc::now().to_rfc3339();
+                if prompt.created_at.is_empty() {
+                    prompt.created_at = now.clone();
+                }
+                if prompt.updated_at.is_empty() {
+                    prompt.updated_at = now;
+                }
+                true
+            }
+            HealthIssueKind::FolderMismatch => {
+                prompt.folder = prompt.directory();
+                true
+            }
+            HealthIssueKind::InvalidColor => {
+                prompt.color = Prompt::DEFAULT_COLOR.to_string();
+                true
+            }
+            HealthIssueKind::UndeclaredVariable => {
+                let missing: Vec<String> = prompt
+                    .placeholders()
+                    .into_iter()
+                    .filter(|name| !prompt.variables.iter().any(|v| &v.name == name))
+                    .collect();
+                for name in &missing {
+                    prompt.variables.push(Variable {
+                        name: name.clone(),
+                        default: String::new(),
+                        required: true,
+                    });
+                }
+                !missing.is_empty()
+            }
+            _ => false,
+        }
+    }
+
+    /// Add `name: <file stem>` as the first frontmatter line of an unparseable file
+    fn insert_name_from_file_stem(&self, id: &str) -> Result<bool, String> {
+        let path = self.prompts_dir.join(id);
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", id, e))?;
+        let Some(rest) = content.strip_prefix("---") else {
+            return Ok(false);
+        };
+
+        let name = serde_yaml::to_string(&file_stem(id))
+            .map_err(|e| format!("Failed to serialize name: {}", e))?;
+        let fixed = format!("---\nname: {}{}", name.trim_end(), rest);
+        fs::write(&path, fixed)
+            .map_err(|e| format!("Failed to write {}: {}", id, e))?;
+        Ok(true)
+    }
+}
+
+fn file_stem(id: &str) -> String {
+    Path::new(id)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(id)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::infrastructure::persistence::FilePromptRepository;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, LibraryHealthService<FilePromptRepository>) {
+        let temp_dir = TempDir::new().unwrap();
+        let prompts_dir = temp_dir.path().to_path_buf();
+        let repository = FilePromptRepository::with_directory(prompts_dir.clone()).unwrap();
+        (temp_dir, LibraryHealthService::new(repository, prompts_dir))
+    }
+
+    fn write_prompt(dir: &TempDir, id: &str, frontmatter: &str, content: &str) {
+        let path = dir.path().join(id);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, format!("---\n{}\n---\n{}", frontmatter, content)).unwrap();
+    }
+
+    fn healthy_frontmatter(name: &str, folder: &str) -> String {
+        format!(
+            "name: {}\nfolder: {}\ncolor: \"#3B82F6\"\ncreated_at: \"2025-01-01T00:00:00Z\"\nupdated_at: \"2025-01-01T00:00:00Z\"",
+            name, folder
+        )
+    }
+
+    fn kinds(report: &HealthReport, id: &str) -> Vec<HealthIssueKind> {
+        report.issues.iter().filter(|i| i.prompt_id == id).map(|i| i.kind).collect()
+    }
+
+    #[test]
+    fn test_healthy_library() {
+        let (temp_dir, service) = setup();
+        write_prompt(&temp_dir, "Coding/review.md", &healthy_frontmatter("Review", "Coding"), "Review this");
+
+        let report = service.check().unwrap();
+        assert_eq!(report.files_checked, 1);
+        assert!(report.is_healthy(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_reports_parse_error_with_line() {
+        let (temp_dir, service) = setup();
+        write_prompt(&temp_dir, "broken.md", "name: Broken\ntags: [unclosed", "Content");
+
+        let report = service.check().unwrap();
+        let issue = &report.issues[0];
+        assert_eq!(issue.kind, HealthIssueKind::ParseError);
+        assert!(issue.line.is_some());
+        assert!(!issue.fixable);
+    }
+
+    #[test]
+    fn test_reports_prompt_issues() {
+        let (temp_dir, service) = setup();
+        write_prompt(
+            &temp_dir,
+            "Coding/review.md",
+            "name: Review\nfolder: Writing\ncolor: blue\nvariables:\n  - name: lang\n    default: rust\n    required: false",
+            "Review {{code}}",
+        );
+
+        let report = service.check().unwrap();
+        assert_eq!(
+            kinds(&report, "Coding/review.md"),
+            vec![
+                HealthIssueKind::MissingField,
+                HealthIssueKind::FolderMismatch,
+                HealthIssueKind::InvalidColor,
+                HealthIssueKind::UndeclaredVariable,
+                HealthIssueKind::UnusedVariable,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reports_duplicate_names_per_folder() {
+        let (temp_dir, service) = setup();
+        write_prompt(&temp_dir, "Coding/a.md", &healthy_frontmatter("Review", "Coding"), "A");
+        write_prompt(&temp_dir, "Coding/b.md", &healthy_frontmatter("review", "Coding"), "B");
+        write_prompt(&temp_dir, "Writing/c.md", &healthy_frontmatter("Review", "Writing"), "C");
+
+        let report = service.check().unwrap();
+        assert_eq!(kinds(&report, "Coding/a.md"), vec![HealthIssueKind::DuplicateName]);
+        assert_eq!(kinds(&report, "Coding/b.md"), vec![HealthIssueKind::DuplicateName]);
+        assert!(kinds(&report, "Writing/c.md").is_empty());
+    }
+
+    #[test]
+    fn test_repair_fixes_safe_issues_only() {
+        let (temp_dir, service) = setup();
+        write_prompt(
+            &temp_dir,
+            "Coding/review.md",
+            "name: Review\nfolder: Writing\ncolor: blue\nvariables:\n  - name: lang\n    default: rust\n    required: false",
+            "Review {{code}}",
+        );
+
+        let report = service.repair().unwrap();
+        assert_eq!(report.fixed.len(), 4);
+        assert_eq!(kinds(&report, "Coding/review.md"), vec![HealthIssueKind::UnusedVariable]);
+
+        let prompt = service.repository.find_by_id(&"Coding/review.md".into()).unwrap();
+        assert_eq!(prompt.folder, "Coding");
+        assert_eq!(prompt.color, Prompt::DEFAULT_COLOR);
+        assert!(prompt.variables.iter().any(|v| v.name == "code"));
+        assert!(!prompt.created_at.is_empty());
+    }
+
+    #[test]
+    fn test_repair_adds_missing_name_to_unparseable_file() {
+        let (temp_dir, service) = setup();
+        write_prompt(&temp_dir, "notes.md", "folder: \"\"\ncolor: \"#3B82F6\"", "Content");
+
+        let report = service.check().unwrap();
+        assert_eq!(kinds(&report, "notes.md"), vec![HealthIssueKind::MissingField]);
+
+        let report = service.repair().unwrap();
+        assert_eq!(report.fixed.len(), 1);
+
+        let prompt = service.repository.find_by_id(&"notes.md".into()).unwrap();
+        assert_eq!(prompt.name, "notes");
+    }
+}
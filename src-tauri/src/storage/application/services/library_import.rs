@@ -0,0 +1,354 @@
+use crate::storage::domain::entities::{
+    ConflictResolution, ImportAction, ImportBatch, ImportItem, ImportReport, ImportStatus, ParseFailure, Prompt,
+};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::PromptRepository;
+use crate::storage::domain::value_objects::ContentHash;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::path::Path;
+use uuid::Uuid;
+
+/// Application service that merges an import batch into the library
+/// Classifies each prompt as new, duplicate or conflicting and applies per-file resolutions
+pub struct LibraryImportService<R: PromptRepository> {
+    repository: R,
+}
+
+/// Lookup tables of prompts already in (or planned for) the library
+#[derive(Default)]
+struct LibraryIndex {
+    by_id: HashMap<String, String>,
+    by_name: HashMap<(String, String), String>,
+    by_hash: HashMap<ContentHash, String>,
+    /// Abbreviation and id of each prompt that has one
+    abbreviations: Vec<(String, String)>,
+}
+
+impl LibraryIndex {
+    fn insert(&mut self, prompt: &Prompt) {
+        self.by_id.insert(prompt.id.to_lowercase(), prompt.id.clone());
+        self.by_name.insert(Self::name_key(prompt), prompt.id.clone());
+        self.by_hash.insert(ContentHash::of(&prompt.content), prompt.id.clone());
+        if let Some(abbreviation) = &prompt.abbreviation {
+            self.abbreviations.push((abbreviation.clone(), prompt.id.clone()));
+        }
+    }
+
+    /// Whether a prompt other than `id` has an abbreviation that clashes with `abbreviation`
+    fn abbreviation_taken(&self, abbreviation: &str, id: &str) -> bool {
+        self.abbreviations
+            .iter()
+            .any(|(other, owner)| owner != id && Prompt::abbreviations_clash(abbreviation, other))
+    }
+
+    fn name_key(prompt: &Prompt) -> (String, String) {
+        (prompt.directory().to_lowercase(), prompt.name.trim().to_lowercase())
+    }
+
+    /// Business rule: identical content is a duplicate; same path or same name in
+    /// the same folder with different content is a conflict
+    fn classify(&self, prompt: &Prompt) -> (ImportStatus, Option<String>) {
+        if let Some(id) = self.by_hash.get(&ContentHash::of(&prompt.content)) {
+            return (ImportStatus::Duplicate, Some(id.clone()));
+        }
+        let conflict = self
+            .by_id
+            .get(&prompt.id.to_lowercase())
+            .or_else(|| self.by_name.get(&Self::name_key(prompt)));
+        match conflict {
+            Some(id) => (ImportStatus::Conflict, Some(id.clone())),
+            None => (ImportStatus::New, None),
+        }
+    }
+}
+
+impl<R: PromptRepository> LibraryImportService<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    /// Plan the import and, unless `dry_run`, write it
+    /// `resolutions` is keyed by source path; unresolved duplicates and conflicts are skipped
+    pub fn import(
+        &self,
+        batch: ImportBatch,
+        resolutions: &HashMap<String, ConflictResolution>,
+        dry_run: bool,
+    ) -> Result<ImportReport, StorageError> {
+        let mut report = self.plan(batch, resolutions)?;
+        report.dry_run = dry_run;
+        if dry_run {
+            return Ok(report);
+        }
+
+        for item in &mut report.items {
+            let Some(target_id) = item.target_id.clone() else {
+                continue;
+            };
+            item.prompt.id = target_id;
+            item.prompt.folder = item.prompt.directory();
+
+            if let Err(error) = self.repository.save(&item.prompt) {
+                report.errors.push(ParseFailure {
+                    id: item.source_path.clone(),
+                    message: error.to_string(),
+                    line: None,
+                });
+                item.action = ImportAction::Skip;
+                item.target_id = None;
+            }
+        }
+
+        log::info!(
+            "[LibraryImportService] Imported {} new, {} overwritten, {} kept both, {} skipped, {} errors",
+            report.count(ImportAction::Create),
+            report.count(ImportAction::Overwrite),
+            report.count(ImportAction::KeepBoth),
+            report.count(ImportAction::Skip),
+            report.errors.len()
+        );
+        Ok(report)
+    }
+
+    fn plan(&self, batch: ImportBatch, resolutions: &HashMap<String, ConflictResolution>) -> Result<ImportReport, StorageError> {
+        let mut index = LibraryIndex::default();
+        for prompt in self.repository.find_all()? {
+            index.insert(&prompt);
+        }
+
+        let mut report = ImportReport {
+            errors: batch.errors,
+            ..Default::default()
+        };
+        let now = Utc::now().to_rfc3339();
+
+        for mut prompt in batch.prompts {
+            let source_path = prompt.id.clone();
+            if let Err(message) = prompt.validate() {
+                report.errors.push(ParseFailure {
+                    id: source_path,
+                    message,
+                    line: None,
+                });
+                continue;
+            }
+
+            // Folders come from the source layout; timestamps are kept when present
+            prompt.folder = prompt.directory();
+            if prompt.created_at.is_empty() {
+                prompt.created_at = now.clone();
+            }
+            if prompt.updated_at.is_empty() {
+                prompt.updated_at = prompt.created_at.clone();
+            }
+
+            let (status, existing_id) = index.classify(&prompt);
+            let (action, target_id) = match (status, &existing_id) {
+                (ImportStatus::New, _) | (_, None) => (ImportAction::Create, Some(prompt.id.clone())),
+                (_, Some(existing_id)) => match resolutions.get(&source_path).copied().unwrap_or_default() {
+                    ConflictResolution::Skip => (ImportAction::Skip, None),
+                    ConflictResolution::Overwrite => (ImportAction::Overwrite, Some(existing_id.clone())),
+                    ConflictResolution::KeepBoth => (ImportAction::KeepBoth, Some(Self::unique_id(&prompt.id))),
+                },
+            };
+
+            // An abbreviation another prompt already answers to is dropped rather than the prompt
+            if let (Some(target_id), Some(abbreviation)) = (&target_id, prompt.abbreviation.as_deref()) {
+                if index.abbreviation_taken(abbreviation, target_id) {
+                    log::warn!("[LibraryImportService] Dropping a clashing abbreviation from an imported prompt");
+                    prompt.abbreviation = None;
+                }
+            }
+
+            // Later files in the same batch are matched against earlier ones too
+            if let Some(target_id) = &target_id {
+                let mut planned = prompt.clone();
+                planned.id = target_id.clone();
+                index.insert(&planned);
+            }
+
+            report.items.push(ImportItem {
+                source_path,
+                name: prompt.name.clone(),
+                status,
+                existing_id,
+                action,
+                target_id,
+                prompt,
+            });
+        }
+
+        Ok(report)
+    }
+
+    /// "Coding/review.md" -> "Coding/review-1a2b3c4d.md"
+    fn unique_id(id: &str) -> String {
+        let path = Path::new(id);
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("prompt");
+        let uuid_suffix = &Uuid::new_v4().to_string()[..8];
+        let file_name = format!("{}-{}.md", stem, uuid_suffix);
+        match path.parent().and_then(|p| p.to_str()).filter(|p| !p.is_empty()) {
+            Some(dir) => format!("{}/{}", dir.replace('\\', "/"), file_name),
+            None => file_name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::domain::value_objects::PromptId;
+    use crate::storage::infrastructure::persistence::FilePromptRepository;
+    use tempfile::TempDir;
+
+    fn create_prompt(id: &str, name: &str, content: &str) -> Prompt {
+        Prompt {
+            name: name.to_string(),
+            content: content.to_string(),
+            created_at: "2024-05-01T00:00:00Z".to_string(),
+            updated_at: "2024-06-01T00:00:00Z".to_string(),
+            ..Prompt::for_test(id)
+        }
+    }
+
+    fn setup(existing: &[Prompt]) -> (TempDir, LibraryImportService<FilePromptRepository>) {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = FilePromptRepository::with_directory(temp_dir.path().to_path_buf()).unwrap();
+        for prompt in existing {
+            repository.save(prompt).unwrap();
+        }
+        (temp_dir, LibraryImportService::new(repository))
+    }
+
+    fn batch(prompts: Vec<Prompt>) -> ImportBatch {
+        ImportBatch {
+            prompts,
+            errors: vec![],
+        }
+    }
+
+    #[test]
+    fn test_plan_classifies_new_duplicate_and_conflict() {
+        let (_temp_dir, service) = setup(&[create_prompt("Coding/review.md", "Review", "Review this")]);
+
+        let report = service
+            .import(
+                batch(vec![
+                    create_prompt("Coding/new.md", "New", "Something new"),
+                    create_prompt("Other/copy.md", "Copy", "Review this"),
+                    create_prompt("Coding/review-v2.md", "review", "Review that"),
+                ]),
+                &HashMap::new(),
+                true,
+            )
+            .unwrap();
+
+        let statuses: Vec<ImportStatus> = report.items.iter().map(|i| i.status).collect();
+        assert_eq!(statuses, vec![ImportStatus::New, ImportStatus::Duplicate, ImportStatus::Conflict]);
+        assert_eq!(report.items[2].existing_id.as_deref(), Some("Coding/review.md"));
+        assert_eq!(report.count(ImportAction::Create), 1);
+        assert_eq!(report.count(ImportAction::Skip), 2);
+        assert!(service.repository.find_by_id(&PromptId::new("Coding/new.md")).is_err());
+    }
+
+    #[test]
+    fn test_import_preserves_folders_and_timestamps() {
+        let (_temp_dir, service) = setup(&[]);
+
+        service
+            .import(batch(vec![create_prompt("Writing/Blog/intro.md", "Intro", "Write an intro")]), &HashMap::new(), false)
+            .unwrap();
+
+        let prompt = service.repository.find_by_id(&PromptId::new("Writing/Blog/intro.md")).unwrap();
+        assert_eq!(prompt.folder, "Writing/Blog");
+        assert_eq!(prompt.created_at, "2024-05-01T00:00:00Z");
+        assert_eq!(prompt.updated_at, "2024-06-01T00:00:00Z");
+    }
+
+    #[test]
+    fn test_resolutions_overwrite_and_keep_both() {
+        let (_temp_dir, service) = setup(&[
+            create_prompt("Coding/review.md", "Review", "Old review"),
+            create_prompt("Coding/explain.md", "Explain", "Old explain"),
+        ]);
+        let resolutions = HashMap::from([
+            ("Coding/review.md".to_string(), ConflictResolution::Overwrite),
+            ("Coding/explain.md".to_string(), ConflictResolution::KeepBoth),
+        ]);
+
+        let report = service
+            .import(
+                batch(vec![
+                    create_prompt("Coding/review.md", "Review", "New review"),
+                    create_prompt("Coding/explain.md", "Explain", "New explain"),
+                ]),
+                &resolutions,
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(report.items[0].action, ImportAction::Overwrite);
+        assert_eq!(report.items[1].action, ImportAction::KeepBoth);
+
+        let overwritten = service.repository.find_by_id(&PromptId::new("Coding/review.md")).unwrap();
+        assert_eq!(overwritten.content, "New review");
+
+        let kept_id = report.items[1].target_id.clone().unwrap();
+        assert!(kept_id.starts_with("Coding/explain-"));
+        assert_eq!(service.repository.find_by_id(&PromptId::new(&kept_id)).unwrap().content, "New explain");
+        assert_eq!(service.repository.find_by_id(&PromptId::new("Coding/explain.md")).unwrap().content, "Old explain");
+    }
+
+    #[test]
+    fn test_invalid_prompts_reported_as_errors() {
+        let (_temp_dir, service) = setup(&[]);
+        let mut batch = batch(vec![create_prompt("empty.md", "Empty", "")]);
+        batch.errors.push(ParseFailure {
+            id: "broken.md".to_string(),
+            message: "Failed to parse YAML frontmatter".to_string(),
+            line: Some(2),
+        });
+
+        let report = service.import(batch, &HashMap::new(), false).unwrap();
+        assert!(report.items.is_empty());
+        let failed: Vec<&str> = report.errors.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(failed, vec!["broken.md", "empty.md"]);
+    }
+
+    #[test]
+    fn test_clashing_abbreviations_are_dropped() {
+        let existing = Prompt {
+            abbreviation: Some(";;review".to_string()),
+            ..create_prompt("review.md", "Review", "Old")
+        };
+        let (_temp_dir, service) = setup(&[existing]);
+
+        let short = Prompt { abbreviation: Some(";;r".to_string()), ..create_prompt("short.md", "Short", "Short") };
+        let fix = Prompt { abbreviation: Some(";;fix".to_string()), ..create_prompt("fix.md", "Fix", "Fix") };
+        let report = service.import(batch(vec![short, fix]), &HashMap::new(), false).unwrap();
+
+        assert_eq!(report.items[0].prompt.abbreviation, None);
+        assert_eq!(report.items[1].prompt.abbreviation.as_deref(), Some(";;fix"));
+        assert_eq!(service.repository.find_by_id(&PromptId::new("short.md")).unwrap().abbreviation, None);
+    }
+
+    #[test]
+    fn test_duplicates_within_batch() {
+        let (_temp_dir, service) = setup(&[]);
+
+        let report = service
+            .import(
+                batch(vec![
+                    create_prompt("a.md", "A", "Same content"),
+                    create_prompt("b.md", "B", "Same content"),
+                ]),
+                &HashMap::new(),
+                true,
+            )
+            .unwrap();
+
+        assert_eq!(report.items[1].status, ImportStatus::Duplicate);
+        assert_eq!(report.items[1].existing_id.as_deref(), Some("a.md"));
+    }
+}
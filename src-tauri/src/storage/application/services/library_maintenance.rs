@@ -0,0 +1,508 @@
+use crate::logging::redact;
+use crate::storage::application::services::FrecencyCalculator;
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::PromptRepository;
+use crate::storage::domain::value_objects::ContentHash;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Directory (under the prompts dir) holding version snapshots per prompt ID
+pub const VERSIONS_DIR_NAME: &str = ".versions";
+
+/// Result of a maintenance run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MaintenanceReport {
+    /// True if nothing was changed on disk
+    pub dry_run: bool,
+    /// True if the orphans below were deleted
+    pub pruned: bool,
+    /// Orphaned data moved to a renamed/moved prompt file with the same content
+    pub reassociated: Vec<Reassociation>,
+    /// Usage entries whose prompt no longer exists (deleted if `pruned`)
+    pub orphaned_usage: Vec<String>,
+    /// Version directories whose prompt no longer exists (deleted if `pruned`)
+    pub orphaned_versions: Vec<String>,
+}
+
+impl MaintenanceReport {
+    pub fn is_clean(&self) -> bool {
+        self.reassociated.is_empty() && self.orphaned_usage.is_empty() && self.orphaned_versions.is_empty()
+    }
+}
+
+/// Orphaned data matched to a prompt by content hash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reassociation {
+    pub old_id: String,
+    pub new_id: String,
+    /// Whether usage stats were moved
+    pub usage: bool,
+    /// Whether version history was moved
+    pub versions: bool,
+}
+
+/// Only the fields of a stored version that maintenance needs
+#[derive(Debug, Deserialize)]
+struct VersionSnapshot {
+    #[serde(default)]
+    content: String,
+}
+
+/// Application service that garbage-collects usage and version data
+/// left behind when prompt files are deleted or renamed outside the app
+pub struct LibraryMaintenanceService<R: PromptRepository> {
+    repository: R,
+    frecency: FrecencyCalculator,
+    prompts_dir: PathBuf,
+}
+
+impl<R: PromptRepository> LibraryMaintenanceService<R> {
+    pub fn new(repository: R, frecency: FrecencyCalculator, prompts_dir: PathBuf) -> Self {
+        Self {
+            repository,
+            frecency,
+            prompts_dir,
+        }
+    }
+
+    fn versions_dir(&self) -> PathBuf {
+        self.prompts_dir.join(VERSIONS_DIR_NAME)
+    }
+
+    /// Detect orphans, re-associate renamed files and prune the rest
+    /// With `dry_run` the report is computed but nothing is written
+    pub fn run(&mut self, dry_run: bool) -> Result<MaintenanceReport, StorageError> {
+        self.run_with(dry_run, !dry_run)
+    }
+
+    /// Re-associate renamed files but keep the remaining orphans
+    /// A prompt missing now may only be absent for a while (a sync still pulling it, an
+    /// unmounted shared folder), so deleting its history is left to an explicit run
+    pub fn reassociate(&mut self) -> Result<MaintenanceReport, StorageError> {
+        self.run_with(false, false)
+    }
+
+    fn run_with(&mut self, dry_run: bool, prune: bool) -> Result<MaintenanceReport, StorageError> {
+        let prompts = self.repository.find_all()?;
+        let live_ids: HashSet<&str> = prompts.iter().map(|p| p.id.as_str()).collect();
+        // A locked prompt's content is blank until unlocked, so it has no usable fingerprint
+        let live_hashes: HashMap<String, ContentHash> = prompts
+            .iter()
+            .filter(|p| !p.locked)
+            .map(|p| (p.id.clone(), ContentHash::of(&p.content)))
+            .collect();
+
+        let usage_ids: HashSet<String> = self.frecency.tracked_ids().into_iter().collect();
+        let version_ids = self.list_version_ids();
+
+        // Unparseable files still exist, so their data is not orphaned
+        let is_live = |id: &str| live_ids.contains(id) || self.prompts_dir.join(id).is_file();
+        let orphan_ids: BTreeSet<String> = usage_ids
+            .iter()
+            .chain(version_ids.iter())
+            .filter(|id| !is_live(id))
+            .cloned()
+            .collect();
+
+        // Rename candidates: live prompts nothing has been recorded for yet
+        let mut candidates: HashMap<&ContentHash, Vec<&String>> = HashMap::new();
+        for (id, hash) in &live_hashes {
+            if !usage_ids.contains(id) && !version_ids.contains(id) {
+                candidates.entry(hash).or_default().push(id);
+            }
+        }
+
+        let mut report = MaintenanceReport {
+            dry_run,
+            pruned: prune,
+            ..Default::default()
+        };
+        let mut claimed: HashSet<String> = HashSet::new();
+
+        for orphan in orphan_ids {
+            let has_usage = usage_ids.contains(&orphan);
+            let has_versions = version_ids.contains(&orphan);
+
+            let mut fingerprints: Vec<ContentHash> = self.read_version_hashes(&orphan);
+            if let Some(hash) = self.frecency.content_hash(&orphan) {
+                fingerprints.push(hash.clone());
+            }
+
+            let matches: BTreeSet<&String> = fingerprints
+                .iter()
+                .filter_map(|hash| candidates.get(hash))
+                .flatten()
+                .copied()
+                .filter(|id| !claimed.contains(*id))
+                .collect();
+
+            // Only an unambiguous match is safe to re-associate
+            if matches.len() == 1 {
+                let new_id = matches.into_iter().next().cloned().unwrap_or_default();
+                log::info!("[LibraryMaintenance] Re-associating {} -> {}", redact(&orphan), redact(&new_id));
+
+                if !dry_run {
+                    if has_usage {
+                        self.frecency.rename_usage(&orphan, &new_id);
+                    }
+                    if has_versions {
+                        self.move_versions(&orphan, &new_id)?;
+                    }
+                }
+
+                claimed.insert(new_id.clone());
+                report.reassociated.push(Reassociation {
+                    old_id: orphan,
+                    new_id,
+                    usage: has_usage,
+                    versions: has_versions,
+                });
+                continue;
+            }
+
+            if has_usage {
+                if prune {
+                    self.frecency.remove_usage(&orphan);
+                }
+                report.orphaned_usage.push(orphan.clone());
+            }
+            if has_versions {
+                if prune {
+                    self.remove_versions(&orphan)?;
+                }
+                report.orphaned_versions.push(orphan);
+            }
+        }
+
+        // Stamp fingerprints so later renames can be recognised
+        for id in self.frecency.tracked_ids() {
+            if let Some(hash) = live_hashes.get(&id) {
+                self.frecency.set_content_hash(&id, hash.clone());
+            }
+        }
+
+        if !dry_run {
+            self.frecency.save_to_disk()?;
+        }
+
+        log::info!(
+            "[LibraryMaintenance] {} re-associated, {} orphaned usage entries, {} orphaned version dirs (dry_run={}, pruned={})",
+            report.reassociated.len(),
+            report.orphaned_usage.len(),
+            report.orphaned_versions.len(),
+            dry_run,
+            prune
+        );
+
+        Ok(report)
+    }
+
+    /// Prompt IDs that have at least one stored version
+    fn list_version_ids(&self) -> HashSet<String> {
+        let versions_dir = self.versions_dir();
+        WalkDir::new(&versions_dir)
+            .min_depth(2)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file() && e.path().extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|e| {
+                e.path()
+                    .parent()
+                    .and_then(|dir| dir.strip_prefix(&versions_dir).ok())
+                    .and_then(|rel| rel.to_str())
+                    .map(|rel| rel.replace('\\', "/"))
+            })
+            .collect()
+    }
+
+    /// Content hashes of every stored version of a prompt
+    fn read_version_hashes(&self, id: &str) -> Vec<ContentHash> {
+        Self::version_files(&self.versions_dir().join(id))
+            .iter()
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .filter_map(|json| serde_json::from_str::<VersionSnapshot>(&json).ok())
+            .map(|snapshot| ContentHash::of(&snapshot.content))
+            .collect()
+    }
+
+    fn version_files(dir: &Path) -> Vec<PathBuf> {
+        fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .map(|e| e.path())
+                    .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Move a version directory to a new prompt ID and update `prompt_id` inside each version
+    fn move_versions(&self, old_id: &str, new_id: &str) -> Result<(), String> {
+        let versions_dir = self.versions_dir();
+        let source = versions_dir.join(old_id);
+        let target = versions_dir.join(new_id);
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create versions directory: {}", e))?;
+        }
+        fs::rename(&source, &target)
+            .map_err(|e| format!("Failed to move versions of {}: {}", old_id, e))?;
+
+        for path in Self::version_files(&target) {
+            let content = fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read version file: {}", e))?;
+            let mut version: serde_json::Value = serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse version: {}", e))?;
+            version["prompt_id"] = serde_json::Value::String(new_id.to_string());
+            let content = serde_json::to_string_pretty(&version)
+                .map_err(|e| format!("Failed to serialize version: {}", e))?;
+            fs::write(&path, content)
+                .map_err(|e| format!("Failed to write version: {}", e))?;
+        }
+
+        self.remove_empty_parents(&source);
+        Ok(())
+    }
+
+    fn remove_versions(&self, id: &str) -> Result<(), String> {
+        let dir = self.versions_dir().join(id);
+        fs::remove_dir_all(&dir)
+            .map_err(|e| format!("Failed to delete versions of {}: {}", id, e))?;
+        self.remove_empty_parents(&dir);
+        Ok(())
+    }
+
+    /// Remove folder directories under `.versions` left empty after a move or prune
+    fn remove_empty_parents(&self, removed: &Path) {
+        let versions_dir = self.versions_dir();
+        let mut current = removed.parent();
+        while let Some(dir) = current {
+            if dir == versions_dir || !dir.starts_with(&versions_dir) {
+                break;
+            }
+            // remove_dir fails on non-empty directories, which ends the walk
+            if fs::remove_dir(dir).is_err() {
+                break;
+            }
+            current = dir.parent();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::domain::entities::Prompt;
+    use crate::storage::domain::value_objects::PromptId;
+    use crate::storage::infrastructure::encryption::PassphraseVault;
+    use crate::storage::infrastructure::persistence::FilePromptRepository;
+    use tempfile::TempDir;
+
+    struct Fixture {
+        _temp_dir: TempDir,
+        prompts_dir: PathBuf,
+        usage_path: PathBuf,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let temp_dir = TempDir::new().unwrap();
+            let prompts_dir = temp_dir.path().join("prompts");
+            let usage_path = temp_dir.path().join("usage.json");
+            Self {
+                _temp_dir: temp_dir,
+                prompts_dir,
+                usage_path,
+            }
+        }
+
+        fn repository(&self) -> FilePromptRepository {
+            FilePromptRepository::with_directory(self.prompts_dir.clone()).unwrap()
+        }
+
+        fn frecency(&self) -> FrecencyCalculator {
+            FrecencyCalculator::with_storage_path(self.usage_path.clone()).unwrap()
+        }
+
+        fn service(&self) -> LibraryMaintenanceService<FilePromptRepository> {
+            LibraryMaintenanceService::new(self.repository(), self.frecency(), self.prompts_dir.clone())
+        }
+
+        fn write_version(&self, id: &str, content: &str) {
+            let dir = self.prompts_dir.join(VERSIONS_DIR_NAME).join(id);
+            fs::create_dir_all(&dir).unwrap();
+            let version = serde_json::json!({
+                "id": "v1",
+                "prompt_id": id,
+                "version_number": 1,
+                "content": content,
+                "name": "Name",
+                "description": "",
+                "change_summary": null,
+                "created_at": "2025-01-01T00:00:00Z",
+                "created_by": null
+            });
+            fs::write(dir.join("v1.json"), version.to_string()).unwrap();
+        }
+    }
+
+    fn create_test_prompt(id: &str, content: &str) -> Prompt {
+        Prompt {
+            name: "Name".to_string(),
+            content: content.to_string(),
+            folder: "General".to_string(),
+            ..Prompt::for_test(id)
+        }
+    }
+
+    #[test]
+    fn test_clean_library_reports_nothing() {
+        let fixture = Fixture::new();
+        fixture.repository().save(&create_test_prompt("General/a.md", "A")).unwrap();
+        fixture.frecency().record_usage(&PromptId::new("General/a.md"), None).unwrap();
+        fixture.write_version("General/a.md", "old A");
+
+        let report = fixture.service().run(false).unwrap();
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn test_dry_run_reports_orphans_without_pruning() {
+        let fixture = Fixture::new();
+        fixture.frecency().record_usage(&PromptId::new("General/deleted.md"), None).unwrap();
+        fixture.write_version("General/deleted.md", "gone");
+
+        let report = fixture.service().run(true).unwrap();
+
+        assert!(report.dry_run);
+        assert_eq!(report.orphaned_usage, vec!["General/deleted.md"]);
+        assert_eq!(report.orphaned_versions, vec!["General/deleted.md"]);
+        assert_eq!(fixture.frecency().tracked_ids(), vec!["General/deleted.md".to_string()]);
+        assert!(fixture.prompts_dir.join(".versions/General/deleted.md").exists());
+    }
+
+    #[test]
+    fn test_run_prunes_orphans() {
+        let fixture = Fixture::new();
+        fixture.frecency().record_usage(&PromptId::new("General/deleted.md"), None).unwrap();
+        fixture.write_version("General/deleted.md", "gone");
+
+        let report = fixture.service().run(false).unwrap();
+
+        assert_eq!(report.orphaned_usage.len(), 1);
+        assert!(fixture.frecency().tracked_ids().is_empty());
+        assert!(!fixture.prompts_dir.join(".versions/General").exists());
+    }
+
+    #[test]
+    fn test_reassociate_keeps_orphans() {
+        let fixture = Fixture::new();
+        fixture.repository().save(&create_test_prompt("Work/renamed.md", "Same text")).unwrap();
+        fixture.write_version("General/original.md", "Same text");
+        fixture.frecency().record_usage(&PromptId::new("General/not-synced-yet.md"), None).unwrap();
+        fixture.write_version("General/not-synced-yet.md", "elsewhere");
+
+        let report = fixture.service().reassociate().unwrap();
+
+        assert!(!report.dry_run && !report.pruned);
+        assert_eq!(report.reassociated[0].new_id, "Work/renamed.md");
+        assert!(fixture.prompts_dir.join(".versions/Work/renamed.md/v1.json").exists());
+        assert_eq!(report.orphaned_usage, vec!["General/not-synced-yet.md"]);
+        assert_eq!(fixture.frecency().tracked_ids(), vec!["General/not-synced-yet.md".to_string()]);
+        assert!(fixture.prompts_dir.join(".versions/General/not-synced-yet.md").exists());
+    }
+
+    #[test]
+    fn test_renamed_file_reassociated_by_version_content() {
+        let fixture = Fixture::new();
+        fixture.repository().save(&create_test_prompt("Work/renamed.md", "Same text")).unwrap();
+        fixture.frecency().record_usage(&PromptId::new("General/original.md"), None).unwrap();
+        fixture.write_version("General/original.md", "Same text");
+
+        let report = fixture.service().run(false).unwrap();
+
+        assert_eq!(report.reassociated.len(), 1);
+        assert_eq!(report.reassociated[0].new_id, "Work/renamed.md");
+        assert!(report.reassociated[0].usage && report.reassociated[0].versions);
+        assert_eq!(fixture.frecency().tracked_ids(), vec!["Work/renamed.md".to_string()]);
+
+        let moved = fs::read_to_string(fixture.prompts_dir.join(".versions/Work/renamed.md/v1.json")).unwrap();
+        assert!(moved.contains("\"prompt_id\": \"Work/renamed.md\""));
+    }
+
+    #[test]
+    fn test_renamed_file_reassociated_by_stamped_usage_hash() {
+        let fixture = Fixture::new();
+        let repository = fixture.repository();
+        repository.save(&create_test_prompt("General/original.md", "Stamped")).unwrap();
+        fixture.frecency().record_usage(&PromptId::new("General/original.md"), None).unwrap();
+
+        // First run stamps the fingerprint
+        assert!(fixture.service().run(false).unwrap().is_clean());
+
+        // File renamed outside the app
+        fs::rename(
+            fixture.prompts_dir.join("General/original.md"),
+            fixture.prompts_dir.join("General/renamed.md"),
+        )
+        .unwrap();
+
+        let report = fixture.service().run(false).unwrap();
+        assert_eq!(report.reassociated.len(), 1);
+        assert_eq!(report.reassociated[0].old_id, "General/original.md");
+        assert_eq!(report.reassociated[0].new_id, "General/renamed.md");
+        assert_eq!(fixture.frecency().tracked_ids(), vec!["General/renamed.md".to_string()]);
+    }
+
+    #[test]
+    fn test_ambiguous_match_is_treated_as_orphan() {
+        let fixture = Fixture::new();
+        let repository = fixture.repository();
+        repository.save(&create_test_prompt("General/copy-1.md", "Duplicate")).unwrap();
+        repository.save(&create_test_prompt("General/copy-2.md", "Duplicate")).unwrap();
+        fixture.write_version("General/original.md", "Duplicate");
+
+        let report = fixture.service().run(true).unwrap();
+
+        assert!(report.reassociated.is_empty());
+        assert_eq!(report.orphaned_versions, vec!["General/original.md"]);
+    }
+
+    #[test]
+    fn test_unparseable_prompt_file_is_not_an_orphan() {
+        let fixture = Fixture::new();
+        fs::create_dir_all(fixture.prompts_dir.join("General")).unwrap();
+        fs::write(fixture.prompts_dir.join("General/broken.md"), "no frontmatter").unwrap();
+        fixture.frecency().record_usage(&PromptId::new("General/broken.md"), None).unwrap();
+
+        let report = fixture.service().run(false).unwrap();
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn test_locked_prompts_are_live_but_not_fingerprinted() {
+        let fixture = Fixture::new();
+        let vault = PassphraseVault::with_path(fixture.prompts_dir.with_file_name("vault.json"));
+        vault.setup("correct horse").unwrap();
+        vault.unlock("correct horse", None).unwrap();
+        let repository = fixture.repository().with_vault(vault);
+        repository.save(&Prompt { private: true, ..create_test_prompt("General/private.md", "Secret") }).unwrap();
+        repository.save(&Prompt { private: true, ..create_test_prompt("General/unused.md", "Other") }).unwrap();
+        fixture.frecency().record_usage(&PromptId::new("General/private.md"), None).unwrap();
+        // An orphan whose last known content was blank must not be matched to the locked prompt
+        fixture.write_version("General/deleted.md", "");
+
+        // Without the vault the private prompt loads locked
+        let report = fixture.service().run(false).unwrap();
+
+        assert!(report.reassociated.is_empty());
+        assert!(report.orphaned_usage.is_empty());
+        assert_eq!(report.orphaned_versions, vec!["General/deleted.md"]);
+        assert!(fixture.frecency().content_hash("General/private.md").is_none());
+    }
+}
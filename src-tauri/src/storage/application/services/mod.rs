@@ -1,4 +1,14 @@
 // Application services
 pub mod frecency_calculator;
+pub mod library_export;
+pub mod library_health;
+pub mod library_import;
+pub mod library_maintenance;
+pub mod secret_service;
 
 pub use frecency_calculator::FrecencyCalculator;
+pub use library_export::LibraryExportService;
+pub use library_health::{HealthIssue, HealthIssueKind, HealthReport, LibraryHealthService};
+pub use library_import::LibraryImportService;
+pub use library_maintenance::{LibraryMaintenanceService, MaintenanceReport, VERSIONS_DIR_NAME};
+pub use secret_service::SecretService;
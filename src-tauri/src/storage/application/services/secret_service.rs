@@ -0,0 +1,116 @@
+use crate::storage::domain::entities::{content_segments, validate_secret_name, ContentSegment, SecretInfo};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::SecretStore;
+
+/// Application service for `{{secret:name}}` references
+/// Values are only read to fill text at paste time; they are never returned to the UI
+pub struct SecretService<S: SecretStore> {
+    store: S,
+}
+
+impl<S: SecretStore> SecretService<S> {
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+
+    pub fn list(&self) -> Result<Vec<SecretInfo>, StorageError> {
+        self.store.list()
+    }
+
+    pub fn set(&self, name: &str, value: &str) -> Result<(), StorageError> {
+        validate_secret_name(name).map_err(StorageError::InvalidInput)?;
+        if value.is_empty() {
+            return Err(StorageError::InvalidInput("Secret value cannot be empty".to_string()));
+        }
+        self.store.set(name, value)
+    }
+
+    pub fn delete(&self, name: &str) -> Result<(), StorageError> {
+        if !self.store.delete(name)? {
+            return Err(StorageError::SecretNotFound(name.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Number of `{{secret:name}}` references in text
+    pub fn count_references(&self, text: &str) -> usize {
+        content_segments(text)
+            .iter()
+            .filter(|segment| matches!(segment, ContentSegment::Secret(_)))
+            .count()
+    }
+
+    /// Replace every `{{secret:name}}` in rendered text with its value
+    /// Fails on the first unknown secret so a prompt is never pasted half-filled
+    pub fn resolve(&self, text: &str) -> Result<String, StorageError> {
+        if self.count_references(text) == 0 {
+            return Ok(text.to_string());
+        }
+
+        let mut resolved = String::with_capacity(text.len());
+        for segment in content_segments(text) {
+            match segment {
+                ContentSegment::Text(text) => resolved.push_str(text),
+                // Variables the user left unfilled stay as written
+                ContentSegment::Placeholder(name) => resolved.push_str(&format!("{{{{{}}}}}", name)),
+                ContentSegment::Secret(name) => {
+                    let value = self
+                        .store
+                        .get(name)?
+                        .ok_or_else(|| StorageError::SecretNotFound(name.to_string()))?;
+                    resolved.push_str(&value);
+                }
+            }
+        }
+        Ok(resolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::infrastructure::encryption::{EncryptedSecretStore, PassphraseVault};
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, PassphraseVault, SecretService<EncryptedSecretStore>) {
+        let temp_dir = TempDir::new().unwrap();
+        let vault = PassphraseVault::with_path(temp_dir.path().join("vault.json"));
+        vault.setup("correct horse").unwrap();
+        vault.unlock("correct horse", None).unwrap();
+        let store = EncryptedSecretStore::with_path(temp_dir.path().join("secrets.json"), vault.clone());
+        (temp_dir, vault, SecretService::new(store))
+    }
+
+    #[test]
+    fn test_resolve_fills_secrets_and_keeps_other_text() {
+        let (_temp_dir, _vault, service) = setup();
+        service.set("api_key", "sk-123").unwrap();
+        service.set("signature", "Jane").unwrap();
+
+        let text = "Use {{secret:api_key}} for {{ spaced }} and {{unfilled}}.\n-- {{secret:signature}}";
+        assert_eq!(service.count_references(text), 2);
+        assert_eq!(
+            service.resolve(text).unwrap(),
+            "Use sk-123 for {{ spaced }} and {{unfilled}}.\n-- Jane"
+        );
+        assert_eq!(service.resolve("No secrets here").unwrap(), "No secrets here");
+    }
+
+    #[test]
+    fn test_resolve_fails_for_unknown_secret_or_locked_vault() {
+        let (_temp_dir, vault, service) = setup();
+        service.set("api_key", "sk-123").unwrap();
+
+        assert_eq!(service.resolve("{{secret:other}}").unwrap_err().to_string(), "Secret not found: other");
+        vault.lock();
+        assert_eq!(service.resolve("{{secret:api_key}}").unwrap_err().code(), "vault_locked");
+    }
+
+    #[test]
+    fn test_set_and_delete_validate_input() {
+        let (_temp_dir, _vault, service) = setup();
+        assert!(service.set("bad name", "x").is_err());
+        assert!(service.set("empty", "").is_err());
+        assert_eq!(service.delete("missing").unwrap_err().code(), "not_found");
+    }
+}
@@ -1,8 +1,8 @@
-use crate::storage::domain::value_objects::PromptId;
+use crate::storage::domain::value_objects::{PromptId, TargetApp};
 use crate::storage::application::services::FrecencyCalculator;
 
 /// Record prompt usage use case
-/// Tracks usage for frecency scoring
+/// Tracks usage for frecency scoring, per target app when known
 pub struct RecordUsageUseCase {
     frecency: FrecencyCalculator,
 }
@@ -12,8 +12,8 @@ impl RecordUsageUseCase {
         Self { frecency }
     }
 
-    pub fn execute(&mut self, id: &PromptId) -> Result<(), String> {
-        self.frecency.record_usage(id)
+    pub fn execute(&mut self, id: &PromptId, target_app: Option<&TargetApp>) -> Result<(), String> {
+        self.frecency.record_usage(id, target_app)
     }
 }
 
@@ -27,6 +27,15 @@ mod tests {
         let mut use_case = RecordUsageUseCase::new(frecency);
         let id = PromptId::new("test-prompt");
 
-        assert!(use_case.execute(&id).is_ok());
+        assert!(use_case.execute(&id, None).is_ok());
+    }
+
+    #[test]
+    fn test_record_usage_with_target_app_succeeds() {
+        let frecency = FrecencyCalculator::default();
+        let mut use_case = RecordUsageUseCase::new(frecency);
+        let id = PromptId::new("test-prompt");
+
+        assert!(use_case.execute(&id, Some(&TargetApp::new("code"))).is_ok());
     }
 }
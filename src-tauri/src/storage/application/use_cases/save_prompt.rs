@@ -1,4 +1,5 @@
 use crate::storage::domain::entities::Prompt;
+use crate::storage::domain::errors::StorageError;
 use crate::storage::domain::ports::PromptRepository;
 
 /// Save prompt use case
@@ -12,9 +13,9 @@ impl<R: PromptRepository> SavePromptUseCase<R> {
         Self { repository }
     }
 
-    pub fn execute(&self, prompt: &Prompt) -> Result<(), String> {
+    pub fn execute(&self, prompt: &Prompt) -> Result<(), StorageError> {
         // Validate business rules
-        prompt.validate()?;
+        prompt.validate().map_err(StorageError::Validation)?;
 
         // Delegate to repository
         self.repository.save(prompt)
@@ -29,37 +30,29 @@ mod tests {
     struct MockRepository;
 
     impl PromptRepository for MockRepository {
-        fn find_all(&self) -> Result<Vec<Prompt>, String> {
+        fn find_all(&self) -> Result<Vec<Prompt>, StorageError> {
             unimplemented!()
         }
 
-        fn find_by_id(&self, _id: &PromptId) -> Result<Prompt, String> {
+        fn find_by_id(&self, _id: &PromptId) -> Result<Prompt, StorageError> {
             unimplemented!()
         }
 
-        fn save(&self, _prompt: &Prompt) -> Result<(), String> {
+        fn save(&self, _prompt: &Prompt) -> Result<(), StorageError> {
             Ok(())
         }
 
-        fn delete(&self, _id: &PromptId) -> Result<(), String> {
+        fn delete(&self, _id: &PromptId) -> Result<(), StorageError> {
             unimplemented!()
         }
     }
 
     fn create_valid_prompt() -> Prompt {
         Prompt {
-            id: "test-prompt".to_string(),
-            name: "Test Prompt".to_string(),
             description: "Test".to_string(),
-            content: "Test content".to_string(),
             folder: "test".to_string(),
-            icon: "📝".to_string(),
             color: "#000000".to_string(),
-            tags: vec![],
-            variables: vec![],
-            auto_paste: false,
-            created_at: "2025-01-01T00:00:00Z".to_string(),
-            updated_at: "2025-01-01T00:00:00Z".to_string(),
+            ..Prompt::for_test("test-prompt")
         }
     }
 
@@ -1,5 +1,7 @@
+use crate::storage::domain::errors::StorageError;
 use crate::storage::domain::ports::{PromptRepository, SearchResult, SearchService};
 use crate::storage::application::services::FrecencyCalculator;
+use crate::storage::domain::value_objects::TargetApp;
 
 /// Search prompts use case
 /// Orchestrates search with frecency ranking
@@ -18,13 +20,14 @@ impl<R: PromptRepository, S: SearchService> SearchPromptsUseCase<R, S> {
         }
     }
 
-    pub fn execute(&self, query: &str) -> Result<Vec<SearchResult>, String> {
+    /// `target_app` is the app the launcher was opened from, used to rank the empty-query list
+    pub fn execute(&self, query: &str, target_app: Option<&TargetApp>) -> Result<Vec<SearchResult>, StorageError> {
         // Get all prompts
         let mut prompts = self.repository.find_all()?;
 
-        // If empty query, return all prompts sorted by frecency
+        // If empty query, return all prompts sorted by frecency (pinned and app-affine first)
         if query.trim().is_empty() {
-            self.frecency.sort_by_frecency(&mut prompts);
+            self.frecency.sort_by_frecency_for_app(&mut prompts, target_app);
             return Ok(prompts
                 .into_iter()
                 .map(|p| SearchResult {
@@ -44,6 +47,7 @@ impl<R: PromptRepository, S: SearchService> SearchPromptsUseCase<R, S> {
 mod tests {
     use super::*;
     use crate::storage::domain::entities::Prompt;
+    use crate::storage::domain::errors::StorageError;
     use crate::storage::domain::ports::{MatchRange, PromptRepository, SearchService};
     use crate::storage::domain::value_objects::PromptId;
 
@@ -52,19 +56,19 @@ mod tests {
     }
 
     impl PromptRepository for MockRepository {
-        fn find_all(&self) -> Result<Vec<Prompt>, String> {
+        fn find_all(&self) -> Result<Vec<Prompt>, StorageError> {
             Ok(self.prompts.clone())
         }
 
-        fn find_by_id(&self, _id: &PromptId) -> Result<Prompt, String> {
+        fn find_by_id(&self, _id: &PromptId) -> Result<Prompt, StorageError> {
             unimplemented!()
         }
 
-        fn save(&self, _prompt: &Prompt) -> Result<(), String> {
+        fn save(&self, _prompt: &Prompt) -> Result<(), StorageError> {
             unimplemented!()
         }
 
-        fn delete(&self, _id: &PromptId) -> Result<(), String> {
+        fn delete(&self, _id: &PromptId) -> Result<(), StorageError> {
             unimplemented!()
         }
     }
@@ -86,18 +90,11 @@ mod tests {
 
     fn create_test_prompt(id: &str, name: &str) -> Prompt {
         Prompt {
-            id: id.to_string(),
             name: name.to_string(),
             description: "Test".to_string(),
-            content: "Test content".to_string(),
             folder: "test".to_string(),
-            icon: "📝".to_string(),
             color: "#000000".to_string(),
-            tags: vec![],
-            variables: vec![],
-            auto_paste: false,
-            created_at: "2025-01-01T00:00:00Z".to_string(),
-            updated_at: "2025-01-01T00:00:00Z".to_string(),
+            ..Prompt::for_test(id)
         }
     }
 
@@ -113,10 +110,25 @@ mod tests {
         let frecency = FrecencyCalculator::default();
         let use_case = SearchPromptsUseCase::new(repository, search_service, frecency);
 
-        let results = use_case.execute("").unwrap();
+        let results = use_case.execute("", None).unwrap();
         assert_eq!(results.len(), 2);
     }
 
+    #[test]
+    fn test_empty_query_lists_prompts_pinned_to_target_app_first() {
+        let mut pinned = create_test_prompt("pinned", "Pinned");
+        pinned.apps = vec!["code".to_string()];
+        let repository = MockRepository {
+            prompts: vec![create_test_prompt("other", "Other"), pinned],
+        };
+        let search_service = MockSearchService;
+        let frecency = FrecencyCalculator::default();
+        let use_case = SearchPromptsUseCase::new(repository, search_service, frecency);
+
+        let results = use_case.execute("", Some(&TargetApp::new("Code.exe"))).unwrap();
+        assert_eq!(results[0].prompt.id, "pinned");
+    }
+
     #[test]
     fn test_query_uses_search_service() {
         let repository = MockRepository {
@@ -126,7 +138,7 @@ mod tests {
         let frecency = FrecencyCalculator::default();
         let use_case = SearchPromptsUseCase::new(repository, search_service, frecency);
 
-        let results = use_case.execute("test query").unwrap();
+        let results = use_case.execute("test query", None).unwrap();
         assert_eq!(results.len(), 1);
     }
 }
@@ -0,0 +1,165 @@
+use crate::storage::domain::entities::Prompt;
+use serde::{Deserialize, Serialize};
+
+/// Version of the JSON/YAML bundle layout; bump on incompatible changes
+pub const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Which prompts to export
+/// A prompt is selected if it matches any criterion; an empty selection exports everything
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportSelection {
+    /// Folders, including their subfolders (e.g. "Coding" also selects "Coding/Rust")
+    pub folders: Vec<String>,
+    pub tags: Vec<String>,
+    pub favorites: bool,
+    pub ids: Vec<String>,
+}
+
+impl ExportSelection {
+    pub fn is_empty(&self) -> bool {
+        self.folders.is_empty() && self.tags.is_empty() && !self.favorites && self.ids.is_empty()
+    }
+
+    /// Business rule: folder and tag matches ignore case, like folder filtering in the UI
+    pub fn matches(&self, prompt: &Prompt) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+
+        let directory = prompt.directory().to_lowercase();
+        let in_folder = self.folders.iter().any(|folder| {
+            let folder = folder.trim_matches('/').to_lowercase();
+            directory == folder || directory.starts_with(&format!("{}/", folder))
+        });
+        let has_tag = self
+            .tags
+            .iter()
+            .any(|tag| prompt.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)));
+
+        in_folder || has_tag || (self.favorites && prompt.is_favorite) || self.ids.contains(&prompt.id)
+    }
+}
+
+/// Output layout of an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// Zip of native .md files (folders preserved), like the prompts directory
+    Zip,
+    /// Single JSON bundle with schema version
+    Json,
+    /// Single YAML bundle with schema version
+    Yaml,
+    /// VS Code `.code-snippets` file
+    #[serde(rename = "vscode")]
+    VsCode,
+    /// JetBrains live templates XML
+    #[serde(rename = "jetbrains")]
+    JetBrains,
+    /// Espanso match YAML
+    Espanso,
+}
+
+impl ExportFormat {
+    /// Snippet formats only carry prompt text and variables
+    pub fn is_snippet_format(&self) -> bool {
+        matches!(self, Self::VsCode | Self::JetBrains | Self::Espanso)
+    }
+}
+
+/// A prompt feature lost when exporting to a snippet format
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportWarning {
+    pub prompt_id: String,
+    pub message: String,
+}
+
+/// Usage stats carried in an export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedUsage {
+    pub use_count: u32,
+    pub last_used: String,
+}
+
+/// A prompt with its optional history and stats
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedPrompt {
+    #[serde(flatten)]
+    pub prompt: Prompt,
+    /// Stored version snapshots, kept verbatim
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub versions: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<ExportedUsage>,
+}
+
+/// Everything an export contains, independent of the output format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportBundle {
+    pub schema_version: u32,
+    pub exported_at: String,
+    pub prompts: Vec<ExportedPrompt>,
+}
+
+/// Result of writing an export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportSummary {
+    pub path: String,
+    pub format: ExportFormat,
+    pub prompts: usize,
+    pub versions: usize,
+    pub size_bytes: u64,
+    /// Features that could not be represented in the chosen format
+    #[serde(default)]
+    pub warnings: Vec<ExportWarning>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_prompt(id: &str, tags: &[&str], is_favorite: bool) -> Prompt {
+        Prompt {
+            name: "Test".to_string(),
+            content: "Content".to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            is_favorite,
+            created_at: String::new(),
+            updated_at: String::new(),
+            ..Prompt::for_test(id)
+        }
+    }
+
+    #[test]
+    fn test_empty_selection_matches_everything() {
+        let selection = ExportSelection::default();
+        assert!(selection.matches(&create_prompt("Coding/a.md", &[], false)));
+    }
+
+    #[test]
+    fn test_folder_selection_includes_subfolders() {
+        let selection = ExportSelection {
+            folders: vec!["coding".to_string()],
+            ..Default::default()
+        };
+        assert!(selection.matches(&create_prompt("Coding/a.md", &[], false)));
+        assert!(selection.matches(&create_prompt("Coding/Rust/b.md", &[], false)));
+        assert!(!selection.matches(&create_prompt("CodingTips/c.md", &[], false)));
+        assert!(!selection.matches(&create_prompt("d.md", &[], false)));
+    }
+
+    #[test]
+    fn test_criteria_are_combined_with_or() {
+        let selection = ExportSelection {
+            tags: vec!["Review".to_string()],
+            favorites: true,
+            ids: vec!["Other/x.md".to_string()],
+            ..Default::default()
+        };
+        assert!(selection.matches(&create_prompt("a.md", &["review"], false)));
+        assert!(selection.matches(&create_prompt("b.md", &[], true)));
+        assert!(selection.matches(&create_prompt("Other/x.md", &[], false)));
+        assert!(!selection.matches(&create_prompt("c.md", &["draft"], false)));
+    }
+}
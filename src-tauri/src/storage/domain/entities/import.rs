@@ -0,0 +1,100 @@
+use crate::storage::domain::entities::{ParseFailure, Prompt};
+use serde::{Deserialize, Serialize};
+
+/// Prompts read from an import source, before they are matched against the library
+/// Each prompt's `id` is its intended path in the library (e.g. "Coding/review.md")
+#[derive(Debug, Clone, Default)]
+pub struct ImportBatch {
+    pub prompts: Vec<Prompt>,
+    /// Files in the source that could not be read or parsed
+    pub errors: Vec<ParseFailure>,
+}
+
+/// Format of an import source
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportFormat {
+    /// Prompter's own .md files with YAML frontmatter
+    #[default]
+    Prompter,
+    /// Espanso match YAML files
+    Espanso,
+    /// VS Code `.code-snippets` / language snippet JSON
+    #[serde(rename = "vscode")]
+    VsCode,
+    /// Raycast snippets JSON export
+    Raycast,
+    /// TextExpander CSV export
+    #[serde(rename = "textexpander")]
+    TextExpander,
+    /// Plain .txt files
+    Text,
+}
+
+/// How an imported prompt relates to the existing library
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportStatus {
+    /// Nothing similar in the library
+    New,
+    /// Same content already exists in the library
+    Duplicate,
+    /// Same path, or same name in the same folder, with different content
+    Conflict,
+}
+
+/// What to do with a duplicate or conflicting prompt
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictResolution {
+    /// Leave the library untouched (default)
+    #[default]
+    Skip,
+    /// Replace the existing prompt
+    Overwrite,
+    /// Import under a new ID next to the existing prompt
+    KeepBoth,
+}
+
+/// What the import does (or did) with one prompt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportAction {
+    Create,
+    Skip,
+    Overwrite,
+    KeepBoth,
+}
+
+/// One prompt of an import plan
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportItem {
+    /// Path of the file in the import source
+    pub source_path: String,
+    pub name: String,
+    pub status: ImportStatus,
+    /// Library prompt this one duplicates or conflicts with
+    pub existing_id: Option<String>,
+    pub action: ImportAction,
+    /// ID the prompt is (or would be) written to; None when skipped
+    pub target_id: Option<String>,
+    #[serde(skip)]
+    pub prompt: Prompt,
+}
+
+/// Plan (dry run) or result of an import
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportReport {
+    /// True if nothing was written
+    pub dry_run: bool,
+    pub items: Vec<ImportItem>,
+    /// Per-file errors (unparseable source files, failed writes)
+    pub errors: Vec<ParseFailure>,
+}
+
+impl ImportReport {
+    /// Number of items with the given action
+    pub fn count(&self, action: ImportAction) -> usize {
+        self.items.iter().filter(|item| item.action == action).count()
+    }
+}
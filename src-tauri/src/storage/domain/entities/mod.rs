@@ -1,5 +1,20 @@
 // Domain entities
+pub mod export;
+pub mod import;
+pub mod parse_failure;
 pub mod prompt;
+pub mod secret;
+pub mod vault;
 
-pub use prompt::Prompt;
+pub use export::{
+    ExportBundle, ExportFormat, ExportSelection, ExportSummary, ExportWarning, ExportedPrompt, ExportedUsage,
+    EXPORT_SCHEMA_VERSION,
+};
+pub use import::{
+    ConflictResolution, ImportAction, ImportBatch, ImportFormat, ImportItem, ImportReport, ImportStatus,
+};
+pub use parse_failure::ParseFailure;
+pub use prompt::{content_segments, ContentSegment, Prompt, SECRET_PREFIX};
 pub use prompt::Variable;
+pub use secret::{validate_secret_name, SecretInfo};
+pub use vault::VaultStatus;
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// A prompt file that exists on disk but could not be loaded
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParseFailure {
+    /// Relative path of the file (same format as Prompt::id)
+    pub id: String,
+    pub message: String,
+    /// 1-based line in the file where parsing failed, if known
+    pub line: Option<usize>,
+}
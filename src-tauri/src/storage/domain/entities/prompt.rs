@@ -1,3 +1,4 @@
+use crate::storage::domain::value_objects::{MacroStep, PasteFormat, PasteMode, TargetApp};
 use serde::{Deserialize, Serialize};
 
 /// Prompt entity with business rules
@@ -28,6 +29,31 @@ pub struct Prompt {
     pub created_at: String,
     #[serde(default)]
     pub updated_at: String,
+    /// Applications this prompt is pinned to (e.g. ["code", "slack.exe"])
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub apps: Vec<String>,
+    /// Description, content and variables are stored encrypted
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub private: bool,
+    /// Private prompt loaded while the vault is locked: description, content and variables are empty
+    /// Set by the repository, never written to the prompt file
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub locked: bool,
+    /// Global hotkey that pastes this prompt without opening the launcher (e.g. "Ctrl+Alt+1")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hotkey: Option<String>,
+    /// Text that expands into this prompt when typed in any app (e.g. ";;review")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abbreviation: Option<String>,
+    /// Overrides the per-app paste mode (e.g. always type into a remote desktop)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paste_mode: Option<PasteMode>,
+    /// Overrides the per-app choice between plain and rich text (HTML/RTF)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paste_format: Option<PasteFormat>,
+    /// Macro run instead of a single paste (paste content, press keys, wait, paste segments)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub steps: Vec<MacroStep>,
 }
 
 fn default_icon() -> String {
@@ -35,9 +61,21 @@ fn default_icon() -> String {
 }
 
 fn default_color() -> String {
-    "#6B7280".to_string()
+    Prompt::DEFAULT_COLOR.to_string()
 }
 
+/// Piece of prompt content: literal text, a `{{name}}` placeholder or a `{{secret:name}}` reference
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentSegment<'a> {
+    Text(&'a str),
+    Placeholder(&'a str),
+    /// Name of a stored secret, filled in only when the prompt is pasted
+    Secret(&'a str),
+}
+
+/// Prefix that turns a placeholder into a secret reference
+pub const SECRET_PREFIX: &str = "secret:";
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Variable {
     pub name: String,
@@ -46,17 +84,136 @@ pub struct Variable {
 }
 
 impl Prompt {
+    pub const DEFAULT_COLOR: &'static str = "#6B7280";
+    /// Shorter abbreviations would fire while typing ordinary words
+    pub const MIN_ABBREVIATION_LEN: usize = 2;
+
     /// Business rule: Validate prompt name is not empty
     pub fn validate(&self) -> Result<(), String> {
         if self.name.trim().is_empty() {
             return Err("Prompt name cannot be empty".to_string());
         }
-        if self.content.trim().is_empty() {
+        // A locked prompt's content is unknown until unlocked, not missing
+        if !self.locked && self.content.trim().is_empty() {
             return Err("Prompt content cannot be empty".to_string());
         }
+        if let Some(abbreviation) = &self.abbreviation {
+            if !Self::is_valid_abbreviation(abbreviation) {
+                return Err(format!(
+                    "Abbreviation must be at least {} characters without spaces",
+                    Self::MIN_ABBREVIATION_LEN
+                ));
+            }
+        }
+        for (i, step) in self.steps.iter().enumerate() {
+            step.validate().map_err(|e| format!("Step {}: {}", i + 1, e))?;
+        }
         Ok(())
     }
 
+    /// Business rule: an abbreviation is at least MIN_ABBREVIATION_LEN characters with no
+    /// whitespace, since it is matched against typed keystrokes
+    pub fn is_valid_abbreviation(abbreviation: &str) -> bool {
+        abbreviation.chars().count() >= Self::MIN_ABBREVIATION_LEN
+            && !abbreviation.chars().any(|c| c.is_whitespace() || c.is_control())
+    }
+
+    /// Business rule: two abbreviations clash when one contains the other, because the
+    /// shorter one expands while the longer one is still being typed
+    pub fn abbreviations_clash(a: &str, b: &str) -> bool {
+        a.contains(b) || b.contains(a)
+    }
+
+    /// Business rule: A prompt is pinned to an app if any `apps` entry names it
+    pub fn is_pinned_to(&self, app: &TargetApp) -> bool {
+        self.apps.iter().any(|a| TargetApp::new(a) == *app)
+    }
+
+    /// Business rule: The folder a prompt belongs to is its file's directory
+    /// relative to the prompts dir ("" for prompts at the root)
+    pub fn directory(&self) -> String {
+        std::path::Path::new(&self.id)
+            .parent()
+            .and_then(|p| p.to_str())
+            .unwrap_or_default()
+            .replace('\\', "/")
+    }
+
+    /// Business rule: Colors are hex codes (#RGB, #RRGGBB or #RRGGBBAA)
+    pub fn has_valid_color(&self) -> bool {
+        self.color
+            .strip_prefix('#')
+            .is_some_and(|hex| matches!(hex.len(), 3 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit()))
+    }
+
+    /// Business rule: `{{name}}` placeholders in content, in order of first appearance
+    pub fn placeholders(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for segment in self.content_segments() {
+            if let ContentSegment::Placeholder(name) = segment {
+                if !names.iter().any(|n| n == name) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names
+    }
+
+    /// Business rule: `{{secret:name}}` references in content, in order of first appearance
+    /// Secrets are not variables: they are never asked for and never declared
+    pub fn secret_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for segment in self.content_segments() {
+            if let ContentSegment::Secret(name) = segment {
+                if !names.iter().any(|n| n == name) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names
+    }
+
+    /// Business rule: Content with declared variables filled in from their defaults
+    /// Undeclared placeholders and secret references are left as written;
+    /// fails if a required variable has no default (it needs the launcher's form)
+    pub fn render_with_defaults(&self) -> Result<String, String> {
+        self.render_text_with_defaults(&self.content)
+    }
+
+    /// Same as `render_with_defaults`, for other text using this prompt's variables
+    /// (e.g. a macro's paste segments)
+    pub fn render_text_with_defaults(&self, text: &str) -> Result<String, String> {
+        let mut rendered = String::with_capacity(text.len());
+        for segment in content_segments(text) {
+            match segment {
+                ContentSegment::Text(text) => rendered.push_str(text),
+                ContentSegment::Placeholder(name) => match self.variables.iter().find(|v| v.name == name) {
+                    Some(v) if v.required && v.default.is_empty() => {
+                        return Err(format!("Variable '{}' has no default; use the launcher to fill it in", name));
+                    }
+                    Some(v) => rendered.push_str(&v.default),
+                    None => {
+                        rendered.push_str("{{");
+                        rendered.push_str(name);
+                        rendered.push_str("}}");
+                    }
+                },
+                ContentSegment::Secret(name) => {
+                    rendered.push_str("{{");
+                    rendered.push_str(SECRET_PREFIX);
+                    rendered.push_str(name);
+                    rendered.push_str("}}");
+                }
+            }
+        }
+        Ok(rendered)
+    }
+
+    /// Split content into literal text, placeholders and secret references
+    pub fn content_segments(&self) -> Vec<ContentSegment<'_>> {
+        content_segments(&self.content)
+    }
+
     /// Business rule: Extract folder from ID (file path)
     /// Reserved for future folder-based filtering feature
     #[allow(dead_code)]
@@ -69,25 +226,79 @@ impl Prompt {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Split text into literal text, `{{name}}` placeholders and `{{secret:name}}` references
+pub fn content_segments(content: &str) -> Vec<ContentSegment<'_>> {
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut cursor = 0;
 
-    fn create_valid_prompt() -> Prompt {
-        Prompt {
-            id: "test-folder/test-prompt.md".to_string(),
+    while let Some(open) = content[cursor..].find("{{").map(|i| cursor + i) {
+        let Some(close) = content[open + 2..].find("}}").map(|i| open + 2 + i) else { break };
+        // Substitution is literal, so "{{ name }}" is not a placeholder
+        let name = &content[open + 2..close];
+        let is_name = !name.is_empty() && !name.contains(|c: char| c.is_whitespace() || c == '{');
+        let segment = match name.strip_prefix(SECRET_PREFIX) {
+            _ if !is_name => None,
+            Some(secret) => (!secret.is_empty()).then_some(ContentSegment::Secret(secret)),
+            None => Some(ContentSegment::Placeholder(name)),
+        };
+        if let Some(segment) = segment {
+            if open > text_start {
+                segments.push(ContentSegment::Text(&content[text_start..open]));
+            }
+            segments.push(segment);
+            text_start = close + 2;
+        }
+        cursor = close + 2;
+    }
+
+    if text_start < content.len() {
+        segments.push(ContentSegment::Text(&content[text_start..]));
+    }
+    segments
+}
+
+#[cfg(test)]
+impl Prompt {
+    /// A valid prompt with placeholder fields; fixtures override what they need with
+    /// `Prompt { name, ..Prompt::for_test(id) }`
+    pub fn for_test(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
             name: "Test Prompt".to_string(),
-            description: "Test description".to_string(),
+            description: String::new(),
             content: "Test content".to_string(),
-            folder: "test-folder".to_string(),
+            folder: String::new(),
             icon: "📝".to_string(),
             color: "#3B82F6".to_string(),
-            tags: vec!["test".to_string()],
+            tags: vec![],
             variables: vec![],
             auto_paste: false,
             is_favorite: false,
             created_at: "2025-01-01T00:00:00Z".to_string(),
             updated_at: "2025-01-01T00:00:00Z".to_string(),
+            apps: vec![],
+            private: false,
+            locked: false,
+            hotkey: None,
+            abbreviation: None,
+            paste_mode: None,
+            paste_format: None,
+            steps: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_valid_prompt() -> Prompt {
+        Prompt {
+            description: "Test description".to_string(),
+            folder: "test-folder".to_string(),
+            tags: vec!["test".to_string()],
+            ..Prompt::for_test("test-folder/test-prompt.md")
         }
     }
 
@@ -126,6 +337,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_locked_prompt_passes_validation_without_content() {
+        let mut prompt = create_valid_prompt();
+        prompt.private = true;
+        prompt.locked = true;
+        prompt.content = "".to_string();
+        assert!(prompt.validate().is_ok());
+    }
+
     #[test]
     fn test_extract_folder_from_path() {
         let prompt = create_valid_prompt();
@@ -140,4 +360,134 @@ mod tests {
         let folder = prompt.extract_folder();
         assert_eq!(folder, None);
     }
+
+    #[test]
+    fn test_directory_of_nested_and_root_prompts() {
+        let mut prompt = create_valid_prompt();
+        prompt.id = "Coding/Rust/review.md".to_string();
+        assert_eq!(prompt.directory(), "Coding/Rust");
+
+        prompt.id = "review.md".to_string();
+        assert_eq!(prompt.directory(), "");
+    }
+
+    #[test]
+    fn test_has_valid_color() {
+        let mut prompt = create_valid_prompt();
+        for valid in ["#3B82F6", "#fff", "#3B82F680"] {
+            prompt.color = valid.to_string();
+            assert!(prompt.has_valid_color(), "{}", valid);
+        }
+        for invalid in ["blue", "3B82F6", "#3B82F", "#GGGGGG", ""] {
+            prompt.color = invalid.to_string();
+            assert!(!prompt.has_valid_color(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_placeholders_in_order_without_duplicates() {
+        let mut prompt = create_valid_prompt();
+        prompt.content = "Hi {{name}}, see {{place}} and {{name}} again. {{ spaced }} {{}} {{unclosed".to_string();
+        assert_eq!(prompt.placeholders(), vec!["name", "place"]);
+    }
+
+    #[test]
+    fn test_content_segments() {
+        let mut prompt = create_valid_prompt();
+        prompt.content = "Hi {{name}}! {{ not }} {{x}}".to_string();
+        assert_eq!(
+            prompt.content_segments(),
+            vec![
+                ContentSegment::Text("Hi "),
+                ContentSegment::Placeholder("name"),
+                ContentSegment::Text("! {{ not }} "),
+                ContentSegment::Placeholder("x"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_secret_references_are_not_placeholders() {
+        let mut prompt = create_valid_prompt();
+        prompt.content = "Key {{secret:api_key}} for {{user}}, {{secret:api_key}} {{secret:}}".to_string();
+        assert_eq!(prompt.placeholders(), vec!["user"]);
+        assert_eq!(prompt.secret_names(), vec!["api_key"]);
+        assert_eq!(prompt.content_segments()[1], ContentSegment::Secret("api_key"));
+        assert_eq!(prompt.content_segments().last(), Some(&ContentSegment::Text(" {{secret:}}")));
+    }
+
+    #[test]
+    fn test_is_pinned_to_matches_normalized_app_names() {
+        let mut prompt = create_valid_prompt();
+        prompt.apps = vec!["Code.exe".to_string(), "slack".to_string()];
+
+        assert!(prompt.is_pinned_to(&TargetApp::new("code")));
+        assert!(prompt.is_pinned_to(&TargetApp::new("Slack.exe")));
+        assert!(!prompt.is_pinned_to(&TargetApp::new("outlook")));
+    }
+
+    #[test]
+    fn test_unpinned_prompt_matches_no_app() {
+        let prompt = create_valid_prompt();
+        assert!(!prompt.is_pinned_to(&TargetApp::new("code")));
+    }
+
+    #[test]
+    fn test_render_with_defaults_fills_declared_variables_only() {
+        let mut prompt = create_valid_prompt();
+        prompt.content = "Review {{lang}} code for {{user}} with {{secret:token}}".to_string();
+        prompt.variables = vec![Variable {
+            name: "lang".to_string(),
+            default: "Rust".to_string(),
+            required: true,
+        }];
+
+        assert_eq!(
+            prompt.render_with_defaults().unwrap(),
+            "Review Rust code for {{user}} with {{secret:token}}"
+        );
+
+        prompt.variables[0].default.clear();
+        assert!(prompt.render_with_defaults().unwrap_err().contains("'lang'"));
+    }
+
+    #[test]
+    fn test_validate_abbreviation() {
+        let mut prompt = create_valid_prompt();
+        prompt.abbreviation = Some(";;review".to_string());
+        assert!(prompt.validate().is_ok());
+
+        prompt.abbreviation = Some(";".to_string());
+        assert!(prompt.validate().is_err());
+
+        prompt.abbreviation = Some(";;code review".to_string());
+        assert!(prompt.validate().is_err());
+    }
+
+    #[test]
+    fn test_abbreviations_clash_when_one_contains_the_other() {
+        assert!(Prompt::abbreviations_clash(";;review", ";;review"));
+        assert!(Prompt::abbreviations_clash(";;r", ";;review"));
+        assert!(Prompt::abbreviations_clash(";;review", ";;r"));
+        assert!(Prompt::abbreviations_clash("x;;rx", ";;r"));
+        assert!(!Prompt::abbreviations_clash(";;review", ";;fix"));
+    }
+
+    #[test]
+    fn test_validate_steps_names_the_bad_step() {
+        let mut prompt = create_valid_prompt();
+        prompt.steps = vec![MacroStep::Content, MacroStep::WaitMs(MacroStep::MAX_WAIT_MS + 1)];
+        assert!(prompt.validate().unwrap_err().starts_with("Step 2:"));
+    }
+
+    #[test]
+    fn test_render_text_with_defaults_uses_prompt_variables() {
+        let mut prompt = create_valid_prompt();
+        prompt.variables = vec![Variable {
+            name: "email".to_string(),
+            default: "me@example.com".to_string(),
+            required: false,
+        }];
+        assert_eq!(prompt.render_text_with_defaults("To: {{email}}").unwrap(), "To: me@example.com");
+    }
 }
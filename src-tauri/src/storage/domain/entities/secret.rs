@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+/// A stored secret as shown to the UI; the value never leaves the backend
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecretInfo {
+    pub name: String,
+    pub updated_at: String,
+}
+
+/// Business rule: secret names are letters, digits, `_`, `-` and `.` so that
+/// `{{secret:name}}` is unambiguous inside prompt content
+pub fn validate_secret_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Secret name cannot be empty".to_string());
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')) {
+        return Err(format!("Invalid secret name '{}': use letters, digits, '_', '-' or '.'", name));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_secret_name() {
+        assert!(validate_secret_name("openai_key").is_ok());
+        assert!(validate_secret_name("work.signature-v2").is_ok());
+        assert!(validate_secret_name("").is_err());
+        assert!(validate_secret_name("api key").is_err());
+        assert!(validate_secret_name("a}}b").is_err());
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// State of the private prompt vault on this machine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VaultStatus {
+    /// A passphrase has been set
+    pub configured: bool,
+    pub unlocked: bool,
+    /// Folders whose prompts are always encrypted
+    pub private_folders: Vec<String>,
+}
@@ -0,0 +1,165 @@
+use crate::storage::domain::entities::ParseFailure;
+use std::io;
+use std::path::Path;
+use thiserror::Error;
+
+/// Errors returned by the prompt repository and the use cases built on it
+#[derive(Debug, Error)]
+pub enum StorageError {
+    #[error("Prompt not found: {0}")]
+    NotFound(String),
+
+    /// A file other than a prompt (import source, data file) is missing
+    #[error("File not found: {0}")]
+    FileNotFound(String),
+
+    #[error("Secret not found: {0}")]
+    SecretNotFound(String),
+
+    #[error("Permission denied: {path}")]
+    PermissionDenied { path: String },
+
+    #[error("Failed to parse {id}: {message}")]
+    Parse { id: String, message: String, line: Option<usize> },
+
+    #[error("{0}")]
+    Validation(String),
+
+    #[error("Unlock private prompts to save {0}")]
+    Locked(String),
+
+    /// The vault passphrase is needed (private prompts, secrets) but not entered
+    #[error("{0}")]
+    VaultLocked(String),
+
+    #[error("Wrong passphrase")]
+    WrongPassphrase,
+
+    /// A bad request (empty secret value, short passphrase, invalid name)
+    #[error("{0}")]
+    InvalidInput(String),
+
+    #[error("Encryption error: {0}")]
+    Encryption(String),
+
+    #[error("Failed to serialize prompt: {0}")]
+    Serialization(String),
+
+    #[error("I/O error on {path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("{0}")]
+    Other(String),
+}
+
+impl StorageError {
+    /// Wrap an I/O error, keeping missing files and permission problems distinguishable
+    pub fn io(path: &Path, source: io::Error) -> Self {
+        let path = path.display().to_string();
+        match source.kind() {
+            io::ErrorKind::NotFound => Self::FileNotFound(path),
+            io::ErrorKind::PermissionDenied => Self::PermissionDenied { path },
+            _ => Self::Io { path, source },
+        }
+    }
+
+    /// Wrap an I/O error on a prompt file; a missing file means the prompt doesn't exist
+    pub fn prompt_io(id: &str, path: &Path, source: io::Error) -> Self {
+        match source.kind() {
+            io::ErrorKind::NotFound => Self::NotFound(id.to_string()),
+            _ => Self::io(path, source),
+        }
+    }
+
+    /// Stable machine-readable code sent to the frontend
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotFound(_) | Self::FileNotFound(_) | Self::SecretNotFound(_) => "not_found",
+            Self::PermissionDenied { .. } => "permission_denied",
+            Self::Parse { .. } => "parse_error",
+            Self::Validation(_) => "invalid_prompt",
+            Self::Locked(_) | Self::VaultLocked(_) => "vault_locked",
+            Self::WrongPassphrase => "wrong_passphrase",
+            Self::InvalidInput(_) => "invalid_input",
+            Self::Encryption(_) => "encryption_error",
+            Self::Serialization(_) => "serialization_error",
+            Self::Io { .. } => "io_error",
+            Self::Other(_) => "storage_error",
+        }
+    }
+
+    /// The prompt or file the error is about, and the failing line for parse errors
+    pub fn details(&self) -> Option<String> {
+        match self {
+            Self::NotFound(id) | Self::Locked(id) | Self::SecretNotFound(id) => Some(id.clone()),
+            Self::FileNotFound(path) | Self::PermissionDenied { path } | Self::Io { path, .. } => Some(path.clone()),
+            Self::Parse { id, line: Some(line), .. } => Some(format!("{}:{}", id, line)),
+            Self::Parse { id, line: None, .. } => Some(id.clone()),
+            Self::Validation(_)
+            | Self::VaultLocked(_)
+            | Self::WrongPassphrase
+            | Self::InvalidInput(_)
+            | Self::Encryption(_)
+            | Self::Serialization(_)
+            | Self::Other(_) => None,
+        }
+    }
+}
+
+impl From<ParseFailure> for StorageError {
+    fn from(failure: ParseFailure) -> Self {
+        Self::Parse {
+            id: failure.id,
+            message: failure.message,
+            line: failure.line,
+        }
+    }
+}
+
+/// Helpers that still report plain messages (constructors, file listing)
+impl From<String> for StorageError {
+    fn from(message: String) -> Self {
+        Self::Other(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_io_error_keeps_permission_and_not_found_apart() {
+        let path = Path::new("Coding/review.md");
+
+        let denied = StorageError::io(path, io::Error::from(io::ErrorKind::PermissionDenied));
+        assert_eq!(denied.code(), "permission_denied");
+        assert_eq!(denied.details().as_deref(), Some("Coding/review.md"));
+
+        let missing = StorageError::io(Path::new("usage.json"), io::Error::from(io::ErrorKind::NotFound));
+        assert_eq!(missing.code(), "not_found");
+        assert_eq!(missing.to_string(), "File not found: usage.json");
+
+        let missing_prompt = StorageError::prompt_io("Coding/review.md", path, io::Error::from(io::ErrorKind::NotFound));
+        assert_eq!(missing_prompt.to_string(), "Prompt not found: Coding/review.md");
+
+        let other = StorageError::io(path, io::Error::from(io::ErrorKind::WriteZero));
+        assert_eq!(other.code(), "io_error");
+    }
+
+    #[test]
+    fn test_parse_failure_details_include_line() {
+        let error = StorageError::from(ParseFailure {
+            id: "notes.md".to_string(),
+            message: "bad YAML".to_string(),
+            line: Some(3),
+        });
+
+        assert_eq!(error.code(), "parse_error");
+        assert_eq!(error.details().as_deref(), Some("notes.md:3"));
+        assert_eq!(error.to_string(), "Failed to parse notes.md: bad YAML");
+    }
+}
@@ -1,4 +1,5 @@
 // Domain module - pure business logic with no external dependencies
 pub mod entities;
+pub mod errors;
 pub mod value_objects;
 pub mod ports;
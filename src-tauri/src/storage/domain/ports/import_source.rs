@@ -0,0 +1,9 @@
+use crate::storage::domain::entities::ImportBatch;
+use crate::storage::domain::errors::StorageError;
+
+/// Source of prompts to import (Port)
+/// Infrastructure layer implements one adapter per source format
+pub trait ImportSource {
+    /// Read every prompt in the source; per-file problems go into `ImportBatch::errors`
+    fn load(&self) -> Result<ImportBatch, StorageError>;
+}
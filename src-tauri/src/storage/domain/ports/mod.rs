@@ -1,6 +1,12 @@
 // Domain ports (interfaces) - infrastructure layer will implement these
+pub mod import_source;
 pub mod prompt_repository;
+pub mod prompt_vault;
 pub mod search_service;
+pub mod secret_store;
 
+pub use import_source::ImportSource;
 pub use prompt_repository::PromptRepository;
+pub use prompt_vault::PromptVault;
 pub use search_service::{SearchService, SearchResult, MatchRange};
+pub use secret_store::SecretStore;
@@ -1,4 +1,5 @@
-use crate::storage::domain::entities::Prompt;
+use crate::storage::domain::entities::{ParseFailure, Prompt};
+use crate::storage::domain::errors::StorageError;
 use crate::storage::domain::value_objects::PromptId;
 
 /// Repository interface (Port)
@@ -6,16 +7,22 @@ use crate::storage::domain::value_objects::PromptId;
 /// Abstracts data access to allow swapping storage backends
 pub trait PromptRepository: Send + Sync {
     /// Find all prompts
-    fn find_all(&self) -> Result<Vec<Prompt>, String>;
+    fn find_all(&self) -> Result<Vec<Prompt>, StorageError>;
+
+    /// Find all prompts, also returning the files that failed to parse
+    /// Backends without unparseable files can rely on the default
+    fn find_all_with_failures(&self) -> Result<(Vec<Prompt>, Vec<ParseFailure>), StorageError> {
+        Ok((self.find_all()?, Vec::new()))
+    }
 
     /// Find a specific prompt by ID
-    fn find_by_id(&self, id: &PromptId) -> Result<Prompt, String>;
+    fn find_by_id(&self, id: &PromptId) -> Result<Prompt, StorageError>;
 
     /// Save a prompt (create or update)
-    fn save(&self, prompt: &Prompt) -> Result<(), String>;
+    fn save(&self, prompt: &Prompt) -> Result<(), StorageError>;
 
     /// Delete a prompt by ID
     /// Part of complete CRUD interface - reserved for future use
     #[allow(dead_code)]
-    fn delete(&self, id: &PromptId) -> Result<(), String>;
+    fn delete(&self, id: &PromptId) -> Result<(), StorageError>;
 }
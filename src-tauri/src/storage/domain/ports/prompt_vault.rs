@@ -0,0 +1,17 @@
+use crate::storage::domain::errors::StorageError;
+
+/// Encryption of private prompt content (Port)
+/// Infrastructure layer will implement this trait
+pub trait PromptVault: Send + Sync {
+    /// True while the passphrase is known (false once locked or the auto-lock timeout passed)
+    fn is_unlocked(&self) -> bool;
+
+    /// True if prompts saved in this folder are always encrypted (parent folders count)
+    fn is_private_folder(&self, folder: &str) -> bool;
+
+    /// Encrypt to self-describing text safe to store in a prompt file
+    fn encrypt(&self, plaintext: &[u8]) -> Result<String, StorageError>;
+
+    /// Decrypt text produced by `encrypt`; fails while locked or if it was tampered with
+    fn decrypt(&self, sealed: &str) -> Result<Vec<u8>, StorageError>;
+}
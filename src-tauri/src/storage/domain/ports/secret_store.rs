@@ -0,0 +1,18 @@
+use crate::storage::domain::entities::SecretInfo;
+use crate::storage::domain::errors::StorageError;
+
+/// Encrypted storage of named secrets (Port)
+/// Infrastructure layer will implement this trait
+pub trait SecretStore: Send + Sync {
+    /// Stored secrets by name; values are not included
+    fn list(&self) -> Result<Vec<SecretInfo>, StorageError>;
+
+    /// The value of a secret, or None if no secret has that name
+    fn get(&self, name: &str) -> Result<Option<String>, StorageError>;
+
+    /// Create or replace a secret
+    fn set(&self, name: &str, value: &str) -> Result<(), StorageError>;
+
+    /// Remove a secret; returns false if it did not exist
+    fn delete(&self, name: &str) -> Result<bool, StorageError>;
+}
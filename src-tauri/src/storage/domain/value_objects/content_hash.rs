@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Stable fingerprint of prompt content (hex SHA-256)
+/// Used to recognise a prompt whose file was renamed or moved outside the app
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ContentHash(String);
+
+impl ContentHash {
+    /// Hash content, ignoring surrounding whitespace and line ending style
+    pub fn of(content: &str) -> Self {
+        let normalized = content.trim().replace("\r\n", "\n");
+        Self(format!("{:x}", Sha256::digest(normalized.as_bytes())))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for ContentHash {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+
+impl std::fmt::Display for ContentHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_hash_is_hex_sha256() {
+        let hash = ContentHash::of("hello");
+        assert_eq!(
+            hash.as_str(),
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+    }
+
+    #[test]
+    fn test_content_hash_ignores_whitespace_and_line_endings() {
+        assert_eq!(ContentHash::of("a\r\nb\n"), ContentHash::of("  a\nb"));
+    }
+
+    #[test]
+    fn test_content_hash_differs_for_different_content() {
+        assert_ne!(ContentHash::of("a"), ContentHash::of("b"));
+    }
+}
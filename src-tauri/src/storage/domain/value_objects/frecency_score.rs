@@ -1,36 +1,106 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Firefox-style visit buckets: (max age in days, weight)
+/// Uses older than the last bucket get `OLDEST_BUCKET_WEIGHT`
+const VISIT_BUCKETS: [(f64, f64); 4] = [(4.0, 100.0), (14.0, 70.0), (31.0, 50.0), (90.0, 30.0)];
+const OLDEST_BUCKET_WEIGHT: f64 = 10.0;
+
+/// Number of most recent uses sampled by the visit bucket model
+const VISIT_BUCKET_SAMPLES: usize = 10;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Frecency algorithm, selectable in config
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "algorithm", rename_all = "snake_case")]
+pub enum FrecencyModel {
+    /// Each use contributes 0.5^(age / half_life_days)
+    HalfLife { half_life_days: f64 },
+    /// Average bucket weight of the most recent uses, times the number of uses
+    /// sampled (Firefox), so a long-gone burst of use can't outweigh recent use
+    VisitBuckets,
+}
+
+impl Default for FrecencyModel {
+    fn default() -> Self {
+        Self::HalfLife { half_life_days: 14.0 }
+    }
+}
 
 /// Encapsulated frecency scoring logic
-/// Formula: score = use_count * recency_decay
-/// where recency_decay = 1.0 / (1.0 + days_since_last_use * 0.1)
+/// Scores a prompt from its lifetime use count and its most recent use timestamps.
+/// Uses beyond the recorded timestamps are counted as happening at the oldest one.
 #[derive(Debug, Clone)]
 pub struct FrecencyScore {
     use_count: u32,
-    last_used: DateTime<Utc>,
+    uses: Vec<DateTime<Utc>>,
 }
 
 impl FrecencyScore {
-    pub fn new(use_count: u32, last_used: DateTime<Utc>) -> Self {
-        Self {
-            use_count,
-            last_used,
+    pub fn new(use_count: u32, mut uses: Vec<DateTime<Utc>>) -> Self {
+        // Most recent first
+        uses.sort_by(|a, b| b.cmp(a));
+        Self { use_count, uses }
+    }
+
+    /// Calculate frecency score now
+    pub fn calculate(&self, model: FrecencyModel) -> f64 {
+        self.calculate_at(model, Utc::now())
+    }
+
+    /// Calculate frecency score at a specific point in time (deterministic)
+    pub fn calculate_at(&self, model: FrecencyModel, now: DateTime<Utc>) -> f64 {
+        if self.use_count == 0 || self.uses.is_empty() {
+            return 0.0;
+        }
+
+        match model {
+            FrecencyModel::HalfLife { half_life_days } => self.half_life_score(half_life_days, now),
+            FrecencyModel::VisitBuckets => self.visit_bucket_score(now),
         }
     }
 
-    /// Calculate frecency score using time decay algorithm
-    pub fn calculate(&self) -> f64 {
-        let now = Utc::now();
-        let days_since = (now - self.last_used).num_days() as f64;
-        let recency_decay = 1.0 / (1.0 + days_since * 0.1);
-        self.use_count as f64 * recency_decay
+    fn half_life_score(&self, half_life_days: f64, now: DateTime<Utc>) -> f64 {
+        let half_life_days = half_life_days.max(f64::EPSILON);
+        let decay = |used_at: &DateTime<Utc>| 0.5_f64.powf(Self::age_in_days(used_at, now) / half_life_days);
+
+        let recorded: f64 = self.uses.iter().map(decay).sum();
+
+        // Uses we no longer have timestamps for are at least as old as the oldest recorded one
+        let unrecorded = self.use_count.saturating_sub(self.uses.len() as u32) as f64;
+        let oldest = self.uses.last().map(decay).unwrap_or(0.0);
+
+        recorded + unrecorded * oldest
     }
 
-    /// Calculate frecency score at a specific point in time (for testing)
-    #[cfg(test)]
-    pub fn calculate_at(&self, now: DateTime<Utc>) -> f64 {
-        let days_since = (now - self.last_used).num_days() as f64;
-        let recency_decay = 1.0 / (1.0 + days_since * 0.1);
-        self.use_count as f64 * recency_decay
+    fn visit_bucket_score(&self, now: DateTime<Utc>) -> f64 {
+        let samples: Vec<f64> = self
+            .uses
+            .iter()
+            .take(VISIT_BUCKET_SAMPLES)
+            .map(|used_at| Self::bucket_weight(Self::age_in_days(used_at, now)))
+            .collect();
+
+        let average_weight = samples.iter().sum::<f64>() / samples.len() as f64;
+
+        // Scaled by the uses sampled, not the lifetime count, or 500 uses two years ago
+        // would outrank this week's handful. A single use right now scores 1.0
+        let sampled_count = self.use_count.min(VISIT_BUCKET_SAMPLES as u32) as f64;
+        sampled_count * average_weight / VISIT_BUCKETS[0].1
+    }
+
+    fn bucket_weight(age_days: f64) -> f64 {
+        VISIT_BUCKETS
+            .iter()
+            .find(|(max_age, _)| age_days <= *max_age)
+            .map(|(_, weight)| *weight)
+            .unwrap_or(OLDEST_BUCKET_WEIGHT)
+    }
+
+    /// Fractional days between a use and `now` (future timestamps count as now)
+    fn age_in_days(used_at: &DateTime<Utc>, now: DateTime<Utc>) -> f64 {
+        ((now - *used_at).num_seconds().max(0) as f64) / SECONDS_PER_DAY
     }
 }
 
@@ -39,69 +109,119 @@ mod tests {
     use super::*;
     use chrono::Duration;
 
+    const HALF_LIFE_14: FrecencyModel = FrecencyModel::HalfLife { half_life_days: 14.0 };
+
+    fn uses_at(now: DateTime<Utc>, days_ago: &[i64]) -> Vec<DateTime<Utc>> {
+        days_ago.iter().map(|d| now - Duration::days(*d)).collect()
+    }
+
     #[test]
-    fn test_frecency_score_just_used() {
+    fn test_half_life_just_used() {
         let now = Utc::now();
-        let score = FrecencyScore::new(10, now);
-        let calculated = score.calculate_at(now);
+        let score = FrecencyScore::new(1, uses_at(now, &[0]));
 
-        // Just used: recency_decay = 1.0 / (1.0 + 0 * 0.1) = 1.0
-        // score = 10 * 1.0 = 10.0
-        assert_eq!(calculated, 10.0);
+        // 0.5^0 = 1.0
+        assert_eq!(score.calculate_at(HALF_LIFE_14, now), 1.0);
     }
 
     #[test]
-    fn test_frecency_score_decays_over_time() {
+    fn test_half_life_halves_after_one_half_life() {
         let now = Utc::now();
-        let thirty_days_ago = now - Duration::days(30);
-        let score = FrecencyScore::new(10, thirty_days_ago);
-        let calculated = score.calculate_at(now);
+        let score = FrecencyScore::new(1, uses_at(now, &[14]));
 
-        // 30 days ago: recency_decay = 1.0 / (1.0 + 30 * 0.1) = 1.0 / 4.0 = 0.25
-        // score = 10 * 0.25 = 2.5
-        assert_eq!(calculated, 2.5);
+        assert!((score.calculate_at(HALF_LIFE_14, now) - 0.5).abs() < 1e-9);
     }
 
     #[test]
-    fn test_frecency_score_high_usage_old_prompt() {
+    fn test_half_life_uses_fractional_days() {
         let now = Utc::now();
-        let sixty_days_ago = now - Duration::days(60);
-        let score = FrecencyScore::new(100, sixty_days_ago);
-        let calculated = score.calculate_at(now);
+        let score = FrecencyScore::new(1, vec![now - Duration::hours(12)]);
+        let calculated = score.calculate_at(HALF_LIFE_14, now);
 
-        // 60 days ago: recency_decay = 1.0 / (1.0 + 60 * 0.1) = 1.0 / 7.0 ≈ 0.142857
-        // score = 100 * 0.142857 ≈ 14.2857
-        assert!((calculated - 14.285714).abs() < 0.001);
+        // Half a day old must score below a use right now
+        assert!(calculated < 1.0);
+        assert!(calculated > 0.97);
     }
 
     #[test]
-    fn test_frecency_score_low_usage_recent_prompt() {
+    fn test_half_life_sums_individual_uses() {
         let now = Utc::now();
-        let one_day_ago = now - Duration::days(1);
-        let score = FrecencyScore::new(2, one_day_ago);
-        let calculated = score.calculate_at(now);
+        let score = FrecencyScore::new(2, uses_at(now, &[0, 14]));
 
-        // 1 day ago: recency_decay = 1.0 / (1.0 + 1 * 0.1) = 1.0 / 1.1 ≈ 0.909090
-        // score = 2 * 0.909090 ≈ 1.818181
-        assert!((calculated - 1.818181).abs() < 0.001);
+        assert!((score.calculate_at(HALF_LIFE_14, now) - 1.5).abs() < 1e-9);
     }
 
     #[test]
-    fn test_frecency_score_zero_usage() {
+    fn test_half_life_unrecorded_uses_count_at_oldest_timestamp() {
         let now = Utc::now();
-        let score = FrecencyScore::new(0, now);
-        assert_eq!(score.calculate_at(now), 0.0);
+        // 10 uses, only the latest one recorded (legacy usage data)
+        let score = FrecencyScore::new(10, uses_at(now, &[14]));
+
+        assert!((score.calculate_at(HALF_LIFE_14, now) - 5.0).abs() < 1e-9);
     }
 
     #[test]
-    fn test_frecency_score_very_old_prompt() {
+    fn test_half_life_recent_favourite_beats_old_heavy_use() {
         let now = Utc::now();
-        let year_ago = now - Duration::days(365);
-        let score = FrecencyScore::new(10, year_ago);
-        let calculated = score.calculate_at(now);
+        let old_heavy = FrecencyScore::new(500, uses_at(now, &[730]));
+        let this_week = FrecencyScore::new(5, uses_at(now, &[0, 1, 2, 3, 4]));
+
+        assert!(this_week.calculate_at(HALF_LIFE_14, now) > old_heavy.calculate_at(HALF_LIFE_14, now));
+    }
+
+    #[test]
+    fn test_visit_buckets_recent_use() {
+        let now = Utc::now();
+        let score = FrecencyScore::new(3, uses_at(now, &[0, 1, 2]));
+
+        // All in the 4-day bucket: 3 * 100 / 100
+        assert_eq!(score.calculate_at(FrecencyModel::VisitBuckets, now), 3.0);
+    }
+
+    #[test]
+    fn test_visit_buckets_average_bucket_weight() {
+        let now = Utc::now();
+        let score = FrecencyScore::new(2, uses_at(now, &[1, 60]));
+
+        // (100 + 30) / 2 = 65 -> 2 * 65 / 100 = 1.3
+        assert!((score.calculate_at(FrecencyModel::VisitBuckets, now) - 1.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_visit_buckets_very_old_use() {
+        let now = Utc::now();
+        let score = FrecencyScore::new(10, uses_at(now, &[365]));
+
+        // Oldest bucket weight 10 -> 10 * 10 / 100 = 1.0
+        assert_eq!(score.calculate_at(FrecencyModel::VisitBuckets, now), 1.0);
+    }
+
+    #[test]
+    fn test_visit_buckets_recent_favourite_beats_old_heavy_use() {
+        let now = Utc::now();
+        let old_heavy = FrecencyScore::new(500, uses_at(now, &[730, 731, 732]));
+        let this_week = FrecencyScore::new(5, uses_at(now, &[0, 1, 2, 3, 4]));
+
+        // 10 * 10 / 100 = 1.0 against 5 * 100 / 100 = 5.0
+        assert_eq!(old_heavy.calculate_at(FrecencyModel::VisitBuckets, now), 1.0);
+        assert_eq!(this_week.calculate_at(FrecencyModel::VisitBuckets, now), 5.0);
+    }
+
+    #[test]
+    fn test_zero_usage_scores_zero() {
+        let now = Utc::now();
+        let score = FrecencyScore::new(0, vec![]);
+
+        assert_eq!(score.calculate_at(HALF_LIFE_14, now), 0.0);
+        assert_eq!(score.calculate_at(FrecencyModel::VisitBuckets, now), 0.0);
+    }
+
+    #[test]
+    fn test_model_config_round_trip() {
+        let json = serde_json::to_string(&HALF_LIFE_14).unwrap();
+        assert_eq!(json, r#"{"algorithm":"half_life","half_life_days":14.0}"#);
 
-        // 365 days ago: recency_decay = 1.0 / (1.0 + 365 * 0.1) = 1.0 / 37.5 ≈ 0.0267
-        // score = 10 * 0.0267 ≈ 0.267
-        assert!((calculated - 0.266666).abs() < 0.01);
+        let model: FrecencyModel = serde_json::from_str(r#"{"algorithm":"visit_buckets"}"#).unwrap();
+        assert_eq!(model, FrecencyModel::VisitBuckets);
     }
 }
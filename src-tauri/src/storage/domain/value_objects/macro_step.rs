@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+/// One step of a prompt macro; a prompt with steps runs them in order instead of a single paste
+/// Stored as a one-key map (`- keys: Enter`, `- wait_ms: 200`, `- paste: "..."`, `- content: true`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "MacroStepFields", into = "MacroStepFields")]
+pub enum MacroStep {
+    /// Paste the prompt's rendered content
+    Content,
+    /// Paste another segment; placeholders take their defaults and secrets are filled in
+    Paste(String),
+    /// Press a key chord (e.g. "Enter", "Tab", "Ctrl+Shift+Left")
+    Keys(String),
+    /// Pause, e.g. while a form moves to its next field
+    WaitMs(u64),
+}
+
+impl MacroStep {
+    /// Longest pause a step may ask for
+    pub const MAX_WAIT_MS: u64 = 10_000;
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            MacroStep::Content => Ok(()),
+            MacroStep::Paste(text) if text.is_empty() => Err("Paste step needs text".to_string()),
+            MacroStep::Keys(chord) if chord.trim().is_empty() => Err("Keys step needs a key".to_string()),
+            MacroStep::WaitMs(ms) if *ms > Self::MAX_WAIT_MS => {
+                Err(format!("Wait step can be at most {} ms", Self::MAX_WAIT_MS))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// File representation of a step: exactly one field is set
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MacroStepFields {
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    content: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    paste: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    keys: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_ms: Option<u64>,
+}
+
+impl TryFrom<MacroStepFields> for MacroStep {
+    type Error = String;
+
+    fn try_from(fields: MacroStepFields) -> Result<Self, Self::Error> {
+        match fields {
+            MacroStepFields { content: true, paste: None, keys: None, wait_ms: None } => Ok(MacroStep::Content),
+            MacroStepFields { content: false, paste: Some(text), keys: None, wait_ms: None } => Ok(MacroStep::Paste(text)),
+            MacroStepFields { content: false, paste: None, keys: Some(chord), wait_ms: None } => Ok(MacroStep::Keys(chord)),
+            MacroStepFields { content: false, paste: None, keys: None, wait_ms: Some(ms) } => Ok(MacroStep::WaitMs(ms)),
+            _ => Err("a macro step needs exactly one of content, paste, keys or wait_ms".to_string()),
+        }
+    }
+}
+
+impl From<MacroStep> for MacroStepFields {
+    fn from(step: MacroStep) -> Self {
+        match step {
+            MacroStep::Content => Self { content: true, ..Default::default() },
+            MacroStep::Paste(text) => Self { paste: Some(text), ..Default::default() },
+            MacroStep::Keys(chord) => Self { keys: Some(chord), ..Default::default() },
+            MacroStep::WaitMs(ms) => Self { wait_ms: Some(ms), ..Default::default() },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yaml_round_trip() {
+        let steps = vec![
+            MacroStep::Content,
+            MacroStep::Keys("Tab".to_string()),
+            MacroStep::WaitMs(200),
+            MacroStep::Paste("{{email}}".to_string()),
+        ];
+        let yaml = serde_yaml::to_string(&steps).unwrap();
+        assert_eq!(yaml, "- content: true\n- keys: Tab\n- wait_ms: 200\n- paste: '{{email}}'\n");
+        assert_eq!(serde_yaml::from_str::<Vec<MacroStep>>(&yaml).unwrap(), steps);
+    }
+
+    #[test]
+    fn test_step_needs_exactly_one_field() {
+        assert!(serde_yaml::from_str::<MacroStep>("keys: Enter\nwait_ms: 5").is_err());
+        assert!(serde_yaml::from_str::<MacroStep>("content: false").is_err());
+        assert!(serde_yaml::from_str::<MacroStep>("click: 1").is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(MacroStep::WaitMs(MacroStep::MAX_WAIT_MS).validate().is_ok());
+        assert!(MacroStep::WaitMs(MacroStep::MAX_WAIT_MS + 1).validate().is_err());
+        assert!(MacroStep::Keys(" ".to_string()).validate().is_err());
+        assert!(MacroStep::Paste(String::new()).validate().is_err());
+    }
+}
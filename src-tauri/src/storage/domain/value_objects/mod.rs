@@ -1,6 +1,16 @@
 // Domain value objects - immutable, type-safe wrappers
 pub mod prompt_id;
 pub mod frecency_score;
+pub mod target_app;
+pub mod content_hash;
+pub mod paste_format;
+pub mod paste_mode;
+pub mod macro_step;
 
 pub use prompt_id::PromptId;
-pub use frecency_score::FrecencyScore;
+pub use frecency_score::{FrecencyModel, FrecencyScore};
+pub use target_app::TargetApp;
+pub use content_hash::ContentHash;
+pub use paste_format::PasteFormat;
+pub use paste_mode::PasteMode;
+pub use macro_step::MacroStep;
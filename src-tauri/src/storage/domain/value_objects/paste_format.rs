@@ -0,0 +1,57 @@
+use crate::storage::domain::value_objects::TargetApp;
+use serde::{Deserialize, Serialize};
+
+/// Which clipboard formats a prompt is pasted in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasteFormat {
+    /// The markdown as written
+    #[default]
+    Plain,
+    /// Markdown rendered to HTML and RTF (plain text included for other apps)
+    Rich,
+}
+
+impl PasteFormat {
+    /// Business rule: the prompt's own format wins, then the per-app rich text list
+    pub fn resolve(prompt_format: Option<PasteFormat>, target_app: Option<&TargetApp>, rich_text_apps: &[TargetApp]) -> Self {
+        match prompt_format {
+            Some(format) => format,
+            None if target_app.is_some_and(|app| rich_text_apps.contains(app)) => Self::Rich,
+            None => Self::Plain,
+        }
+    }
+}
+
+impl std::str::FromStr for PasteFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "plain" => Ok(Self::Plain),
+            "rich" => Ok(Self::Rich),
+            other => Err(format!("Unknown paste format '{}' (expected plain or rich)", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_prefers_prompt_format_over_app_list() {
+        let rich_text_apps = vec![TargetApp::new("OUTLOOK.EXE")];
+        let outlook = TargetApp::new("outlook.exe");
+        let terminal = TargetApp::new("WindowsTerminal.exe");
+
+        assert_eq!(PasteFormat::resolve(None, Some(&outlook), &rich_text_apps), PasteFormat::Rich);
+        assert_eq!(PasteFormat::resolve(None, Some(&terminal), &rich_text_apps), PasteFormat::Plain);
+        assert_eq!(
+            PasteFormat::resolve(Some(PasteFormat::Plain), Some(&outlook), &rich_text_apps),
+            PasteFormat::Plain
+        );
+        assert_eq!("Rich".parse::<PasteFormat>(), Ok(PasteFormat::Rich));
+        assert!("html".parse::<PasteFormat>().is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use crate::storage::domain::value_objects::TargetApp;
+use serde::{Deserialize, Serialize};
+
+/// How a prompt is put into the target app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasteMode {
+    /// Copy to the clipboard and press Ctrl+V
+    #[default]
+    Clipboard,
+    /// Type the text as keystrokes, for apps that ignore clipboard paste
+    /// (remote desktops, some terminals)
+    Type,
+}
+
+impl PasteMode {
+    /// Business rule: the prompt's own mode wins, then the per-app type-out list
+    pub fn resolve(prompt_mode: Option<PasteMode>, target_app: Option<&TargetApp>, type_out_apps: &[TargetApp]) -> Self {
+        match prompt_mode {
+            Some(mode) => mode,
+            None if target_app.is_some_and(|app| type_out_apps.contains(app)) => Self::Type,
+            None => Self::Clipboard,
+        }
+    }
+}
+
+impl std::str::FromStr for PasteMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "clipboard" => Ok(Self::Clipboard),
+            "type" => Ok(Self::Type),
+            other => Err(format!("Unknown paste mode '{}' (expected clipboard or type)", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_prefers_prompt_mode_over_app_list() {
+        let type_out_apps = vec![TargetApp::new("mstsc.exe")];
+        let rdp = TargetApp::new("MSTSC.EXE");
+        let editor = TargetApp::new("Code.exe");
+
+        assert_eq!(PasteMode::resolve(None, Some(&rdp), &type_out_apps), PasteMode::Type);
+        assert_eq!(PasteMode::resolve(None, Some(&editor), &type_out_apps), PasteMode::Clipboard);
+        assert_eq!(PasteMode::resolve(None, None, &type_out_apps), PasteMode::Clipboard);
+        assert_eq!(
+            PasteMode::resolve(Some(PasteMode::Clipboard), Some(&rdp), &type_out_apps),
+            PasteMode::Clipboard
+        );
+        assert_eq!(PasteMode::resolve(Some(PasteMode::Type), Some(&editor), &[]), PasteMode::Type);
+    }
+}
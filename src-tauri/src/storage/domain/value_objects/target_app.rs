@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+/// Application a prompt is pasted into (normalized executable name)
+/// "Code.exe", "code.EXE" and "code" all map to the same app
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TargetApp(String);
+
+impl TargetApp {
+    pub fn new(name: impl AsRef<str>) -> Self {
+        Self(Self::normalize(name.as_ref()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Business rule: app names are compared by lower-cased file stem
+    fn normalize(name: &str) -> String {
+        let file_name = name
+            .trim()
+            .rsplit(['/', '\\'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match file_name.strip_suffix(".exe") {
+            Some(stem) => stem.to_string(),
+            None => file_name,
+        }
+    }
+}
+
+impl From<&str> for TargetApp {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl std::fmt::Display for TargetApp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_app_strips_exe_and_lowercases() {
+        assert_eq!(TargetApp::new("Code.exe").as_str(), "code");
+        assert_eq!(TargetApp::new("SLACK.EXE").as_str(), "slack");
+    }
+
+    #[test]
+    fn test_target_app_strips_directories() {
+        let app = TargetApp::new("C:\\Program Files\\Microsoft VS Code\\Code.exe");
+        assert_eq!(app.as_str(), "code");
+
+        let app = TargetApp::new("/usr/bin/gnome-terminal");
+        assert_eq!(app.as_str(), "gnome-terminal");
+    }
+
+    #[test]
+    fn test_target_app_equality_ignores_spelling() {
+        assert_eq!(TargetApp::new("code"), TargetApp::new(" Code.exe "));
+    }
+}
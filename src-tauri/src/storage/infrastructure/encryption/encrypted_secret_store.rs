@@ -0,0 +1,175 @@
+use crate::storage::domain::entities::{validate_secret_name, SecretInfo};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::{PromptVault, SecretStore};
+use crate::storage::infrastructure::encryption::PassphraseVault;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+const SECRETS_FILE_NAME: &str = "secrets.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SecretsFile {
+    #[serde(default)]
+    secrets: BTreeMap<String, StoredSecret>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredSecret {
+    /// Armored envelope from the vault
+    value: String,
+    updated_at: String,
+}
+
+/// Secrets stored in the data directory (~/.prompter/secrets.json), each value
+/// encrypted with the vault passphrase; names stay readable so they can be listed while locked
+/// Kept outside the prompts directory, so sync, backups and exports never pick it up
+pub struct EncryptedSecretStore {
+    path: PathBuf,
+    vault: Box<dyn PromptVault>,
+}
+
+impl EncryptedSecretStore {
+    pub fn new() -> Result<Self, String> {
+        let home = dirs::home_dir().ok_or("Could not determine home directory")?;
+        Ok(Self {
+            path: home.join(".prompter").join(SECRETS_FILE_NAME),
+            vault: Box::new(PassphraseVault::new()?),
+        })
+    }
+
+    /// Create a store with a custom file and vault (for testing)
+    #[cfg(test)]
+    pub fn with_path(path: PathBuf, vault: impl PromptVault + 'static) -> Self {
+        Self {
+            path,
+            vault: Box::new(vault),
+        }
+    }
+
+    fn check_unlocked(&self) -> Result<(), StorageError> {
+        if !self.vault.is_unlocked() {
+            return Err(StorageError::VaultLocked("Unlock the vault to use secrets".to_string()));
+        }
+        Ok(())
+    }
+
+    fn load(&self) -> Result<SecretsFile, String> {
+        if !self.path.exists() {
+            return Ok(SecretsFile::default());
+        }
+        let content = fs::read_to_string(&self.path)
+            .map_err(|e| format!("Failed to read secrets: {}", e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse secrets: {}", e))
+    }
+
+    fn save(&self, file: &SecretsFile) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create data directory: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(file)
+            .map_err(|e| format!("Failed to serialize secrets: {}", e))?;
+
+        // Write then rename so an interrupted save never loses every secret
+        let temp_path = self.path.with_extension("json.tmp");
+        fs::write(&temp_path, content)
+            .map_err(|e| format!("Failed to write secrets: {}", e))?;
+        fs::rename(&temp_path, &self.path)
+            .map_err(|e| format!("Failed to write secrets: {}", e))
+    }
+}
+
+impl SecretStore for EncryptedSecretStore {
+    fn list(&self) -> Result<Vec<SecretInfo>, StorageError> {
+        Ok(self
+            .load()?
+            .secrets
+            .into_iter()
+            .map(|(name, stored)| SecretInfo {
+                name,
+                updated_at: stored.updated_at,
+            })
+            .collect())
+    }
+
+    fn get(&self, name: &str) -> Result<Option<String>, StorageError> {
+        let Some(stored) = self.load()?.secrets.remove(name) else {
+            return Ok(None);
+        };
+        self.check_unlocked()?;
+        let plaintext = self.vault.decrypt(&stored.value)?;
+        String::from_utf8(plaintext)
+            .map(Some)
+            .map_err(|_| StorageError::Encryption(format!("Secret '{}' is not valid text", name)))
+    }
+
+    fn set(&self, name: &str, value: &str) -> Result<(), StorageError> {
+        validate_secret_name(name).map_err(StorageError::InvalidInput)?;
+        self.check_unlocked()?;
+        let mut file = self.load()?;
+        file.secrets.insert(
+            name.to_string(),
+            StoredSecret {
+                value: self.vault.encrypt(value.as_bytes())?,
+                updated_at: Utc::now().to_rfc3339(),
+            },
+        );
+        Ok(self.save(&file)?)
+    }
+
+    fn delete(&self, name: &str) -> Result<bool, StorageError> {
+        let mut file = self.load()?;
+        if file.secrets.remove(name).is_none() {
+            return Ok(false);
+        }
+        self.save(&file)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn store(temp_dir: &TempDir) -> (EncryptedSecretStore, PassphraseVault) {
+        let vault = PassphraseVault::with_path(temp_dir.path().join("vault.json"));
+        vault.setup("correct horse").unwrap();
+        vault.unlock("correct horse", None).unwrap();
+        let store = EncryptedSecretStore::with_path(temp_dir.path().join(SECRETS_FILE_NAME), vault.clone());
+        (store, vault)
+    }
+
+    #[test]
+    fn test_values_are_encrypted_at_rest() {
+        let temp_dir = TempDir::new().unwrap();
+        let (store, _vault) = store(&temp_dir);
+        store.set("openai_key", "sk-live-123").unwrap();
+
+        let file = fs::read_to_string(temp_dir.path().join(SECRETS_FILE_NAME)).unwrap();
+        assert!(file.contains("openai_key"));
+        assert!(!file.contains("sk-live-123"));
+        assert_eq!(store.get("openai_key").unwrap().as_deref(), Some("sk-live-123"));
+        assert_eq!(store.get("missing").unwrap(), None);
+    }
+
+    #[test]
+    fn test_locked_vault_lists_but_does_not_reveal() {
+        let temp_dir = TempDir::new().unwrap();
+        let (store, vault) = store(&temp_dir);
+        store.set("signature", "Jane Doe, Acme").unwrap();
+        vault.lock();
+
+        let names: Vec<String> = store.list().unwrap().into_iter().map(|s| s.name).collect();
+        assert_eq!(names, vec!["signature"]);
+        assert_eq!(store.get("signature").unwrap_err().code(), "vault_locked");
+        assert!(store.set("other", "x").is_err());
+
+        assert!(store.delete("signature").unwrap());
+        assert!(!store.delete("signature").unwrap());
+    }
+}
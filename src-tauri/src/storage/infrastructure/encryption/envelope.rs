@@ -0,0 +1,254 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use zeroize::Zeroizing;
+
+// No "---" in the markers: the prompt file parser splits frontmatter on it
+const BEGIN_MARKER: &str = "===== BEGIN PROMPTER ENCRYPTED CONTENT =====";
+const END_MARKER: &str = "===== END PROMPTER ENCRYPTED CONTENT =====";
+const FORMAT_VERSION: u8 = 1;
+const LINE_WIDTH: usize = 64;
+
+pub const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const KEY_LEN: usize = 32;
+/// Version byte plus the three u32 Argon2 parameters
+const HEADER_LEN: usize = 1 + 12;
+
+/// Upper bounds on the cost parameters read from a file; prompts arrive through sync,
+/// import and restore, and must not be able to demand gigabytes of memory or minutes of work
+const MAX_MEMORY_KIB: u32 = 1024 * 1024;
+const MAX_ITERATIONS: u32 = 10;
+const MAX_PARALLELISM: u32 = 8;
+
+pub type Salt = [u8; SALT_LEN];
+pub type Key = Zeroizing<[u8; KEY_LEN]>;
+
+/// Argon2id cost parameters, stored in every envelope so they can be raised later
+/// without breaking prompts encrypted before
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KdfParams {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for KdfParams {
+    /// OWASP's recommended minimum for Argon2id
+    fn default() -> Self {
+        Self {
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+impl KdfParams {
+    fn within_limits(&self) -> bool {
+        self.memory_kib <= MAX_MEMORY_KIB && self.iterations <= MAX_ITERATIONS && self.parallelism <= MAX_PARALLELISM
+    }
+
+    /// Cheap parameters so tests do not spend seconds deriving keys
+    #[cfg(test)]
+    pub fn fast() -> Self {
+        Self {
+            memory_kib: 8,
+            iterations: 1,
+            parallelism: 1,
+        }
+    }
+}
+
+/// Derive the 256-bit content key for a passphrase
+pub fn derive_key(passphrase: &str, salt: &Salt, kdf: KdfParams) -> Result<Key, String> {
+    let params = Params::new(kdf.memory_kib, kdf.iterations, kdf.parallelism, Some(KEY_LEN))
+        .map_err(|e| format!("Invalid key derivation parameters: {}", e))?;
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, key.as_mut())
+        .map_err(|e| format!("Failed to derive key: {}", e))?;
+    Ok(key)
+}
+
+pub fn random_salt() -> Salt {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    salt
+}
+
+/// Encrypted text in the self-describing format stored in prompt files:
+/// base64 of `version | kdf params | salt | nonce | ciphertext` between armor lines
+/// The header is authenticated along with the ciphertext
+#[derive(Debug, Clone, PartialEq)]
+pub struct Envelope {
+    pub kdf: KdfParams,
+    pub salt: Salt,
+    nonce: [u8; NONCE_LEN],
+    ciphertext: Vec<u8>,
+}
+
+impl Envelope {
+    /// Encrypt with a fresh random nonce
+    pub fn seal(key: &Key, kdf: KdfParams, salt: Salt, plaintext: &[u8]) -> Result<Self, String> {
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+        let header = header(kdf, &salt);
+        let ciphertext = XChaCha20Poly1305::new(key.as_ref().into())
+            .encrypt(XNonce::from_slice(&nonce), Payload { msg: plaintext, aad: &header })
+            .map_err(|_| "Failed to encrypt content".to_string())?;
+        Ok(Self {
+            kdf,
+            salt,
+            nonce,
+            ciphertext,
+        })
+    }
+
+    pub fn open(&self, key: &Key) -> Result<Zeroizing<Vec<u8>>, String> {
+        let header = header(self.kdf, &self.salt);
+        XChaCha20Poly1305::new(key.as_ref().into())
+            .decrypt(
+                XNonce::from_slice(&self.nonce),
+                Payload {
+                    msg: &self.ciphertext,
+                    aad: &header,
+                },
+            )
+            .map(Zeroizing::new)
+            .map_err(|_| "Wrong passphrase or corrupted encrypted content".to_string())
+    }
+
+    /// True if the text looks like an armored envelope (it may still fail to parse)
+    pub fn is_armored(text: &str) -> bool {
+        text.trim_start().starts_with(BEGIN_MARKER)
+    }
+
+    pub fn armor(&self) -> String {
+        let mut bytes = header(self.kdf, &self.salt);
+        bytes.extend_from_slice(&self.nonce);
+        bytes.extend_from_slice(&self.ciphertext);
+        let encoded = BASE64.encode(bytes);
+
+        let mut armored = format!("{}\n", BEGIN_MARKER);
+        for line in encoded.as_bytes().chunks(LINE_WIDTH) {
+            armored.push_str(&String::from_utf8_lossy(line));
+            armored.push('\n');
+        }
+        armored.push_str(END_MARKER);
+        armored
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let body = text
+            .trim()
+            .strip_prefix(BEGIN_MARKER)
+            .and_then(|rest| rest.strip_suffix(END_MARKER))
+            .ok_or("Not an encrypted prompt block")?;
+        let encoded: String = body.split_whitespace().collect();
+        let bytes = BASE64
+            .decode(encoded)
+            .map_err(|e| format!("Invalid encrypted prompt block: {}", e))?;
+
+        // The tag alone is 16 bytes, so anything shorter cannot be valid
+        if bytes.len() < HEADER_LEN + SALT_LEN + NONCE_LEN + 16 {
+            return Err("Encrypted prompt block is truncated".to_string());
+        }
+        if bytes[0] != FORMAT_VERSION {
+            return Err(format!("Unsupported encrypted prompt format: v{}", bytes[0]));
+        }
+
+        let u32_at = |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        let kdf = KdfParams {
+            memory_kib: u32_at(1),
+            iterations: u32_at(5),
+            parallelism: u32_at(9),
+        };
+        if !kdf.within_limits() {
+            return Err(format!(
+                "Encrypted prompt block asks for excessive key derivation cost ({} KiB, {} iterations, {} lanes)",
+                kdf.memory_kib, kdf.iterations, kdf.parallelism
+            ));
+        }
+        let salt_end = HEADER_LEN + SALT_LEN;
+        let nonce_end = salt_end + NONCE_LEN;
+        Ok(Self {
+            kdf,
+            salt: bytes[HEADER_LEN..salt_end].try_into().unwrap(),
+            nonce: bytes[salt_end..nonce_end].try_into().unwrap(),
+            ciphertext: bytes[nonce_end..].to_vec(),
+        })
+    }
+}
+
+fn header(kdf: KdfParams, salt: &Salt) -> Vec<u8> {
+    let mut header = Vec::with_capacity(HEADER_LEN + SALT_LEN);
+    header.push(FORMAT_VERSION);
+    header.extend_from_slice(&kdf.memory_kib.to_le_bytes());
+    header.extend_from_slice(&kdf.iterations.to_le_bytes());
+    header.extend_from_slice(&kdf.parallelism.to_le_bytes());
+    header.extend_from_slice(salt);
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sealed(passphrase: &str, plaintext: &str) -> Envelope {
+        let salt = random_salt();
+        let key = derive_key(passphrase, &salt, KdfParams::fast()).unwrap();
+        Envelope::seal(&key, KdfParams::fast(), salt, plaintext.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_armor_round_trip_decrypts_with_same_passphrase() {
+        let envelope = sealed("correct horse", "Review this diff for secrets");
+        let armored = envelope.armor();
+        assert!(Envelope::is_armored(&armored));
+        assert!(!armored.contains("secrets"));
+
+        let parsed = Envelope::parse(&armored).unwrap();
+        assert_eq!(parsed, envelope);
+        let key = derive_key("correct horse", &parsed.salt, parsed.kdf).unwrap();
+        assert_eq!(parsed.open(&key).unwrap().as_slice(), b"Review this diff for secrets");
+    }
+
+    #[test]
+    fn test_wrong_passphrase_or_tampering_fails() {
+        let envelope = sealed("correct horse", "secret");
+        let wrong = derive_key("battery staple", &envelope.salt, envelope.kdf).unwrap();
+        assert!(envelope.open(&wrong).is_err());
+
+        // Changing the stored cost parameters breaks authentication too
+        let mut tampered = envelope.clone();
+        tampered.kdf.iterations += 1;
+        let key = derive_key("correct horse", &envelope.salt, envelope.kdf).unwrap();
+        assert!(tampered.open(&key).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_excessive_kdf_cost() {
+        let mut envelope = sealed("correct horse", "secret");
+        envelope.kdf.memory_kib = MAX_MEMORY_KIB + 1;
+        assert!(Envelope::parse(&envelope.armor()).is_err());
+
+        envelope.kdf = KdfParams { iterations: MAX_ITERATIONS + 1, ..KdfParams::fast() };
+        assert!(Envelope::parse(&envelope.armor()).is_err());
+
+        envelope.kdf = KdfParams { parallelism: MAX_PARALLELISM + 1, ..KdfParams::fast() };
+        assert!(Envelope::parse(&envelope.armor()).is_err());
+
+        assert!(Envelope::parse(&sealed("correct horse", "secret").armor()).is_ok());
+    }
+
+    #[test]
+    fn test_parse_rejects_plain_text() {
+        assert!(!Envelope::is_armored("Just a prompt"));
+        assert!(Envelope::parse("Just a prompt").is_err());
+        assert!(Envelope::parse(&format!("{}\nAAAA\n{}", BEGIN_MARKER, END_MARKER)).is_err());
+    }
+}
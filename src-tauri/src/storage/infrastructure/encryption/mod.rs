@@ -0,0 +1,8 @@
+// Encryption adapters for private prompts and secrets
+pub mod encrypted_secret_store;
+pub mod envelope;
+pub mod passphrase_vault;
+
+pub use encrypted_secret_store::EncryptedSecretStore;
+pub use envelope::Envelope;
+pub use passphrase_vault::PassphraseVault;
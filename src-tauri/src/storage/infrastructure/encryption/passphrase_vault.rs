@@ -0,0 +1,331 @@
+use crate::storage::domain::entities::VaultStatus;
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::PromptVault;
+use crate::storage::infrastructure::encryption::envelope::{derive_key, random_salt, Envelope, KdfParams, Key, Salt};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use zeroize::Zeroizing;
+
+const VAULT_FILE_NAME: &str = "vault.json";
+const MIN_PASSPHRASE_LEN: usize = 8;
+/// Known plaintext encrypted at setup; decrypting it proves a passphrase is right
+const VERIFIER: &[u8] = b"prompter-vault-v1";
+
+fn locked() -> StorageError {
+    StorageError::VaultLocked("Private prompts are locked".to_string())
+}
+
+/// Vault settings stored in the data directory (~/.prompter/vault.json)
+/// Holds no secrets: the verifier is only useful to someone who knows the passphrase
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct VaultFile {
+    /// Armored envelope of VERIFIER; its salt and cost parameters are used for new prompts
+    #[serde(default)]
+    verifier: Option<String>,
+    #[serde(default)]
+    private_folders: Vec<String>,
+}
+
+/// What an unlocked vault remembers; lives only in memory
+#[derive(Default)]
+struct Session {
+    passphrase: Option<Zeroizing<String>>,
+    /// Salt and cost parameters of this machine's vault, used for new envelopes
+    own: Option<(KdfParams, Salt)>,
+    /// Keys derived so far (prompts synced from another device carry that device's salt)
+    keys: HashMap<(KdfParams, Salt), Key>,
+    auto_lock: Option<Duration>,
+    last_used: Option<Instant>,
+}
+
+impl Session {
+    fn lock(&mut self) {
+        // Zeroizing wrappers wipe the passphrase and keys as they are dropped
+        *self = Self::default();
+    }
+
+    /// Business rule: an unlocked vault left unused for the auto-lock timeout locks itself
+    fn expire(&mut self) {
+        let idle = self.last_used.map(|t| t.elapsed()).unwrap_or_default();
+        if self.passphrase.is_some() && self.auto_lock.is_some_and(|timeout| idle >= timeout) {
+            log::info!("[PassphraseVault] Locked after {} idle seconds", idle.as_secs());
+            self.lock();
+        }
+    }
+}
+
+/// The app-wide session, so every repository instance sees the same lock state
+static SESSION: Lazy<Arc<Mutex<Session>>> = Lazy::new(|| Arc::new(Mutex::new(Session::default())));
+
+/// Passphrase-based vault adapter (implements PromptVault trait)
+/// Keys are derived with Argon2id and content is encrypted with XChaCha20-Poly1305
+/// Clones share the same session
+#[derive(Clone)]
+pub struct PassphraseVault {
+    path: PathBuf,
+    session: Arc<Mutex<Session>>,
+    /// Cost parameters for a newly set passphrase
+    kdf: KdfParams,
+}
+
+impl PassphraseVault {
+    pub fn new() -> Result<Self, String> {
+        let home = dirs::home_dir().ok_or("Could not determine home directory")?;
+        Ok(Self {
+            path: home.join(".prompter").join(VAULT_FILE_NAME),
+            session: SESSION.clone(),
+            kdf: KdfParams::default(),
+        })
+    }
+
+    /// Create a vault with its own settings file and session (for testing)
+    #[cfg(test)]
+    pub fn with_path(path: PathBuf) -> Self {
+        Self {
+            path,
+            session: Arc::new(Mutex::new(Session::default())),
+            kdf: KdfParams::fast(),
+        }
+    }
+
+    pub fn status(&self) -> Result<VaultStatus, StorageError> {
+        let file = self.load()?;
+        Ok(VaultStatus {
+            configured: file.verifier.is_some(),
+            unlocked: self.is_unlocked(),
+            private_folders: file.private_folders,
+        })
+    }
+
+    /// Set the passphrase for the first time
+    /// There is no recovery: prompts encrypted with a forgotten passphrase stay locked
+    pub fn setup(&self, passphrase: &str) -> Result<(), StorageError> {
+        let mut file = self.load()?;
+        if file.verifier.is_some() {
+            return Err(StorageError::InvalidInput("A vault passphrase is already set".to_string()));
+        }
+        if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+            return Err(StorageError::InvalidInput(format!(
+                "Passphrase must be at least {} characters",
+                MIN_PASSPHRASE_LEN
+            )));
+        }
+
+        let salt = random_salt();
+        let key = derive_key(passphrase, &salt, self.kdf).map_err(StorageError::Encryption)?;
+        let verifier = Envelope::seal(&key, self.kdf, salt, VERIFIER).map_err(StorageError::Encryption)?;
+        file.verifier = Some(verifier.armor());
+        self.save(&file)?;
+        log::info!("[PassphraseVault] Vault passphrase set");
+        Ok(())
+    }
+
+    /// Check the passphrase and keep it in memory until locked
+    /// `auto_lock` of None keeps the vault unlocked until `lock` or the app exits
+    pub fn unlock(&self, passphrase: &str, auto_lock: Option<Duration>) -> Result<(), StorageError> {
+        let verifier = self
+            .load()?
+            .verifier
+            .ok_or_else(|| StorageError::InvalidInput("No vault passphrase has been set".to_string()))?;
+        let envelope = Envelope::parse(&verifier).map_err(StorageError::Encryption)?;
+        let key = derive_key(passphrase, &envelope.salt, envelope.kdf).map_err(StorageError::Encryption)?;
+        if envelope.open(&key).ok().as_deref().map(|v| v.as_slice()) != Some(VERIFIER) {
+            return Err(StorageError::WrongPassphrase);
+        }
+
+        let mut session = self.session();
+        session.lock();
+        session.passphrase = Some(Zeroizing::new(passphrase.to_string()));
+        session.own = Some((envelope.kdf, envelope.salt));
+        session.keys.insert((envelope.kdf, envelope.salt), key);
+        session.auto_lock = auto_lock;
+        session.last_used = Some(Instant::now());
+        log::info!("[PassphraseVault] Unlocked");
+        Ok(())
+    }
+
+    pub fn lock(&self) {
+        self.session().lock();
+        log::info!("[PassphraseVault] Locked");
+    }
+
+    /// Mark a folder (and its subfolders) as private, or stop doing so
+    /// Only affects where new saves are encrypted; existing prompts keep their setting
+    pub fn set_folder_private(&self, folder: &str, private: bool) -> Result<(), StorageError> {
+        let folder = folder.trim_matches('/').to_string();
+        if folder.is_empty() {
+            return Err(StorageError::InvalidInput("Folder name cannot be empty".to_string()));
+        }
+        let mut file = self.load()?;
+        file.private_folders.retain(|f| *f != folder);
+        if private {
+            file.private_folders.push(folder);
+            file.private_folders.sort();
+        }
+        Ok(self.save(&file)?)
+    }
+
+    fn session(&self) -> MutexGuard<'_, Session> {
+        let mut session = self.session.lock().unwrap();
+        session.expire();
+        session
+    }
+
+    /// The key for an envelope's salt and cost parameters, derived on first use
+    /// Argon2 is slow by design, so it runs without holding the session lock
+    fn key(&self, kdf: KdfParams, salt: Salt) -> Result<Key, StorageError> {
+        let passphrase = {
+            let mut session = self.session();
+            let passphrase = session.passphrase.clone().ok_or_else(locked)?;
+            session.last_used = Some(Instant::now());
+            if let Some(key) = session.keys.get(&(kdf, salt)) {
+                return Ok(key.clone());
+            }
+            passphrase
+        };
+
+        let key = derive_key(&passphrase, &salt, kdf).map_err(StorageError::Encryption)?;
+
+        // The vault may have been locked, or unlocked with another passphrase, meanwhile
+        let mut session = self.session();
+        if session.passphrase.as_ref().map(|p| p.as_str()) != Some(passphrase.as_str()) {
+            return Err(locked());
+        }
+        session.keys.insert((kdf, salt), key.clone());
+        Ok(key)
+    }
+
+    fn load(&self) -> Result<VaultFile, String> {
+        if !self.path.exists() {
+            return Ok(VaultFile::default());
+        }
+        let content = fs::read_to_string(&self.path)
+            .map_err(|e| format!("Failed to read vault settings: {}", e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse vault settings: {}", e))
+    }
+
+    fn save(&self, file: &VaultFile) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create data directory: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(file)
+            .map_err(|e| format!("Failed to serialize vault settings: {}", e))?;
+
+        // Write then rename so an interrupted save never loses the verifier
+        let temp_path = self.path.with_extension("json.tmp");
+        fs::write(&temp_path, content)
+            .map_err(|e| format!("Failed to write vault settings: {}", e))?;
+        fs::rename(&temp_path, &self.path)
+            .map_err(|e| format!("Failed to write vault settings: {}", e))
+    }
+}
+
+impl PromptVault for PassphraseVault {
+    fn is_unlocked(&self) -> bool {
+        self.session().passphrase.is_some()
+    }
+
+    fn is_private_folder(&self, folder: &str) -> bool {
+        let folder = folder.trim_matches('/');
+        self.load()
+            .map(|file| {
+                file.private_folders.iter().any(|private| {
+                    folder == private || folder.strip_prefix(private.as_str()).is_some_and(|rest| rest.starts_with('/'))
+                })
+            })
+            .unwrap_or(false)
+    }
+
+    fn encrypt(&self, plaintext: &[u8]) -> Result<String, StorageError> {
+        let (kdf, salt) = self.session().own.ok_or_else(locked)?;
+        let key = self.key(kdf, salt)?;
+        Ok(Envelope::seal(&key, kdf, salt, plaintext).map_err(StorageError::Encryption)?.armor())
+    }
+
+    fn decrypt(&self, sealed: &str) -> Result<Vec<u8>, StorageError> {
+        let envelope = Envelope::parse(sealed).map_err(StorageError::Encryption)?;
+        let key = self.key(envelope.kdf, envelope.salt)?;
+        envelope
+            .open(&key)
+            .map(|plaintext| plaintext.to_vec())
+            .map_err(StorageError::Encryption)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn vault(temp_dir: &TempDir) -> PassphraseVault {
+        PassphraseVault::with_path(temp_dir.path().join(VAULT_FILE_NAME))
+    }
+
+    #[test]
+    fn test_unlock_checks_passphrase() {
+        let temp_dir = TempDir::new().unwrap();
+        let vault = vault(&temp_dir);
+        assert!(vault.unlock("correct horse", None).is_err());
+        assert!(vault.setup("short").is_err());
+
+        vault.setup("correct horse").unwrap();
+        assert!(vault.setup("another one").is_err());
+        assert_eq!(vault.unlock("battery staple", None).unwrap_err().code(), "wrong_passphrase");
+        assert!(!vault.is_unlocked());
+
+        vault.unlock("correct horse", None).unwrap();
+        assert!(vault.status().unwrap().unlocked);
+        vault.lock();
+        assert!(vault.encrypt(b"secret").is_err());
+    }
+
+    #[test]
+    fn test_encrypted_content_opens_on_another_device_with_same_passphrase() {
+        let temp_dir = TempDir::new().unwrap();
+        let laptop = PassphraseVault::with_path(temp_dir.path().join("laptop.json"));
+        let desktop = PassphraseVault::with_path(temp_dir.path().join("desktop.json"));
+        for device in [&laptop, &desktop] {
+            device.setup("correct horse").unwrap();
+            device.unlock("correct horse", None).unwrap();
+        }
+
+        let sealed = laptop.encrypt(b"Review this diff").unwrap();
+        assert_eq!(desktop.decrypt(&sealed).unwrap(), b"Review this diff");
+        desktop.lock();
+        assert!(desktop.decrypt(&sealed).is_err());
+    }
+
+    #[test]
+    fn test_auto_lock_after_idle_timeout() {
+        let temp_dir = TempDir::new().unwrap();
+        let vault = vault(&temp_dir);
+        vault.setup("correct horse").unwrap();
+        vault.unlock("correct horse", Some(Duration::from_millis(50))).unwrap();
+        assert!(vault.is_unlocked());
+
+        std::thread::sleep(Duration::from_millis(80));
+        assert!(!vault.is_unlocked());
+    }
+
+    #[test]
+    fn test_private_folders_include_subfolders() {
+        let temp_dir = TempDir::new().unwrap();
+        let vault = vault(&temp_dir);
+        vault.set_folder_private("Clients/", true).unwrap();
+
+        assert!(vault.is_private_folder("Clients"));
+        assert!(vault.is_private_folder("Clients/Acme"));
+        assert!(!vault.is_private_folder("ClientsArchive"));
+        assert_eq!(vault.status().unwrap().private_folders, vec!["Clients"]);
+
+        vault.set_folder_private("Clients", false).unwrap();
+        assert!(!vault.is_private_folder("Clients/Acme"));
+    }
+}
@@ -0,0 +1,253 @@
+use crate::logging::redact;
+use crate::storage::application::services::VERSIONS_DIR_NAME;
+use crate::storage::domain::entities::{ExportBundle, ExportFormat, ExportSummary, ExportWarning, ExportedUsage, Prompt};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::infrastructure::export::{espanso_exporter, jetbrains_exporter, vscode_exporter};
+use crate::storage::infrastructure::persistence::YamlParser;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Bundle metadata written as `manifest.json` at the root of zip exports
+#[derive(Serialize)]
+struct ZipManifest<'a> {
+    schema_version: u32,
+    exported_at: &'a str,
+    prompts: usize,
+}
+
+/// Writes export bundles as a zip of .md files, a JSON or YAML bundle, or a snippet file
+pub struct BundleWriter {
+    parser: YamlParser,
+}
+
+impl BundleWriter {
+    pub fn new() -> Self {
+        Self {
+            parser: YamlParser::new(),
+        }
+    }
+
+    /// Write `bundle` to `path`, replacing any existing file
+    /// Snippet formats drop versions and usage and report what else they could not represent
+    pub fn write(&self, bundle: &ExportBundle, format: ExportFormat, path: &Path) -> Result<ExportSummary, StorageError> {
+        let prompts: Vec<Prompt> = bundle.prompts.iter().map(|p| p.prompt.clone()).collect();
+        let (bytes, warnings) = match format {
+            ExportFormat::Zip => (self.to_zip(bundle)?, Vec::new()),
+            ExportFormat::Json => (
+                serde_json::to_vec_pretty(bundle).map_err(|e| format!("Failed to serialize export: {}", e))?,
+                Vec::new(),
+            ),
+            ExportFormat::Yaml => (
+                serde_yaml::to_string(bundle)
+                    .map_err(|e| format!("Failed to serialize export: {}", e))?
+                    .into_bytes(),
+                Vec::new(),
+            ),
+            ExportFormat::VsCode => text_export(vscode_exporter::export(&prompts)?),
+            ExportFormat::JetBrains => text_export(jetbrains_exporter::export(&prompts)?),
+            ExportFormat::Espanso => text_export(espanso_exporter::export(&prompts)?),
+        };
+
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| StorageError::io(parent, e))?;
+        }
+        fs::write(path, &bytes).map_err(|e| StorageError::io(path, e))?;
+
+        log::info!(
+            "[BundleWriter] Exported {} prompts to {} ({} bytes)",
+            bundle.prompts.len(),
+            path.display(),
+            bytes.len()
+        );
+        for warning in &warnings {
+            log::warn!("[BundleWriter] {}: {}", redact(&warning.prompt_id), warning.message);
+        }
+
+        let versions = if format.is_snippet_format() {
+            0
+        } else {
+            bundle.prompts.iter().map(|p| p.versions.len()).sum()
+        };
+        Ok(ExportSummary {
+            path: path.display().to_string(),
+            format,
+            prompts: bundle.prompts.len(),
+            versions,
+            size_bytes: bytes.len() as u64,
+            warnings,
+        })
+    }
+
+    /// Same layout as the prompts directory: `<id>.md`, `.versions/<id>/<n>.json`, plus usage.json
+    fn to_zip(&self, bundle: &ExportBundle) -> Result<Vec<u8>, String> {
+        let mut zip = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        let mut add = |name: &str, content: &[u8]| -> Result<(), String> {
+            zip.start_file(name, options)
+                .map_err(|e| format!("Failed to add {} to export: {}", name, e))?;
+            zip.write_all(content)
+                .map_err(|e| format!("Failed to write {} to export: {}", name, e))
+        };
+
+        let manifest = ZipManifest {
+            schema_version: bundle.schema_version,
+            exported_at: &bundle.exported_at,
+            prompts: bundle.prompts.len(),
+        };
+        add("manifest.json", &to_json(&manifest)?)?;
+
+        let mut usage: BTreeMap<&str, &ExportedUsage> = BTreeMap::new();
+        for exported in &bundle.prompts {
+            let id = &exported.prompt.id;
+            add(id, self.parser.serialize(&exported.prompt)?.as_bytes())?;
+
+            for (index, version) in exported.versions.iter().enumerate() {
+                let name = version
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+                    .unwrap_or_else(|| (index + 1).to_string());
+                add(&format!("{}/{}/{}.json", VERSIONS_DIR_NAME, id, name), &to_json(version)?)?;
+            }
+
+            if let Some(stats) = &exported.usage {
+                usage.insert(id, stats);
+            }
+        }
+        if !usage.is_empty() {
+            add("usage.json", &to_json(&usage)?)?;
+        }
+
+        let cursor = zip.finish()
+            .map_err(|e| format!("Failed to finish export archive: {}", e))?;
+        Ok(cursor.into_inner())
+    }
+}
+
+impl Default for BundleWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn text_export((text, warnings): (String, Vec<ExportWarning>)) -> (Vec<u8>, Vec<ExportWarning>) {
+    (text.into_bytes(), warnings)
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<Vec<u8>, String> {
+    serde_json::to_vec_pretty(value).map_err(|e| format!("Failed to serialize export: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::domain::entities::{ExportedPrompt, Prompt, EXPORT_SCHEMA_VERSION};
+    use crate::storage::domain::ports::ImportSource;
+    use crate::storage::infrastructure::import::MarkdownImportSource;
+    use std::io::Read;
+    use tempfile::TempDir;
+    use zip::ZipArchive;
+
+    fn create_bundle() -> ExportBundle {
+        let prompt = Prompt {
+            name: "Review".to_string(),
+            content: "Review {{code}}".to_string(),
+            folder: "Coding".to_string(),
+            ..Prompt::for_test("Coding/review.md")
+        };
+        ExportBundle {
+            schema_version: EXPORT_SCHEMA_VERSION,
+            exported_at: "2025-02-01T00:00:00Z".to_string(),
+            prompts: vec![ExportedPrompt {
+                prompt,
+                versions: vec![serde_json::json!({"id": "v1", "version_number": 1, "content": "old"})],
+                usage: Some(ExportedUsage {
+                    use_count: 3,
+                    last_used: "2025-01-15T00:00:00Z".to_string(),
+                }),
+            }],
+        }
+    }
+
+    fn zip_entries(path: &Path) -> BTreeMap<String, String> {
+        let mut archive = ZipArchive::new(fs::File::open(path).unwrap()).unwrap();
+        (0..archive.len())
+            .map(|i| {
+                let mut entry = archive.by_index(i).unwrap();
+                let mut content = String::new();
+                entry.read_to_string(&mut content).unwrap();
+                (entry.name().to_string(), content)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_zip_export_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("pack.zip");
+
+        let summary = BundleWriter::new().write(&create_bundle(), ExportFormat::Zip, &path).unwrap();
+        assert_eq!(summary.prompts, 1);
+        assert_eq!(summary.versions, 1);
+
+        let entries = zip_entries(&path);
+        let names: Vec<&str> = entries.keys().map(|k| k.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![".versions/Coding/review.md/v1.json", "Coding/review.md", "manifest.json", "usage.json"]
+        );
+        assert!(entries["Coding/review.md"].contains("Review {{code}}"));
+        assert!(entries["usage.json"].contains("\"use_count\": 3"));
+    }
+
+    #[test]
+    fn test_zip_export_can_be_imported() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("pack.zip");
+        BundleWriter::new().write(&create_bundle(), ExportFormat::Zip, &path).unwrap();
+
+        let batch = MarkdownImportSource::new(path).load().unwrap();
+        assert!(batch.errors.is_empty());
+        assert_eq!(batch.prompts[0].id, "Coding/review.md");
+        assert_eq!(batch.prompts[0].content, "Review {{code}}");
+    }
+
+    #[test]
+    fn test_json_and_yaml_bundles_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let json_path = temp_dir.path().join("pack.json");
+        let yaml_path = temp_dir.path().join("pack.yaml");
+        let writer = BundleWriter::new();
+        writer.write(&create_bundle(), ExportFormat::Json, &json_path).unwrap();
+        writer.write(&create_bundle(), ExportFormat::Yaml, &yaml_path).unwrap();
+
+        let from_json: ExportBundle = serde_json::from_str(&fs::read_to_string(&json_path).unwrap()).unwrap();
+        let from_yaml: ExportBundle = serde_yaml::from_str(&fs::read_to_string(&yaml_path).unwrap()).unwrap();
+        for bundle in [from_json, from_yaml] {
+            assert_eq!(bundle.schema_version, EXPORT_SCHEMA_VERSION);
+            assert_eq!(bundle.prompts[0].prompt.content, "Review {{code}}");
+            assert_eq!(bundle.prompts[0].versions.len(), 1);
+            assert_eq!(bundle.prompts[0].usage.as_ref().unwrap().use_count, 3);
+        }
+    }
+
+    #[test]
+    fn test_snippet_export_drops_history_and_reports_warnings() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("prompter.code-snippets");
+        let mut bundle = create_bundle();
+        bundle.prompts[0].prompt.apps = vec!["code".to_string()];
+
+        let summary = BundleWriter::new().write(&bundle, ExportFormat::VsCode, &path).unwrap();
+        assert_eq!(summary.prompts, 1);
+        assert_eq!(summary.versions, 0);
+        assert_eq!(summary.warnings.len(), 1);
+        assert_eq!(summary.warnings[0].prompt_id, "Coding/review.md");
+        assert!(fs::read_to_string(&path).unwrap().contains("Review ${1}"));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::storage::domain::entities::{ContentSegment, ExportWarning, Prompt};
+use crate::storage::infrastructure::export::snippet::{common_warnings, secret_reference, snippet_variables, warning, UniqueNames};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Serialize)]
+struct EspansoFile {
+    matches: Vec<EspansoMatch>,
+}
+
+#[derive(Serialize)]
+struct EspansoMatch {
+    trigger: String,
+    label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    form: Option<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    form_fields: BTreeMap<String, EspansoField>,
+}
+
+#[derive(Serialize)]
+struct EspansoField {
+    default: String,
+}
+
+/// Render prompts as an Espanso match file (`match/prompter.yml`)
+/// Prompts with variables become forms with `[[name]]` fields; others a plain `replace`
+pub fn export(prompts: &[Prompt]) -> Result<(String, Vec<ExportWarning>), String> {
+    let mut matches = Vec::with_capacity(prompts.len());
+    let mut warnings = Vec::new();
+    let mut triggers = UniqueNames::default();
+
+    for prompt in prompts {
+        warnings.extend(common_warnings(prompt, "Espanso"));
+        let variables = snippet_variables(prompt);
+        let trigger = format!(":{}", triggers.trigger(prompt));
+
+        if variables.is_empty() {
+            matches.push(EspansoMatch {
+                trigger,
+                label: prompt.name.clone(),
+                replace: Some(prompt.content.clone()),
+                form: None,
+                form_fields: BTreeMap::new(),
+            });
+            continue;
+        }
+
+        let mut form = String::new();
+        for segment in prompt.content_segments() {
+            match segment {
+                ContentSegment::Text(text) => {
+                    if text.contains("[[") || text.contains("]]") {
+                        warnings.push(warning(prompt, "Literal '[[' or ']]' is read as a form field by Espanso".to_string()));
+                    }
+                    form.push_str(text);
+                }
+                ContentSegment::Placeholder(name) => form.push_str(&format!("[[{}]]", name)),
+                ContentSegment::Secret(name) => form.push_str(&secret_reference(name)),
+            }
+        }
+
+        matches.push(EspansoMatch {
+            trigger,
+            label: prompt.name.clone(),
+            replace: None,
+            form: Some(form),
+            form_fields: variables
+                .into_iter()
+                .filter(|v| !v.default.is_empty())
+                .map(|v| (v.name, EspansoField { default: v.default }))
+                .collect(),
+        });
+    }
+
+    let yaml = serde_yaml::to_string(&EspansoFile { matches })
+        .map_err(|e| format!("Failed to serialize Espanso matches: {}", e))?;
+    Ok((yaml, warnings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::domain::entities::Variable;
+    use crate::storage::domain::ports::ImportSource;
+    use crate::storage::infrastructure::import::EspansoImportSource;
+    use tempfile::TempDir;
+
+    fn create_prompt(name: &str, content: &str, variables: Vec<Variable>) -> Prompt {
+        Prompt {
+            name: name.to_string(),
+            content: content.to_string(),
+            folder: "Writing".to_string(),
+            variables,
+            created_at: String::new(),
+            updated_at: String::new(),
+            ..Prompt::for_test("Writing/reply.md")
+        }
+    }
+
+    #[test]
+    fn test_export_replace_and_form_matches() {
+        let prompts = vec![
+            create_prompt("Sign off", "Best regards", vec![]),
+            create_prompt(
+                "Reply",
+                "Hi {{name}}, thanks for {{topic}}",
+                vec![Variable {
+                    name: "topic".to_string(),
+                    default: "your note".to_string(),
+                    required: false,
+                }],
+            ),
+            create_prompt("Reply", "Hello", vec![]),
+        ];
+        let (yaml, warnings) = export(&prompts).unwrap();
+        assert!(warnings.is_empty());
+
+        let value: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        let matches = value["matches"].as_sequence().unwrap();
+        assert_eq!(matches[0]["trigger"].as_str(), Some(":sign-off"));
+        assert_eq!(matches[0]["replace"].as_str(), Some("Best regards"));
+        assert_eq!(matches[1]["form"].as_str(), Some("Hi [[name]], thanks for [[topic]]"));
+        assert_eq!(matches[1]["form_fields"]["topic"]["default"].as_str(), Some("your note"));
+        assert_eq!(matches[2]["trigger"].as_str(), Some(":reply-2"));
+    }
+
+    #[test]
+    fn test_warns_about_literal_form_brackets() {
+        let prompt = create_prompt("List", "[[x]] means {{item}}", vec![]);
+        let (_, warnings) = export(&[prompt]).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("form field"));
+    }
+
+    #[test]
+    fn test_round_trips_through_importer() {
+        let prompt = create_prompt("Reply", "Hi {{name}}", vec![]);
+        let (yaml, _) = export(&[prompt]).unwrap();
+
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("base.yml"), yaml).unwrap();
+        let batch = EspansoImportSource::new(temp_dir.path().to_path_buf()).load().unwrap();
+
+        assert_eq!(batch.prompts[0].name, "Reply");
+        assert_eq!(batch.prompts[0].content, "Hi {{name}}");
+    }
+}
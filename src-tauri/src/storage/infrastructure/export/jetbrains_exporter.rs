@@ -0,0 +1,155 @@
+use crate::storage::domain::entities::{ContentSegment, ExportWarning, Prompt};
+use crate::storage::infrastructure::export::snippet::{common_warnings, secret_reference, snippet_variables, warning, UniqueNames};
+
+/// Template group the exported live templates appear under in the IDE
+const TEMPLATE_GROUP: &str = "Prompter";
+
+/// Variables the IDE predefines; a prompt variable with one of these names is renamed
+const RESERVED_VARIABLES: &[&str] = &["END", "SELECTION"];
+
+/// Render prompts as a JetBrains live templates XML file (`templates/Prompter.xml`)
+/// Variables become `$NAME$` with the default as a string expression; `$` in text is doubled
+pub fn export(prompts: &[Prompt]) -> Result<(String, Vec<ExportWarning>), String> {
+    let mut xml = format!("<templateSet group=\"{}\">\n", TEMPLATE_GROUP);
+    let mut warnings = Vec::new();
+    let mut names = UniqueNames::default();
+
+    for prompt in prompts {
+        warnings.extend(common_warnings(prompt, "JetBrains live templates"));
+
+        let placeholders = snippet_variables(prompt);
+        let variables: Vec<(String, &str)> = placeholders
+            .iter()
+            .map(|v| {
+                let renamed = template_variable_name(&v.name);
+                if renamed != v.name {
+                    warnings.push(warning(prompt, format!("Variable '{}' renamed to '{}'", v.name, renamed)));
+                }
+                (renamed, v.default.as_str())
+            })
+            .collect();
+
+        let mut value = String::new();
+        for segment in prompt.content_segments() {
+            match segment {
+                ContentSegment::Text(text) => value.push_str(&text.replace('$', "$$")),
+                ContentSegment::Secret(name) => value.push_str(&secret_reference(name)),
+                ContentSegment::Placeholder(name) => {
+                    if let Some(index) = placeholders.iter().position(|v| v.name == name) {
+                        value.push_str(&format!("${}$", variables[index].0));
+                    }
+                }
+            }
+        }
+
+        xml.push_str(&format!(
+            "  <template name=\"{}\" value=\"{}\" description=\"{}\" toReformat=\"false\" toShortenFQNames=\"true\">\n",
+            escape_xml(&names.trigger(prompt)),
+            escape_xml(&value),
+            escape_xml(if prompt.description.is_empty() { &prompt.name } else { &prompt.description })
+        ));
+        for (name, default) in &variables {
+            let default_value = if default.is_empty() { String::new() } else { string_expression(default) };
+            xml.push_str(&format!(
+                "    <variable name=\"{}\" expression=\"\" defaultValue=\"{}\" alwaysStopAt=\"true\" />\n",
+                escape_xml(name),
+                escape_xml(&default_value)
+            ));
+        }
+        xml.push_str("    <context>\n      <option name=\"OTHER\" value=\"true\" />\n    </context>\n  </template>\n");
+    }
+
+    xml.push_str("</templateSet>\n");
+    Ok((xml, warnings))
+}
+
+/// Live template variables are identifiers and must not shadow predefined ones
+fn template_variable_name(name: &str) -> String {
+    let mut renamed: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    if renamed.is_empty() || renamed.starts_with(|c: char| c.is_ascii_digit()) {
+        renamed.insert(0, '_');
+    }
+    if RESERVED_VARIABLES.contains(&renamed.to_uppercase().as_str()) {
+        renamed.push_str("_VALUE");
+    }
+    renamed
+}
+
+/// Default values are template expressions; literal text is a quoted string
+fn string_expression(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Escape for an XML attribute value; newlines are kept as character references
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::domain::entities::Variable;
+
+    fn create_prompt(name: &str, content: &str, variables: &[(&str, &str)]) -> Prompt {
+        Prompt {
+            name: name.to_string(),
+            content: content.to_string(),
+            folder: "Coding".to_string(),
+            variables: variables
+                .iter()
+                .map(|(name, default)| Variable {
+                    name: name.to_string(),
+                    default: default.to_string(),
+                    required: false,
+                })
+                .collect(),
+            created_at: String::new(),
+            updated_at: String::new(),
+            ..Prompt::for_test("Coding/review.md")
+        }
+    }
+
+    #[test]
+    fn test_export_template_with_variables() {
+        let prompt = create_prompt("Review", "Review <{{code}}>\nfor $5 \"now\"", &[("code", "say \"hi\"")]);
+        let (xml, warnings) = export(&[prompt]).unwrap();
+
+        assert!(warnings.is_empty());
+        assert!(xml.starts_with("<templateSet group=\"Prompter\">"));
+        assert!(xml.contains(
+            "<template name=\"review\" value=\"Review &lt;$code$&gt;&#10;for $$5 &quot;now&quot;\" description=\"Review\""
+        ));
+        assert!(xml.contains("<variable name=\"code\" expression=\"\" defaultValue=\"&quot;say \\&quot;hi\\&quot;&quot;\""));
+    }
+
+    #[test]
+    fn test_renames_invalid_and_reserved_variables() {
+        let prompt = create_prompt("Wrap", "{{selection}} and {{file.name}}", &[]);
+        let (xml, warnings) = export(&[prompt]).unwrap();
+
+        assert!(xml.contains("value=\"$selection_VALUE$ and $file_name$\""));
+        let messages: Vec<&str> = warnings.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Variable 'selection' renamed to 'selection_VALUE'",
+                "Variable 'file.name' renamed to 'file_name'",
+            ]
+        );
+    }
+}
@@ -0,0 +1,8 @@
+// Export adapters - write an ExportBundle to disk in one of the supported formats
+pub mod bundle_writer;
+pub mod espanso_exporter;
+pub mod jetbrains_exporter;
+pub mod snippet;
+pub mod vscode_exporter;
+
+pub use bundle_writer::BundleWriter;
@@ -0,0 +1,81 @@
+use crate::storage::domain::entities::{ExportWarning, Prompt, Variable, SECRET_PREFIX};
+use crate::storage::infrastructure::import::converter::slugify;
+use std::collections::HashSet;
+
+/// Variables in placeholder order, as snippet formats need them
+/// Undeclared placeholders get an empty default; unused declarations are dropped
+pub fn snippet_variables(prompt: &Prompt) -> Vec<Variable> {
+    prompt
+        .placeholders()
+        .into_iter()
+        .map(|name| {
+            prompt.variables.iter().find(|v| v.name == name).cloned().unwrap_or(Variable {
+                name,
+                default: String::new(),
+                required: false,
+            })
+        })
+        .collect()
+}
+
+/// Secret references are exported as written; the value never leaves the secrets store
+pub fn secret_reference(name: &str) -> String {
+    format!("{{{{{}{}}}}}", SECRET_PREFIX, name)
+}
+
+/// Warnings shared by all snippet formats: app pins, required variables and secrets have no equivalent
+pub fn common_warnings(prompt: &Prompt, format: &str) -> Vec<ExportWarning> {
+    let mut warnings = Vec::new();
+    if !prompt.apps.is_empty() {
+        warnings.push(warning(prompt, format!("App pins ({}) are not supported by {}", prompt.apps.join(", "), format)));
+    }
+    let secrets = prompt.secret_names();
+    if !secrets.is_empty() {
+        warnings.push(warning(
+            prompt,
+            format!("{} cannot fill secrets ({}); they are exported as references", format, secrets.join(", ")),
+        ));
+    }
+
+    let required: Vec<String> = snippet_variables(prompt)
+        .into_iter()
+        .filter(|v| v.required)
+        .map(|v| v.name)
+        .collect();
+    if !required.is_empty() {
+        warnings.push(warning(
+            prompt,
+            format!("{} cannot enforce required variables ({})", format, required.join(", ")),
+        ));
+    }
+    warnings
+}
+
+pub fn warning(prompt: &Prompt, message: String) -> ExportWarning {
+    ExportWarning {
+        prompt_id: prompt.id.clone(),
+        message,
+    }
+}
+
+/// Hands out unique snippet keys ("review", "review-2", ...)
+#[derive(Default)]
+pub struct UniqueNames {
+    used: HashSet<String>,
+}
+
+impl UniqueNames {
+    pub fn trigger(&mut self, prompt: &Prompt) -> String {
+        self.unique(&slugify(&prompt.name))
+    }
+
+    pub fn unique(&mut self, base: &str) -> String {
+        let mut name = base.to_string();
+        let mut counter = 2;
+        while !self.used.insert(name.to_lowercase()) {
+            name = format!("{}-{}", base, counter);
+            counter += 1;
+        }
+        name
+    }
+}
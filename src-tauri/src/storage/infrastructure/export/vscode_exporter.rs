@@ -0,0 +1,141 @@
+use crate::storage::domain::entities::{ContentSegment, ExportWarning, Prompt};
+use crate::storage::infrastructure::export::snippet::{common_warnings, secret_reference, snippet_variables, UniqueNames};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Serialize)]
+struct VsCodeSnippet {
+    prefix: String,
+    body: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    description: String,
+}
+
+/// Render prompts as a VS Code `.code-snippets` file
+/// Variables become tab stops `${1:default}`; repeated placeholders mirror the same tab stop
+pub fn export(prompts: &[Prompt]) -> Result<(String, Vec<ExportWarning>), String> {
+    let mut snippets = BTreeMap::new();
+    let mut warnings = Vec::new();
+    let mut names = UniqueNames::default();
+    let mut prefixes = UniqueNames::default();
+
+    for prompt in prompts {
+        warnings.extend(common_warnings(prompt, "VS Code snippets"));
+        let snippet = VsCodeSnippet {
+            prefix: prefixes.trigger(prompt),
+            body: body(prompt).split('\n').map(str::to_string).collect(),
+            description: prompt.description.clone(),
+        };
+        snippets.insert(names.unique(prompt.name.trim()), snippet);
+    }
+
+    let json = serde_json::to_string_pretty(&snippets)
+        .map_err(|e| format!("Failed to serialize VS Code snippets: {}", e))?;
+    Ok((json, warnings))
+}
+
+fn body(prompt: &Prompt) -> String {
+    let variables = snippet_variables(prompt);
+    let mut seen = vec![false; variables.len()];
+    let mut body = String::new();
+
+    for segment in prompt.content_segments() {
+        match segment {
+            ContentSegment::Text(text) => body.push_str(&escape(text)),
+            ContentSegment::Secret(name) => body.push_str(&escape(&secret_reference(name))),
+            ContentSegment::Placeholder(name) => {
+                let Some(index) = variables.iter().position(|v| v.name == name) else { continue };
+                let default = &variables[index].default;
+                if seen[index] || default.is_empty() {
+                    body.push_str(&format!("${{{}}}", index + 1));
+                } else {
+                    body.push_str(&format!("${{{}:{}}}", index + 1, escape(default).replace('}', "\\}")));
+                }
+                seen[index] = true;
+            }
+        }
+    }
+    body
+}
+
+/// `$` starts a tab stop and `\` escapes; both must be escaped in literal text
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('$', "\\$")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::domain::entities::Variable;
+    use crate::storage::domain::ports::ImportSource;
+    use crate::storage::infrastructure::import::VsCodeImportSource;
+    use tempfile::TempDir;
+
+    fn create_prompt(name: &str, content: &str) -> Prompt {
+        Prompt {
+            name: name.to_string(),
+            description: "Review a change".to_string(),
+            content: content.to_string(),
+            folder: "Coding".to_string(),
+            variables: vec![
+                Variable {
+                    name: "code".to_string(),
+                    default: "the diff".to_string(),
+                    required: false,
+                },
+                Variable {
+                    name: "lang".to_string(),
+                    default: String::new(),
+                    required: true,
+                },
+            ],
+            created_at: String::new(),
+            updated_at: String::new(),
+            ..Prompt::for_test("Coding/review.md")
+        }
+    }
+
+    #[test]
+    fn test_export_tab_stops_and_escapes() {
+        let prompt = create_prompt("Review code", "Review {{code}} in {{lang}} for ${{cost}}, then {{code}} again");
+        let (json, _) = export(&[prompt]).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let snippet = &value["Review code"];
+        assert_eq!(snippet["prefix"], "review-code");
+        assert_eq!(snippet["body"][0], "Review ${1:the diff} in ${2} for \\$${3}, then ${1} again");
+    }
+
+    #[test]
+    fn test_reports_unsupported_features() {
+        let mut prompt = create_prompt("Pinned", "Hello {{lang}}, key {{secret:api_key}}");
+        prompt.apps = vec!["code".to_string()];
+
+        let (json, warnings) = export(&[prompt]).unwrap();
+        let messages: Vec<&str> = warnings.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "App pins (code) are not supported by VS Code snippets",
+                "VS Code snippets cannot fill secrets (api_key); they are exported as references",
+                "VS Code snippets cannot enforce required variables (lang)",
+            ]
+        );
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["Pinned"]["body"][0], "Hello ${1}, key {{secret:api_key}}");
+    }
+
+    #[test]
+    fn test_round_trips_through_importer() {
+        let prompt = create_prompt("Review code", "Review {{code}}\nin {{lang}} for $5");
+        let (json, _) = export(&[prompt]).unwrap();
+
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("prompter.code-snippets"), json).unwrap();
+        let batch = VsCodeImportSource::new(temp_dir.path().to_path_buf()).load().unwrap();
+
+        let imported = &batch.prompts[0];
+        assert_eq!(imported.content, "Review {{field1}}\nin {{field2}} for $5");
+        assert_eq!(imported.variables[0].default, "the diff");
+    }
+}
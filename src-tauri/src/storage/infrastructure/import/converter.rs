@@ -0,0 +1,185 @@
+use crate::storage::domain::entities::{ImportBatch, ParseFailure, Prompt, Variable};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::infrastructure::import::source_files::{read_source_files, SourceFile};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Read every matching file under `path` and convert it with `convert`
+/// Shared by the foreign-format sources: handles UTF-8 decoding, per-file errors
+/// and filling missing timestamps from the file's modification time
+pub fn load_files<F>(path: &Path, extensions: &[&str], mut convert: F) -> Result<ImportBatch, StorageError>
+where
+    F: FnMut(&SourceFile, &str) -> Result<Vec<Prompt>, String>,
+{
+    let mut batch = ImportBatch::default();
+
+    for file in read_source_files(path, extensions)? {
+        match file.text().and_then(|text| convert(&file, text)) {
+            Ok(prompts) => {
+                let modified = file.modified.map(|m| m.to_rfc3339()).unwrap_or_default();
+                batch.prompts.extend(prompts.into_iter().map(|mut prompt| {
+                    if prompt.created_at.is_empty() {
+                        prompt.created_at = modified.clone();
+                    }
+                    if prompt.updated_at.is_empty() {
+                        prompt.updated_at = modified.clone();
+                    }
+                    prompt
+                }));
+            }
+            Err(message) => batch.errors.push(ParseFailure {
+                id: file.path.clone(),
+                message,
+                line: None,
+            }),
+        }
+    }
+
+    Ok(batch)
+}
+
+/// Build a prompt from converted content
+/// Placeholders not covered by `variables` are declared as required with no default
+pub fn build_prompt(id: String, name: &str, content: String, variables: Vec<Variable>, tags: Vec<String>) -> Prompt {
+    let mut prompt = Prompt {
+        id,
+        name: name.trim().to_string(),
+        description: String::new(),
+        content: content.trim().to_string(),
+        folder: String::new(),
+        icon: "file-text".to_string(),
+        color: Prompt::DEFAULT_COLOR.to_string(),
+        tags,
+        variables: Vec::new(),
+        auto_paste: true,
+        is_favorite: false,
+        created_at: String::new(),
+        updated_at: String::new(),
+        apps: Vec::new(),
+        private: false,
+        locked: false,
+        hotkey: None,
+        abbreviation: None,
+        paste_mode: None,
+        paste_format: None,
+        steps: Vec::new(),
+    };
+
+    // Only keep declarations that the content actually uses, in placeholder order
+    for name in prompt.placeholders() {
+        let variable = variables
+            .iter()
+            .find(|v| v.name == name)
+            .cloned()
+            .unwrap_or(Variable {
+                name,
+                default: String::new(),
+                required: true,
+            });
+        prompt.variables.push(variable);
+    }
+    prompt
+}
+
+/// Allocates unique, filename-safe prompt IDs within one import
+#[derive(Default)]
+pub struct IdAllocator {
+    used: HashSet<String>,
+}
+
+impl IdAllocator {
+    /// "Espanso", "Say hello!" -> "Espanso/say-hello.md" (then "say-hello-2.md", ...)
+    pub fn allocate(&mut self, folder: &str, name: &str) -> String {
+        let slug = slugify(name);
+        let prefix = if folder.is_empty() { String::new() } else { format!("{}/", folder) };
+
+        let mut id = format!("{}{}.md", prefix, slug);
+        let mut counter = 2;
+        while !self.used.insert(id.to_lowercase()) {
+            id = format!("{}{}-{}.md", prefix, slug, counter);
+            counter += 1;
+        }
+        id
+    }
+}
+
+/// Lowercase, '-'-separated file name stem ("prompt" if nothing is left)
+pub fn slugify(name: &str) -> String {
+    let slug = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+        .collect::<String>()
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        "prompt".to_string()
+    } else {
+        slug
+    }
+}
+
+/// Rewrite every `{{name}}` in `content` to `{{f(name)}}`
+pub fn map_placeholders(content: &str, f: impl Fn(&str) -> String) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else { break };
+        result.push_str(&rest[..start]);
+        result.push_str(&format!("{{{{{}}}}}", f(&rest[start + 2..start + 2 + end])));
+        rest = &rest[start + 2 + end + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Turn a placeholder label into a variable name usable in `{{name}}`
+pub fn variable_name(label: &str) -> String {
+    label
+        .trim()
+        .chars()
+        .map(|c| if c.is_whitespace() || c == '{' || c == '}' { '_' } else { c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_prompt_declares_missing_placeholders() {
+        let declared = vec![Variable {
+            name: "name".to_string(),
+            default: "Bob".to_string(),
+            required: false,
+        }];
+        let prompt = build_prompt(
+            "a.md".to_string(),
+            " Greet ",
+            "Hi {{name}} from {{city}}".to_string(),
+            declared,
+            vec![],
+        );
+
+        assert_eq!(prompt.name, "Greet");
+        let names: Vec<&str> = prompt.variables.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["name", "city"]);
+        assert_eq!(prompt.variables[0].default, "Bob");
+        assert!(prompt.variables[1].required);
+    }
+
+    #[test]
+    fn test_map_placeholders() {
+        let mapped = map_placeholders("{{form1.name}} and {{x}} {{open", |n| n.rsplit('.').next().unwrap().to_string());
+        assert_eq!(mapped, "{{name}} and {{x}} {{open");
+    }
+
+    #[test]
+    fn test_id_allocator_deduplicates() {
+        let mut ids = IdAllocator::default();
+        assert_eq!(ids.allocate("Espanso", "Say hello!"), "Espanso/say-hello.md");
+        assert_eq!(ids.allocate("Espanso", "say hello"), "Espanso/say-hello-2.md");
+        assert_eq!(ids.allocate("", "???"), "prompt.md");
+    }
+}
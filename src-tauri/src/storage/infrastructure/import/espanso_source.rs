@@ -0,0 +1,253 @@
+use crate::storage::domain::entities::{ImportBatch, Prompt, Variable};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::ImportSource;
+use crate::storage::infrastructure::import::converter::{
+    build_prompt, load_files, map_placeholders, variable_name, IdAllocator,
+};
+use serde_yaml::Value;
+use std::path::{Path, PathBuf};
+
+/// Imports Espanso match files (`match/*.yml`)
+/// `{{var}}` placeholders and `[[field]]` form fields become prompt variables
+pub struct EspansoImportSource {
+    path: PathBuf,
+}
+
+impl EspansoImportSource {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl ImportSource for EspansoImportSource {
+    fn load(&self) -> Result<ImportBatch, StorageError> {
+        let mut ids = IdAllocator::default();
+        let mut abbreviations = Vec::new();
+        load_files(&self.path, &["yml", "yaml"], |file, text| {
+            let stem = Path::new(&file.path).file_stem().and_then(|s| s.to_str()).unwrap_or("base");
+            let folder = if stem == "base" { "Espanso".to_string() } else { format!("Espanso/{}", stem) };
+            convert(text, &folder, &mut ids, &mut abbreviations)
+        })
+    }
+}
+
+/// `abbreviations` collects the triggers already given to earlier snippets in the batch
+fn convert(
+    text: &str,
+    folder: &str,
+    ids: &mut IdAllocator,
+    abbreviations: &mut Vec<String>,
+) -> Result<Vec<Prompt>, String> {
+    let root: Value = serde_yaml::from_str(text)
+        .map_err(|e| format!("Failed to parse Espanso YAML: {}", e))?;
+    let matches = match root.get("matches") {
+        Some(Value::Sequence(matches)) => matches.as_slice(),
+        // Config files and match files with only imports/global_vars have nothing to import
+        _ => &[],
+    };
+
+    Ok(matches
+        .iter()
+        .filter_map(|m| convert_match(m, folder, ids, abbreviations))
+        .collect())
+}
+
+/// Convert one match; image matches and matches without text are skipped
+fn convert_match(m: &Value, folder: &str, ids: &mut IdAllocator, abbreviations: &mut Vec<String>) -> Option<Prompt> {
+    let triggers: Vec<String> = match (m.get("trigger"), m.get("triggers")) {
+        (Some(trigger), _) => trigger.as_str().map(str::to_string).into_iter().collect(),
+        (None, Some(Value::Sequence(list))) => list.iter().filter_map(|t| t.as_str().map(str::to_string)).collect(),
+        _ => m.get("regex").and_then(Value::as_str).map(str::to_string).into_iter().collect(),
+    };
+
+    let mut variables = Vec::new();
+    let content = if let Some(form) = str_field(m, "form") {
+        variables.extend(form_field_variables(m.get("form_fields")));
+        form_to_placeholders(form)
+    } else {
+        let text = str_field(m, "replace")
+            .or_else(|| str_field(m, "markdown"))
+            .or_else(|| str_field(m, "html"))?;
+        if let Some(Value::Sequence(vars)) = m.get("vars") {
+            variables.extend(vars.iter().flat_map(var_variables));
+        }
+        // Form vars are referenced as {{form1.field}}; our variables are flat
+        map_placeholders(text, |name| variable_name(name.rsplit('.').next().unwrap_or(name)))
+    };
+    let content = content.replace("$|$", "");
+
+    let name = str_field(m, "label")
+        .map(str::to_string)
+        .or_else(|| triggers.first().map(|t| t.trim_start_matches(':').to_string()))
+        .unwrap_or_else(|| "Espanso snippet".to_string());
+
+    let id = ids.allocate(folder, &name);
+    let mut prompt = build_prompt(id, &name, content, variables, vec!["espanso".to_string()]);
+    if !triggers.is_empty() {
+        prompt.description = format!("Espanso trigger: {}", triggers.join(", "));
+    }
+    // A literal trigger becomes the abbreviation; regex triggers have no equivalent, and a
+    // trigger that is too short or clashes with an earlier one leaves the snippet without one
+    if m.get("regex").is_none() {
+        prompt.abbreviation = triggers
+            .first()
+            .filter(|t| Prompt::is_valid_abbreviation(t))
+            .filter(|t| !abbreviations.iter().any(|a| Prompt::abbreviations_clash(a, t)))
+            .cloned();
+        abbreviations.extend(prompt.abbreviation.clone());
+    }
+    Some(prompt)
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value.get(key).and_then(Value::as_str)
+}
+
+/// "[[field]]" -> "{{field}}"
+fn form_to_placeholders(form: &str) -> String {
+    form.replace("[[", "{{").replace("]]", "}}")
+}
+
+/// Variables declared by an Espanso `vars` entry
+fn var_variables(var: &Value) -> Vec<Variable> {
+    let Some(name) = str_field(var, "name") else {
+        return Vec::new();
+    };
+    let params = var.get("params");
+    let param = |key: &str| params.and_then(|p| p.get(key));
+
+    match str_field(var, "type").unwrap_or_default() {
+        "form" => form_field_variables(param("fields")),
+        "echo" => vec![optional(name, param("echo").and_then(Value::as_str).unwrap_or_default())],
+        "choice" | "list" => vec![optional(name, &first_value(param("values")))],
+        // date, clipboard, shell, script, random... are computed by Espanso; ask the user instead
+        _ => vec![Variable {
+            name: variable_name(name),
+            default: String::new(),
+            required: true,
+        }],
+    }
+}
+
+/// Variables for `form_fields` / form `fields` maps
+fn form_field_variables(fields: Option<&Value>) -> Vec<Variable> {
+    let Some(Value::Mapping(fields)) = fields else {
+        return Vec::new();
+    };
+    fields
+        .iter()
+        .filter_map(|(name, field)| {
+            let default = field
+                .get("default")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| first_value(field.get("values")));
+            name.as_str().map(|name| optional(name, &default))
+        })
+        .collect()
+}
+
+fn optional(name: &str, default: &str) -> Variable {
+    Variable {
+        name: variable_name(name),
+        default: default.to_string(),
+        required: default.is_empty(),
+    }
+}
+
+/// First option of a choice/list (`values` is a list, a multiline string, or label/id maps)
+fn first_value(values: Option<&Value>) -> String {
+    match values {
+        Some(Value::Sequence(list)) => list
+            .first()
+            .and_then(|v| v.as_str().or_else(|| str_field(v, "id")))
+            .unwrap_or_default()
+            .to_string(),
+        Some(Value::String(lines)) => lines.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or_default().to_string(),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn load_fixture() -> ImportBatch {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("match")).unwrap();
+        fs::write(temp_dir.path().join("match/base.yml"), include_str!("fixtures/espanso.yml")).unwrap();
+        EspansoImportSource::new(temp_dir.path().to_path_buf()).load().unwrap()
+    }
+
+    fn prompt<'a>(batch: &'a ImportBatch, id: &str) -> &'a Prompt {
+        batch.prompts.iter().find(|p| p.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_imports_text_matches_and_skips_images() {
+        let batch = load_fixture();
+        assert!(batch.errors.is_empty());
+
+        let ids: Vec<&str> = batch.prompts.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["Espanso/sig.md", "Espanso/greeting.md", "Espanso/bug.md", "Espanso/meet.md"]);
+
+        let sig = prompt(&batch, "Espanso/sig.md");
+        assert_eq!(sig.content, "Best regards,\nJane");
+        assert_eq!(sig.description, "Espanso trigger: :sig");
+        assert_eq!(sig.abbreviation.as_deref(), Some(":sig"));
+        assert_eq!(sig.tags, vec!["espanso"]);
+        assert!(!sig.created_at.is_empty());
+    }
+
+    #[test]
+    fn test_maps_vars_to_variables() {
+        let batch = load_fixture();
+        let greet = prompt(&batch, "Espanso/greeting.md");
+
+        assert_eq!(greet.content, "Hello {{name}}, it's {{today}}!");
+        assert_eq!(greet.variables[0], optional("name", "there"));
+        assert_eq!(greet.variables[1].name, "today");
+        assert!(greet.variables[1].required);
+    }
+
+    #[test]
+    fn test_maps_forms_to_variables() {
+        let batch = load_fixture();
+
+        let bug = prompt(&batch, "Espanso/bug.md");
+        assert_eq!(bug.content, "Bug in {{component}}\nSeverity: {{severity}}");
+        assert_eq!(bug.description, "Espanso trigger: :bug, :issue");
+        assert_eq!(bug.variables, vec![optional("component", "core"), optional("severity", "high")]);
+
+        let meet = prompt(&batch, "Espanso/meet.md");
+        assert_eq!(meet.content, "Meeting with {{who}} about {{topic}}");
+        assert_eq!(meet.variables[0].name, "who");
+        assert!(meet.variables[0].required);
+        assert_eq!(meet.variables[1], optional("topic", "planning"));
+    }
+
+    #[test]
+    fn test_keeps_only_valid_unique_triggers_as_abbreviations() {
+        let yaml = "matches:\n  - trigger: x\n    replace: short\n  - trigger: \":sig\"\n    replace: first\n  \
+                    - trigger: \":sig\"\n    replace: again\n  - trigger: \":si\"\n    replace: prefix\n";
+        let prompts = convert(yaml, "Espanso", &mut IdAllocator::default(), &mut Vec::new()).unwrap();
+
+        let abbreviations: Vec<Option<&str>> = prompts.iter().map(|p| p.abbreviation.as_deref()).collect();
+        assert_eq!(abbreviations, vec![None, Some(":sig"), None, None]);
+        // Snippets without an abbreviation are still imported and pass validation
+        assert_eq!(prompts.len(), 4);
+        assert!(prompts.iter().all(|p| p.validate().is_ok()));
+    }
+
+    #[test]
+    fn test_invalid_yaml_is_reported() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("broken.yml"), "matches: [oops").unwrap();
+
+        let batch = EspansoImportSource::new(temp_dir.path().to_path_buf()).load().unwrap();
+        assert!(batch.prompts.is_empty());
+        assert_eq!(batch.errors[0].id, "broken.yml");
+    }
+}
@@ -0,0 +1,85 @@
+use crate::storage::domain::entities::{ImportBatch, ParseFailure};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::ImportSource;
+use crate::storage::infrastructure::import::source_files::read_source_files;
+use crate::storage::infrastructure::persistence::YamlParser;
+use std::path::PathBuf;
+
+/// Imports Prompter's own format: .md files with YAML frontmatter,
+/// from a directory tree, a zip archive or a single file
+pub struct MarkdownImportSource {
+    path: PathBuf,
+    parser: YamlParser,
+}
+
+impl MarkdownImportSource {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            parser: YamlParser::new(),
+        }
+    }
+}
+
+impl ImportSource for MarkdownImportSource {
+    fn load(&self) -> Result<ImportBatch, StorageError> {
+        let mut batch = ImportBatch::default();
+
+        for file in read_source_files(&self.path, &["md"])? {
+            let parsed = file
+                .text()
+                .map_err(|message| ParseFailure {
+                    id: file.path.clone(),
+                    message,
+                    line: None,
+                })
+                .and_then(|text| self.parser.parse_str(text, &file.path));
+
+            match parsed {
+                Ok(mut prompt) => {
+                    // Files without timestamps keep their modification time
+                    if let Some(modified) = file.modified.filter(|_| prompt.created_at.is_empty()) {
+                        prompt.created_at = modified.to_rfc3339();
+                    }
+                    if let Some(modified) = file.modified.filter(|_| prompt.updated_at.is_empty()) {
+                        prompt.updated_at = modified.to_rfc3339();
+                    }
+                    batch.prompts.push(prompt);
+                }
+                Err(failure) => batch.errors.push(failure),
+            }
+        }
+
+        Ok(batch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("Coding")).unwrap();
+        fs::write(
+            temp_dir.path().join("Coding/review.md"),
+            "---\nname: Review\ncreated_at: \"2024-01-01T00:00:00Z\"\n---\nReview this",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("broken.md"), "---\nname: [oops\n---\nBody").unwrap();
+
+        let batch = MarkdownImportSource::new(temp_dir.path().to_path_buf()).load().unwrap();
+
+        assert_eq!(batch.prompts.len(), 1);
+        assert_eq!(batch.prompts[0].id, "Coding/review.md");
+        assert_eq!(batch.prompts[0].created_at, "2024-01-01T00:00:00Z");
+        assert!(!batch.prompts[0].updated_at.is_empty());
+
+        assert_eq!(batch.errors.len(), 1);
+        assert_eq!(batch.errors[0].id, "broken.md");
+        assert_eq!(batch.errors[0].line, Some(2));
+    }
+}
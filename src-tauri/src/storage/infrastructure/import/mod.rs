@@ -0,0 +1,32 @@
+// Import adapters - one ImportSource per source format
+pub mod converter;
+pub mod espanso_source;
+pub mod markdown_source;
+pub mod raycast_source;
+pub mod source_files;
+pub mod text_source;
+pub mod textexpander_source;
+pub mod vscode_source;
+
+pub use espanso_source::EspansoImportSource;
+pub use markdown_source::MarkdownImportSource;
+pub use raycast_source::RaycastImportSource;
+pub use text_source::TextImportSource;
+pub use textexpander_source::TextExpanderImportSource;
+pub use vscode_source::VsCodeImportSource;
+
+use crate::storage::domain::entities::ImportFormat;
+use crate::storage::domain::ports::ImportSource;
+use std::path::PathBuf;
+
+/// Pick the adapter for a source format
+pub fn import_source(format: ImportFormat, path: PathBuf) -> Box<dyn ImportSource> {
+    match format {
+        ImportFormat::Prompter => Box::new(MarkdownImportSource::new(path)),
+        ImportFormat::Espanso => Box::new(EspansoImportSource::new(path)),
+        ImportFormat::VsCode => Box::new(VsCodeImportSource::new(path)),
+        ImportFormat::Raycast => Box::new(RaycastImportSource::new(path)),
+        ImportFormat::TextExpander => Box::new(TextExpanderImportSource::new(path)),
+        ImportFormat::Text => Box::new(TextImportSource::new(path)),
+    }
+}
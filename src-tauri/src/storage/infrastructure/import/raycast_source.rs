@@ -0,0 +1,166 @@
+use crate::storage::domain::entities::{ImportBatch, Variable};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::ImportSource;
+use crate::storage::infrastructure::import::converter::{build_prompt, load_files, variable_name, IdAllocator};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+/// Raycast placeholders that become prompt variables (`{cursor}` is dropped)
+const DYNAMIC_PLACEHOLDERS: [&str; 8] = ["clipboard", "selection", "date", "time", "datetime", "day", "uuid", "snippet"];
+
+/// One entry of a Raycast snippets export
+#[derive(Debug, Deserialize)]
+struct RaycastSnippet {
+    name: String,
+    text: String,
+    #[serde(default)]
+    keyword: Option<String>,
+}
+
+/// Imports Raycast snippet exports (JSON array of `{name, text, keyword}`)
+/// `{argument name="x" default="y"}` becomes `{{x}}`; `{clipboard}`, `{date}`... become variables
+pub struct RaycastImportSource {
+    path: PathBuf,
+}
+
+impl RaycastImportSource {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl ImportSource for RaycastImportSource {
+    fn load(&self) -> Result<ImportBatch, StorageError> {
+        let mut ids = IdAllocator::default();
+        load_files(&self.path, &["json"], |_, text| {
+            let snippets: Vec<RaycastSnippet> = serde_json::from_str(text)
+                .map_err(|e| format!("Failed to parse Raycast snippets: {}", e))?;
+
+            Ok(snippets
+                .into_iter()
+                .map(|snippet| {
+                    let (content, variables) = convert_text(&snippet.text);
+                    let id = ids.allocate("Raycast", &snippet.name);
+                    let mut prompt = build_prompt(id, &snippet.name, content, variables, vec!["raycast".to_string()]);
+                    if let Some(keyword) = snippet.keyword.filter(|k| !k.is_empty()) {
+                        prompt.description = format!("Raycast keyword: {}", keyword);
+                    }
+                    prompt
+                })
+                .collect())
+        })
+    }
+}
+
+/// Convert `{keyword attr="value" | modifier}` placeholders; other braces stay literal
+fn convert_text(text: &str) -> (String, Vec<Variable>) {
+    let mut out = String::with_capacity(text.len());
+    let mut variables: Vec<Variable> = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            out.push_str(&rest[start..]);
+            rest = "";
+            break;
+        };
+
+        let inner = &after[..end];
+        let keyword: String = inner.chars().take_while(|c| c.is_ascii_alphabetic()).collect();
+        let attrs = inner[keyword.len()..].split('|').next().unwrap_or_default();
+
+        if keyword == "cursor" && attrs.trim().is_empty() {
+            // Cursor position has no equivalent; drop it
+        } else if keyword == "argument" {
+            let name = attribute(attrs, "name").unwrap_or_else(|| "argument".to_string());
+            let default = attribute(attrs, "default")
+                .or_else(|| attribute(attrs, "options").and_then(|o| o.split(',').next().map(|s| s.trim().to_string())))
+                .unwrap_or_default();
+            let name = variable_name(&name);
+            out.push_str(&format!("{{{{{}}}}}", name));
+            if !variables.iter().any(|v| v.name == name) {
+                variables.push(Variable {
+                    name,
+                    required: default.is_empty(),
+                    default,
+                });
+            }
+        } else if DYNAMIC_PLACEHOLDERS.contains(&keyword.as_str()) && (attrs.is_empty() || attrs.starts_with(' ')) {
+            // Raycast computes these; the user fills them in instead
+            out.push_str(&format!("{{{{{}}}}}", keyword));
+        } else {
+            out.push_str(&rest[start..start + 1 + end + 1]);
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    (out, variables)
+}
+
+/// Value of `key="value"` in a placeholder's attribute list
+fn attribute(attrs: &str, key: &str) -> Option<String> {
+    let pattern = format!("{}=\"", key);
+    let start = attrs.find(&pattern)? + pattern.len();
+    let len = attrs[start..].find('"')?;
+    Some(attrs[start..start + len].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn load_fixture() -> ImportBatch {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("snippets.json");
+        fs::write(&path, include_str!("fixtures/raycast.json")).unwrap();
+        RaycastImportSource::new(path).load().unwrap()
+    }
+
+    #[test]
+    fn test_imports_snippets_with_keywords() {
+        let batch = load_fixture();
+        assert!(batch.errors.is_empty());
+        assert_eq!(batch.prompts.len(), 3);
+
+        let signature = &batch.prompts[0];
+        assert_eq!(signature.id, "Raycast/email-signature.md");
+        assert_eq!(signature.content, "Cheers,\nSam");
+        assert_eq!(signature.description, "Raycast keyword: !sig");
+        assert_eq!(signature.tags, vec!["raycast"]);
+    }
+
+    #[test]
+    fn test_maps_arguments_to_variables() {
+        let batch = load_fixture();
+        let standup = &batch.prompts[1];
+
+        assert_eq!(standup.content, "Yesterday: {{yesterday}}\nToday: {{today}}\nMood: {{mood}}\nDate: {{date}}");
+        let defaults: Vec<(&str, &str, bool)> = standup
+            .variables
+            .iter()
+            .map(|v| (v.name.as_str(), v.default.as_str(), v.required))
+            .collect();
+        assert_eq!(
+            defaults,
+            vec![
+                ("yesterday", "", true),
+                ("today", "same as yesterday", false),
+                ("mood", "good", false),
+                ("date", "", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dynamic_placeholders_and_literal_braces() {
+        let batch = load_fixture();
+        let quote = &batch.prompts[2];
+
+        assert_eq!(quote.content, "> {{clipboard}}\n\nJSON stays literal: {\"a\": 1}");
+        assert_eq!(quote.variables[0].name, "clipboard");
+    }
+}
@@ -0,0 +1,199 @@
+use crate::storage::domain::errors::StorageError;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path};
+use walkdir::WalkDir;
+use zip::ZipArchive;
+
+/// A file read from an import source (directory, zip archive or single file)
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    /// Path relative to the source root, '/'-separated
+    pub path: String,
+    pub content: Vec<u8>,
+    pub modified: Option<DateTime<Utc>>,
+}
+
+impl SourceFile {
+    pub fn text(&self) -> Result<&str, String> {
+        std::str::from_utf8(&self.content).map_err(|_| "File is not valid UTF-8".to_string())
+    }
+}
+
+/// Read all files with one of `extensions` from a directory tree, a .zip archive or a single file
+/// Hidden files and directories (".versions", ".git", "__MACOSX") are skipped
+pub fn read_source_files(path: &Path, extensions: &[&str]) -> Result<Vec<SourceFile>, StorageError> {
+    if !path.exists() {
+        return Err(StorageError::FileNotFound(path.display().to_string()));
+    }
+
+    let mut files = if path.is_dir() {
+        read_directory(path, extensions)?
+    } else if has_extension(path, &["zip"]) {
+        read_zip(path, extensions)?
+    } else {
+        let name = path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| StorageError::Other("Invalid file name".to_string()))?;
+        vec![read_file(path, name.to_string())?]
+    };
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|s| s.to_str())
+        .is_some_and(|ext| extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)))
+}
+
+fn is_hidden(relative: &Path) -> bool {
+    relative.components().any(|c| match c {
+        Component::Normal(name) => name.to_str().is_some_and(|n| n.starts_with('.') || n == "__MACOSX"),
+        _ => false,
+    })
+}
+
+fn read_file(path: &Path, relative: String) -> Result<SourceFile, String> {
+    let content = fs::read(path).map_err(|e| format!("Failed to read {}: {}", relative, e))?;
+    let modified = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .map(DateTime::<Utc>::from);
+    Ok(SourceFile {
+        path: relative,
+        content,
+        modified,
+    })
+}
+
+fn read_directory(dir: &Path, extensions: &[&str]) -> Result<Vec<SourceFile>, String> {
+    let mut files = Vec::new();
+    for entry in WalkDir::new(dir).follow_links(true).into_iter().filter_map(|e| e.ok()) {
+        let path = entry.path();
+        let Ok(relative) = path.strip_prefix(dir) else {
+            continue;
+        };
+        if !path.is_file() || is_hidden(relative) || !has_extension(path, extensions) {
+            continue;
+        }
+        let relative = relative.to_str().ok_or("Invalid path encoding")?.replace('\\', "/");
+        files.push(read_file(path, relative)?);
+    }
+    Ok(files)
+}
+
+fn read_zip(path: &Path, extensions: &[&str]) -> Result<Vec<SourceFile>, String> {
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open archive: {}", e))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| format!("Failed to read archive: {}", e))?;
+
+    let mut files = Vec::new();
+    for index in 0..archive.len() {
+        let mut entry = archive
+            .by_index(index)
+            .map_err(|e| format!("Failed to read archive entry: {}", e))?;
+
+        // enclosed_name rejects absolute paths and ".." (zip-slip)
+        let Some(relative) = entry.enclosed_name() else {
+            continue;
+        };
+        if entry.is_dir() || is_hidden(&relative) || !has_extension(&relative, extensions) {
+            continue;
+        }
+        let relative = relative.to_str().ok_or("Invalid path encoding")?.replace('\\', "/");
+
+        let mut content = Vec::new();
+        entry
+            .read_to_end(&mut content)
+            .map_err(|e| format!("Failed to read {}: {}", relative, e))?;
+        let modified = entry.last_modified().and_then(|dt| {
+            NaiveDate::from_ymd_opt(dt.year() as i32, dt.month() as u32, dt.day() as u32)?
+                .and_hms_opt(dt.hour() as u32, dt.minute() as u32, dt.second() as u32)
+                .map(|naive| naive.and_utc())
+        });
+        files.push(SourceFile {
+            path: relative,
+            content,
+            modified,
+        });
+    }
+
+    strip_common_root(&mut files, path);
+    Ok(files)
+}
+
+/// Archives often wrap everything in one folder named after the archive ("export.zip" ->
+/// "export/") or the library folder ("prompts/"); drop it so folders map onto the library
+fn strip_common_root(files: &mut [SourceFile], archive_path: &Path) {
+    let archive_stem = archive_path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+    for root in ["prompts", archive_stem] {
+        let prefix = format!("{}/", root);
+        if !root.is_empty() && !files.is_empty() && files.iter().all(|f| f.path.starts_with(&prefix)) {
+            for file in files.iter_mut() {
+                file.path = file.path[prefix.len()..].to_string();
+            }
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    #[test]
+    fn test_read_directory_skips_hidden_and_other_extensions() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("Coding")).unwrap();
+        fs::create_dir_all(temp_dir.path().join(".versions")).unwrap();
+        fs::write(temp_dir.path().join("Coding/review.md"), "x").unwrap();
+        fs::write(temp_dir.path().join(".versions/old.md"), "x").unwrap();
+        fs::write(temp_dir.path().join("notes.json"), "x").unwrap();
+
+        let files = read_source_files(temp_dir.path(), &["md"]).unwrap();
+        let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["Coding/review.md"]);
+        assert!(files[0].modified.is_some());
+    }
+
+    #[test]
+    fn test_read_zip_strips_common_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let zip_path = temp_dir.path().join("export.zip");
+        let mut zip = ZipWriter::new(fs::File::create(&zip_path).unwrap());
+        for name in ["export/Coding/review.md", "export/intro.md", "export/readme.txt"] {
+            zip.start_file(name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(b"x").unwrap();
+        }
+        zip.finish().unwrap();
+
+        let files = read_source_files(&zip_path, &["md"]).unwrap();
+        let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["Coding/review.md", "intro.md"]);
+    }
+
+    #[test]
+    fn test_read_zip_keeps_real_top_level_folder() {
+        let temp_dir = TempDir::new().unwrap();
+        let zip_path = temp_dir.path().join("export.zip");
+        let mut zip = ZipWriter::new(fs::File::create(&zip_path).unwrap());
+        zip.start_file("Coding/review.md", SimpleFileOptions::default()).unwrap();
+        zip.write_all(b"x").unwrap();
+        zip.finish().unwrap();
+
+        let files = read_source_files(&zip_path, &["md"]).unwrap();
+        assert_eq!(files[0].path, "Coding/review.md");
+    }
+
+    #[test]
+    fn test_missing_source() {
+        assert!(read_source_files(Path::new("/nonexistent/import"), &["md"]).is_err());
+    }
+}
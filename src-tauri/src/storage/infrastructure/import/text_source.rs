@@ -0,0 +1,55 @@
+use crate::storage::domain::entities::ImportBatch;
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::ImportSource;
+use crate::storage::infrastructure::import::converter::{build_prompt, load_files};
+use std::path::{Path, PathBuf};
+
+/// Imports a folder of plain `.txt` files: one prompt per file, named after the file,
+/// keeping the folder structure; `{{name}}` placeholders are declared as variables
+pub struct TextImportSource {
+    path: PathBuf,
+}
+
+impl TextImportSource {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl ImportSource for TextImportSource {
+    fn load(&self) -> Result<ImportBatch, StorageError> {
+        load_files(&self.path, &["txt"], |file, text| {
+            let path = Path::new(&file.path);
+            let name = path.file_stem().and_then(|s| s.to_str()).unwrap_or("Untitled");
+            let id = path.with_extension("md").to_string_lossy().replace('\\', "/");
+            Ok(vec![build_prompt(id, name, text.to_string(), Vec::new(), Vec::new())])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_imports_text_files_with_folders() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("Email")).unwrap();
+        fs::write(temp_dir.path().join("Email/reply.txt"), include_str!("fixtures/text/Email/reply.txt")).unwrap();
+        fs::write(temp_dir.path().join("summarize.txt"), include_str!("fixtures/text/summarize.txt")).unwrap();
+        fs::write(temp_dir.path().join("empty.txt"), "").unwrap();
+
+        let batch = TextImportSource::new(temp_dir.path().to_path_buf()).load().unwrap();
+        let ids: Vec<&str> = batch.prompts.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["Email/reply.md", "empty.md", "summarize.md"]);
+
+        let reply = &batch.prompts[0];
+        assert_eq!(reply.name, "reply");
+        assert_eq!(reply.content, "Hi {{name}},\n\nThanks for reaching out about {{topic}}.");
+        let names: Vec<&str> = reply.variables.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["name", "topic"]);
+        assert!(!reply.created_at.is_empty());
+    }
+}
@@ -0,0 +1,184 @@
+use crate::storage::domain::entities::{ImportBatch, Variable};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::ImportSource;
+use crate::storage::infrastructure::import::converter::{build_prompt, load_files, variable_name, IdAllocator};
+use std::path::PathBuf;
+
+/// TextExpander date/time macros (`%Y`, `%m`, ...), collapsed into one `{{date}}` variable
+const DATE_MACROS: &str = "YymBbdeAaHIMSpZzj";
+const DATE_SEPARATORS: [char; 6] = ['-', '/', '.', ':', ' ', ','];
+
+/// Imports TextExpander CSV exports (`abbreviation,content,label` rows)
+/// Fill-ins (`%filltext:name=x:default=y%`, `%fillpopup`, `%fillarea`) become variables
+pub struct TextExpanderImportSource {
+    path: PathBuf,
+}
+
+impl TextExpanderImportSource {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl ImportSource for TextExpanderImportSource {
+    fn load(&self) -> Result<ImportBatch, StorageError> {
+        let mut ids = IdAllocator::default();
+        load_files(&self.path, &["csv"], |_, text| {
+            let mut reader = csv::ReaderBuilder::new()
+                .has_headers(false)
+                .flexible(true)
+                .from_reader(text.as_bytes());
+
+            let mut prompts = Vec::new();
+            for (index, record) in reader.records().enumerate() {
+                let record = record.map_err(|e| format!("Failed to parse TextExpander CSV: {}", e))?;
+                let abbreviation = record.get(0).unwrap_or_default().trim();
+                let content = record.get(1).unwrap_or_default();
+                let label = record.get(2).unwrap_or_default().trim();
+
+                if index == 0 && abbreviation.eq_ignore_ascii_case("abbreviation") {
+                    continue;
+                }
+                if content.trim().is_empty() {
+                    continue;
+                }
+
+                let name = if label.is_empty() { abbreviation } else { label };
+                let (content, variables) = convert_text(content);
+                let id = ids.allocate("TextExpander", name);
+                let mut prompt = build_prompt(id, name, content, variables, vec!["textexpander".to_string()]);
+                if !abbreviation.is_empty() {
+                    prompt.description = format!("TextExpander abbreviation: {}", abbreviation);
+                }
+                prompts.push(prompt);
+            }
+            Ok(prompts)
+        })
+    }
+}
+
+/// Convert TextExpander `%` macros; unknown macros stay literal
+fn convert_text(text: &str) -> (String, Vec<Variable>) {
+    let mut out = String::with_capacity(text.len());
+    let mut variables: Vec<Variable> = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('%') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        if let Some(tail) = after.strip_prefix('%') {
+            out.push('%');
+            rest = tail;
+        } else if let Some(tail) = after.strip_prefix('|') {
+            // Cursor position has no equivalent
+            rest = tail;
+        } else if let Some(tail) = after.strip_prefix("clipboard") {
+            out.push_str("{{clipboard}}");
+            rest = tail;
+        } else if let (true, Some(end)) = (after.starts_with("fill"), after.find('%')) {
+            if let Some(variable) = fill_in(&after[..end]) {
+                out.push_str(&format!("{{{{{}}}}}", variable.name));
+                if !variables.iter().any(|v| v.name == variable.name) {
+                    variables.push(variable);
+                }
+            }
+            rest = &after[end + 1..];
+        } else if let Some(tail) = after.strip_prefix(|c: char| DATE_MACROS.contains(c)) {
+            // Adjacent date parts ("%Y-%m-%d") become a single {{date}}
+            let previous_date = out.trim_end_matches(DATE_SEPARATORS).len();
+            if out[..previous_date].ends_with("{{date}}") {
+                out.truncate(previous_date);
+            } else {
+                out.push_str("{{date}}");
+            }
+            rest = tail;
+        } else {
+            out.push('%');
+            rest = after;
+        }
+    }
+    out.push_str(rest);
+    (out, variables)
+}
+
+/// Parse `filltext:name=x:default=y:width=20` (also fillarea, fillpopup)
+/// `fillpart` section markers yield nothing
+fn fill_in(spec: &str) -> Option<Variable> {
+    let mut parts = spec.split(':');
+    let kind = parts.next()?;
+    if !matches!(kind, "filltext" | "fillarea" | "fillpopup") {
+        return None;
+    }
+
+    let mut name = None;
+    let mut default = None;
+    for part in parts {
+        match part.split_once('=') {
+            Some(("name", value)) => name = Some(value.to_string()),
+            Some(("default", value)) => default = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    let default = default.unwrap_or_default();
+    Some(Variable {
+        name: variable_name(&name.unwrap_or_else(|| kind.to_string())),
+        required: default.is_empty(),
+        default,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn load_fixture() -> ImportBatch {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("group.csv");
+        fs::write(&path, include_str!("fixtures/textexpander.csv")).unwrap();
+        TextExpanderImportSource::new(path).load().unwrap()
+    }
+
+    #[test]
+    fn test_imports_rows_and_skips_header() {
+        let batch = load_fixture();
+        assert!(batch.errors.is_empty());
+        assert_eq!(batch.prompts.len(), 3);
+
+        let signature = &batch.prompts[0];
+        assert_eq!(signature.id, "TextExpander/signature.md");
+        assert_eq!(signature.content, "Thanks,\nAlex");
+        assert_eq!(signature.description, "TextExpander abbreviation: ;sig");
+    }
+
+    #[test]
+    fn test_maps_fill_ins_and_dates() {
+        let batch = load_fixture();
+        let meeting = &batch.prompts[1];
+
+        // No label: named after the abbreviation
+        assert_eq!(meeting.name, ";mtg");
+        assert_eq!(
+            meeting.content,
+            "Meeting with {{attendee}} on {{date}} about {{topic}}.\n{{notes}}"
+        );
+        let defaults: Vec<(&str, &str)> = meeting
+            .variables
+            .iter()
+            .map(|v| (v.name.as_str(), v.default.as_str()))
+            .collect();
+        assert_eq!(
+            defaults,
+            vec![("attendee", "the team"), ("date", ""), ("topic", "roadmap"), ("notes", "")]
+        );
+    }
+
+    #[test]
+    fn test_clipboard_and_escaped_percent() {
+        let batch = load_fixture();
+        assert_eq!(batch.prompts[2].content, "Quote: {{clipboard}} (100% sure)");
+    }
+}
@@ -0,0 +1,289 @@
+use crate::storage::domain::entities::{ImportBatch, Prompt, Variable};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::ImportSource;
+use crate::storage::infrastructure::import::converter::{build_prompt, load_files, IdAllocator};
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// Imports VS Code snippet files (`*.code-snippets` and per-language `<lang>.json`)
+/// Tabstops `$1`/`${1:default}`/`${1|a,b|}` become `{{field1}}` variables,
+/// snippet variables like `$TM_SELECTED_TEXT` become `{{TM_SELECTED_TEXT}}`
+pub struct VsCodeImportSource {
+    path: PathBuf,
+}
+
+impl VsCodeImportSource {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl ImportSource for VsCodeImportSource {
+    fn load(&self) -> Result<ImportBatch, StorageError> {
+        let mut ids = IdAllocator::default();
+        load_files(&self.path, &["code-snippets", "json"], |_, text| convert(text, &mut ids))
+    }
+}
+
+fn convert(text: &str, ids: &mut IdAllocator) -> Result<Vec<Prompt>, String> {
+    let root: Value = serde_json::from_str(&strip_jsonc(text))
+        .map_err(|e| format!("Failed to parse VS Code snippets: {}", e))?;
+    let snippets = root.as_object().ok_or("VS Code snippets must be a JSON object")?;
+
+    let mut prompts = Vec::new();
+    for (name, snippet) in snippets {
+        let body = match snippet.get("body") {
+            Some(Value::String(line)) => line.clone(),
+            Some(Value::Array(lines)) => lines.iter().filter_map(Value::as_str).collect::<Vec<_>>().join("\n"),
+            _ => continue,
+        };
+
+        let (content, variables) = convert_body(&body);
+        let tags = std::iter::once("vscode".to_string())
+            .chain(
+                snippet
+                    .get("scope")
+                    .and_then(Value::as_str)
+                    .into_iter()
+                    .flat_map(|scope| scope.split(','))
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty()),
+            )
+            .collect();
+
+        let mut prompt = build_prompt(ids.allocate("VS Code", name), name, content, variables, tags);
+        prompt.description = snippet
+            .get("description")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        prompts.push(prompt);
+    }
+    Ok(prompts)
+}
+
+/// Convert snippet syntax to `{{name}}` placeholders plus their declarations
+fn convert_body(body: &str) -> (String, Vec<Variable>) {
+    let mut variables: Vec<Variable> = Vec::new();
+    let content = convert_text(&body.chars().collect::<Vec<_>>(), &mut variables);
+    (content, variables)
+}
+
+fn convert_text(chars: &[char], variables: &mut Vec<Variable>) -> String {
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if matches!(chars.get(i + 1), Some('$' | '}' | '\\')) => {
+                out.push(chars[i + 1]);
+                i += 2;
+            }
+            '$' => match parse_placeholder(chars, i + 1, variables) {
+                Some((replacement, next)) => {
+                    out.push_str(&replacement);
+                    i = next;
+                }
+                None => {
+                    out.push('$');
+                    i += 1;
+                }
+            },
+            c => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Parse what follows a '$'; returns the replacement text and the index after it
+fn parse_placeholder(chars: &[char], start: usize, variables: &mut Vec<Variable>) -> Option<(String, usize)> {
+    let braced = chars.get(start) == Some(&'{');
+    let name_start = if braced { start + 1 } else { start };
+    let name_end = (name_start..chars.len())
+        .find(|&j| !(chars[j].is_ascii_alphanumeric() || chars[j] == '_'))
+        .unwrap_or(chars.len());
+    if name_end == name_start {
+        return None;
+    }
+    let raw: String = chars[name_start..name_end].iter().collect();
+    // Tabstops are numbered; $0 is the final cursor position and has no value
+    let name = if raw.chars().all(|c| c.is_ascii_digit()) { format!("field{}", raw) } else { raw.clone() };
+
+    let (default, end) = if !braced {
+        (String::new(), name_end)
+    } else {
+        let close = matching_brace(chars, name_end)?;
+        let inner = &chars[name_end..close];
+        let default = match inner.first() {
+            // ${1:default} (may contain nested placeholders; use their defaults as text)
+            Some(':') => {
+                let text = convert_text(&inner[1..], variables);
+                fill_defaults(&text, variables)
+            }
+            // ${1|one,two|} choice: first option
+            Some('|') => {
+                let options: String = inner[1..].iter().collect();
+                options.trim_end_matches('|').split(',').next().unwrap_or_default().to_string()
+            }
+            // ${TM_FILENAME/(.*)/$1/} transforms are not supported; keep the variable
+            _ => String::new(),
+        };
+        (default, close + 1)
+    };
+
+    if raw == "0" {
+        return Some((default, end));
+    }
+    match variables.iter_mut().find(|v| v.name == name) {
+        Some(existing) if existing.default.is_empty() && !default.is_empty() => {
+            existing.default = default;
+            existing.required = false;
+        }
+        Some(_) => {}
+        None => variables.push(Variable {
+            name: name.clone(),
+            required: default.is_empty(),
+            default,
+        }),
+    }
+    Some((format!("{{{{{}}}}}", name), end))
+}
+
+/// Index of the '}' closing the placeholder opened before `from`
+fn matching_brace(chars: &[char], from: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = from;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(i),
+            '}' => depth -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Replace `{{name}}` placeholders with their defaults, for use as plain text
+fn fill_defaults(text: &str, variables: &[Variable]) -> String {
+    variables
+        .iter()
+        .fold(text.to_string(), |text, v| text.replace(&format!("{{{{{}}}}}", v.name), &v.default))
+}
+
+/// Remove `//` and `/* */` comments and trailing commas (VS Code snippet files are JSONC)
+fn strip_jsonc(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    let mut in_string = false;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if in_string {
+            out.push(c);
+            if c == '\\' {
+                if let Some(&next) = chars.get(i + 1) {
+                    out.push(next);
+                    i += 1;
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            i += 1;
+            continue;
+        }
+
+        match (c, chars.get(i + 1)) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+                i += 1;
+            }
+            ('/', Some('/')) => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            ('/', Some('*')) => {
+                i += 2;
+                while i + 1 < chars.len() && !(chars[i] == '*' && chars[i + 1] == '/') {
+                    i += 1;
+                }
+                i += 2;
+            }
+            (',', _) => {
+                // Drop the comma if only whitespace separates it from a closing bracket
+                let next = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+                if !matches!(next, Some('}' | ']')) {
+                    out.push(c);
+                }
+                i += 1;
+            }
+            _ => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn load_fixture() -> ImportBatch {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("global.code-snippets"), include_str!("fixtures/vscode.code-snippets")).unwrap();
+        VsCodeImportSource::new(temp_dir.path().to_path_buf()).load().unwrap()
+    }
+
+    fn prompt<'a>(batch: &'a ImportBatch, name: &str) -> &'a Prompt {
+        batch.prompts.iter().find(|p| p.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_imports_snippets_with_scope_tags() {
+        let batch = load_fixture();
+        assert!(batch.errors.is_empty(), "{:?}", batch.errors);
+        assert_eq!(batch.prompts.len(), 3);
+
+        let log = prompt(&batch, "Print to console");
+        assert_eq!(log.id, "VS Code/print-to-console.md");
+        assert_eq!(log.content, "console.log('{{field1}}', {{field2}});");
+        assert_eq!(log.description, "Log output to console");
+        assert_eq!(log.tags, vec!["vscode", "javascript", "typescript"]);
+        assert_eq!(log.variables[0].default, "message");
+        assert!(log.variables[1].required);
+    }
+
+    #[test]
+    fn test_maps_variables_choices_and_escapes() {
+        let batch = load_fixture();
+        let review = prompt(&batch, "Review request");
+
+        assert_eq!(
+            review.content,
+            "Please review {{TM_SELECTED_TEXT}} for {{field1}} issues.\nCost: $5 /* not a comment */"
+        );
+        assert_eq!(review.variables[0].name, "TM_SELECTED_TEXT");
+        assert_eq!(review.variables[1].default, "bugs");
+    }
+
+    #[test]
+    fn test_nested_placeholders() {
+        let batch = load_fixture();
+        let nested = prompt(&batch, "Nested");
+
+        assert_eq!(nested.content, "{{field1}} and {{field2}}");
+        assert_eq!(nested.variables[0].default, "outer inner");
+        assert_eq!(nested.variables[1].default, "inner");
+    }
+}
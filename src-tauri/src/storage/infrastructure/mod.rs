@@ -1,3 +1,6 @@
 // Infrastructure layer - adapters implementing domain ports
+pub mod encryption;
+pub mod export;
+pub mod import;
 pub mod persistence;
 pub mod search;
@@ -1,15 +1,31 @@
-use crate::storage::domain::entities::Prompt;
-use crate::storage::domain::ports::PromptRepository;
+use crate::logging::redact;
+use crate::storage::domain::entities::{ParseFailure, Prompt, Variable};
+use crate::storage::domain::errors::StorageError;
+use crate::storage::domain::ports::{PromptRepository, PromptVault};
 use crate::storage::domain::value_objects::PromptId;
+use crate::storage::infrastructure::encryption::{Envelope, PassphraseVault};
 use crate::storage::infrastructure::persistence::YamlParser;
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
+use zeroize::Zeroizing;
 
 /// File-based repository adapter (implements PromptRepository trait)
 /// Stores prompts as markdown files with YAML frontmatter
+/// Private prompts keep name, folder and tags readable; the rest is one encrypted block
 pub struct FilePromptRepository {
     prompts_dir: PathBuf,
     parser: YamlParser,
+    /// None means private prompts always load locked and cannot be re-encrypted
+    vault: Option<Box<dyn PromptVault>>,
+}
+
+/// The fields of a private prompt that are stored encrypted
+#[derive(Serialize, Deserialize)]
+struct PrivateFields {
+    description: String,
+    content: String,
+    variables: Vec<Variable>,
 }
 
 impl FilePromptRepository {
@@ -26,6 +42,7 @@ impl FilePromptRepository {
         Ok(Self {
             prompts_dir,
             parser: YamlParser::new(),
+            vault: Some(Box::new(PassphraseVault::new()?)),
         })
     }
 
@@ -38,15 +55,25 @@ impl FilePromptRepository {
         Ok(Self {
             prompts_dir,
             parser: YamlParser::new(),
+            vault: None,
         })
     }
 
+    /// Use this vault for private prompts (for testing)
+    #[cfg(test)]
+    pub fn with_vault(mut self, vault: impl PromptVault + 'static) -> Self {
+        self.vault = Some(Box::new(vault));
+        self
+    }
+
     fn list_prompt_files(&self) -> Result<Vec<PathBuf>, String> {
         let mut files = Vec::new();
 
+        // Hidden directories hold app data (.versions) or a sync working tree (.git), not prompts
         for entry in WalkDir::new(&self.prompts_dir)
             .follow_links(true)
             .into_iter()
+            .filter_entry(|e| e.depth() == 0 || !e.file_name().to_string_lossy().starts_with('.'))
             .filter_map(|e| e.ok())
         {
             let path = entry.path();
@@ -66,28 +93,112 @@ impl FilePromptRepository {
     pub fn get_prompts_dir(&self) -> &PathBuf {
         &self.prompts_dir
     }
+
+    fn unlocked_vault(&self) -> Option<&dyn PromptVault> {
+        self.vault.as_deref().filter(|vault| vault.is_unlocked())
+    }
+
+    /// Decrypt a private prompt, or mark it locked with its private fields blanked
+    fn reveal(&self, mut prompt: Prompt) -> Prompt {
+        if !prompt.private {
+            return prompt;
+        }
+        let Some(vault) = self.unlocked_vault() else {
+            return Self::locked(prompt);
+        };
+        // Marked private by hand but not encrypted yet; it will be on the next save
+        if !Envelope::is_armored(&prompt.content) {
+            return prompt;
+        }
+
+        let fields = vault.decrypt(&prompt.content).and_then(|plaintext| {
+            serde_json::from_slice::<PrivateFields>(&Zeroizing::new(plaintext))
+                .map_err(|e| StorageError::Encryption(format!("Failed to parse decrypted prompt: {}", e)))
+        });
+        match fields {
+            Ok(fields) => {
+                prompt.description = fields.description;
+                prompt.content = fields.content;
+                prompt.variables = fields.variables;
+                prompt
+            }
+            Err(e) => {
+                // e.g. encrypted on another device with a different passphrase
+                log::warn!("[FilePromptRepository] Could not decrypt {}: {}", redact(&prompt.id), e);
+                Self::locked(prompt)
+            }
+        }
+    }
+
+    fn locked(mut prompt: Prompt) -> Prompt {
+        prompt.locked = true;
+        prompt.description = String::new();
+        prompt.content = String::new();
+        prompt.variables = Vec::new();
+        prompt
+    }
+
+    /// Serialize a private prompt with description, content and variables replaced by one encrypted block
+    fn serialize_private(&self, prompt: &Prompt, path: &Path) -> Result<String, StorageError> {
+        let sealed = if prompt.locked {
+            // Only metadata changed (e.g. favorite toggled while locked): keep the block on disk
+            self.existing_sealed(path)
+                .ok_or_else(|| StorageError::Locked(prompt.id.clone()))?
+        } else {
+            let vault = self
+                .unlocked_vault()
+                .ok_or_else(|| StorageError::Locked(prompt.id.clone()))?;
+            let fields = PrivateFields {
+                description: prompt.description.clone(),
+                content: prompt.content.clone(),
+                variables: prompt.variables.clone(),
+            };
+            let plaintext = Zeroizing::new(
+                serde_json::to_vec(&fields).map_err(|e| StorageError::Serialization(e.to_string()))?,
+            );
+            vault.encrypt(&plaintext)?
+        };
+
+        let mut stored = prompt.clone();
+        stored.private = true;
+        stored.locked = false;
+        stored.description = String::new();
+        stored.content = sealed;
+        stored.variables = Vec::new();
+        self.parser.serialize(&stored).map_err(StorageError::Serialization)
+    }
+
+    /// The encrypted block currently stored for a prompt file, if any
+    fn existing_sealed(&self, path: &Path) -> Option<String> {
+        let existing = self.parser.parse(path).ok()?;
+        Envelope::is_armored(&existing.content).then_some(existing.content)
+    }
 }
 
 impl PromptRepository for FilePromptRepository {
-    fn find_all(&self) -> Result<Vec<Prompt>, String> {
-        log::info!("[FilePromptRepository] find_all() called, prompts_dir: {:?}", self.prompts_dir);
+    fn find_all(&self) -> Result<Vec<Prompt>, StorageError> {
+        self.find_all_with_failures().map(|(prompts, _)| prompts)
+    }
+
+    fn find_all_with_failures(&self) -> Result<(Vec<Prompt>, Vec<ParseFailure>), StorageError> {
+        log::debug!("[FilePromptRepository] find_all() called, prompts_dir: {:?}", self.prompts_dir);
 
         let files = self.list_prompt_files()?;
-        log::info!("[FilePromptRepository] Found {} .md files", files.len());
+        log::debug!("[FilePromptRepository] Found {} .md files", files.len());
 
         let mut prompts = Vec::new();
-        let mut parse_errors = 0;
+        let mut failures = Vec::new();
 
         for file in &files {
-            // Use parse_with_base to set ID as relative path from prompts_dir
-            match self.parser.parse_with_base(file, &self.prompts_dir) {
+            // Use parse_detailed to set ID as relative path from prompts_dir
+            match self.parser.parse_detailed(file, &self.prompts_dir) {
                 Ok(prompt) => {
-                    log::debug!("[FilePromptRepository] Parsed: {} -> {}", file.display(), prompt.name);
-                    prompts.push(prompt);
+                    log::debug!("[FilePromptRepository] Parsed: {} -> {}", redact(file.display()), redact(&prompt.name));
+                    prompts.push(self.reveal(prompt));
                 }
-                Err(e) => {
-                    log::warn!("[FilePromptRepository] Failed to parse {}: {}", file.display(), e);
-                    parse_errors += 1;
+                Err(failure) => {
+                    log::warn!("[FilePromptRepository] Failed to parse {}: {}", redact(file.display()), failure.message);
+                    failures.push(failure);
                 }
             }
         }
@@ -95,43 +206,50 @@ impl PromptRepository for FilePromptRepository {
         log::info!(
             "[FilePromptRepository] Result: {} prompts loaded, {} parse errors out of {} files",
             prompts.len(),
-            parse_errors,
+            failures.len(),
             files.len()
         );
 
-        Ok(prompts)
+        Ok((prompts, failures))
     }
 
-    fn find_by_id(&self, id: &PromptId) -> Result<Prompt, String> {
+    fn find_by_id(&self, id: &PromptId) -> Result<Prompt, StorageError> {
         let path = self.get_prompt_path(id);
         if !path.exists() {
-            return Err(format!("Prompt not found: {}", id));
+            return Err(StorageError::NotFound(id.to_string()));
         }
-        // Use parse_with_base to ensure consistent ID format
-        self.parser.parse_with_base(&path, &self.prompts_dir)
+        // Use parse_detailed to ensure consistent ID format
+        let prompt = self.parser.parse_detailed(&path, &self.prompts_dir)?;
+        Ok(self.reveal(prompt))
     }
 
-    fn save(&self, prompt: &Prompt) -> Result<(), String> {
+    fn save(&self, prompt: &Prompt) -> Result<(), StorageError> {
         let path = self.get_prompt_path(&PromptId::new(&prompt.id));
 
         // Create parent directory if needed
         if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent)
-                .map_err(|e| format!("Failed to create directory: {}", e))?;
+            std::fs::create_dir_all(parent).map_err(|e| StorageError::io(parent, e))?;
         }
 
-        let content = self.parser.serialize(prompt)?;
-        std::fs::write(&path, content)
-            .map_err(|e| format!("Failed to write file: {}", e))
+        // Business rule: prompts in a private folder are always encrypted, and a locked
+        // prompt can never be written out in the clear since its content is unknown
+        let private = prompt.private
+            || prompt.locked
+            || self.vault.as_ref().is_some_and(|vault| vault.is_private_folder(&prompt.directory()));
+        let content = if private {
+            self.serialize_private(prompt, &path)?
+        } else {
+            self.parser.serialize(prompt).map_err(StorageError::Serialization)?
+        };
+        std::fs::write(&path, content).map_err(|e| StorageError::io(&path, e))
     }
 
-    fn delete(&self, id: &PromptId) -> Result<(), String> {
+    fn delete(&self, id: &PromptId) -> Result<(), StorageError> {
         let path = self.get_prompt_path(id);
         if !path.exists() {
-            return Err(format!("Prompt not found: {}", id));
+            return Err(StorageError::NotFound(id.to_string()));
         }
-        std::fs::remove_file(&path)
-            .map_err(|e| format!("Failed to delete file: {}", e))
+        std::fs::remove_file(&path).map_err(|e| StorageError::prompt_io(&id.to_string(), &path, e))
     }
 }
 
@@ -141,6 +259,7 @@ impl Default for FilePromptRepository {
             Self {
                 prompts_dir: PathBuf::from(".prompter/prompts"),
                 parser: YamlParser::new(),
+                vault: None,
             }
         })
     }
@@ -153,19 +272,11 @@ mod tests {
 
     fn create_test_prompt(id: &str, name: &str) -> Prompt {
         Prompt {
-            id: id.to_string(),
             name: name.to_string(),
             description: "Test description".to_string(),
-            content: "Test content".to_string(),
             folder: "test".to_string(),
-            icon: "📝".to_string(),
-            color: "#3B82F6".to_string(),
             tags: vec!["test".to_string()],
-            variables: vec![],
-            auto_paste: false,
-            is_favorite: false,
-            created_at: "2025-01-01T00:00:00Z".to_string(),
-            updated_at: "2025-01-01T00:00:00Z".to_string(),
+            ..Prompt::for_test(id)
         }
     }
 
@@ -194,6 +305,110 @@ mod tests {
         assert_eq!(prompts.len(), 2);
     }
 
+    #[test]
+    fn test_find_all_with_failures_reports_unparseable_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FilePromptRepository::with_directory(temp_dir.path().to_path_buf()).unwrap();
+
+        repo.save(&create_test_prompt("good.md", "Good")).unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("Coding")).unwrap();
+        std::fs::write(temp_dir.path().join("Coding/bad.md"), "no frontmatter").unwrap();
+
+        let (prompts, failures) = repo.find_all_with_failures().unwrap();
+        assert_eq!(prompts.len(), 1);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].id, "Coding/bad.md");
+    }
+
+    #[test]
+    fn test_find_all_skips_hidden_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FilePromptRepository::with_directory(temp_dir.path().to_path_buf()).unwrap();
+
+        repo.save(&create_test_prompt("prompt.md", "Prompt")).unwrap();
+        std::fs::create_dir_all(temp_dir.path().join(".git")).unwrap();
+        std::fs::write(temp_dir.path().join(".git/notes.md"), "not a prompt").unwrap();
+
+        let (prompts, failures) = repo.find_all_with_failures().unwrap();
+        assert_eq!(prompts.len(), 1);
+        assert!(failures.is_empty());
+    }
+
+    fn private_repo(temp_dir: &TempDir) -> (FilePromptRepository, PassphraseVault) {
+        let vault = PassphraseVault::with_path(temp_dir.path().join("vault.json"));
+        vault.setup("correct horse").unwrap();
+        vault.unlock("correct horse", None).unwrap();
+        let repo = FilePromptRepository::with_directory(temp_dir.path().join("prompts"))
+            .unwrap()
+            .with_vault(vault.clone());
+        (repo, vault)
+    }
+
+    #[test]
+    fn test_private_prompt_is_encrypted_on_disk() {
+        let temp_dir = TempDir::new().unwrap();
+        let (repo, _vault) = private_repo(&temp_dir);
+
+        let mut prompt = create_test_prompt("Clients/acme.md", "Acme brief");
+        prompt.content = "Budget is 40k, contact jane@acme.test".to_string();
+        prompt.private = true;
+        repo.save(&prompt).unwrap();
+
+        let file = std::fs::read_to_string(temp_dir.path().join("prompts/Clients/acme.md")).unwrap();
+        assert!(file.contains("name: Acme brief"));
+        assert!(!file.contains("jane@acme.test"));
+        assert!(!file.contains("Test description"));
+
+        let loaded = repo.find_by_id(&PromptId::new("Clients/acme.md")).unwrap();
+        assert_eq!(loaded.content, prompt.content);
+        assert_eq!(loaded.description, "Test description");
+        assert!(loaded.private && !loaded.locked);
+    }
+
+    #[test]
+    fn test_locked_prompt_hides_content_and_keeps_it_on_metadata_save() {
+        let temp_dir = TempDir::new().unwrap();
+        let (repo, vault) = private_repo(&temp_dir);
+        let mut prompt = create_test_prompt("acme.md", "Acme brief");
+        prompt.content = "Budget is 40k".to_string();
+        prompt.private = true;
+        repo.save(&prompt).unwrap();
+        vault.lock();
+
+        let mut locked = repo.find_all().unwrap().remove(0);
+        assert!(locked.locked);
+        assert!(locked.content.is_empty() && locked.description.is_empty());
+
+        // Favorite toggled while locked: only metadata changes
+        locked.is_favorite = true;
+        repo.save(&locked).unwrap();
+        locked.private = false;
+        repo.save(&locked).unwrap();
+        assert!(!std::fs::read_to_string(temp_dir.path().join("prompts/acme.md")).unwrap().contains("locked"));
+
+        vault.unlock("correct horse", None).unwrap();
+        let unlocked = repo.find_by_id(&PromptId::new("acme.md")).unwrap();
+        assert!(unlocked.is_favorite && unlocked.private);
+        assert_eq!(unlocked.content, "Budget is 40k");
+    }
+
+    #[test]
+    fn test_private_folder_encrypts_and_locked_vault_refuses_new_content() {
+        let temp_dir = TempDir::new().unwrap();
+        let (repo, vault) = private_repo(&temp_dir);
+        vault.set_folder_private("Clients", true).unwrap();
+
+        repo.save(&create_test_prompt("Clients/Acme/brief.md", "Brief")).unwrap();
+        let file = std::fs::read_to_string(temp_dir.path().join("prompts/Clients/Acme/brief.md")).unwrap();
+        assert!(file.contains("private: true"));
+        assert!(!file.contains("Test content"));
+
+        vault.lock();
+        let error = repo.save(&create_test_prompt("Clients/new.md", "New")).unwrap_err();
+        assert!(matches!(error, StorageError::Locked(_)));
+        repo.save(&create_test_prompt("General/new.md", "New")).unwrap();
+    }
+
     #[test]
     fn test_delete_prompt() {
         let temp_dir = TempDir::new().unwrap();
@@ -214,7 +429,7 @@ mod tests {
         let repo = FilePromptRepository::with_directory(temp_dir.path().to_path_buf()).unwrap();
 
         let result = repo.find_by_id(&PromptId::new("nonexistent.md"));
-        assert!(result.is_err());
+        assert!(matches!(result, Err(StorageError::NotFound(_))));
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use crate::storage::domain::entities::Prompt;
+use crate::storage::domain::entities::{ParseFailure, Prompt};
 use std::path::Path;
 
 /// YAML + Markdown parser
@@ -11,50 +11,87 @@ impl YamlParser {
     }
 
     /// Parse a prompt file with a base directory to compute relative path for ID
-    pub fn parse_with_base(&self, path: &Path, base_dir: &Path) -> Result<Prompt, String> {
-        let mut prompt = self.parse(path)?;
+    /// Failures keep the file ID and the line that failed
+    pub fn parse_detailed(&self, path: &Path, base_dir: &Path) -> Result<Prompt, ParseFailure> {
+        let id = path
+            .strip_prefix(base_dir)
+            .ok()
+            .and_then(|p| p.to_str())
+            .map(|s| s.replace('\\', "/")) // Normalize path separators for cross-platform consistency
+            .ok_or_else(|| ParseFailure {
+                id: path.display().to_string(),
+                message: "Path is not under base directory".to_string(),
+                line: None,
+            })?;
+
+        let mut prompt = self.parse_file(path).map_err(|(message, line)| ParseFailure {
+            id: id.clone(),
+            message,
+            line,
+        })?;
 
         // Set ID as relative path from base_dir (e.g., "Coding/my-prompt.md")
+        prompt.id = id;
+        Ok(prompt)
+    }
+
+    pub fn parse(&self, path: &Path) -> Result<Prompt, String> {
+        let mut prompt = self.parse_file(path).map_err(|(message, _)| message)?;
+
+        // Set ID from filename only (for backwards compatibility)
+        // Use parse_detailed for proper relative path IDs
         prompt.id = path
-            .strip_prefix(base_dir)
-            .map_err(|_| "Path is not under base directory")?
-            .to_str()
-            .ok_or("Invalid path encoding")?
-            .replace('\\', "/"); // Normalize path separators for cross-platform consistency
+            .file_name()
+            .and_then(|s| s.to_str())
+            .ok_or("Invalid file name")?
+            .to_string();
 
         Ok(prompt)
     }
 
-    pub fn parse(&self, path: &Path) -> Result<Prompt, String> {
+    /// Parse file content that did not come from the prompts dir (e.g. an import)
+    /// `id` becomes the prompt ID and is used to label failures
+    pub fn parse_str(&self, content: &str, id: &str) -> Result<Prompt, ParseFailure> {
+        let mut prompt = self.parse_content(content).map_err(|(message, line)| ParseFailure {
+            id: id.to_string(),
+            message,
+            line,
+        })?;
+        prompt.id = id.to_string();
+        Ok(prompt)
+    }
+
+    /// Read and parse a file; errors carry the 1-based file line when known
+    fn parse_file(&self, path: &Path) -> Result<Prompt, (String, Option<usize>)> {
         let content = std::fs::read_to_string(path)
-            .map_err(|e| format!("Failed to read file: {}", e))?;
+            .map_err(|e| (format!("Failed to read file: {}", e), None))?;
+        self.parse_content(&content)
+    }
 
+    fn parse_content(&self, content: &str) -> Result<Prompt, (String, Option<usize>)> {
         // Split on --- delimiters
         let parts: Vec<&str> = content.split("---").collect();
         if parts.len() < 3 {
-            return Err("Invalid file format: missing frontmatter delimiters".to_string());
+            return Err(("Invalid file format: missing frontmatter delimiters".to_string(), None));
         }
 
         // Parse YAML frontmatter (parts[1] is between first and second ---)
         let frontmatter = parts[1].trim();
         if frontmatter.is_empty() {
-            return Err("Invalid file format: empty frontmatter".to_string());
+            return Err(("Invalid file format: empty frontmatter".to_string(), None));
         }
 
-        let mut prompt: Prompt = serde_yaml::from_str(frontmatter)
-            .map_err(|e| format!("Failed to parse YAML frontmatter: {}", e))?;
+        let mut prompt: Prompt = serde_yaml::from_str(frontmatter).map_err(|e| {
+            // YAML locations are relative to the trimmed frontmatter
+            let frontmatter_offset = parts[0].len() + 3 + (parts[1].len() - parts[1].trim_start().len());
+            let first_line = content[..frontmatter_offset].matches('\n').count() + 1;
+            let line = e.location().map(|loc| first_line + loc.line() - 1);
+            (format!("Failed to parse YAML frontmatter: {}", e), line)
+        })?;
 
         // Extract content (everything after second ---)
         prompt.content = parts[2..].join("---").trim().to_string();
 
-        // Set ID from filename only (for backwards compatibility)
-        // Use parse_with_base for proper relative path IDs
-        prompt.id = path
-            .file_name()
-            .and_then(|s| s.to_str())
-            .ok_or("Invalid file name")?
-            .to_string();
-
         Ok(prompt)
     }
 
@@ -118,6 +155,38 @@ mod tests {
         assert_eq!(prompt.tags, vec!["test", "example"]);
     }
 
+    #[test]
+    fn test_parse_apps_frontmatter() {
+        let temp_dir = TempDir::new().unwrap();
+        let frontmatter = concat!(
+            "name: \"Commit message\"\n",
+            "apps: [\"code\", \"WindowsTerminal.exe\"]"
+        );
+        let path = create_test_prompt_file(&temp_dir, "commit.md", frontmatter, "Write a commit message");
+
+        let parser = YamlParser::new();
+        let prompt = parser.parse(&path).unwrap();
+
+        assert_eq!(prompt.apps, vec!["code", "WindowsTerminal.exe"]);
+    }
+
+    #[test]
+    fn test_parse_detailed_reports_file_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let frontmatter = concat!(
+            "name: \"Broken\"\n",
+            "tags: [unclosed"
+        );
+        let path = create_test_prompt_file(&temp_dir, "broken.md", frontmatter, "Content");
+
+        let parser = YamlParser::new();
+        let failure = parser.parse_detailed(&path, temp_dir.path()).unwrap_err();
+
+        assert_eq!(failure.id, "broken.md");
+        assert!(failure.message.contains("Failed to parse YAML"));
+        assert!(failure.line.is_some_and(|line| line >= 3));
+    }
+
     #[test]
     fn test_parse_missing_delimiters() {
         let temp_dir = TempDir::new().unwrap();
@@ -152,18 +221,13 @@ mod tests {
     fn test_serialize_prompt() {
         let parser = YamlParser::new();
         let prompt = Prompt {
-            id: "test.md".to_string(),
             name: "Test".to_string(),
             description: "Test desc".to_string(),
-            content: "Test content".to_string(),
             folder: "test".to_string(),
             icon: "T".to_string(),
             color: "#000000".to_string(),
             tags: vec!["test".to_string()],
-            variables: vec![],
-            auto_paste: false,
-            created_at: "2025-01-01T00:00:00Z".to_string(),
-            updated_at: "2025-01-01T00:00:00Z".to_string(),
+            ..Prompt::for_test("test.md")
         };
 
         let serialized = parser.serialize(&prompt).unwrap();
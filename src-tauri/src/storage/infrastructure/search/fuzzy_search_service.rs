@@ -106,18 +106,11 @@ mod tests {
 
     fn create_test_prompt(id: &str, name: &str, description: &str, tags: Vec<&str>) -> Prompt {
         Prompt {
-            id: id.to_string(),
             name: name.to_string(),
             description: description.to_string(),
-            content: "Test content".to_string(),
             folder: "test".to_string(),
-            icon: "📝".to_string(),
-            color: "#3B82F6".to_string(),
             tags: tags.iter().map(|s| s.to_string()).collect(),
-            variables: vec![],
-            auto_paste: false,
-            created_at: "2025-01-01T00:00:00Z".to_string(),
-            updated_at: "2025-01-01T00:00:00Z".to_string(),
+            ..Prompt::for_test(id)
         }
     }
 
@@ -4,10 +4,20 @@ pub mod application;
 pub mod infrastructure;
 
 // Re-export types used by commands layer
-pub use domain::entities::Prompt;
-pub use domain::value_objects::PromptId;
-pub use domain::ports::{PromptRepository, SearchResult};
-pub use application::services::FrecencyCalculator;
+pub use domain::entities::{
+    ConflictResolution, ExportFormat, ExportSelection, ExportSummary, ImportFormat, ImportReport, Prompt,
+    SecretInfo, VaultStatus,
+};
+pub use domain::errors::StorageError;
+pub use domain::value_objects::{FrecencyModel, MacroStep, PasteFormat, PasteMode, PromptId, TargetApp};
+pub use domain::ports::{ImportSource, PromptRepository, PromptVault, SearchResult};
+pub use application::services::{
+    FrecencyCalculator, HealthReport, LibraryExportService, LibraryHealthService, LibraryImportService,
+    LibraryMaintenanceService, MaintenanceReport, SecretService,
+};
 pub use application::use_cases::{SearchPromptsUseCase, SavePromptUseCase, RecordUsageUseCase};
+pub use infrastructure::encryption::{EncryptedSecretStore, PassphraseVault};
+pub use infrastructure::export::BundleWriter;
+pub use infrastructure::import::import_source;
 pub use infrastructure::persistence::FilePromptRepository;
 pub use infrastructure::search::FuzzySearchService;
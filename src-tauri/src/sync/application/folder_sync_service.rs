@@ -0,0 +1,323 @@
+use crate::logging::redact;
+use crate::storage::domain::value_objects::ContentHash;
+use crate::sync::domain::entities::{
+    conflict_copy_path, plan_folder_sync, FolderAction, FolderConflict, FolderManifest, FolderSyncItem,
+    FolderSyncReport, FolderSyncStatus, TreeHashes,
+};
+use crate::sync::domain::errors::SyncError;
+use crate::sync::domain::ports::{FileTree, ManifestStore, TreeFiles};
+use chrono::Utc;
+
+/// Shared-folder sync use cases
+/// Orchestrates: read both trees → plan against the last-synced manifest → copy,
+/// delete or keep both versions → record the new manifest
+pub struct FolderSyncService<L: FileTree, S: FileTree, M: ManifestStore> {
+    local: L,
+    shared: S,
+    manifests: M,
+    /// Labels this machine's version in conflict copies
+    host: String,
+}
+
+/// Both trees and the manifest that applies to them
+struct SyncState {
+    local: TreeFiles,
+    shared: TreeFiles,
+    manifest: FolderManifest,
+}
+
+impl<L: FileTree, S: FileTree, M: ManifestStore> FolderSyncService<L, S, M> {
+    pub fn new(local: L, shared: S, manifests: M, host: &str) -> Self {
+        Self {
+            local,
+            shared,
+            manifests,
+            host: host.to_string(),
+        }
+    }
+
+    /// What the next sync would do; does not write anything
+    pub fn status(&self) -> Result<FolderSyncStatus, SyncError> {
+        let folder = self.shared.location();
+        let manifest = self.load_manifest()?;
+        let mut status = FolderSyncStatus {
+            folder,
+            folder_available: self.shared.is_available(),
+            last_synced_at: manifest.synced_at.clone(),
+            tracked: manifest.entries.len(),
+            pending: Vec::new(),
+        };
+        if status.folder_available {
+            let state = self.load_state()?;
+            status.pending = plan_folder_sync(&hashes(&state.local), &hashes(&state.shared), &state.manifest);
+        }
+        Ok(status)
+    }
+
+    /// Bring the library and the shared folder in line
+    pub fn sync(&self) -> Result<FolderSyncReport, SyncError> {
+        let state = self.load_state()?;
+        let local_hashes = hashes(&state.local);
+        let shared_hashes = hashes(&state.shared);
+        let plan = plan_folder_sync(&local_hashes, &shared_hashes, &state.manifest);
+        Self::check_mass_delete(&plan, &state)?;
+
+        // Prompts identical on both sides stay as they are
+        let mut synced: TreeHashes = local_hashes
+            .iter()
+            .filter(|(path, hash)| shared_hashes.get(*path) == Some(hash))
+            .map(|(path, hash)| (path.clone(), hash.clone()))
+            .collect();
+        let mut report = FolderSyncReport::default();
+
+        for item in plan {
+            let path = item.path;
+            match item.action {
+                FolderAction::Upload => {
+                    self.shared.write(&path, &state.local[&path])?;
+                    synced.insert(path.clone(), local_hashes[&path].clone());
+                    report.uploaded.push(path);
+                }
+                FolderAction::Download => {
+                    self.local.write(&path, &state.shared[&path])?;
+                    synced.insert(path.clone(), shared_hashes[&path].clone());
+                    report.downloaded.push(path);
+                }
+                FolderAction::DeleteLocal => {
+                    self.local.delete(&path)?;
+                    report.deleted_local.push(path);
+                }
+                FolderAction::DeleteShared => {
+                    self.shared.delete(&path)?;
+                    report.deleted_shared.push(path);
+                }
+                FolderAction::Conflict => {
+                    // The shared version keeps its place; ours is kept next to it on both sides
+                    let copy_path = conflict_copy_path(&path, &self.host, |candidate| {
+                        state.local.contains_key(candidate)
+                            || state.shared.contains_key(candidate)
+                            || synced.contains_key(candidate)
+                    });
+                    let ours = &state.local[&path];
+                    self.local.write(&copy_path, ours)?;
+                    self.shared.write(&copy_path, ours)?;
+                    self.local.write(&path, &state.shared[&path])?;
+
+                    synced.insert(path.clone(), shared_hashes[&path].clone());
+                    synced.insert(copy_path.clone(), local_hashes[&path].clone());
+                    log::warn!("[FolderSyncService] Conflict in {}; local version saved as {}", redact(&path), redact(&copy_path));
+                    report.conflicts.push(FolderConflict { path, copy_path });
+                }
+            }
+        }
+
+        let mut manifest = state.manifest;
+        manifest.record(synced, Utc::now().to_rfc3339());
+        self.manifests.save(&manifest)?;
+
+        log::info!(
+            "[FolderSyncService] Synced with {}: {} up, {} down, {} deleted, {} conflicts",
+            manifest.folder,
+            report.uploaded.len(),
+            report.downloaded.len(),
+            report.deleted_local.len() + report.deleted_shared.len(),
+            report.conflicts.len()
+        );
+        Ok(report)
+    }
+
+    /// The saved manifest if it belongs to the current shared folder, else an empty one
+    fn load_manifest(&self) -> Result<FolderManifest, SyncError> {
+        let folder = self.shared.location();
+        Ok(self
+            .manifests
+            .load()?
+            .filter(|manifest| manifest.folder == folder)
+            .unwrap_or(FolderManifest {
+                folder,
+                ..Default::default()
+            }))
+    }
+
+    fn load_state(&self) -> Result<SyncState, SyncError> {
+        if !self.shared.is_available() {
+            return Err(SyncError::FolderNotFound(self.shared.location()));
+        }
+        Ok(SyncState {
+            local: self.local.read_all()?,
+            shared: self.shared.read_all()?,
+            manifest: self.load_manifest()?,
+        })
+    }
+
+    /// Business rule: a side that suddenly has no prompts at all is far more likely an
+    /// unmounted drive or a wiped folder than a deliberate deletion; refuse to propagate it
+    fn check_mass_delete(plan: &[FolderSyncItem], state: &SyncState) -> Result<(), SyncError> {
+        let count = |action: FolderAction| plan.iter().filter(|item| item.action == action).count();
+        if state.shared.is_empty() && !state.local.is_empty() && count(FolderAction::DeleteLocal) == state.local.len() {
+            return Err(SyncError::Other(format!(
+                "Sync folder is empty; refusing to delete all {} local prompts",
+                state.local.len()
+            )));
+        }
+        if state.local.is_empty() && !state.shared.is_empty() && count(FolderAction::DeleteShared) == state.shared.len() {
+            return Err(SyncError::Other(format!(
+                "Library is empty; refusing to delete all {} prompts from the sync folder",
+                state.shared.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn hashes(files: &TreeFiles) -> TreeHashes {
+    files
+        .iter()
+        .map(|(path, content)| (path.clone(), ContentHash::of(&String::from_utf8_lossy(content))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::infrastructure::{DirectoryTree, JsonManifestStore};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use tempfile::TempDir;
+
+    type Service = FolderSyncService<DirectoryTree, DirectoryTree, JsonManifestStore>;
+
+    /// Two machines ("laptop" and "desktop") sharing one folder, each with its own manifest
+    struct Setup {
+        _temp_dir: TempDir,
+        laptop_dir: PathBuf,
+        desktop_dir: PathBuf,
+        shared_dir: PathBuf,
+        laptop: Service,
+        desktop: Service,
+    }
+
+    fn machine(root: &Path, name: &str, shared_dir: &Path) -> (PathBuf, Service) {
+        let dir = root.join(name);
+        fs::create_dir_all(&dir).unwrap();
+        let service = FolderSyncService::new(
+            DirectoryTree::new(dir.clone()),
+            DirectoryTree::new(shared_dir.to_path_buf()),
+            JsonManifestStore::with_path(root.join(format!("{}-manifest.json", name))),
+            name,
+        );
+        (dir, service)
+    }
+
+    fn setup() -> Setup {
+        let temp_dir = TempDir::new().unwrap();
+        let shared_dir = temp_dir.path().join("OneDrive/Prompts");
+        fs::create_dir_all(&shared_dir).unwrap();
+        let (laptop_dir, laptop) = machine(temp_dir.path(), "laptop", &shared_dir);
+        let (desktop_dir, desktop) = machine(temp_dir.path(), "desktop", &shared_dir);
+        Setup {
+            _temp_dir: temp_dir,
+            laptop_dir,
+            desktop_dir,
+            shared_dir,
+            laptop,
+            desktop,
+        }
+    }
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let file = dir.join(path);
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        fs::write(file, content).unwrap();
+    }
+
+    fn read(dir: &Path, path: &str) -> String {
+        fs::read_to_string(dir.join(path)).unwrap()
+    }
+
+    #[test]
+    fn test_edits_and_deletes_reach_other_machine() {
+        let s = setup();
+        write(&s.laptop_dir, "Coding/review.md", "v1");
+        write(&s.laptop_dir, "old.md", "old");
+        s.laptop.sync().unwrap();
+        s.desktop.sync().unwrap();
+        assert_eq!(read(&s.desktop_dir, "Coding/review.md"), "v1");
+
+        write(&s.desktop_dir, "Coding/review.md", "v2");
+        fs::remove_file(s.desktop_dir.join("old.md")).unwrap();
+        let report = s.desktop.sync().unwrap();
+        assert_eq!(report.uploaded, vec!["Coding/review.md"]);
+        assert_eq!(report.deleted_shared, vec!["old.md"]);
+
+        let report = s.laptop.sync().unwrap();
+        assert_eq!(report.downloaded, vec!["Coding/review.md"]);
+        assert_eq!(report.deleted_local, vec!["old.md"]);
+        assert_eq!(read(&s.laptop_dir, "Coding/review.md"), "v2");
+        assert!(!s.laptop_dir.join("old.md").exists());
+    }
+
+    #[test]
+    fn test_divergent_edits_keep_both_versions() {
+        let s = setup();
+        write(&s.laptop_dir, "review.md", "base");
+        s.laptop.sync().unwrap();
+        s.desktop.sync().unwrap();
+
+        write(&s.laptop_dir, "review.md", "laptop edit");
+        s.laptop.sync().unwrap();
+        write(&s.desktop_dir, "review.md", "desktop edit");
+
+        let report = s.desktop.sync().unwrap();
+        assert_eq!(
+            report.conflicts,
+            vec![FolderConflict {
+                path: "review.md".to_string(),
+                copy_path: "review (conflict from desktop).md".to_string(),
+            }]
+        );
+        assert_eq!(read(&s.desktop_dir, "review.md"), "laptop edit");
+        assert_eq!(read(&s.desktop_dir, "review (conflict from desktop).md"), "desktop edit");
+        assert_eq!(read(&s.shared_dir, "review (conflict from desktop).md"), "desktop edit");
+
+        // Both machines converge on both versions
+        s.laptop.sync().unwrap();
+        assert_eq!(read(&s.laptop_dir, "review (conflict from desktop).md"), "desktop edit");
+        assert!(s.laptop.status().unwrap().pending.is_empty());
+    }
+
+    #[test]
+    fn test_status_previews_without_writing() {
+        let s = setup();
+        write(&s.laptop_dir, "new.md", "new");
+        write(&s.shared_dir, "remote.md", "remote");
+
+        let status = s.laptop.status().unwrap();
+        assert!(status.folder_available);
+        assert_eq!(status.last_synced_at, None);
+        let pending: Vec<(&str, FolderAction)> = status.pending.iter().map(|i| (i.path.as_str(), i.action)).collect();
+        assert_eq!(pending, vec![("new.md", FolderAction::Upload), ("remote.md", FolderAction::Download)]);
+        assert!(!s.shared_dir.join("new.md").exists());
+
+        s.laptop.sync().unwrap();
+        let status = s.laptop.status().unwrap();
+        assert_eq!(status.tracked, 2);
+        assert!(status.last_synced_at.is_some());
+    }
+
+    #[test]
+    fn test_refuses_to_propagate_empty_folder() {
+        let s = setup();
+        write(&s.laptop_dir, "a.md", "a");
+        write(&s.laptop_dir, "b.md", "b");
+        s.laptop.sync().unwrap();
+
+        fs::remove_dir_all(&s.shared_dir).unwrap();
+        assert_eq!(s.laptop.sync().unwrap_err().code(), "not_found");
+        assert!(!s.laptop.status().unwrap().folder_available);
+
+        fs::create_dir_all(&s.shared_dir).unwrap();
+        assert!(s.laptop.sync().unwrap_err().to_string().contains("refusing"));
+        assert!(s.laptop_dir.join("a.md").exists());
+    }
+}
@@ -0,0 +1,6 @@
+// Application layer - orchestrates git and shared-folder synchronization
+pub mod folder_sync_service;
+pub mod sync_service;
+
+pub use folder_sync_service::FolderSyncService;
+pub use sync_service::SyncService;
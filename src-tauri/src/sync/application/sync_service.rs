@@ -0,0 +1,355 @@
+use crate::logging::redact;
+use crate::sync::domain::entities::{commit_message, ConflictChoice, SyncConflict, SyncReport, SyncStatus};
+use crate::sync::domain::errors::SyncError;
+use crate::sync::domain::ports::VersionControl;
+
+/// Sync use cases
+/// Orchestrates: commit local changes → fetch → rebase onto the remote → push,
+/// pausing on conflicts until each one is resolved
+pub struct SyncService<V: VersionControl> {
+    vcs: V,
+    remote: String,
+}
+
+impl<V: VersionControl> SyncService<V> {
+    pub fn new(vcs: V, remote: &str) -> Self {
+        Self {
+            vcs,
+            remote: remote.to_string(),
+        }
+    }
+
+    /// Working tree state without touching the network
+    pub fn status(&self) -> Result<SyncStatus, SyncError> {
+        let mut status = SyncStatus {
+            remote: self.remote.clone(),
+            ..Default::default()
+        };
+        if !self.vcs.is_repository() {
+            return Ok(status);
+        }
+
+        status.is_repository = true;
+        status.branch = self.vcs.current_branch()?;
+        status.has_remote = self.vcs.has_remote(&self.remote)?;
+        status.changes = self.vcs.changes()?;
+        status.conflicts = self.vcs.conflicted_paths()?;
+        if let (Some(branch), true) = (&status.branch, status.has_remote) {
+            (status.ahead, status.behind) = self.vcs.ahead_behind(&self.remote, branch)?;
+        }
+        Ok(status)
+    }
+
+    /// Commit uncommitted changes with a generated message
+    /// Returns the message, or None if there was nothing to commit or a conflict is pending
+    pub fn commit_changes(&self) -> Result<Option<String>, SyncError> {
+        self.ensure_repository()?;
+        if self.vcs.is_rebasing() {
+            return Ok(None);
+        }
+
+        let changes = self.vcs.changes()?;
+        if changes.is_empty() {
+            return Ok(None);
+        }
+
+        let message = commit_message(&changes);
+        self.vcs.commit_all(&message)?;
+        log::info!("[SyncService] Committed {} changed files", changes.len());
+        Ok(Some(message))
+    }
+
+    /// Commit, pull (rebase) and push
+    /// Stops with the conflicts listed in the report if the rebase cannot be completed
+    pub fn sync(&self) -> Result<SyncReport, SyncError> {
+        self.ensure_repository()?;
+        if self.vcs.is_rebasing() {
+            return Ok(SyncReport {
+                conflicts: self.conflicts()?,
+                ..Default::default()
+            });
+        }
+
+        let mut report = SyncReport {
+            committed: self.commit_changes()?,
+            ..Default::default()
+        };
+        if !self.vcs.has_remote(&self.remote)? {
+            log::info!("[SyncService] No remote '{}'; committed locally only", self.remote);
+            return Ok(report);
+        }
+
+        let branch = self.branch()?;
+        self.vcs.fetch(&self.remote)?;
+        let (_, behind) = self.vcs.ahead_behind(&self.remote, &branch)?;
+        if behind > 0 {
+            self.vcs.rebase(&self.remote, &branch)?;
+            report.pulled = behind;
+            if self.vcs.is_rebasing() {
+                report.conflicts = self.conflicts()?;
+                log::warn!("[SyncService] Sync paused on {} conflicts", report.conflicts.len());
+                return Ok(report);
+            }
+        }
+
+        report.pushed = self.push_pending(&branch)?;
+        log::info!("[SyncService] Synced: pulled {}, pushed {}", report.pulled, report.pushed);
+        Ok(report)
+    }
+
+    /// Unresolved conflicts with all three versions of each file
+    pub fn conflicts(&self) -> Result<Vec<SyncConflict>, SyncError> {
+        self.vcs
+            .conflicted_paths()?
+            .into_iter()
+            .map(|path| {
+                let versions = self.vcs.conflict_versions(&path)?;
+                Ok(SyncConflict {
+                    path,
+                    base: versions.base,
+                    local: versions.local,
+                    remote: versions.remote,
+                })
+            })
+            .collect()
+    }
+
+    /// Resolve one conflict; once none remain the rebase continues and the result is pushed
+    /// The report lists any conflicts still (or newly) pending
+    pub fn resolve(&self, path: &str, choice: ConflictChoice) -> Result<SyncReport, SyncError> {
+        if !self.vcs.conflicted_paths()?.iter().any(|p| p == path) {
+            return Err(SyncError::NoConflict(path.to_string()));
+        }
+
+        let content = match choice {
+            ConflictChoice::Local => self.vcs.conflict_versions(path)?.local,
+            ConflictChoice::Remote => self.vcs.conflict_versions(path)?.remote,
+            ConflictChoice::Merged(content) => Some(content),
+        };
+        self.vcs.resolve(path, content.as_deref())?;
+        log::info!("[SyncService] Resolved conflict in {}", redact(path));
+
+        let mut report = SyncReport::default();
+        if self.vcs.conflicted_paths()?.is_empty() {
+            self.vcs.continue_rebase()?;
+        }
+        if self.vcs.is_rebasing() {
+            report.conflicts = self.conflicts()?;
+            return Ok(report);
+        }
+
+        report.pushed = self.push_pending(&self.branch()?)?;
+        Ok(report)
+    }
+
+    /// Give up a paused sync, restoring the library to its state before the pull
+    pub fn abort(&self) -> Result<(), SyncError> {
+        if self.vcs.is_rebasing() {
+            self.vcs.abort_rebase()?;
+            log::info!("[SyncService] Aborted paused sync");
+        }
+        Ok(())
+    }
+
+    fn push_pending(&self, branch: &str) -> Result<usize, SyncError> {
+        let (ahead, _) = self.vcs.ahead_behind(&self.remote, branch)?;
+        if ahead > 0 {
+            self.vcs.push(&self.remote, branch)?;
+        }
+        Ok(ahead)
+    }
+
+    fn branch(&self) -> Result<String, SyncError> {
+        self.vcs.current_branch()?.ok_or_else(|| {
+            SyncError::NotConfigured("Cannot sync: no branch is checked out in the prompts directory".to_string())
+        })
+    }
+
+    fn ensure_repository(&self) -> Result<(), SyncError> {
+        if self.vcs.is_repository() {
+            Ok(())
+        } else {
+            Err(SyncError::NotConfigured("Prompts directory is not a git repository".to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::infrastructure::GitCliVersionControl;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    const PROMPT: &str = "Coding/review.md";
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com", "-C"])
+            .arg(dir)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?}: {}", args, String::from_utf8_lossy(&output.stderr));
+    }
+
+    /// Two clones ("a" and "b") of a local bare repository seeded with one prompt
+    struct Machines {
+        _temp_dir: TempDir,
+        a_dir: PathBuf,
+        b_dir: PathBuf,
+        a: SyncService<GitCliVersionControl>,
+        b: SyncService<GitCliVersionControl>,
+    }
+
+    impl Machines {
+        fn write(dir: &Path, content: &str) {
+            fs::create_dir_all(dir.join("Coding")).unwrap();
+            fs::write(dir.join(PROMPT), content).unwrap();
+        }
+
+        fn read(dir: &Path) -> String {
+            fs::read_to_string(dir.join(PROMPT)).unwrap()
+        }
+    }
+
+    fn setup() -> Machines {
+        let temp_dir = TempDir::new().unwrap();
+        let bare = temp_dir.path().join("remote.git");
+        let a_dir = temp_dir.path().join("a");
+        let b_dir = temp_dir.path().join("b");
+        git(temp_dir.path(), &["init", "--quiet", "--bare", "-b", "main", bare.to_str().unwrap()]);
+
+        fs::create_dir_all(&a_dir).unwrap();
+        git(&a_dir, &["init", "--quiet", "-b", "main"]);
+        git(&a_dir, &["remote", "add", "origin", bare.to_str().unwrap()]);
+        Machines::write(&a_dir, "seed");
+        let a = SyncService::new(GitCliVersionControl::new(a_dir.clone()), "origin");
+        a.sync().unwrap();
+
+        git(temp_dir.path(), &["clone", "--quiet", bare.to_str().unwrap(), b_dir.to_str().unwrap()]);
+        let b = SyncService::new(GitCliVersionControl::new(b_dir.clone()), "origin");
+
+        Machines {
+            _temp_dir: temp_dir,
+            a_dir,
+            b_dir,
+            a,
+            b,
+        }
+    }
+
+    /// Both machines edit the same prompt; "a" syncs first, so "b" stops on a conflict
+    fn setup_conflict() -> Machines {
+        let machines = setup();
+        Machines::write(&machines.a_dir, "from a");
+        machines.a.sync().unwrap();
+        Machines::write(&machines.b_dir, "from b");
+        machines
+    }
+
+    #[test]
+    fn test_sync_commits_pushes_and_pulls() {
+        let machines = setup();
+        Machines::write(&machines.a_dir, "edited on a");
+
+        let report = machines.a.sync().unwrap();
+        assert!(report.committed.unwrap().starts_with("Update Coding/review\n"));
+        assert_eq!(report.pushed, 1);
+
+        let report = machines.b.sync().unwrap();
+        assert_eq!(report.committed, None);
+        assert_eq!(report.pulled, 1);
+        assert_eq!(Machines::read(&machines.b_dir), "edited on a");
+    }
+
+    #[test]
+    fn test_sync_rebases_independent_changes() {
+        let machines = setup();
+        fs::write(machines.a_dir.join("from-a.md"), "a").unwrap();
+        machines.a.sync().unwrap();
+        fs::write(machines.b_dir.join("from-b.md"), "b").unwrap();
+
+        let report = machines.b.sync().unwrap();
+        assert_eq!((report.pulled, report.pushed), (1, 1));
+        assert!(report.conflicts.is_empty());
+        assert!(machines.b_dir.join("from-a.md").exists());
+
+        machines.a.sync().unwrap();
+        assert!(machines.a_dir.join("from-b.md").exists());
+        let status = machines.a.status().unwrap();
+        assert_eq!((status.ahead, status.behind), (0, 0));
+    }
+
+    #[test]
+    fn test_conflict_reported_with_all_versions() {
+        let machines = setup_conflict();
+
+        let report = machines.b.sync().unwrap();
+        assert_eq!(
+            report.conflicts,
+            vec![SyncConflict {
+                path: PROMPT.to_string(),
+                base: Some("seed".to_string()),
+                local: Some("from b".to_string()),
+                remote: Some("from a".to_string()),
+            }]
+        );
+        assert_eq!(machines.b.status().unwrap().conflicts, vec![PROMPT]);
+
+        // Further syncs and saves wait for the conflict to be resolved
+        assert_eq!(machines.b.sync().unwrap().conflicts.len(), 1);
+        assert_eq!(machines.b.commit_changes().unwrap(), None);
+    }
+
+    #[test]
+    fn test_resolve_with_merged_content_pushes() {
+        let machines = setup_conflict();
+        machines.b.sync().unwrap();
+
+        let report = machines.b.resolve(PROMPT, ConflictChoice::Merged("from a and b".to_string())).unwrap();
+        assert!(report.conflicts.is_empty());
+        assert_eq!(report.pushed, 1);
+
+        machines.a.sync().unwrap();
+        assert_eq!(Machines::read(&machines.a_dir), "from a and b");
+    }
+
+    #[test]
+    fn test_resolve_with_remote_drops_local_edit() {
+        let machines = setup_conflict();
+        machines.b.sync().unwrap();
+
+        let report = machines.b.resolve(PROMPT, ConflictChoice::Remote).unwrap();
+        assert_eq!(report.pushed, 0);
+        assert_eq!(Machines::read(&machines.b_dir), "from a");
+
+        let status = machines.b.status().unwrap();
+        assert!(status.conflicts.is_empty() && status.changes.is_empty());
+        assert_eq!((status.ahead, status.behind), (0, 0));
+    }
+
+    #[test]
+    fn test_abort_restores_local_version() {
+        let machines = setup_conflict();
+        machines.b.sync().unwrap();
+        assert!(matches!(machines.b.resolve("other.md", ConflictChoice::Local), Err(SyncError::NoConflict(_))));
+
+        machines.b.abort().unwrap();
+        assert_eq!(Machines::read(&machines.b_dir), "from b");
+        let status = machines.b.status().unwrap();
+        assert!(status.conflicts.is_empty());
+        assert_eq!((status.ahead, status.behind), (1, 1));
+    }
+
+    #[test]
+    fn test_not_a_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        let service = SyncService::new(GitCliVersionControl::new(temp_dir.path().to_path_buf()), "origin");
+
+        assert!(!service.status().unwrap().is_repository);
+        assert_eq!(service.sync().unwrap_err().code(), "sync_not_configured");
+    }
+}
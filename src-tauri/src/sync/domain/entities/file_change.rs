@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+/// Top-level directory holding version history; changes there are not prompt edits
+const VERSIONS_DIR_PREFIX: &str = ".versions/";
+
+/// How a file differs from the last commit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    Deleted,
+}
+
+impl ChangeKind {
+    fn verb(&self) -> &'static str {
+        match self {
+            Self::Added => "Add",
+            Self::Modified => "Update",
+            Self::Deleted => "Delete",
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Modified => "modified",
+            Self::Deleted => "deleted",
+        }
+    }
+}
+
+/// An uncommitted change in the prompts directory
+/// `path` is relative to the prompts directory, e.g. "Coding/review.md"
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileChange {
+    pub path: String,
+    pub kind: ChangeKind,
+}
+
+impl FileChange {
+    /// Prompt files, as opposed to version history and other library data
+    pub fn is_prompt(&self) -> bool {
+        self.path.ends_with(".md") && !self.path.starts_with(VERSIONS_DIR_PREFIX)
+    }
+}
+
+/// Business rule: the subject names the prompt for a single edit and counts prompts otherwise;
+/// the body lists every changed file
+pub fn commit_message(changes: &[FileChange]) -> String {
+    let prompts: Vec<&FileChange> = changes.iter().filter(|c| c.is_prompt()).collect();
+    let subject = match prompts.as_slice() {
+        [] => "Update prompt history".to_string(),
+        [change] => format!("{} {}", change.kind.verb(), change.path.trim_end_matches(".md")),
+        many if many.iter().all(|c| c.kind == many[0].kind) => {
+            format!("{} {} prompts", many[0].kind.verb(), many.len())
+        }
+        many => format!("Update {} prompts", many.len()),
+    };
+
+    let body: Vec<String> = changes
+        .iter()
+        .map(|c| format!("{}: {}", c.kind.as_str(), c.path))
+        .collect();
+    format!("{}\n\n{}", subject, body.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(path: &str, kind: ChangeKind) -> FileChange {
+        FileChange {
+            path: path.to_string(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_single_prompt_subject() {
+        let message = commit_message(&[
+            change("Coding/review.md", ChangeKind::Modified),
+            change(".versions/Coding/review.md/abc.json", ChangeKind::Added),
+        ]);
+        assert_eq!(
+            message,
+            "Update Coding/review\n\nmodified: Coding/review.md\nadded: .versions/Coding/review.md/abc.json"
+        );
+    }
+
+    #[test]
+    fn test_many_prompts_subject() {
+        let added = commit_message(&[change("a.md", ChangeKind::Added), change("b.md", ChangeKind::Added)]);
+        assert!(added.starts_with("Add 2 prompts\n"));
+
+        let mixed = commit_message(&[change("a.md", ChangeKind::Added), change("b.md", ChangeKind::Deleted)]);
+        assert!(mixed.starts_with("Update 2 prompts\n"));
+    }
+
+    #[test]
+    fn test_history_only_subject() {
+        let message = commit_message(&[change(".versions/a.md/1.json", ChangeKind::Deleted)]);
+        assert!(message.starts_with("Update prompt history\n"));
+    }
+}
@@ -0,0 +1,234 @@
+use crate::storage::domain::value_objects::ContentHash;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Content hashes keyed by path relative to the library root, e.g. "Coding/review.md"
+pub type TreeHashes = BTreeMap<String, ContentHash>;
+
+/// What this machine last synced with a shared folder
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FolderManifest {
+    /// Shared folder the entries refer to; a different folder starts from scratch
+    pub folder: String,
+    pub synced_at: Option<String>,
+    pub entries: BTreeMap<String, ManifestEntry>,
+}
+
+/// Last-synced state of one prompt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub hash: ContentHash,
+    /// Incremented every time a different version of the prompt is synced
+    pub revision: u64,
+}
+
+impl FolderManifest {
+    /// Record `synced` as the new last-synced state, bumping revisions of changed prompts
+    pub fn record(&mut self, synced: TreeHashes, synced_at: String) {
+        let entries = synced
+            .into_iter()
+            .map(|(path, hash)| {
+                let revision = match self.entries.get(&path) {
+                    Some(entry) if entry.hash == hash => entry.revision,
+                    Some(entry) => entry.revision + 1,
+                    None => 1,
+                };
+                (path, ManifestEntry { hash, revision })
+            })
+            .collect();
+        self.entries = entries;
+        self.synced_at = Some(synced_at);
+    }
+}
+
+/// What a folder sync does with one prompt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FolderAction {
+    /// Copy the local version to the shared folder
+    Upload,
+    /// Copy the shared version into the library
+    Download,
+    /// Deleted in the shared folder; delete locally
+    DeleteLocal,
+    /// Deleted locally; delete from the shared folder
+    DeleteShared,
+    /// Edited on both sides; keep both versions
+    Conflict,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FolderSyncItem {
+    pub path: String,
+    pub action: FolderAction,
+}
+
+/// Business rule: compare both sides against the last-synced hash to see who changed what
+/// A prompt changed on both sides (or differing with no common history) is a conflict;
+/// an edit always wins over a delete on the other side
+pub fn plan_folder_sync(local: &TreeHashes, shared: &TreeHashes, manifest: &FolderManifest) -> Vec<FolderSyncItem> {
+    let mut paths: Vec<&String> = local.keys().chain(shared.keys()).collect();
+    paths.sort();
+    paths.dedup();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let base = manifest.entries.get(path).map(|entry| &entry.hash);
+            let action = match (local.get(path), shared.get(path)) {
+                (Some(l), Some(s)) if l == s => return None,
+                (Some(l), Some(_)) if base == Some(l) => FolderAction::Download,
+                (Some(_), Some(s)) if base == Some(s) => FolderAction::Upload,
+                (Some(_), Some(_)) => FolderAction::Conflict,
+                (Some(l), None) if base == Some(l) => FolderAction::DeleteLocal,
+                (Some(_), None) => FolderAction::Upload,
+                (None, Some(s)) if base == Some(s) => FolderAction::DeleteShared,
+                (None, Some(_)) => FolderAction::Download,
+                (None, None) => return None,
+            };
+            Some(FolderSyncItem {
+                path: path.clone(),
+                action,
+            })
+        })
+        .collect()
+}
+
+/// "Coding/review.md" -> "Coding/review (conflict from HOST).md", numbered if already taken
+pub fn conflict_copy_path(path: &str, host: &str, is_taken: impl Fn(&str) -> bool) -> String {
+    let file = Path::new(path);
+    let stem = file.file_stem().and_then(|s| s.to_str()).unwrap_or("prompt");
+    let extension = file.extension().and_then(|s| s.to_str()).unwrap_or("md");
+    let dir = path.rsplit_once('/').map(|(dir, _)| format!("{}/", dir)).unwrap_or_default();
+
+    let mut counter = 1;
+    loop {
+        let suffix = if counter == 1 { String::new() } else { format!(" {}", counter) };
+        let candidate = format!("{}{} (conflict from {}{}).{}", dir, stem, host, suffix, extension);
+        if !is_taken(&candidate) {
+            return candidate;
+        }
+        counter += 1;
+    }
+}
+
+/// A prompt edited on both sides; the shared version keeps the path, the local one is copied
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FolderConflict {
+    pub path: String,
+    /// Where this machine's version was saved
+    pub copy_path: String,
+}
+
+/// Pending changes between the library and the shared folder
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FolderSyncStatus {
+    pub folder: String,
+    /// False if the shared folder cannot be reached
+    pub folder_available: bool,
+    pub last_synced_at: Option<String>,
+    /// Prompts in the last-synced manifest
+    pub tracked: usize,
+    /// What the next sync would do
+    pub pending: Vec<FolderSyncItem>,
+}
+
+/// What a folder sync did
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FolderSyncReport {
+    pub uploaded: Vec<String>,
+    pub downloaded: Vec<String>,
+    pub deleted_local: Vec<String>,
+    pub deleted_shared: Vec<String>,
+    pub conflicts: Vec<FolderConflict>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hashes(files: &[(&str, &str)]) -> TreeHashes {
+        files
+            .iter()
+            .map(|(path, content)| (path.to_string(), ContentHash::of(content)))
+            .collect()
+    }
+
+    fn manifest(files: &[(&str, &str)]) -> FolderManifest {
+        let mut manifest = FolderManifest::default();
+        manifest.record(hashes(files), "2025-01-01T00:00:00Z".to_string());
+        manifest
+    }
+
+    fn plan(local: &[(&str, &str)], shared: &[(&str, &str)], base: &[(&str, &str)]) -> Vec<(String, FolderAction)> {
+        plan_folder_sync(&hashes(local), &hashes(shared), &manifest(base))
+            .into_iter()
+            .map(|item| (item.path, item.action))
+            .collect()
+    }
+
+    #[test]
+    fn test_one_sided_edits_propagate() {
+        let base = [("a.md", "a"), ("b.md", "b")];
+        let actions = plan(&[("a.md", "a2"), ("b.md", "b")], &[("a.md", "a"), ("b.md", "b2")], &base);
+        assert_eq!(
+            actions,
+            vec![("a.md".to_string(), FolderAction::Upload), ("b.md".to_string(), FolderAction::Download)]
+        );
+    }
+
+    #[test]
+    fn test_divergent_edits_conflict() {
+        assert_eq!(
+            plan(&[("a.md", "local")], &[("a.md", "shared")], &[("a.md", "base")]),
+            vec![("a.md".to_string(), FolderAction::Conflict)]
+        );
+        // No common history: differing content cannot be merged either
+        assert_eq!(
+            plan(&[("a.md", "local")], &[("a.md", "shared")], &[]),
+            vec![("a.md".to_string(), FolderAction::Conflict)]
+        );
+        assert!(plan(&[("a.md", "same")], &[("a.md", "same")], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_deletes_propagate_unless_edited() {
+        let base = [("gone-locally.md", "x"), ("gone-shared.md", "y"), ("edited.md", "z")];
+        let actions = plan(
+            &[("gone-shared.md", "y"), ("edited.md", "z2")],
+            &[("gone-locally.md", "x")],
+            &base,
+        );
+        assert_eq!(
+            actions,
+            vec![
+                ("edited.md".to_string(), FolderAction::Upload),
+                ("gone-locally.md".to_string(), FolderAction::DeleteShared),
+                ("gone-shared.md".to_string(), FolderAction::DeleteLocal),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_record_bumps_revisions_of_changed_prompts() {
+        let mut manifest = manifest(&[("a.md", "a"), ("b.md", "b")]);
+        manifest.record(hashes(&[("a.md", "a2"), ("b.md", "b"), ("c.md", "c")]), "later".to_string());
+
+        let revisions: Vec<(&str, u64)> = manifest.entries.iter().map(|(p, e)| (p.as_str(), e.revision)).collect();
+        assert_eq!(revisions, vec![("a.md", 2), ("b.md", 1), ("c.md", 1)]);
+        assert_eq!(manifest.synced_at.as_deref(), Some("later"));
+    }
+
+    #[test]
+    fn test_conflict_copy_path() {
+        assert_eq!(
+            conflict_copy_path("Coding/review.md", "LAPTOP", |_| false),
+            "Coding/review (conflict from LAPTOP).md"
+        );
+        assert_eq!(
+            conflict_copy_path("review.md", "LAPTOP", |p| p == "review (conflict from LAPTOP).md"),
+            "review (conflict from LAPTOP 2).md"
+        );
+    }
+}
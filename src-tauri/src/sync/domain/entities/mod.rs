@@ -0,0 +1,13 @@
+// Domain entities
+pub mod file_change;
+pub mod folder_sync;
+pub mod sync_policy;
+pub mod sync_state;
+
+pub use file_change::{commit_message, ChangeKind, FileChange};
+pub use folder_sync::{
+    conflict_copy_path, plan_folder_sync, FolderAction, FolderConflict, FolderManifest, FolderSyncItem,
+    FolderSyncReport, FolderSyncStatus, ManifestEntry, TreeHashes,
+};
+pub use sync_policy::{SyncMode, SyncPolicy};
+pub use sync_state::{ConflictChoice, SyncConflict, SyncReport, SyncStatus};
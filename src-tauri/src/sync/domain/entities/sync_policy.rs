@@ -0,0 +1,101 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How the library is synchronized
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncMode {
+    /// The prompts directory is a git working tree pushed to a remote
+    #[default]
+    Git,
+    /// Prompts are mirrored to a shared folder (OneDrive, Syncthing, network drive)
+    Folder,
+}
+
+/// Sync settings (from config)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncPolicy {
+    pub enabled: bool,
+    pub mode: SyncMode,
+    pub interval_minutes: u32,
+    /// Git remote to pull from and push to, e.g. "origin"
+    pub remote: String,
+    /// Shared folder used in folder mode
+    pub folder: String,
+    /// Commit the library after every save (git mode)
+    pub commit_on_save: bool,
+}
+
+impl SyncPolicy {
+    /// Build a policy from raw config values, clamping nonsensical ones
+    pub fn new(
+        enabled: bool,
+        mode: SyncMode,
+        interval_minutes: i32,
+        remote: &str,
+        folder: &str,
+        commit_on_save: bool,
+    ) -> Self {
+        let remote = remote.trim();
+        Self {
+            enabled,
+            mode,
+            interval_minutes: interval_minutes.max(1) as u32,
+            remote: if remote.is_empty() { "origin".to_string() } else { remote.to_string() },
+            folder: folder.trim().to_string(),
+            commit_on_save,
+        }
+    }
+
+    /// Business rule: a sync is due when enabled and the last sync is older than the interval
+    pub fn is_due(&self, last_sync: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        match last_sync {
+            Some(last) => now - last >= Duration::minutes(self.interval_minutes as i64),
+            None => true,
+        }
+    }
+
+    /// Business rule: saves are committed only while git sync is enabled
+    pub fn commits_on_save(&self) -> bool {
+        self.enabled && self.mode == SyncMode::Git && self.commit_on_save
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_due_after_interval() {
+        let policy = SyncPolicy::new(true, SyncMode::Git, 15, "origin", "", true);
+        let now = Utc::now();
+
+        assert!(policy.is_due(None, now));
+        assert!(!policy.is_due(Some(now - Duration::minutes(14)), now));
+        assert!(policy.is_due(Some(now - Duration::minutes(15)), now));
+    }
+
+    #[test]
+    fn test_disabled_policy_never_syncs_or_commits() {
+        let policy = SyncPolicy::new(false, SyncMode::Git, 15, "origin", "", true);
+        assert!(!policy.is_due(None, Utc::now()));
+        assert!(!policy.commits_on_save());
+    }
+
+    #[test]
+    fn test_new_clamps_invalid_values() {
+        let policy = SyncPolicy::new(true, SyncMode::Git, 0, "  ", " /mnt/share ", false);
+        assert_eq!(policy.interval_minutes, 1);
+        assert_eq!(policy.remote, "origin");
+        assert_eq!(policy.folder, "/mnt/share");
+    }
+
+    #[test]
+    fn test_folder_mode_does_not_commit_on_save() {
+        let policy = SyncPolicy::new(true, SyncMode::Folder, 15, "origin", "/mnt/share", true);
+        assert!(!policy.commits_on_save());
+    }
+}
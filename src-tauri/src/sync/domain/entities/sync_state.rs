@@ -0,0 +1,59 @@
+use crate::sync::domain::entities::FileChange;
+use serde::{Deserialize, Serialize};
+
+/// State of the prompts directory's git working tree
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyncStatus {
+    /// False if the prompts directory is not a git working tree; other fields are then empty
+    pub is_repository: bool,
+    pub branch: Option<String>,
+    pub remote: String,
+    pub has_remote: bool,
+    /// Local commits not yet pushed (as of the last fetch)
+    pub ahead: usize,
+    /// Remote commits not yet pulled (as of the last fetch)
+    pub behind: usize,
+    /// Uncommitted changes
+    pub changes: Vec<FileChange>,
+    /// Paths with unresolved merge conflicts; sync is paused until they are resolved
+    pub conflicts: Vec<String>,
+}
+
+/// A file changed both locally and on the remote
+/// Contents are None when that side deleted the file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncConflict {
+    /// Path relative to the prompts directory (the prompt ID for .md files)
+    pub path: String,
+    /// Common ancestor
+    pub base: Option<String>,
+    /// This machine's version
+    pub local: Option<String>,
+    /// The remote's version
+    pub remote: Option<String>,
+}
+
+/// How to resolve a conflict
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "choice", content = "content", rename_all = "snake_case")]
+pub enum ConflictChoice {
+    /// Keep this machine's version
+    Local,
+    /// Take the remote's version
+    Remote,
+    /// Use hand-merged content
+    Merged(String),
+}
+
+/// What a sync did
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyncReport {
+    /// Message of the commit made for uncommitted changes, if any
+    pub committed: Option<String>,
+    /// Remote commits rebased onto
+    pub pulled: usize,
+    /// Local commits pushed
+    pub pushed: usize,
+    /// Conflicts stopping the sync; resolve them to continue
+    pub conflicts: Vec<SyncConflict>,
+}
@@ -0,0 +1,49 @@
+use thiserror::Error;
+
+/// Errors returned by git sync, folder sync and their adapters
+#[derive(Debug, Error)]
+pub enum SyncError {
+    /// Sync can't run with the current setup (no repository, no branch, no folder)
+    #[error("{0}")]
+    NotConfigured(String),
+
+    #[error("Sync folder not found: {0}")]
+    FolderNotFound(String),
+
+    #[error("No sync conflict for {0}")]
+    NoConflict(String),
+
+    /// git ran but failed (network, credentials, rejected push)
+    #[error("{0}")]
+    Git(String),
+
+    #[error("{0}")]
+    Other(String),
+}
+
+impl SyncError {
+    /// Stable machine-readable code sent to the frontend
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotConfigured(_) => "sync_not_configured",
+            Self::FolderNotFound(_) | Self::NoConflict(_) => "not_found",
+            Self::Git(_) => "git_error",
+            Self::Other(_) => "sync_error",
+        }
+    }
+
+    /// The folder or file the error is about
+    pub fn details(&self) -> Option<String> {
+        match self {
+            Self::FolderNotFound(path) | Self::NoConflict(path) => Some(path.clone()),
+            Self::NotConfigured(_) | Self::Git(_) | Self::Other(_) => None,
+        }
+    }
+}
+
+/// File and manifest failures are reported as plain messages
+impl From<String> for SyncError {
+    fn from(message: String) -> Self {
+        Self::Other(message)
+    }
+}
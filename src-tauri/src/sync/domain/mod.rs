@@ -0,0 +1,6 @@
+// Domain layer - sync entities, commit message rules and the version control port
+pub mod entities;
+pub mod errors;
+pub mod ports;
+
+pub use errors::SyncError;
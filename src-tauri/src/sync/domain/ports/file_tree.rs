@@ -0,0 +1,23 @@
+use crate::sync::domain::errors::SyncError;
+use std::collections::BTreeMap;
+
+/// Prompt files keyed by path relative to the tree root, e.g. "Coding/review.md"
+pub type TreeFiles = BTreeMap<String, Vec<u8>>;
+
+/// A directory of prompt files: the library or a shared sync folder (Port)
+/// Infrastructure layer will implement this trait
+pub trait FileTree: Send + Sync {
+    /// Location shown to the user
+    fn location(&self) -> String;
+
+    /// True if the tree can be reached (e.g. the shared folder is mounted)
+    fn is_available(&self) -> bool;
+
+    /// Read every prompt file
+    fn read_all(&self) -> Result<TreeFiles, SyncError>;
+
+    /// Create or replace a file, creating parent directories
+    fn write(&self, path: &str, content: &[u8]) -> Result<(), SyncError>;
+
+    fn delete(&self, path: &str) -> Result<(), SyncError>;
+}
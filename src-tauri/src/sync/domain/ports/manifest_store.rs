@@ -0,0 +1,11 @@
+use crate::sync::domain::entities::FolderManifest;
+use crate::sync::domain::errors::SyncError;
+
+/// Storage of this machine's folder sync manifest (Port)
+/// Infrastructure layer will implement this trait
+pub trait ManifestStore: Send + Sync {
+    /// The saved manifest, or None before the first sync
+    fn load(&self) -> Result<Option<FolderManifest>, SyncError>;
+
+    fn save(&self, manifest: &FolderManifest) -> Result<(), SyncError>;
+}
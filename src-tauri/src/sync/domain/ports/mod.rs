@@ -0,0 +1,8 @@
+// Domain ports (interfaces) - infrastructure layer will implement these
+pub mod file_tree;
+pub mod manifest_store;
+pub mod version_control;
+
+pub use file_tree::{FileTree, TreeFiles};
+pub use manifest_store::ManifestStore;
+pub use version_control::{ConflictVersions, VersionControl};
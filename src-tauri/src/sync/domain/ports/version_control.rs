@@ -0,0 +1,57 @@
+use crate::sync::domain::entities::FileChange;
+use crate::sync::domain::errors::SyncError;
+
+/// The three sides of a conflicted file; None where a side has no such file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConflictVersions {
+    pub base: Option<String>,
+    pub local: Option<String>,
+    pub remote: Option<String>,
+}
+
+/// Version control of the prompts directory (Port)
+/// Paths are relative to the working tree root
+/// Infrastructure layer will implement this trait
+pub trait VersionControl: Send + Sync {
+    /// True if the directory is a working tree
+    fn is_repository(&self) -> bool;
+
+    /// Checked-out branch; None on a detached HEAD
+    fn current_branch(&self) -> Result<Option<String>, SyncError>;
+
+    fn has_remote(&self, remote: &str) -> Result<bool, SyncError>;
+
+    /// Uncommitted changes, including untracked files
+    fn changes(&self) -> Result<Vec<FileChange>, SyncError>;
+
+    /// Stage everything and commit it
+    fn commit_all(&self, message: &str) -> Result<(), SyncError>;
+
+    fn fetch(&self, remote: &str) -> Result<(), SyncError>;
+
+    /// Commits (ahead, behind) of the local branch relative to its remote counterpart
+    /// A branch missing on the remote counts all local commits as ahead
+    fn ahead_behind(&self, remote: &str, branch: &str) -> Result<(usize, usize), SyncError>;
+
+    /// Replay local commits onto the remote branch; stops (Ok) when conflicts occur
+    fn rebase(&self, remote: &str, branch: &str) -> Result<(), SyncError>;
+
+    /// True while a rebase is stopped on conflicts
+    fn is_rebasing(&self) -> bool;
+
+    /// Paths with unresolved conflicts
+    fn conflicted_paths(&self) -> Result<Vec<String>, SyncError>;
+
+    fn conflict_versions(&self, path: &str) -> Result<ConflictVersions, SyncError>;
+
+    /// Mark a conflict resolved with the given content; None deletes the file
+    fn resolve(&self, path: &str, content: Option<&str>) -> Result<(), SyncError>;
+
+    /// Continue a stopped rebase once all conflicts are resolved; stops (Ok) on further conflicts
+    fn continue_rebase(&self) -> Result<(), SyncError>;
+
+    /// Give up a stopped rebase, restoring the local branch
+    fn abort_rebase(&self) -> Result<(), SyncError>;
+
+    fn push(&self, remote: &str, branch: &str) -> Result<(), SyncError>;
+}
@@ -0,0 +1,134 @@
+use crate::sync::domain::errors::SyncError;
+use crate::sync::domain::ports::{FileTree, TreeFiles};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Prompt files (`*.md`) under a directory
+/// Hidden entries (.versions, .git) and sync-tool temp files ("~$...") are ignored
+pub struct DirectoryTree {
+    root: PathBuf,
+}
+
+impl DirectoryTree {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// Resolve a relative path, rejecting anything that would escape the root
+    fn resolve(&self, path: &str) -> Result<PathBuf, SyncError> {
+        let relative = Path::new(path);
+        if path.is_empty() || !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+            return Err(SyncError::Other(format!("Invalid sync path: {}", path)));
+        }
+        Ok(self.root.join(relative))
+    }
+
+    fn is_ignored(name: &str) -> bool {
+        name.starts_with('.') || name.starts_with('~')
+    }
+}
+
+impl FileTree for DirectoryTree {
+    fn location(&self) -> String {
+        self.root.display().to_string()
+    }
+
+    fn is_available(&self) -> bool {
+        self.root.is_dir()
+    }
+
+    fn read_all(&self) -> Result<TreeFiles, SyncError> {
+        if !self.is_available() {
+            return Err(SyncError::FolderNotFound(self.location()));
+        }
+
+        let mut files = TreeFiles::new();
+        for entry in WalkDir::new(&self.root)
+            .into_iter()
+            .filter_entry(|e| e.depth() == 0 || !Self::is_ignored(&e.file_name().to_string_lossy()))
+        {
+            let entry = entry.map_err(|e| format!("Failed to read sync folder: {}", e))?;
+            let path = entry.path();
+            if !entry.file_type().is_file() || path.extension().and_then(|s| s.to_str()) != Some("md") {
+                continue;
+            }
+
+            let relative = path
+                .strip_prefix(&self.root)
+                .map_err(|e| format!("Failed to resolve sync path: {}", e))?
+                .to_string_lossy()
+                .replace('\\', "/");
+            let content = fs::read(path)
+                .map_err(|e| format!("Failed to read {}: {}", relative, e))?;
+            files.insert(relative, content);
+        }
+        Ok(files)
+    }
+
+    fn write(&self, path: &str, content: &[u8]) -> Result<(), SyncError> {
+        let file = self.resolve(path)?;
+        if let Some(parent) = file.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory for {}: {}", path, e))?;
+        }
+        fs::write(&file, content)
+            .map_err(|e| SyncError::Other(format!("Failed to write {}: {}", path, e)))
+    }
+
+    fn delete(&self, path: &str) -> Result<(), SyncError> {
+        let file = self.resolve(path)?;
+        if file.exists() {
+            fs::remove_file(&file)
+                .map_err(|e| format!("Failed to delete {}: {}", path, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Name of this machine, used to label conflict copies
+pub fn host_name() -> String {
+    ["COMPUTERNAME", "HOSTNAME"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .chain(fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().replace(['/', '\\', ':', '*', '?', '"', '<', '>', '|'], "-"))
+        .find(|name| !name.is_empty())
+        .unwrap_or_else(|| "another device".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_all_lists_prompt_files_only() {
+        let temp_dir = TempDir::new().unwrap();
+        let tree = DirectoryTree::new(temp_dir.path().to_path_buf());
+        tree.write("Coding/review.md", b"review").unwrap();
+        tree.write("notes.txt", b"notes").unwrap();
+        tree.write(".versions/Coding/review.md/1.md", b"old").unwrap();
+        tree.write("~$review.md", b"temp").unwrap();
+
+        let files = tree.read_all().unwrap();
+        let paths: Vec<&str> = files.keys().map(|k| k.as_str()).collect();
+        assert_eq!(paths, vec!["Coding/review.md"]);
+    }
+
+    #[test]
+    fn test_rejects_paths_outside_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let tree = DirectoryTree::new(temp_dir.path().join("share"));
+        assert!(tree.write("../escape.md", b"x").is_err());
+        assert!(tree.delete("/etc/passwd").is_err());
+    }
+
+    #[test]
+    fn test_missing_folder_is_unavailable() {
+        let temp_dir = TempDir::new().unwrap();
+        let tree = DirectoryTree::new(temp_dir.path().join("offline"));
+        assert!(!tree.is_available());
+        assert!(tree.read_all().is_err());
+    }
+}
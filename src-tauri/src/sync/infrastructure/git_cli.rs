@@ -0,0 +1,312 @@
+use crate::sync::domain::entities::{ChangeKind, FileChange};
+use crate::sync::domain::errors::SyncError;
+use crate::sync::domain::ports::{ConflictVersions, VersionControl};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// Identity used for commits when git has none configured
+const FALLBACK_USER_NAME: &str = "Prompter";
+const FALLBACK_USER_EMAIL: &str = "prompter@localhost";
+
+/// Keeps git from flashing a console window on Windows
+#[cfg(windows)]
+const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+/// Version control through the `git` command line
+/// Uses the user's own git install, so credentials, SSH keys and hooks work as in a terminal
+pub struct GitCliVersionControl {
+    work_dir: PathBuf,
+}
+
+impl GitCliVersionControl {
+    pub fn new(work_dir: PathBuf) -> Self {
+        Self { work_dir }
+    }
+
+    fn command(&self, args: &[&str]) -> Command {
+        let mut command = Command::new("git");
+        command
+            .arg("-C")
+            .arg(&self.work_dir)
+            .args(args)
+            // Fail instead of waiting for a credential prompt nobody can see
+            .env("GIT_TERMINAL_PROMPT", "0")
+            // rebase --continue keeps the original commit message
+            .env("GIT_EDITOR", "true");
+        #[cfg(windows)]
+        {
+            use std::os::windows::process::CommandExt;
+            command.creation_flags(CREATE_NO_WINDOW);
+        }
+        command
+    }
+
+    fn output(&self, args: &[&str]) -> Result<Output, SyncError> {
+        self.command(args)
+            .output()
+            .map_err(|e| SyncError::Git(format!("Failed to run git: {}", e)))
+    }
+
+    /// Run git and return stdout; a non-zero exit is an error carrying stderr
+    fn run(&self, args: &[&str]) -> Result<String, SyncError> {
+        let output = self.output(args)?;
+        if !output.status.success() {
+            return Err(SyncError::Git(format!(
+                "git {} failed: {}",
+                args.first().unwrap_or(&""),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Like `output`, with a fallback identity for commands that create commits
+    fn output_committing(&self, args: &[&str]) -> Result<Output, SyncError> {
+        let has_identity = self.output(&["config", "user.email"])?.status.success();
+        if has_identity {
+            return self.output(args);
+        }
+        let name = format!("user.name={}", FALLBACK_USER_NAME);
+        let email = format!("user.email={}", FALLBACK_USER_EMAIL);
+        let mut with_identity = vec!["-c", name.as_str(), "-c", email.as_str()];
+        with_identity.extend_from_slice(args);
+        self.output(&with_identity)
+    }
+
+    /// A rebase step either succeeds or stops on conflicts; anything else is an error
+    fn rebase_step(&self, args: &[&str]) -> Result<(), SyncError> {
+        let output = self.output_committing(args)?;
+        if output.status.success() || (self.is_rebasing() && !self.conflicted_paths()?.is_empty()) {
+            return Ok(());
+        }
+        Err(SyncError::Git(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+
+    fn git_path_exists(&self, name: &str) -> bool {
+        let Ok(path) = self.run(&["rev-parse", "--git-path", name]) else {
+            return false;
+        };
+        let path = Path::new(path.trim());
+        if path.is_absolute() {
+            path.exists()
+        } else {
+            self.work_dir.join(path).exists()
+        }
+    }
+
+    fn show_stage(&self, stage: u8, path: &str) -> Option<String> {
+        self.run(&["show", &format!(":{}:{}", stage, path)]).ok()
+    }
+}
+
+impl VersionControl for GitCliVersionControl {
+    fn is_repository(&self) -> bool {
+        // The directory must be the root of its own working tree, not somewhere inside
+        // an unrelated repository (e.g. a home directory under version control)
+        let Ok(top_level) = self.run(&["rev-parse", "--show-toplevel"]) else {
+            return false;
+        };
+        match (fs::canonicalize(top_level.trim()), fs::canonicalize(&self.work_dir)) {
+            (Ok(top_level), Ok(work_dir)) => top_level == work_dir,
+            _ => false,
+        }
+    }
+
+    fn current_branch(&self) -> Result<Option<String>, SyncError> {
+        let output = self.output(&["symbolic-ref", "--quiet", "--short", "HEAD"])?;
+        Ok(output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string()))
+    }
+
+    fn has_remote(&self, remote: &str) -> Result<bool, SyncError> {
+        Ok(self.run(&["remote"])?.lines().any(|line| line.trim() == remote))
+    }
+
+    fn changes(&self) -> Result<Vec<FileChange>, SyncError> {
+        let status = self.run(&["status", "--porcelain", "-z", "--untracked-files=all", "--no-renames"])?;
+        Ok(status
+            .split('\0')
+            .filter(|entry| entry.len() > 3)
+            .map(|entry| {
+                let (code, path) = entry.split_at(3);
+                let kind = match code.trim() {
+                    "??" | "A" | "AM" => ChangeKind::Added,
+                    code if code.contains('D') => ChangeKind::Deleted,
+                    _ => ChangeKind::Modified,
+                };
+                FileChange {
+                    path: path.to_string(),
+                    kind,
+                }
+            })
+            .collect())
+    }
+
+    fn commit_all(&self, message: &str) -> Result<(), SyncError> {
+        self.run(&["add", "--all"])?;
+        let output = self.output_committing(&["commit", "--quiet", "-m", message])?;
+        if !output.status.success() {
+            return Err(SyncError::Git(format!(
+                "git commit failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(())
+    }
+
+    fn fetch(&self, remote: &str) -> Result<(), SyncError> {
+        self.run(&["fetch", "--quiet", remote]).map(|_| ())
+    }
+
+    fn ahead_behind(&self, remote: &str, branch: &str) -> Result<(usize, usize), SyncError> {
+        let remote_ref = format!("refs/remotes/{}/{}", remote, branch);
+        let remote_exists = self.output(&["rev-parse", "--verify", "--quiet", &remote_ref])?.status.success();
+        if !remote_exists {
+            // Nothing pushed yet (or an empty repository): every local commit is ahead
+            let ahead = self.run(&["rev-list", "--count", "HEAD"]).unwrap_or_default();
+            return Ok((ahead.trim().parse().unwrap_or(0), 0));
+        }
+
+        let counts = self.run(&["rev-list", "--left-right", "--count", &format!("HEAD...{}", remote_ref)])?;
+        let mut counts = counts.split_whitespace().map(|n| n.parse::<usize>().unwrap_or(0));
+        Ok((counts.next().unwrap_or(0), counts.next().unwrap_or(0)))
+    }
+
+    fn rebase(&self, remote: &str, branch: &str) -> Result<(), SyncError> {
+        self.rebase_step(&["rebase", &format!("{}/{}", remote, branch)])
+    }
+
+    fn is_rebasing(&self) -> bool {
+        self.git_path_exists("rebase-merge") || self.git_path_exists("rebase-apply")
+    }
+
+    fn conflicted_paths(&self) -> Result<Vec<String>, SyncError> {
+        Ok(self
+            .run(&["diff", "--name-only", "--diff-filter=U", "-z"])?
+            .split('\0')
+            .filter(|path| !path.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    fn conflict_versions(&self, path: &str) -> Result<ConflictVersions, SyncError> {
+        // While rebasing, "ours" (stage 2) is the upstream and "theirs" (stage 3) the local commit
+        Ok(ConflictVersions {
+            base: self.show_stage(1, path),
+            local: self.show_stage(3, path),
+            remote: self.show_stage(2, path),
+        })
+    }
+
+    fn resolve(&self, path: &str, content: Option<&str>) -> Result<(), SyncError> {
+        match content {
+            Some(content) => {
+                let file = self.work_dir.join(path);
+                if let Some(parent) = file.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|e| format!("Failed to create directory: {}", e))?;
+                }
+                fs::write(&file, content)
+                    .map_err(|e| format!("Failed to write resolved file: {}", e))?;
+                self.run(&["add", "--", path]).map(|_| ())
+            }
+            None => self.run(&["rm", "--quiet", "--force", "--ignore-unmatch", "--", path]).map(|_| ()),
+        }
+    }
+
+    fn continue_rebase(&self) -> Result<(), SyncError> {
+        // A resolution that matches the remote leaves nothing to commit; drop that commit
+        let nothing_staged = self.output(&["diff", "--cached", "--quiet"])?.status.success();
+        if nothing_staged {
+            self.rebase_step(&["rebase", "--skip"])
+        } else {
+            self.rebase_step(&["rebase", "--continue"])
+        }
+    }
+
+    fn abort_rebase(&self) -> Result<(), SyncError> {
+        self.run(&["rebase", "--abort"]).map(|_| ())
+    }
+
+    fn push(&self, remote: &str, branch: &str) -> Result<(), SyncError> {
+        self.run(&["push", "--quiet", remote, branch]).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git").arg("-C").arg(dir).args(args).output().unwrap();
+        assert!(status.status.success(), "git {:?}: {}", args, String::from_utf8_lossy(&status.stderr));
+    }
+
+    fn init_repo(dir: &Path) -> GitCliVersionControl {
+        fs::create_dir_all(dir).unwrap();
+        git(dir, &["init", "--quiet", "-b", "main"]);
+        GitCliVersionControl::new(dir.to_path_buf())
+    }
+
+    #[test]
+    fn test_is_repository_requires_working_tree_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = init_repo(temp_dir.path());
+        let nested = temp_dir.path().join("prompts");
+        fs::create_dir_all(&nested).unwrap();
+
+        assert!(repo.is_repository());
+        assert!(!GitCliVersionControl::new(nested).is_repository());
+        assert!(!GitCliVersionControl::new(temp_dir.path().join("missing")).is_repository());
+    }
+
+    #[test]
+    fn test_changes_and_commit_all() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = init_repo(temp_dir.path());
+        fs::create_dir_all(temp_dir.path().join("Coding")).unwrap();
+        fs::write(temp_dir.path().join("Coding/review.md"), "v1").unwrap();
+        fs::write(temp_dir.path().join("old.md"), "old").unwrap();
+        repo.commit_all("Initial").unwrap();
+
+        fs::write(temp_dir.path().join("Coding/review.md"), "v2").unwrap();
+        fs::remove_file(temp_dir.path().join("old.md")).unwrap();
+        fs::write(temp_dir.path().join("Coding/new prompt.md"), "new").unwrap();
+
+        let mut changes = repo.changes().unwrap();
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+        let summary: Vec<(&str, ChangeKind)> = changes.iter().map(|c| (c.path.as_str(), c.kind)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Coding/new prompt.md", ChangeKind::Added),
+                ("Coding/review.md", ChangeKind::Modified),
+                ("old.md", ChangeKind::Deleted),
+            ]
+        );
+
+        repo.commit_all("Edit").unwrap();
+        assert!(repo.changes().unwrap().is_empty());
+        assert_eq!(repo.current_branch().unwrap().as_deref(), Some("main"));
+    }
+
+    #[test]
+    fn test_ahead_behind_without_remote_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = init_repo(temp_dir.path());
+        assert_eq!(repo.ahead_behind("origin", "main").unwrap(), (0, 0));
+
+        fs::write(temp_dir.path().join("a.md"), "a").unwrap();
+        repo.commit_all("Add a").unwrap();
+        assert_eq!(repo.ahead_behind("origin", "main").unwrap(), (1, 0));
+        assert!(!repo.has_remote("origin").unwrap());
+    }
+}
@@ -0,0 +1,53 @@
+use crate::sync::domain::entities::FolderManifest;
+use crate::sync::domain::errors::SyncError;
+use crate::sync::domain::ports::ManifestStore;
+use std::fs;
+use std::path::PathBuf;
+
+const MANIFEST_FILE_NAME: &str = "folder-sync.json";
+
+/// Folder sync manifest stored as JSON in the data directory (~/.prompter/folder-sync.json)
+/// Kept per machine, outside the shared folder, so devices never overwrite each other's history
+pub struct JsonManifestStore {
+    path: PathBuf,
+}
+
+impl JsonManifestStore {
+    pub fn new() -> Result<Self, SyncError> {
+        let home = dirs::home_dir().ok_or_else(|| SyncError::Other("Could not determine home directory".to_string()))?;
+        Ok(Self::with_path(home.join(".prompter").join(MANIFEST_FILE_NAME)))
+    }
+
+    pub fn with_path(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl ManifestStore for JsonManifestStore {
+    fn load(&self) -> Result<Option<FolderManifest>, SyncError> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&self.path)
+            .map_err(|e| format!("Failed to read sync manifest: {}", e))?;
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|e| SyncError::Other(format!("Failed to parse sync manifest: {}", e)))
+    }
+
+    fn save(&self, manifest: &FolderManifest) -> Result<(), SyncError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create data directory: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(manifest)
+            .map_err(|e| format!("Failed to serialize sync manifest: {}", e))?;
+
+        // Write then rename so an interrupted save never leaves a truncated manifest
+        let temp_path = self.path.with_extension("json.tmp");
+        fs::write(&temp_path, content)
+            .map_err(|e| format!("Failed to write sync manifest: {}", e))?;
+        fs::rename(&temp_path, &self.path)
+            .map_err(|e| SyncError::Other(format!("Failed to write sync manifest: {}", e)))
+    }
+}
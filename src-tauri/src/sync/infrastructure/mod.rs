@@ -0,0 +1,8 @@
+// Infrastructure layer - version control, folder and manifest adapters
+pub mod directory_tree;
+pub mod git_cli;
+pub mod json_manifest_store;
+
+pub use directory_tree::{host_name, DirectoryTree};
+pub use git_cli::GitCliVersionControl;
+pub use json_manifest_store::JsonManifestStore;
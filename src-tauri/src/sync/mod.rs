@@ -0,0 +1,18 @@
+// Sync module - Hexagonal Architecture
+// - domain/ (FileChange, SyncPolicy, SyncStatus, SyncConflict, folder manifest and plan;
+//   SyncError; VersionControl, FileTree and ManifestStore ports)
+// - application/ (SyncService: git commit on save, pull/rebase/push, conflict resolution;
+//   FolderSyncService: shared-folder mirroring with conflict copies)
+// - infrastructure/ (GitCliVersionControl, DirectoryTree, JsonManifestStore)
+
+pub mod domain;
+pub mod application;
+pub mod infrastructure;
+
+// Re-export types used by commands layer
+pub use domain::entities::{
+    ConflictChoice, FolderSyncReport, FolderSyncStatus, SyncConflict, SyncMode, SyncPolicy, SyncReport, SyncStatus,
+};
+pub use domain::SyncError;
+pub use application::{FolderSyncService, SyncService};
+pub use infrastructure::{host_name, DirectoryTree, GitCliVersionControl, JsonManifestStore};
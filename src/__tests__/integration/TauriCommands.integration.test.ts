@@ -502,6 +502,16 @@ describe('TauriPromptService IPC Contract', () => {
         expect(calledArgs).not.toHaveProperty('autoPaste');
         expect(calledArgs).toHaveProperty('auto_paste');
       });
+
+      it('should call copy_and_paste with snake_case prompt_id', async () => {
+        mockInvoke.mockResolvedValue(undefined);
+
+        await service.copyAndPaste('Test', true, 'prompt-1');
+
+        const calledArgs = mockInvoke.mock.calls[0][1] as Record<string, unknown>;
+        expect(calledArgs).not.toHaveProperty('promptId');
+        expect(calledArgs.prompt_id).toBe('prompt-1');
+      });
     });
 
     describe('hideAndRestore', () => {
@@ -710,11 +720,12 @@ describe('TauriPromptService IPC Contract', () => {
         { method: 'recordUsage', param: 'prompt_id' },
         { method: 'getUsageStats', param: 'prompt_id' },
         { method: 'copyAndPaste', param: 'auto_paste' },
+        { method: 'copyAndPaste', param: 'prompt_id' },
         { method: 'openEditorWindow', param: 'prompt_id' },
       ];
 
       // Verify all expected snake_case params are documented
-      expect(snakeCaseParams.length).toBe(9);
+      expect(snakeCaseParams.length).toBe(10);
     });
 
     it('should verify Prompt interface uses snake_case', () => {
@@ -78,7 +78,8 @@ const createMockService = (
   copyAndPaste: vi.fn().mockResolvedValue({
     clipboard_success: true,
     paste_attempted: true,
-    paste_likely_success: true,
+    paste_verified: true,
+    reason: 'pasted',
     message: 'Copied and pasted',
   }),
   hideAndRestore: vi.fn().mockResolvedValue(undefined),
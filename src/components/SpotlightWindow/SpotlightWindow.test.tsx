@@ -87,7 +87,8 @@ const createMockService = (): PromptService => ({
   copyAndPaste: vi.fn().mockResolvedValue({
     clipboard_success: true,
     paste_attempted: true,
-    paste_likely_success: true,
+    paste_verified: true,
+    reason: 'pasted',
     message: 'Copied and pasted',
   }),
   hideAndRestore: vi.fn().mockResolvedValue(undefined),
@@ -353,7 +354,7 @@ describe('SpotlightWindow Integration Tests', () => {
 
       // Should call copyAndPaste with prompt content
       await waitFor(() => {
-        expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true);
+        expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true, expect.any(String));
       });
 
       // Should record usage
@@ -376,7 +377,7 @@ describe('SpotlightWindow Integration Tests', () => {
 
       // Should call copyAndPaste
       await waitFor(() => {
-        expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true);
+        expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true, expect.any(String));
       });
 
       // Should record usage
@@ -727,7 +728,7 @@ describe('SpotlightWindow Integration Tests', () => {
 
       // 4. Verify prompt executed
       await waitFor(() => {
-        expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true);
+        expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true, expect.any(String));
         expect(service.recordUsage).toHaveBeenCalledWith('1');
       });
     });
@@ -802,7 +803,7 @@ describe('SpotlightWindow Integration Tests', () => {
 
       // 5. Verify prompt executed
       await waitFor(() => {
-        expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true);
+        expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true, expect.any(String));
         expect(service.recordUsage).toHaveBeenCalledWith('3');
       });
     });
@@ -821,7 +822,7 @@ describe('SpotlightWindow Integration Tests', () => {
 
       // Verify prompt was executed
       await waitFor(() => {
-        expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true);
+        expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true, expect.any(String));
         expect(service.recordUsage).toHaveBeenCalledWith('1');
       });
     });
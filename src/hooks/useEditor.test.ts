@@ -51,7 +51,7 @@ function createMockService(overrides: Partial<PromptService> = {}): PromptServic
     restoreVersion: vi.fn().mockResolvedValue(mockPrompt),
     recordUsage: vi.fn().mockResolvedValue(undefined),
     getUsageStats: vi.fn().mockResolvedValue({ prompt_id: 'test', total_uses: 0, last_used: null, daily_uses: [], weekly_uses: [], monthly_uses: [] }),
-    copyAndPaste: vi.fn().mockResolvedValue({ clipboard_success: true, paste_attempted: true, paste_likely_success: true, message: 'Copied and pasted' }),
+    copyAndPaste: vi.fn().mockResolvedValue({ clipboard_success: true, paste_attempted: true, paste_verified: true, reason: 'pasted', message: 'Copied and pasted' }),
     hideAndRestore: vi.fn().mockResolvedValue(undefined),
     getConfig: vi.fn().mockResolvedValue({} as AppConfig),
     updateConfig: vi.fn().mockResolvedValue({} as AppConfig),
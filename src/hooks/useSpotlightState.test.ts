@@ -45,7 +45,8 @@ const createMockService = (): PromptService => ({
   copyAndPaste: vi.fn().mockResolvedValue({
     clipboard_success: true,
     paste_attempted: true,
-    paste_likely_success: true,
+    paste_verified: true,
+    reason: 'pasted',
     message: 'Copied and pasted',
   }),
   hideAndRestore: vi.fn().mockResolvedValue(undefined),
@@ -266,7 +267,8 @@ describe('useSpotlightState', () => {
 
       expect(service.copyAndPaste).toHaveBeenCalledWith(
         'Hello Alice, welcome to Wonderland!',
-        true
+        true,
+        expect.any(String)
       );
     });
 
@@ -287,7 +289,8 @@ describe('useSpotlightState', () => {
 
       expect(service.copyAndPaste).toHaveBeenCalledWith(
         'Hello Bob, Hello again Bob!',
-        true
+        true,
+        expect.any(String)
       );
     });
 
@@ -304,6 +307,19 @@ describe('useSpotlightState', () => {
       expect(service.recordUsage).toHaveBeenCalledWith('test-id');
     });
 
+    it('should pass the prompt id so its paste settings apply', async () => {
+      const service = createMockService();
+      const { result } = renderHook(() => useSpotlightState(service));
+
+      const prompt = createMockPrompt('Coding/review', { content: 'Test' });
+
+      await act(async () => {
+        await result.current.handlePromptSelection(prompt, {});
+      });
+
+      expect(service.copyAndPaste).toHaveBeenCalledWith('Test', true, 'Coding/review');
+    });
+
     it('should respect auto_paste setting', async () => {
       const service = createMockService();
       const { result } = renderHook(() => useSpotlightState(service));
@@ -317,7 +333,7 @@ describe('useSpotlightState', () => {
         await result.current.handlePromptSelection(prompt, {});
       });
 
-      expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', false);
+      expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', false, expect.any(String));
     });
 
     it('should close modal after selection', async () => {
@@ -365,7 +381,8 @@ describe('useSpotlightState', () => {
       // Should properly substitute without regex injection
       expect(service.copyAndPaste).toHaveBeenCalledWith(
         'Hello Safe Value!',
-        true
+        true,
+        expect.any(String)
       );
     });
   });
@@ -483,7 +500,8 @@ describe('useSpotlightState', () => {
 
       expect(service.copyAndPaste).toHaveBeenCalledWith(
         'Plain text with no variables',
-        true
+        true,
+        expect.any(String)
       );
     });
 
@@ -499,7 +517,7 @@ describe('useSpotlightState', () => {
         await result.current.handlePromptSelection(prompt, {});
       });
 
-      expect(service.copyAndPaste).toHaveBeenCalledWith('Test {{variable}}', true);
+      expect(service.copyAndPaste).toHaveBeenCalledWith('Test {{variable}}', true, expect.any(String));
     });
   });
 
@@ -513,7 +531,8 @@ describe('useSpotlightState', () => {
       service.copyAndPaste = vi.fn().mockResolvedValue({
         clipboard_success: true,
         paste_attempted: true,
-        paste_likely_success: true,
+        paste_verified: true,
+        reason: 'pasted',
         message: 'Copied and pasted',
       });
       const { result } = renderHook(() => useSpotlightState(service));
@@ -533,7 +552,8 @@ describe('useSpotlightState', () => {
       service.copyAndPaste = vi.fn().mockResolvedValue({
         clipboard_success: false,
         paste_attempted: false,
-        paste_likely_success: false,
+        paste_verified: false,
+        reason: 'clipboard_failed',
         message: 'Clipboard failed',
       });
       const { result } = renderHook(() => useSpotlightState(service), { wrapper });
@@ -549,12 +569,13 @@ describe('useSpotlightState', () => {
       expect(service.copyAndPaste).toHaveBeenCalled();
     });
 
-    it('should show success toast when paste likely succeeded', async () => {
+    it('should show success toast when paste was verified', async () => {
       const service = createMockService();
       service.copyAndPaste = vi.fn().mockResolvedValue({
         clipboard_success: true,
         paste_attempted: true,
-        paste_likely_success: true,
+        paste_verified: true,
+        reason: 'pasted',
         message: 'Copied and pasted',
       });
       const { result } = renderHook(() => useSpotlightState(service), { wrapper });
@@ -574,7 +595,8 @@ describe('useSpotlightState', () => {
       service.copyAndPaste = vi.fn().mockResolvedValue({
         clipboard_success: true,
         paste_attempted: true,
-        paste_likely_success: false,
+        paste_verified: false,
+        reason: 'unverified',
         message: 'Copied but paste uncertain',
       });
       const { result } = renderHook(() => useSpotlightState(service), { wrapper });
@@ -594,7 +616,8 @@ describe('useSpotlightState', () => {
       service.copyAndPaste = vi.fn().mockResolvedValue({
         clipboard_success: true,
         paste_attempted: false,
-        paste_likely_success: false,
+        paste_verified: false,
+        reason: 'copied',
         message: 'Copied to clipboard',
       });
       const { result } = renderHook(() => useSpotlightState(service), { wrapper });
@@ -606,7 +629,7 @@ describe('useSpotlightState', () => {
       });
 
       expect(result.current.showContextModal).toBe(false);
-      expect(service.copyAndPaste).toHaveBeenCalledWith('Test', false);
+      expect(service.copyAndPaste).toHaveBeenCalledWith('Test', false, expect.any(String));
     });
 
     it('should close modal on error and not throw', async () => {
@@ -651,7 +674,7 @@ describe('useSpotlightState', () => {
 
       // Service calls should succeed
       expect(service.recordUsage).toHaveBeenCalledWith('1');
-      expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true);
+      expect(service.copyAndPaste).toHaveBeenCalledWith('Test content', true, expect.any(String));
     });
 
     it('should work with toast provider on error', async () => {
@@ -686,7 +709,7 @@ describe('useSpotlightState', () => {
       });
 
       // Success path completed
-      expect(service.copyAndPaste).toHaveBeenCalledWith('Test', true);
+      expect(service.copyAndPaste).toHaveBeenCalledWith('Test', true, expect.any(String));
     });
 
     it('should show correct message for auto_paste disabled', async () => {
@@ -704,7 +727,7 @@ describe('useSpotlightState', () => {
       });
 
       // Success path completed with auto_paste false
-      expect(service.copyAndPaste).toHaveBeenCalledWith('Test', false);
+      expect(service.copyAndPaste).toHaveBeenCalledWith('Test', false, expect.any(String));
     });
   });
 });
@@ -50,7 +50,7 @@ export function useSpotlightState(service: PromptService) {
 
         // Copy and paste
         log.debug('Calling copyAndPaste...', { auto_paste: prompt.auto_paste });
-        const result = await service.copyAndPaste(content, prompt.auto_paste);
+        const result = await service.copyAndPaste(content, prompt.auto_paste, prompt.id);
         log.debug('copyAndPaste completed', result);
 
         // Close modal
@@ -61,15 +61,12 @@ export function useSpotlightState(service: PromptService) {
         if (!result.clipboard_success) {
           // Clipboard failed - show error
           toast?.error('Copy failed', result.message);
-        } else if (result.paste_likely_success) {
-          // Everything worked - show success
+        } else if (result.paste_verified || result.reason === 'copied') {
+          // Pasted, or auto-paste disabled and copied - show success
           toast?.success(result.message, `"${prompt.name}" is ready to use`);
-        } else if (result.paste_attempted) {
-          // Paste was tried but uncertain - show info with hint
-          toast?.info(result.message, `"${prompt.name}" - press Ctrl+V if needed`);
         } else {
-          // Auto-paste disabled - show success for copy
-          toast?.success(result.message, `"${prompt.name}" is ready to use`);
+          // Unconfirmed or not pasted - the message says whether Ctrl+V will still work
+          toast?.info(result.message, `"${prompt.name}"`);
         }
       } catch (error) {
         log.error('Error in handlePromptSelection:', error);
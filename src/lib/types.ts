@@ -298,6 +298,22 @@ export interface ValidationError {
 // CLIPBOARD TYPES
 // =============================================================================
 
+/**
+ * Why a copy/paste ended the way it did
+ */
+export type PasteReason =
+  | 'copied'
+  | 'pasted'
+  | 'typed'
+  | 'ran_macro'
+  | 'unverified'
+  | 'focus_changed'
+  | 'target_elevated'
+  | 'no_target_window'
+  | 'input_failed'
+  | 'macro_stopped'
+  | 'clipboard_failed';
+
 /**
  * Result from copy and paste operation
  * Provides detailed feedback about what succeeded/failed
@@ -307,8 +323,10 @@ export interface CopyPasteResult {
   clipboard_success: boolean;
   /** Whether auto-paste was attempted */
   paste_attempted: boolean;
-  /** Whether paste is likely to have succeeded (best guess) */
-  paste_likely_success: boolean;
+  /** Whether the paste reached the target window (it still had focus afterwards) */
+  paste_verified: boolean;
+  /** Why the operation ended the way it did */
+  reason: PasteReason;
   /** User-friendly message describing what happened */
   message: string;
 }
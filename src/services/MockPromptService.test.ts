@@ -166,7 +166,8 @@ describe('MockPromptService', () => {
       const result = await service.copyAndPaste('test text', true);
       expect(result.clipboard_success).toBe(true);
       expect(result.paste_attempted).toBe(true);
-      expect(result.paste_likely_success).toBe(true);
+      expect(result.paste_verified).toBe(true);
+      expect(result.reason).toBe('pasted');
       expect(result.message).toBe('Copied and pasted');
     });
 
@@ -174,7 +175,7 @@ describe('MockPromptService', () => {
       const result = await service.copyAndPaste('text', true);
       expect(result.clipboard_success).toBe(true);
       expect(result.paste_attempted).toBe(true);
-      expect(result.paste_likely_success).toBe(true);
+      expect(result.paste_verified).toBe(true);
       expect(result.message).toBe('Copied and pasted');
     });
 
@@ -182,7 +183,8 @@ describe('MockPromptService', () => {
       const result = await service.copyAndPaste('text', false);
       expect(result.clipboard_success).toBe(true);
       expect(result.paste_attempted).toBe(false);
-      expect(result.paste_likely_success).toBe(false);
+      expect(result.paste_verified).toBe(false);
+      expect(result.reason).toBe('copied');
       expect(result.message).toBe('Copied to clipboard');
     });
 
@@ -383,12 +383,13 @@ export class MockPromptService implements PromptService {
   // CLIPBOARD & WINDOW
   // ---------------------------------------------------------------------------
 
-  async copyAndPaste(text: string, auto_paste: boolean): Promise<CopyPasteResult> {
-    console.log('[Mock] Copy and paste:', { text: text.substring(0, 50), auto_paste });
+  async copyAndPaste(text: string, auto_paste: boolean, promptId?: string): Promise<CopyPasteResult> {
+    console.log('[Mock] Copy and paste:', { text: text.substring(0, 50), auto_paste, promptId });
     return Promise.resolve({
       clipboard_success: true,
       paste_attempted: auto_paste,
-      paste_likely_success: auto_paste,
+      paste_verified: auto_paste,
+      reason: auto_paste ? 'pasted' : 'copied',
       message: auto_paste ? 'Copied and pasted' : 'Copied to clipboard',
     });
   }
@@ -173,9 +173,10 @@ export interface PromptService {
 
   /**
    * Copy prompt and paste into previous app
+   * Pass the prompt's ID so its paste mode, format and macro steps apply
    * Returns detailed result about what succeeded/failed
    */
-  copyAndPaste(text: string, auto_paste: boolean): Promise<CopyPasteResult>;
+  copyAndPaste(text: string, auto_paste: boolean, promptId?: string): Promise<CopyPasteResult>;
 
   /**
    * Hide window and restore focus
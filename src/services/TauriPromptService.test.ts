@@ -167,6 +167,18 @@ describe('TauriPromptService', () => {
       });
     });
 
+    it('should send the prompt id as snake_case prompt_id', async () => {
+      (invoke as any).mockResolvedValue(undefined);
+
+      await service.copyAndPaste('content', true, 'Coding/review');
+
+      expect(invoke).toHaveBeenCalledWith('copy_and_paste', {
+        text: 'content',
+        auto_paste: true,
+        prompt_id: 'Coding/review',
+      });
+    });
+
     it('should propagate clipboard errors', async () => {
       (invoke as any).mockRejectedValue(new Error('Clipboard error'));
 
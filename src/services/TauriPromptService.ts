@@ -116,8 +116,8 @@ export class TauriPromptService implements PromptService {
   // CLIPBOARD & WINDOW
   // ---------------------------------------------------------------------------
 
-  async copyAndPaste(text: string, auto_paste: boolean): Promise<CopyPasteResult> {
-    return invoke<CopyPasteResult>('copy_and_paste', { text, auto_paste: auto_paste });
+  async copyAndPaste(text: string, auto_paste: boolean, promptId?: string): Promise<CopyPasteResult> {
+    return invoke<CopyPasteResult>('copy_and_paste', { text, auto_paste: auto_paste, prompt_id: promptId });
   }
 
   async hideAndRestore(): Promise<void> {